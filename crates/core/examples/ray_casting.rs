@@ -1,5 +1,7 @@
 use common_core_geometry::operations::ray_sphere_intersection;
-use common_core_geometry::{Point3D, Ray, Sphere, Vector3D};
+use common_core_geometry::{
+    Bvh, Camera, Plane, Point3D, Ray, RayHit, SVGRenderer, Sphere, Triangle, Vector3D,
+};
 
 fn main() -> Result<(), Box<dyn std::error::Error>> {
     println!("=== Ray Casting Example ===\n");
@@ -46,17 +48,15 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     for (sphere, name, _color) in &spheres {
         print!("  {}: ", name);
         match ray_sphere_intersection(&ray, sphere) {
-            Some((t1, t2)) => {
-                let hit1 = ray.point_at(t1);
-                let hit2 = ray.point_at(t2);
+            Some(hit) => {
                 println!("HIT!");
                 println!(
-                    "    First intersection at t={:.2} -> ({:.2}, {:.2}, {:.2})",
-                    t1, hit1.x, hit1.y, hit1.z
+                    "    Intersection at t={:.2} -> ({:.2}, {:.2}, {:.2})",
+                    hit.t, hit.point.x, hit.point.y, hit.point.z
                 );
                 println!(
-                    "    Second intersection at t={:.2} -> ({:.2}, {:.2}, {:.2})",
-                    t2, hit2.x, hit2.y, hit2.z
+                    "    Surface normal: ({:.2}, {:.2}, {:.2})",
+                    hit.normal.x, hit.normal.y, hit.normal.z
                 );
             }
             None => {
@@ -67,17 +67,15 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     println!("\n=== Finding closest hit ===\n");
 
-    let mut closest_hit: Option<(f64, &str)> = None;
+    let mut closest_hit: Option<(RayHit, &str)> = None;
 
     for (sphere, name, _color) in &spheres {
-        if let Some((t1, _t2)) = ray_sphere_intersection(&ray, sphere) {
-            if t1 > 0.0 {
-                match closest_hit {
-                    None => closest_hit = Some((t1, name)),
-                    Some((current_t, _)) => {
-                        if t1 < current_t {
-                            closest_hit = Some((t1, name));
-                        }
+        if let Some(hit) = ray_sphere_intersection(&ray, sphere) {
+            match closest_hit {
+                None => closest_hit = Some((hit, name)),
+                Some((current, _)) => {
+                    if hit.t < current.t {
+                        closest_hit = Some((hit, name));
                     }
                 }
             }
@@ -85,12 +83,11 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     }
 
     match closest_hit {
-        Some((t, name)) => {
-            let hit_point = ray.point_at(t);
-            println!("Closest hit: {} at t={:.2}", name, t);
+        Some((hit, name)) => {
+            println!("Closest hit: {} at t={:.2}", name, hit.t);
             println!(
                 "Hit point: ({:.2}, {:.2}, {:.2})",
-                hit_point.x, hit_point.y, hit_point.z
+                hit.point.x, hit.point.y, hit.point.z
             );
         }
         None => {
@@ -98,5 +95,71 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
         }
     }
 
+    println!("\n=== Closest hit through a BVH ===\n");
+
+    let mut triangles = Vec::new();
+    for i in 0..100 {
+        for j in 0..100 {
+            let x = i as f64 * 0.2 - 10.0;
+            let y = j as f64 * 0.2 - 10.0;
+            let z = -3.0 + 0.5 * (x * 0.5).sin() * (y * 0.5).cos();
+            triangles.push(Triangle::new(
+                Point3D::new(x, y, z),
+                Point3D::new(x + 0.2, y, z),
+                Point3D::new(x, y + 0.2, z),
+            )?);
+        }
+    }
+
+    let terrain = Bvh::build(triangles);
+    let probe = Ray::new(Point3D::new(0.05, 0.05, 10.0), ray_direction)?;
+    match terrain.closest_hit(&probe) {
+        Some((index, hit)) => println!(
+            "Hit triangle {} of {} at t={:.2} -> ({:.2}, {:.2}, {:.2})",
+            index,
+            terrain.len(),
+            hit.t,
+            hit.point.x,
+            hit.point.y,
+            hit.point.z
+        ),
+        None => println!("The probe missed the terrain"),
+    }
+
+    println!("\n=== Debug visualization ===\n");
+
+    let camera = Camera::perspective(
+        Point3D::new(14.0, 8.0, 14.0),
+        Point3D::new(0.0, 0.0, 2.0),
+        Vector3D::unit_y(),
+        50.0,
+        4.0 / 3.0,
+        0.1,
+        100.0,
+    )?;
+    let mut renderer = SVGRenderer::new(800, 600, camera);
+    renderer.set_background("#ffffff");
+
+    let backdrop = Plane::from_point_normal(&Point3D::new(0.0, 0.0, -3.0), &Vector3D::unit_z())?;
+    renderer.add_plane(
+        &backdrop,
+        &Point3D::origin(),
+        16.0,
+        "#999999",
+        Some("#eeeeee"),
+        1.0,
+    )?;
+    for (sphere, _name, color) in &spheres {
+        renderer.add_sphere(sphere, color, 1.5);
+    }
+    renderer.add_ray(&ray, "#000000", 1.5);
+    if let Some((hit, _name)) = closest_hit {
+        renderer.add_point(&hit.point, "#ff00ff", 4.0);
+        renderer.add_vector(&hit.point, &(hit.normal * 2.0), "#ff00ff", 1.5)?;
+    }
+
+    renderer.to_file("ray_casting.svg")?;
+    println!("Ray, spheres and closest hit normal saved to 'ray_casting.svg'");
+
     Ok(())
 }
@@ -1,4 +1,5 @@
-use common_core_geometry::{Camera, Point3D, SVGRenderer, Sphere, Triangle, Vector3D, AABB};
+use common_core_geometry::svg::Style;
+use common_core_geometry::{Camera, Plane, Point3D, SVGRenderer, Sphere, Triangle, Vector3D, AABB};
 
 fn main() -> Result<(), Box<dyn std::error::Error>> {
     println!("Generating 3D scene as SVG...\n");
@@ -11,7 +12,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
         16.0 / 9.0,
         0.1,
         100.0,
-    );
+    )?;
 
     let mut renderer = SVGRenderer::new(1920, 1080, camera);
     renderer.set_background("#0a0a0a");
@@ -41,10 +42,6 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     renderer.add_aabb(&floor, "#95a5a6", 1.5);
     println!("  ✓ Added floor (gray)");
 
-    let bounding_box = AABB::new(Point3D::new(-3.0, -1.0, -3.0), Point3D::new(3.0, 2.0, 3.0))?;
-    renderer.add_aabb(&bounding_box, "#3498db", 1.0);
-    println!("  ✓ Added bounding box (blue)");
-
     let back_triangle = Triangle::new(
         Point3D::new(-4.0, 0.0, -8.0),
         Point3D::new(4.0, 0.0, -8.0),
@@ -72,6 +69,34 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     }
     println!("  ✓ Added reference points");
 
+    let ground = Plane::from_point_normal(&Point3D::new(0.0, -2.5, 0.0), &Vector3D::unit_y())?;
+    renderer.add_grid(
+        &ground,
+        &Point3D::origin(),
+        20.0,
+        10,
+        &Style::new("#2c3e50", 0.5),
+    )?;
+    println!("  ✓ Added ground grid");
+
+    renderer.add_axes(&Point3D::origin(), 7.0, &Style::default())?;
+    let tick_style = Style::new("#bdc3c7", 1.0).with_font_size(14.0);
+    renderer.add_ticks(
+        &Point3D::origin(),
+        &Point3D::new(6.0, 0.0, 0.0),
+        1.0,
+        8.0,
+        &tick_style,
+    )?;
+    println!("  ✓ Added axes with ticks");
+
+    if let Some(bounds) = renderer.add_scene_bounds(&Style::new("#3498db", 1.0)) {
+        println!(
+            "  ✓ Added scene bounds (blue) from {:?} to {:?}",
+            bounds.min, bounds.max
+        );
+    }
+
     println!("\nRendering scene to SVG...");
     renderer.to_file("scene.svg")?;
 
@@ -83,7 +108,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     println!("  - SVG size: {} bytes", svg_content.len());
     println!("  - Canvas: 1920x1080 pixels");
     println!("  - Camera: Perspective (60° FOV)");
-    println!("  - Primitives: 8 spheres, 3 AABBs, 2 triangles, 3 points");
+    println!("  - Primitives: 4 spheres, 1 AABB, 2 triangles, 3 points");
 
     Ok(())
 }
@@ -5,11 +5,34 @@
 //!
 //! ## Features
 //!
-//! - **Core Primitives**: Point3D, Vector3D, LineSegment, Ray, Plane, Triangle, Sphere, AABB
+//! - **Core Primitives**: Point3D, Vector3D, LineSegment, Ray, Plane, Triangle, Sphere, AABB, OBB, Capsule
+//! - **Bounding Volumes**: Minimum enclosing spheres (Welzl), Ritter approximations, unions
 //! - **Distance Metrics**: Euclidean, Manhattan, and Chebyshev distances
 //! - **Intersection Testing**: Ray-primitive intersections for ray casting and collision detection
+//! - **Continuous Collision**: Time of impact for moving spheres and boxes via `sweep_*` functions
+//! - **Contact Manifolds**: Contact points, normal and penetration depth for physics solvers
+//! - **Convex Queries**: GJK distance/overlap and EPA penetration for any `SupportMap` shape
+//! - **Convex Hulls**: Quickhull triangle meshes with volume and area, plus planar hulls
+//! - **Spatial Acceleration**: SAH bounding volume hierarchy (`Bvh`) for ray casting and overlap queries
+//! - **Nearest Neighbours**: k-d tree (`KdTree`) with k-nearest, radius and approximate queries under pluggable metrics
+//! - **Dynamic Indices**: loose `Octree` and `UniformGrid` spatial hash with insert/remove/move and box, sphere, ray and frustum queries
+//! - **Broad Phase**: incremental sweep-and-prune (`SweepAndPrune`) with pair added/removed events
 //! - **Transformations**: Translation and scaling operations via the `Transformable` trait
 //! - **SVG Rendering**: Project 3D scenes to 2D SVG with perspective and orthographic cameras
+//! - **Frustum Culling**: `Camera::frustum()` classifies points, spheres, boxes and triangles; the renderer skips invisible elements
+//! - **Depth Sorting**: Painter's algorithm or BSP-tree ordering of rendered triangles
+//! - **Hidden-Line Removal**: Dashed or removed hidden edges behind opaque triangles, boxes and spheres
+//! - **Lighting**: Ambient, directional and point lights with flat and Gouraud shading and back-face culling
+//! - **Sphere Silhouettes**: Exact projected sphere outlines drawn as rotated ellipses
+//! - **Styling**: `Style` with dashes, opacity, caps, joins, markers, classes, ids, tooltips and an embedded stylesheet
+//! - **Layers**: Named `<g>` layers with visibility, default style and transform, rendered all together or in subsets
+//! - **Annotations**: Labels, leader lines, linear and angular dimensions, and legends
+//! - **Scene Helpers**: Axis triads, ground grids, tick marks and scene bounding boxes
+//! - **Rays, Planes and Vectors**: View-clipped rays, vector arrows and square plane patches
+//! - **Clipping**: Homogeneous near-plane (and optional frustum) clipping of rendered segments and triangles
+//! - **Picking**: `Camera::screen_to_ray` and `Camera::unproject` map screen coordinates back to world-space rays and points
+//! - **Camera Controls**: Validated constructors plus `orbit`, `pan`, `dolly`, `zoom`, `look_at` and `fit_to_aabb`/`fit_to_sphere`
+//! - **Projection Modes**: Axonometric presets, oblique, off-axis, fisheye and equirectangular cameras
 //!
 //! ## Quick Start
 //!
@@ -25,8 +48,8 @@
 //!
 //! // Test ray-sphere intersection
 //! let sphere = Sphere::new(Point3D::new(1.0, 2.0, 0.0), 1.0)?;
-//! if let Some((t1, t2)) = ray_sphere_intersection(&ray, &sphere) {
-//!     println!("Ray hits sphere at distances: {} and {}", t1, t2);
+//! if let Some(hit) = ray_sphere_intersection(&ray, &sphere) {
+//!     println!("Ray hits sphere at distance {} with normal {:?}", hit.t, hit.normal);
 //! }
 //!
 //! // Create and test axis-aligned bounding box
@@ -98,7 +121,7 @@
 //!     800.0 / 600.0,                     // aspect ratio
 //!     0.1,                               // near plane
 //!     100.0,                             // far plane
-//! );
+//! )?;
 //!
 //! // Create SVG renderer
 //! let mut renderer = SVGRenderer::new(800, 600, camera);
@@ -119,6 +142,7 @@
 //!
 //! - [`primitives`] - Core geometric shapes and structures
 //! - [`operations`] - Geometric operations (distance, intersection, transformation)
+//! - [`spatial`] - Acceleration structures for ray casting and proximity queries
 //! - [`svg`] - SVG rendering with camera projection
 //! - [`error`] - Error types and result aliases
 //! - [`utils`] - Utility functions and constants
@@ -126,10 +150,15 @@
 pub mod error;
 pub mod operations;
 pub mod primitives;
+pub mod spatial;
 pub mod svg;
 pub mod utils;
 
 pub use error::{GeometryError, Result};
-pub use operations::{chebyshev_distance, manhattan_distance, Transformable};
-pub use primitives::{LineSegment, Plane, Point3D, Ray, Sphere, Triangle, Vector3D, AABB};
+pub use operations::{chebyshev_distance, manhattan_distance, RayHit, SupportMap, Transformable};
+pub use primitives::{
+    Capsule, Containment, Frustum, LineSegment, Plane, Point3D, Ray, Sphere, Triangle, Vector3D,
+    AABB, OBB,
+};
+pub use spatial::{Bounded, BspTree, Bvh, KdTree, Octree, RayCast, SweepAndPrune, UniformGrid};
 pub use svg::{Camera, SVGRenderer};
@@ -0,0 +1,685 @@
+use crate::primitives::{
+    closest_points_between, Capsule, Point3D, Sphere, Triangle, Vector3D, AABB, OBB,
+};
+use crate::utils::{approx_zero, EPSILON};
+
+/// One point of a contact manifold. `depth` is the penetration along the
+/// manifold normal; touching shapes report a depth of zero.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Contact {
+    pub point: Point3D,
+    pub depth: f64,
+}
+
+/// Contact data between shape `A` and shape `B`, the first and second
+/// argument of the `contact_*` function that produced it.
+///
+/// `normal` is a unit vector pointing from A towards B: translating B by
+/// `normal * depth` resolves that contact. Contact points lie halfway between
+/// the two surfaces. Box contacts carry at most four points.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Manifold {
+    pub normal: Vector3D,
+    pub contacts: Vec<Contact>,
+}
+
+impl Manifold {
+    fn single(normal: Vector3D, point: Point3D, depth: f64) -> Self {
+        Self {
+            normal,
+            contacts: vec![Contact { point, depth }],
+        }
+    }
+
+    pub fn max_depth(&self) -> f64 {
+        self.contacts.iter().map(|c| c.depth).fold(0.0, f64::max)
+    }
+
+    /// The same manifold seen from the other shape (A and B swapped).
+    pub fn flipped(&self) -> Manifold {
+        Manifold {
+            normal: -self.normal,
+            contacts: self.contacts.clone(),
+        }
+    }
+}
+
+pub fn contact_sphere_sphere(a: &Sphere, b: &Sphere) -> Option<Manifold> {
+    round_contact(&a.center, a.radius, &b.center, b.radius, Vector3D::unit_x())
+}
+
+pub fn contact_sphere_aabb(sphere: &Sphere, aabb: &AABB) -> Option<Manifold> {
+    let center = sphere.center;
+    if !aabb.contains_point(&center) {
+        let closest = aabb.closest_point(&center);
+        return round_contact(&center, sphere.radius, &closest, 0.0, Vector3D::unit_x());
+    }
+
+    // Centre inside the box: push out through the nearest face.
+    let c = coords(&center);
+    let min = coords(&aabb.min);
+    let max = coords(&aabb.max);
+    let mut face_distance = f64::INFINITY;
+    let mut face_normal = Vector3D::zero();
+    for axis in 0..3 {
+        for (distance, sign) in [(c[axis] - min[axis], -1.0), (max[axis] - c[axis], 1.0)] {
+            if distance < face_distance {
+                face_distance = distance;
+                face_normal = unit_axis(axis) * sign;
+            }
+        }
+    }
+
+    let normal = -face_normal;
+    let depth = sphere.radius + face_distance;
+    let point = center + normal * (sphere.radius - depth / 2.0);
+    Some(Manifold::single(normal, point, depth))
+}
+
+pub fn contact_sphere_triangle(sphere: &Sphere, triangle: &Triangle) -> Option<Manifold> {
+    let closest = triangle.closest_point(&sphere.center);
+    round_contact(
+        &sphere.center,
+        sphere.radius,
+        &closest,
+        0.0,
+        -triangle.normal(),
+    )
+}
+
+pub fn contact_aabb_aabb(a: &AABB, b: &AABB) -> Option<Manifold> {
+    if !a.intersects(b) {
+        return None;
+    }
+
+    let (a_min, a_max) = (coords(&a.min), coords(&a.max));
+    let (b_min, b_max) = (coords(&b.min), coords(&b.max));
+    let lo: [f64; 3] = std::array::from_fn(|i| a_min[i].max(b_min[i]));
+    let hi: [f64; 3] = std::array::from_fn(|i| a_max[i].min(b_max[i]));
+
+    let mut axis = 0;
+    for i in 1..3 {
+        if hi[i] - lo[i] < hi[axis] - lo[axis] {
+            axis = i;
+        }
+    }
+
+    let a_center = coords(&a.center());
+    let b_center = coords(&b.center());
+    let sign = if b_center[axis] >= a_center[axis] {
+        1.0
+    } else {
+        -1.0
+    };
+    let normal = unit_axis(axis) * sign;
+    let depth = hi[axis] - lo[axis];
+
+    let (u, v) = ((axis + 1) % 3, (axis + 2) % 3);
+    let mut contacts: Vec<Contact> = Vec::with_capacity(4);
+    for cu in [lo[u], hi[u]] {
+        for cv in [lo[v], hi[v]] {
+            let mut p = [0.0; 3];
+            p[axis] = (lo[axis] + hi[axis]) / 2.0;
+            p[u] = cu;
+            p[v] = cv;
+            let point = Point3D::new(p[0], p[1], p[2]);
+            if !contacts.iter().any(|c| c.point == point) {
+                contacts.push(Contact { point, depth });
+            }
+        }
+    }
+
+    Some(Manifold { normal, contacts })
+}
+
+pub fn contact_capsule_sphere(capsule: &Capsule, sphere: &Sphere) -> Option<Manifold> {
+    let on_axis = capsule.closest_point_on_axis(&sphere.center);
+    round_contact(
+        &on_axis,
+        capsule.radius,
+        &sphere.center,
+        sphere.radius,
+        perpendicular(&capsule.axis()),
+    )
+}
+
+pub fn contact_capsule_capsule(a: &Capsule, b: &Capsule) -> Option<Manifold> {
+    let (on_a, on_b) = a.closest_points_between_axes(b);
+    let mut manifold = round_contact(&on_a, a.radius, &on_b, b.radius, perpendicular(&a.axis()))?;
+
+    // Parallel, overlapping axes touch along a line: report both ends of the
+    // shared span so a solver can resist rotation.
+    let axis_a = a.axis();
+    let axis_b = b.axis();
+    let len_sq = axis_a.magnitude_squared();
+    if approx_zero(len_sq) || approx_zero(axis_b.magnitude_squared()) {
+        return Some(manifold);
+    }
+    if axis_a.cross(&axis_b).magnitude() > 1e-6 * len_sq.sqrt() * axis_b.magnitude() {
+        return Some(manifold);
+    }
+
+    let s0 = (b.start - a.start).dot(&axis_a) / len_sq;
+    let s1 = (b.end - a.start).dot(&axis_a) / len_sq;
+    let lo = s0.min(s1).max(0.0);
+    let hi = s0.max(s1).min(1.0);
+    if (hi - lo) * len_sq.sqrt() > EPSILON {
+        let normal = manifold.normal;
+        manifold.contacts = [lo, hi]
+            .iter()
+            .map(|&s| {
+                let p = a.start + axis_a * s;
+                let q = b.closest_point_on_axis(&p);
+                let depth = a.radius + b.radius - p.distance_to(&q);
+                Contact {
+                    point: p + normal * (a.radius - depth / 2.0),
+                    depth,
+                }
+            })
+            .collect();
+    }
+
+    Some(manifold)
+}
+
+#[derive(Debug, Clone, Copy)]
+enum SeparatingAxis {
+    FaceA(usize),
+    FaceB(usize),
+    Edges(usize, usize),
+}
+
+/// Box-box manifold via the separating axis test over the 15 candidate axes.
+/// Face contacts clip the incident face against the reference face; edge
+/// contacts report the closest points of the two crossing edges.
+pub fn contact_obb_obb(a: &OBB, b: &OBB) -> Option<Manifold> {
+    let offset = b.center - a.center;
+
+    let mut candidates = Vec::with_capacity(15);
+    for i in 0..3 {
+        candidates.push((a.axes[i], SeparatingAxis::FaceA(i)));
+    }
+    for j in 0..3 {
+        candidates.push((b.axes[j], SeparatingAxis::FaceB(j)));
+    }
+    for i in 0..3 {
+        for j in 0..3 {
+            let axis = a.axes[i].cross(&b.axes[j]);
+            let length = axis.magnitude();
+            // Near-parallel edges are already covered by the face axes.
+            if length > 1e-6 {
+                candidates.push((axis / length, SeparatingAxis::Edges(i, j)));
+            }
+        }
+    }
+
+    let mut best: Option<(f64, Vector3D, SeparatingAxis)> = None;
+    for (axis, kind) in candidates {
+        let distance = offset.dot(&axis);
+        let overlap = a.projected_radius(&axis) + b.projected_radius(&axis) - distance.abs();
+        if overlap < 0.0 {
+            return None;
+        }
+
+        let normal = if distance < 0.0 { -axis } else { axis };
+        let better = match (best, kind) {
+            (None, _) => true,
+            // Prefer face contacts; edge axes must be clearly shallower.
+            (Some((depth, _, _)), SeparatingAxis::Edges(..)) => overlap * 1.05 + 1e-6 < depth,
+            (Some((depth, _, _)), _) => overlap < depth,
+        };
+        if better {
+            best = Some((overlap, normal, kind));
+        }
+    }
+
+    let (depth, normal, kind) = best?;
+    match kind {
+        SeparatingAxis::FaceA(i) => Some(Manifold {
+            normal,
+            contacts: face_contacts(a, i, &normal, b, depth),
+        }),
+        SeparatingAxis::FaceB(j) => Some(Manifold {
+            normal,
+            contacts: face_contacts(b, j, &-normal, a, depth),
+        }),
+        SeparatingAxis::Edges(i, j) => {
+            let (a0, a1) = support_edge(a, i, &normal);
+            let (b0, b1) = support_edge(b, j, &-normal);
+            let (p, q) = closest_points_between(&a0, &a1, &b0, &b1);
+            Some(Manifold::single(normal, p.midpoint(&q), depth))
+        }
+    }
+}
+
+/// Contact between two rounded points (sphere centres, capsule axis points)
+/// separated by `ra + rb`. `fallback` is used as the normal when the centres
+/// coincide.
+fn round_contact(
+    a: &Point3D,
+    ra: f64,
+    b: &Point3D,
+    rb: f64,
+    fallback: Vector3D,
+) -> Option<Manifold> {
+    let offset = *b - *a;
+    let distance = offset.magnitude();
+    let depth = ra + rb - distance;
+    if depth < 0.0 {
+        return None;
+    }
+
+    let normal = if approx_zero(distance) {
+        fallback
+    } else {
+        offset / distance
+    };
+    let point = *a + normal * (ra - depth / 2.0);
+    Some(Manifold::single(normal, point, depth))
+}
+
+fn face_contacts(
+    reference: &OBB,
+    axis: usize,
+    ref_normal: &Vector3D,
+    incident: &OBB,
+    depth: f64,
+) -> Vec<Contact> {
+    let face_center = reference.center + *ref_normal * reference.half_extents[axis];
+
+    let mut polygon = incident_face(incident, ref_normal).to_vec();
+    for side in [(axis + 1) % 3, (axis + 2) % 3] {
+        let extent = reference.half_extents[side];
+        for direction in [reference.axes[side], -reference.axes[side]] {
+            polygon = clip_polygon(&polygon, &reference.center, &direction, extent);
+        }
+    }
+
+    let contacts: Vec<Contact> = polygon
+        .iter()
+        .filter_map(|vertex| {
+            let separation = ref_normal.dot(&(*vertex - face_center));
+            (separation <= EPSILON).then(|| Contact {
+                point: *vertex + *ref_normal * (-separation / 2.0),
+                depth: (-separation).max(0.0),
+            })
+        })
+        .collect();
+
+    if contacts.is_empty() {
+        // Numerical corner case: fall back to the deepest incident corner.
+        let deepest = incident
+            .corners()
+            .into_iter()
+            .min_by(|p, q| {
+                ref_normal
+                    .dot(&(*p - face_center))
+                    .total_cmp(&ref_normal.dot(&(*q - face_center)))
+            })
+            .unwrap_or(incident.center);
+        return vec![Contact {
+            point: deepest + *ref_normal * (depth / 2.0),
+            depth,
+        }];
+    }
+
+    reduce_contacts(contacts)
+}
+
+/// The face of `incident` most anti-parallel to `ref_normal`, as a quad.
+fn incident_face(incident: &OBB, ref_normal: &Vector3D) -> [Point3D; 4] {
+    let (axis, alignment) = (0..3)
+        .map(|k| (k, incident.axes[k].dot(ref_normal)))
+        .max_by(|x, y| x.1.abs().total_cmp(&y.1.abs()))
+        .unwrap_or((0, 0.0));
+    let sign = if alignment > 0.0 { -1.0 } else { 1.0 };
+    let center = incident.center + incident.axes[axis] * (sign * incident.half_extents[axis]);
+
+    let (u, v) = ((axis + 1) % 3, (axis + 2) % 3);
+    let eu = incident.axes[u] * incident.half_extents[u];
+    let ev = incident.axes[v] * incident.half_extents[v];
+    [
+        center + eu + ev,
+        center + -eu + ev,
+        center + -eu + -ev,
+        center + eu + -ev,
+    ]
+}
+
+/// Sutherland-Hodgman step keeping the part of `polygon` where
+/// `direction · (p - origin) <= extent`.
+fn clip_polygon(
+    polygon: &[Point3D],
+    origin: &Point3D,
+    direction: &Vector3D,
+    extent: f64,
+) -> Vec<Point3D> {
+    let mut clipped = Vec::with_capacity(polygon.len() + 1);
+    for (i, current) in polygon.iter().enumerate() {
+        let next = polygon[(i + 1) % polygon.len()];
+        let dc = direction.dot(&(*current - *origin)) - extent;
+        let dn = direction.dot(&(next - *origin)) - extent;
+        if dc <= 0.0 {
+            clipped.push(*current);
+        }
+        if (dc <= 0.0) != (dn <= 0.0) {
+            clipped.push(*current + (next - *current) * (dc / (dc - dn)));
+        }
+    }
+    clipped
+}
+
+/// Keeps the deepest contact plus the three that spread the patch the most.
+fn reduce_contacts(mut contacts: Vec<Contact>) -> Vec<Contact> {
+    if contacts.len() <= 4 {
+        return contacts;
+    }
+
+    let deepest = contacts
+        .iter()
+        .enumerate()
+        .max_by(|x, y| x.1.depth.total_cmp(&y.1.depth))
+        .map(|(i, _)| i)
+        .unwrap_or(0);
+    let mut kept = vec![contacts.swap_remove(deepest)];
+
+    while kept.len() < 4 {
+        let farthest = contacts
+            .iter()
+            .enumerate()
+            .map(|(i, c)| {
+                let spread = kept
+                    .iter()
+                    .map(|k| k.point.distance_squared_to(&c.point))
+                    .fold(f64::INFINITY, f64::min);
+                (i, spread)
+            })
+            .max_by(|x, y| x.1.total_cmp(&y.1))
+            .map(|(i, _)| i)
+            .unwrap_or(0);
+        kept.push(contacts.swap_remove(farthest));
+    }
+
+    kept
+}
+
+/// Endpoints of the edge of `obb` parallel to `axis` that lies furthest along
+/// `direction`. The endpoints coincide when the box is flat along `axis`.
+fn support_edge(obb: &OBB, axis: usize, direction: &Vector3D) -> (Point3D, Point3D) {
+    let mut center = obb.center;
+    for k in 0..3 {
+        if k != axis {
+            let sign = if obb.axes[k].dot(direction) >= 0.0 {
+                1.0
+            } else {
+                -1.0
+            };
+            center = center + obb.axes[k] * (sign * obb.half_extents[k]);
+        }
+    }
+    let half = obb.axes[axis] * obb.half_extents[axis];
+    (center + -half, center + half)
+}
+
+fn perpendicular(v: &Vector3D) -> Vector3D {
+    let helper = if v.x.abs() < 0.9 * v.magnitude() {
+        Vector3D::unit_x()
+    } else {
+        Vector3D::unit_y()
+    };
+    v.cross(&helper).normalize().unwrap_or(Vector3D::unit_x())
+}
+
+#[inline]
+fn coords(p: &Point3D) -> [f64; 3] {
+    [p.x, p.y, p.z]
+}
+
+#[inline]
+fn unit_axis(axis: usize) -> Vector3D {
+    match axis {
+        0 => Vector3D::unit_x(),
+        1 => Vector3D::unit_y(),
+        _ => Vector3D::unit_z(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::f64::consts::FRAC_1_SQRT_2;
+
+    fn assert_close(a: f64, b: f64) {
+        assert!((a - b).abs() < 1e-9, "{} != {}", a, b);
+    }
+
+    fn unit_box() -> AABB {
+        AABB::new(Point3D::new(-1.0, -1.0, -1.0), Point3D::new(1.0, 1.0, 1.0)).unwrap()
+    }
+
+    #[test]
+    fn test_sphere_sphere_contact() {
+        let a = Sphere::new(Point3D::origin(), 1.0).unwrap();
+        let b = Sphere::new(Point3D::new(1.5, 0.0, 0.0), 1.0).unwrap();
+        let manifold = contact_sphere_sphere(&a, &b).unwrap();
+        assert_eq!(manifold.normal, Vector3D::unit_x());
+        assert_eq!(manifold.contacts.len(), 1);
+        assert_close(manifold.contacts[0].depth, 0.5);
+        assert_eq!(manifold.contacts[0].point, Point3D::new(0.75, 0.0, 0.0));
+        assert_eq!(manifold.flipped().normal, -Vector3D::unit_x());
+    }
+
+    #[test]
+    fn test_sphere_sphere_separated_and_touching() {
+        let a = Sphere::new(Point3D::origin(), 1.0).unwrap();
+        let far = Sphere::new(Point3D::new(3.0, 0.0, 0.0), 1.0).unwrap();
+        assert!(contact_sphere_sphere(&a, &far).is_none());
+
+        let touching = Sphere::new(Point3D::new(2.0, 0.0, 0.0), 1.0).unwrap();
+        let manifold = contact_sphere_sphere(&a, &touching).unwrap();
+        assert_close(manifold.max_depth(), 0.0);
+    }
+
+    #[test]
+    fn test_sphere_aabb_outside_center() {
+        let sphere = Sphere::new(Point3D::new(0.0, 0.0, 1.5), 1.0).unwrap();
+        let manifold = contact_sphere_aabb(&sphere, &unit_box()).unwrap();
+        assert_eq!(manifold.normal, Vector3D::new(0.0, 0.0, -1.0));
+        assert_close(manifold.contacts[0].depth, 0.5);
+        assert_eq!(manifold.contacts[0].point, Point3D::new(0.0, 0.0, 0.75));
+    }
+
+    #[test]
+    fn test_sphere_aabb_center_inside() {
+        let sphere = Sphere::new(Point3D::new(0.0, 0.0, 0.8), 0.5).unwrap();
+        let manifold = contact_sphere_aabb(&sphere, &unit_box()).unwrap();
+        assert_eq!(manifold.normal, Vector3D::new(0.0, 0.0, -1.0));
+        assert_close(manifold.contacts[0].depth, 0.7);
+    }
+
+    #[test]
+    fn test_sphere_triangle_contact() {
+        let triangle = Triangle::new(
+            Point3D::new(-1.0, -1.0, 0.0),
+            Point3D::new(2.0, -1.0, 0.0),
+            Point3D::new(-1.0, 2.0, 0.0),
+        )
+        .unwrap();
+        let sphere = Sphere::new(Point3D::new(0.0, 0.0, 0.5), 1.0).unwrap();
+        let manifold = contact_sphere_triangle(&sphere, &triangle).unwrap();
+        assert_eq!(manifold.normal, Vector3D::new(0.0, 0.0, -1.0));
+        assert_close(manifold.contacts[0].depth, 0.5);
+
+        let away = Sphere::new(Point3D::new(0.0, 0.0, 2.0), 1.0).unwrap();
+        assert!(contact_sphere_triangle(&away, &triangle).is_none());
+    }
+
+    #[test]
+    fn test_aabb_aabb_contact() {
+        let a = AABB::new(Point3D::origin(), Point3D::new(2.0, 2.0, 2.0)).unwrap();
+        let b = AABB::new(Point3D::new(1.5, 0.5, 0.5), Point3D::new(3.0, 1.5, 1.5)).unwrap();
+        let manifold = contact_aabb_aabb(&a, &b).unwrap();
+        assert_eq!(manifold.normal, Vector3D::unit_x());
+        assert_eq!(manifold.contacts.len(), 4);
+        for contact in &manifold.contacts {
+            assert_close(contact.depth, 0.5);
+            assert_close(contact.point.x, 1.75);
+        }
+
+        let reversed = contact_aabb_aabb(&b, &a).unwrap();
+        assert_eq!(reversed.normal, -Vector3D::unit_x());
+
+        let apart = AABB::new(Point3D::new(5.0, 5.0, 5.0), Point3D::new(6.0, 6.0, 6.0)).unwrap();
+        assert!(contact_aabb_aabb(&a, &apart).is_none());
+    }
+
+    #[test]
+    fn test_capsule_capsule_crossing() {
+        let a = Capsule::new(
+            Point3D::new(-2.0, 0.0, 0.0),
+            Point3D::new(2.0, 0.0, 0.0),
+            1.0,
+        )
+        .unwrap();
+        let b = Capsule::new(
+            Point3D::new(0.0, -2.0, 1.5),
+            Point3D::new(0.0, 2.0, 1.5),
+            1.0,
+        )
+        .unwrap();
+        let manifold = contact_capsule_capsule(&a, &b).unwrap();
+        assert_eq!(manifold.normal, Vector3D::unit_z());
+        assert_eq!(manifold.contacts.len(), 1);
+        assert_close(manifold.contacts[0].depth, 0.5);
+        assert_eq!(manifold.contacts[0].point, Point3D::new(0.0, 0.0, 0.75));
+    }
+
+    #[test]
+    fn test_capsule_capsule_parallel() {
+        let a = Capsule::new(Point3D::origin(), Point3D::new(4.0, 0.0, 0.0), 0.75).unwrap();
+        let b = Capsule::new(
+            Point3D::new(2.0, 0.0, 1.0),
+            Point3D::new(6.0, 0.0, 1.0),
+            0.75,
+        )
+        .unwrap();
+        let manifold = contact_capsule_capsule(&a, &b).unwrap();
+        assert_eq!(manifold.normal, Vector3D::unit_z());
+        assert_eq!(manifold.contacts.len(), 2);
+        assert_close(manifold.contacts[0].point.x, 2.0);
+        assert_close(manifold.contacts[1].point.x, 4.0);
+        for contact in &manifold.contacts {
+            assert_close(contact.depth, 0.5);
+        }
+    }
+
+    #[test]
+    fn test_capsule_sphere_contact() {
+        let capsule = Capsule::new(Point3D::origin(), Point3D::new(0.0, 4.0, 0.0), 1.0).unwrap();
+        let sphere = Sphere::new(Point3D::new(1.5, 2.0, 0.0), 1.0).unwrap();
+        let manifold = contact_capsule_sphere(&capsule, &sphere).unwrap();
+        assert_eq!(manifold.normal, Vector3D::unit_x());
+        assert_close(manifold.contacts[0].depth, 0.5);
+    }
+
+    #[test]
+    fn test_obb_obb_face_contact() {
+        let a = OBB::from_aabb(&unit_box());
+        let b = OBB::new(
+            Point3D::new(0.0, 0.0, 1.9),
+            [
+                Vector3D::new(FRAC_1_SQRT_2, FRAC_1_SQRT_2, 0.0),
+                Vector3D::new(-FRAC_1_SQRT_2, FRAC_1_SQRT_2, 0.0),
+                Vector3D::unit_z(),
+            ],
+            [1.0, 1.0, 1.0],
+        )
+        .unwrap();
+
+        let manifold = contact_obb_obb(&a, &b).unwrap();
+        assert_eq!(manifold.normal, Vector3D::unit_z());
+        assert_eq!(manifold.contacts.len(), 4);
+        for contact in &manifold.contacts {
+            assert_close(contact.depth, 0.1);
+            assert_close(contact.point.z, 0.95);
+        }
+
+        let reversed = contact_obb_obb(&b, &a).unwrap();
+        assert_eq!(reversed.normal, -Vector3D::unit_z());
+        assert_eq!(reversed.contacts.len(), 4);
+    }
+
+    #[test]
+    fn test_obb_obb_edge_contact() {
+        let a = OBB::new(
+            Point3D::origin(),
+            [
+                Vector3D::new(FRAC_1_SQRT_2, 0.0, -FRAC_1_SQRT_2),
+                Vector3D::unit_y(),
+                Vector3D::new(FRAC_1_SQRT_2, 0.0, FRAC_1_SQRT_2),
+            ],
+            [1.0, 1.0, 1.0],
+        )
+        .unwrap();
+        let height = 2.0 * 2.0_f64.sqrt() - 0.1;
+        let b = OBB::new(
+            Point3D::new(0.0, 0.0, height),
+            [
+                Vector3D::unit_x(),
+                Vector3D::new(0.0, FRAC_1_SQRT_2, FRAC_1_SQRT_2),
+                Vector3D::new(0.0, -FRAC_1_SQRT_2, FRAC_1_SQRT_2),
+            ],
+            [1.0, 1.0, 1.0],
+        )
+        .unwrap();
+
+        let manifold = contact_obb_obb(&a, &b).unwrap();
+        assert_eq!(manifold.normal, Vector3D::unit_z());
+        assert_eq!(manifold.contacts.len(), 1);
+        assert_close(manifold.contacts[0].depth, 0.1);
+        assert_eq!(
+            manifold.contacts[0].point,
+            Point3D::new(0.0, 0.0, 2.0_f64.sqrt() - 0.05)
+        );
+    }
+
+    #[test]
+    fn test_obb_obb_flat_box_edge_contact() {
+        // Rz(yaw) * Rx(pitch)
+        let rotation = |yaw: f64, pitch: f64| {
+            let (cy, sy, cp, sp) = (yaw.cos(), yaw.sin(), pitch.cos(), pitch.sin());
+            [
+                Vector3D::new(cy, sy, 0.0),
+                Vector3D::new(-sy * cp, cy * cp, sp),
+                Vector3D::new(sy * sp, -cy * sp, cp),
+            ]
+        };
+        // A square with no thickness: its edges along the second axis have
+        // zero length, and one of them is the deepest edge here.
+        let square =
+            AABB::from_points(&[Point3D::new(-1.0, 0.0, -1.0), Point3D::new(1.0, 0.0, 1.0)])
+                .unwrap();
+        let mut a = OBB::from_aabb(&square);
+        a.axes = rotation(1.6, 2.4);
+        let b = OBB::new(Point3D::new(0.5, 2.0, 0.0), rotation(0.0, 1.2), [1.0; 3]).unwrap();
+
+        let manifold = contact_obb_obb(&a, &b).unwrap();
+        assert_eq!(manifold.contacts.len(), 1);
+        let contact = manifold.contacts[0];
+        assert!(contact.depth > 0.0 && contact.depth.is_finite());
+        assert!(a.distance_to_point(&contact.point) <= contact.depth);
+        assert!(b.distance_to_point(&contact.point) <= contact.depth);
+    }
+
+    #[test]
+    fn test_obb_obb_separated() {
+        let a = OBB::from_aabb(&unit_box());
+        let b = OBB::new(
+            Point3D::new(3.0, 0.0, 0.0),
+            [Vector3D::unit_x(), Vector3D::unit_y(), Vector3D::unit_z()],
+            [1.0, 1.0, 1.0],
+        )
+        .unwrap();
+        assert!(contact_obb_obb(&a, &b).is_none());
+    }
+}
@@ -0,0 +1,627 @@
+use super::contact::{Contact, Manifold};
+use super::support::SupportMap;
+use crate::primitives::{Point3D, Vector3D};
+use crate::utils::{approx_zero, EPSILON};
+
+const GJK_MAX_ITERATIONS: usize = 64;
+const EPA_MAX_ITERATIONS: usize = 128;
+const GJK_TOLERANCE: f64 = 1e-10;
+const EPA_TOLERANCE: f64 = 1e-8;
+const OVERLAP_TOLERANCE: f64 = 1e-12;
+
+/// Closest points between two separated convex shapes.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ClosestPoints {
+    pub distance: f64,
+    pub point_a: Point3D,
+    pub point_b: Point3D,
+}
+
+/// Penetration of two overlapping convex shapes. `normal` points from A
+/// towards B: translating B by `normal * depth` separates the pair. `point_a`
+/// and `point_b` are the deepest points of each shape inside the other.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Penetration {
+    pub depth: f64,
+    pub normal: Vector3D,
+    pub point_a: Point3D,
+    pub point_b: Point3D,
+}
+
+impl Penetration {
+    pub fn to_manifold(&self) -> Manifold {
+        Manifold {
+            normal: self.normal,
+            contacts: vec![Contact {
+                point: self.point_a.midpoint(&self.point_b),
+                depth: self.depth,
+            }],
+        }
+    }
+}
+
+/// A vertex of the Minkowski difference `A - B`, remembering the support
+/// points of each shape that produced it.
+#[derive(Debug, Clone, Copy)]
+struct SimplexVertex {
+    w: Vector3D,
+    a: Point3D,
+    b: Point3D,
+}
+
+enum Gjk {
+    Separated(ClosestPoints),
+    Overlapping(Vec<SimplexVertex>),
+}
+
+pub fn gjk_intersects<A, B>(a: &A, b: &B) -> bool
+where
+    A: SupportMap + ?Sized,
+    B: SupportMap + ?Sized,
+{
+    matches!(gjk(a, b), Gjk::Overlapping(_))
+}
+
+/// Distance and closest points between two convex shapes, or `None` if they
+/// overlap (or touch).
+pub fn gjk_distance<A, B>(a: &A, b: &B) -> Option<ClosestPoints>
+where
+    A: SupportMap + ?Sized,
+    B: SupportMap + ?Sized,
+{
+    match gjk(a, b) {
+        Gjk::Separated(closest) => Some(closest),
+        Gjk::Overlapping(_) => None,
+    }
+}
+
+/// Penetration depth of two overlapping convex shapes via the expanding
+/// polytope algorithm, or `None` if they are separated.
+pub fn epa_penetration<A, B>(a: &A, b: &B) -> Option<Penetration>
+where
+    A: SupportMap + ?Sized,
+    B: SupportMap + ?Sized,
+{
+    match gjk(a, b) {
+        Gjk::Separated(_) => None,
+        Gjk::Overlapping(simplex) => Some(epa(a, b, simplex)),
+    }
+}
+
+fn support_vertex<A, B>(a: &A, b: &B, direction: &Vector3D) -> SimplexVertex
+where
+    A: SupportMap + ?Sized,
+    B: SupportMap + ?Sized,
+{
+    let pa = a.support(direction);
+    let pb = b.support(&-*direction);
+    SimplexVertex {
+        w: pa - pb,
+        a: pa,
+        b: pb,
+    }
+}
+
+fn gjk<A, B>(a: &A, b: &B) -> Gjk
+where
+    A: SupportMap + ?Sized,
+    B: SupportMap + ?Sized,
+{
+    let first = support_vertex(a, b, &Vector3D::unit_x());
+    let mut simplex = vec![(first, 1.0)];
+    let mut v = first.w;
+
+    for _ in 0..GJK_MAX_ITERATIONS {
+        let v_sq = v.magnitude_squared();
+        if v_sq <= OVERLAP_TOLERANCE {
+            return Gjk::Overlapping(simplex.iter().map(|(s, _)| *s).collect());
+        }
+
+        let w = support_vertex(a, b, &-v);
+        if v_sq - v.dot(&w.w) <= GJK_TOLERANCE * v_sq.max(1.0) {
+            break;
+        }
+        if simplex.iter().any(|(s, _)| s.w == w.w) {
+            break;
+        }
+
+        let mut vertices: Vec<SimplexVertex> = simplex.iter().map(|(s, _)| *s).collect();
+        vertices.push(w);
+        match closest_on_simplex(&vertices) {
+            Some(reduced) => {
+                simplex = reduced;
+                v = simplex
+                    .iter()
+                    .fold(Vector3D::zero(), |acc, (s, weight)| acc + s.w * *weight);
+            }
+            None => return Gjk::Overlapping(vertices),
+        }
+    }
+
+    if v.magnitude_squared() <= OVERLAP_TOLERANCE {
+        return Gjk::Overlapping(simplex.iter().map(|(s, _)| *s).collect());
+    }
+
+    let origin = Point3D::origin();
+    let point_a = simplex
+        .iter()
+        .fold(origin, |acc, (s, weight)| acc + (s.a - origin) * *weight);
+    let point_b = simplex
+        .iter()
+        .fold(origin, |acc, (s, weight)| acc + (s.b - origin) * *weight);
+    Gjk::Separated(ClosestPoints {
+        distance: v.magnitude(),
+        point_a,
+        point_b,
+    })
+}
+
+/// Reduces `simplex` to the sub-simplex closest to the origin, with the
+/// barycentric weight of each kept vertex. `None` means the tetrahedron
+/// contains the origin.
+fn closest_on_simplex(simplex: &[SimplexVertex]) -> Option<Vec<(SimplexVertex, f64)>> {
+    match simplex.len() {
+        1 => Some(vec![(simplex[0], 1.0)]),
+        2 => Some(closest_on_segment(simplex[0], simplex[1])),
+        3 => Some(closest_on_triangle(simplex[0], simplex[1], simplex[2])),
+        _ => closest_on_tetrahedron(simplex[0], simplex[1], simplex[2], simplex[3]),
+    }
+}
+
+fn closest_on_segment(a: SimplexVertex, b: SimplexVertex) -> Vec<(SimplexVertex, f64)> {
+    let ab = b.w - a.w;
+    let len_sq = ab.magnitude_squared();
+    if approx_zero(len_sq) {
+        return vec![(a, 1.0)];
+    }
+    let t = -a.w.dot(&ab) / len_sq;
+    if t <= 0.0 {
+        vec![(a, 1.0)]
+    } else if t >= 1.0 {
+        vec![(b, 1.0)]
+    } else {
+        vec![(a, 1.0 - t), (b, t)]
+    }
+}
+
+// Voronoi-region walk from Ericson, "Real-Time Collision Detection" 5.1.5,
+// with the query point at the origin.
+fn closest_on_triangle(
+    a: SimplexVertex,
+    b: SimplexVertex,
+    c: SimplexVertex,
+) -> Vec<(SimplexVertex, f64)> {
+    let ab = b.w - a.w;
+    let ac = c.w - a.w;
+
+    let d1 = -ab.dot(&a.w);
+    let d2 = -ac.dot(&a.w);
+    if d1 <= 0.0 && d2 <= 0.0 {
+        return vec![(a, 1.0)];
+    }
+
+    let d3 = -ab.dot(&b.w);
+    let d4 = -ac.dot(&b.w);
+    if d3 >= 0.0 && d4 <= d3 {
+        return vec![(b, 1.0)];
+    }
+
+    let vc = d1 * d4 - d3 * d2;
+    if vc <= 0.0 && d1 >= 0.0 && d3 <= 0.0 {
+        let t = d1 / (d1 - d3);
+        return vec![(a, 1.0 - t), (b, t)];
+    }
+
+    let d5 = -ab.dot(&c.w);
+    let d6 = -ac.dot(&c.w);
+    if d6 >= 0.0 && d5 <= d6 {
+        return vec![(c, 1.0)];
+    }
+
+    let vb = d5 * d2 - d1 * d6;
+    if vb <= 0.0 && d2 >= 0.0 && d6 <= 0.0 {
+        let t = d2 / (d2 - d6);
+        return vec![(a, 1.0 - t), (c, t)];
+    }
+
+    let va = d3 * d6 - d5 * d4;
+    if va <= 0.0 && (d4 - d3) >= 0.0 && (d5 - d6) >= 0.0 {
+        let t = (d4 - d3) / ((d4 - d3) + (d5 - d6));
+        return vec![(b, 1.0 - t), (c, t)];
+    }
+
+    let sum = va + vb + vc;
+    if approx_zero(sum) {
+        // Collinear vertices: fall back to the best edge.
+        return [(a, b), (b, c), (a, c)]
+            .into_iter()
+            .map(|(p, q)| closest_on_segment(p, q))
+            .min_by(|x, y| weighted_norm(x).total_cmp(&weighted_norm(y)))
+            .unwrap_or_else(|| vec![(a, 1.0)]);
+    }
+    let v = vb / sum;
+    let w = vc / sum;
+    vec![(a, 1.0 - v - w), (b, v), (c, w)]
+}
+
+fn closest_on_tetrahedron(
+    a: SimplexVertex,
+    b: SimplexVertex,
+    c: SimplexVertex,
+    d: SimplexVertex,
+) -> Option<Vec<(SimplexVertex, f64)>> {
+    let faces = [(a, b, c, d), (a, c, d, b), (a, d, b, c), (b, d, c, a)];
+
+    let mut best: Option<Vec<(SimplexVertex, f64)>> = None;
+    for (p, q, r, opposite) in faces {
+        if !origin_outside_face(&p.w, &q.w, &r.w, &opposite.w) {
+            continue;
+        }
+        let candidate = closest_on_triangle(p, q, r);
+        let better = match &best {
+            Some(current) => weighted_norm(&candidate) < weighted_norm(current),
+            None => true,
+        };
+        if better {
+            best = Some(candidate);
+        }
+    }
+    best
+}
+
+/// Whether the origin and `opposite` lie on different sides of the plane
+/// through `p`, `q`, `r`. Degenerate (flat) tetrahedra count every face as
+/// outside so the closest face is still found.
+fn origin_outside_face(p: &Vector3D, q: &Vector3D, r: &Vector3D, opposite: &Vector3D) -> bool {
+    let normal = (*q - *p).cross(&(*r - *p));
+    let side_origin = -normal.dot(p);
+    let side_opposite = normal.dot(&(*opposite - *p));
+    if side_opposite.abs() <= EPSILON * normal.magnitude().max(1.0) {
+        return true;
+    }
+    side_origin * side_opposite < 0.0
+}
+
+fn weighted_norm(simplex: &[(SimplexVertex, f64)]) -> f64 {
+    simplex
+        .iter()
+        .fold(Vector3D::zero(), |acc, (s, weight)| acc + s.w * *weight)
+        .magnitude_squared()
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Face {
+    indices: [usize; 3],
+    normal: Vector3D,
+    distance: f64,
+}
+
+impl Face {
+    fn new(vertices: &[SimplexVertex], indices: [usize; 3]) -> Self {
+        let [i, j, k] = indices;
+        let cross = (vertices[j].w - vertices[i].w).cross(&(vertices[k].w - vertices[i].w));
+        match cross.normalize() {
+            Ok(normal) => Self {
+                indices,
+                normal,
+                distance: normal.dot(&vertices[i].w),
+            },
+            Err(_) => Self {
+                indices,
+                normal: Vector3D::zero(),
+                distance: f64::INFINITY,
+            },
+        }
+    }
+}
+
+fn epa<A, B>(a: &A, b: &B, simplex: Vec<SimplexVertex>) -> Penetration
+where
+    A: SupportMap + ?Sized,
+    B: SupportMap + ?Sized,
+{
+    let vertices = match build_tetrahedron(a, b, simplex) {
+        Ok(vertices) => vertices,
+        Err(vertex) => {
+            // The Minkowski difference is flat: the shapes only touch.
+            return Penetration {
+                depth: 0.0,
+                normal: Vector3D::unit_x(),
+                point_a: vertex.a,
+                point_b: vertex.b,
+            };
+        }
+    };
+
+    let mut vertices = vertices;
+    let mut faces: Vec<Face> = Vec::new();
+    for (indices, opposite) in [
+        ([0, 1, 2], 3),
+        ([0, 3, 1], 2),
+        ([0, 2, 3], 1),
+        ([1, 3, 2], 0),
+    ] {
+        let mut face = Face::new(&vertices, indices);
+        let [i, j, k] = indices;
+        if face.normal.dot(&(vertices[opposite].w - vertices[i].w)) > 0.0 {
+            face = Face::new(&vertices, [i, k, j]);
+        }
+        faces.push(face);
+    }
+
+    let mut closest = faces[0];
+    for _ in 0..EPA_MAX_ITERATIONS {
+        closest = *faces
+            .iter()
+            .min_by(|x, y| x.distance.total_cmp(&y.distance))
+            .expect("polytope has faces");
+
+        let vertex = support_vertex(a, b, &closest.normal);
+        let gain = vertex.w.dot(&closest.normal) - closest.distance;
+        if gain <= EPA_TOLERANCE * closest.distance.abs().max(1.0) {
+            break;
+        }
+
+        let new_index = vertices.len();
+        vertices.push(vertex);
+
+        let mut horizon: Vec<(usize, usize)> = Vec::new();
+        faces.retain(|face| {
+            let visible = face.distance.is_finite()
+                && face.normal.dot(&(vertex.w - vertices[face.indices[0]].w)) > 0.0;
+            if visible {
+                let [i, j, k] = face.indices;
+                for edge in [(i, j), (j, k), (k, i)] {
+                    if let Some(pos) = horizon.iter().position(|&e| e == (edge.1, edge.0)) {
+                        horizon.swap_remove(pos);
+                    } else {
+                        horizon.push(edge);
+                    }
+                }
+            }
+            !visible
+        });
+
+        if horizon.is_empty() {
+            break;
+        }
+        for (i, j) in horizon {
+            faces.push(Face::new(&vertices, [i, j, new_index]));
+        }
+    }
+
+    let [i, j, k] = closest.indices;
+    let projection = closest.normal * closest.distance;
+    let (u, v, w) = barycentric(&projection, &vertices[i].w, &vertices[j].w, &vertices[k].w);
+    let origin = Point3D::origin();
+    let blend = |p: Point3D, q: Point3D, r: Point3D| {
+        origin + (p - origin) * u + (q - origin) * v + (r - origin) * w
+    };
+
+    Penetration {
+        depth: closest.distance.max(0.0),
+        normal: closest.normal,
+        point_a: blend(vertices[i].a, vertices[j].a, vertices[k].a),
+        point_b: blend(vertices[i].b, vertices[j].b, vertices[k].b),
+    }
+}
+
+/// Grows the terminating GJK simplex into a tetrahedron that encloses the
+/// origin. Fails (returning a vertex of the difference) if the Minkowski
+/// difference has no volume.
+fn build_tetrahedron<A, B>(
+    a: &A,
+    b: &B,
+    mut simplex: Vec<SimplexVertex>,
+) -> Result<Vec<SimplexVertex>, SimplexVertex>
+where
+    A: SupportMap + ?Sized,
+    B: SupportMap + ?Sized,
+{
+    let axes = [Vector3D::unit_x(), Vector3D::unit_y(), Vector3D::unit_z()];
+
+    if simplex.len() == 1 {
+        for axis in axes.iter().flat_map(|axis| [*axis, -*axis]) {
+            let vertex = support_vertex(a, b, &axis);
+            if (vertex.w - simplex[0].w).magnitude_squared() > EPSILON {
+                simplex.push(vertex);
+                break;
+            }
+        }
+    }
+
+    if simplex.len() == 2 {
+        let line = simplex[1].w - simplex[0].w;
+        let helper = axes
+            .iter()
+            .min_by(|x, y| x.dot(&line).abs().total_cmp(&y.dot(&line).abs()))
+            .copied()
+            .unwrap_or(Vector3D::unit_x());
+        let first = line.cross(&helper);
+        let second = line.cross(&first);
+        for direction in [first, -first, second, -second] {
+            let vertex = support_vertex(a, b, &direction);
+            let area = line.cross(&(vertex.w - simplex[0].w)).magnitude_squared();
+            if area > EPSILON {
+                simplex.push(vertex);
+                break;
+            }
+        }
+    }
+
+    if simplex.len() == 3 {
+        let normal = (simplex[1].w - simplex[0].w).cross(&(simplex[2].w - simplex[0].w));
+        for direction in [normal, -normal] {
+            let vertex = support_vertex(a, b, &direction);
+            if normal.dot(&(vertex.w - simplex[0].w)).abs() > EPSILON {
+                simplex.push(vertex);
+                break;
+            }
+        }
+    }
+
+    if simplex.len() == 4 {
+        Ok(simplex)
+    } else {
+        Err(simplex[0])
+    }
+}
+
+fn barycentric(p: &Vector3D, a: &Vector3D, b: &Vector3D, c: &Vector3D) -> (f64, f64, f64) {
+    let v0 = *b - *a;
+    let v1 = *c - *a;
+    let v2 = *p - *a;
+    let d00 = v0.dot(&v0);
+    let d01 = v0.dot(&v1);
+    let d11 = v1.dot(&v1);
+    let d20 = v2.dot(&v0);
+    let d21 = v2.dot(&v1);
+    let denom = d00 * d11 - d01 * d01;
+    if approx_zero(denom) {
+        return (1.0, 0.0, 0.0);
+    }
+    let v = (d11 * d20 - d01 * d21) / denom;
+    let w = (d00 * d21 - d01 * d20) / denom;
+    (1.0 - v - w, v, w)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::operations::contact::contact_obb_obb;
+    use crate::operations::support::{ConvexPointSet, MinkowskiSum};
+    use crate::primitives::{LineSegment, Sphere, Triangle, AABB, OBB};
+
+    fn assert_close(a: f64, b: f64, tolerance: f64) {
+        assert!((a - b).abs() < tolerance, "{} != {}", a, b);
+    }
+
+    fn cube(center: Point3D, half: f64) -> AABB {
+        let h = Vector3D::new(half, half, half);
+        AABB::new(center + -h, center + h).unwrap()
+    }
+
+    #[test]
+    fn test_sphere_sphere_distance() {
+        let a = Sphere::new(Point3D::origin(), 1.0).unwrap();
+        let b = Sphere::new(Point3D::new(0.0, 4.0, 0.0), 2.0).unwrap();
+        let closest = gjk_distance(&a, &b).unwrap();
+        assert_close(closest.distance, 1.0, 1e-9);
+        // Curved surfaces converge to within the GJK tolerance, not exactly.
+        assert!(closest.point_a.distance_to(&Point3D::new(0.0, 1.0, 0.0)) < 1e-5);
+        assert!(closest.point_b.distance_to(&Point3D::new(0.0, 2.0, 0.0)) < 1e-5);
+        assert!(!gjk_intersects(&a, &b));
+    }
+
+    #[test]
+    fn test_box_box_distance_and_overlap() {
+        let a = cube(Point3D::origin(), 1.0);
+        let b = cube(Point3D::new(3.0, 0.5, 0.0), 1.0);
+        let closest = gjk_distance(&a, &b).unwrap();
+        assert_close(closest.distance, 1.0, 1e-9);
+        assert_close(closest.point_a.x, 1.0, 1e-9);
+        assert_close(closest.point_b.x, 2.0, 1e-9);
+
+        let c = cube(Point3D::new(1.5, 0.0, 0.0), 1.0);
+        assert!(gjk_intersects(&a, &c));
+        assert!(gjk_distance(&a, &c).is_none());
+    }
+
+    #[test]
+    fn test_triangle_point_and_segment() {
+        let triangle = Triangle::new(
+            Point3D::origin(),
+            Point3D::new(2.0, 0.0, 0.0),
+            Point3D::new(0.0, 2.0, 0.0),
+        )
+        .unwrap();
+        let point = Point3D::new(0.5, 0.5, 3.0);
+        let closest = gjk_distance(&triangle, &point).unwrap();
+        assert_close(closest.distance, 3.0, 1e-9);
+        assert_eq!(closest.point_a, Point3D::new(0.5, 0.5, 0.0));
+
+        let segment =
+            LineSegment::new(Point3D::new(0.5, 0.5, -1.0), Point3D::new(0.5, 0.5, 1.0)).unwrap();
+        assert!(gjk_intersects(&triangle, &segment));
+    }
+
+    #[test]
+    fn test_epa_box_box() {
+        let a = cube(Point3D::origin(), 1.0);
+        let b = cube(Point3D::new(1.5, 0.2, 0.1), 1.0);
+        let penetration = epa_penetration(&a, &b).unwrap();
+        assert_close(penetration.depth, 0.5, 1e-9);
+        assert_eq!(penetration.normal, Vector3D::unit_x());
+        assert!(epa_penetration(&a, &cube(Point3D::new(5.0, 0.0, 0.0), 1.0)).is_none());
+    }
+
+    #[test]
+    fn test_epa_sphere_sphere() {
+        let a = Sphere::new(Point3D::origin(), 1.0).unwrap();
+        let b = Sphere::new(Point3D::new(1.5, 0.0, 0.0), 1.0).unwrap();
+        let penetration = epa_penetration(&a, &b).unwrap();
+        assert_close(penetration.depth, 0.5, 1e-3);
+        assert_close(penetration.normal.x, 1.0, 1e-3);
+        assert_close(penetration.point_a.x, 1.0, 1e-2);
+        assert_close(penetration.point_b.x, 0.5, 1e-2);
+
+        let manifold = penetration.to_manifold();
+        assert_eq!(manifold.contacts.len(), 1);
+        assert_close(manifold.contacts[0].point.x, 0.75, 1e-2);
+    }
+
+    #[test]
+    fn test_epa_matches_obb_contact() {
+        let a = OBB::from_aabb(&cube(Point3D::origin(), 1.0));
+        let (s, c) = 0.4_f64.sin_cos();
+        let b = OBB::new(
+            Point3D::new(0.3, 2.2, 0.1),
+            [
+                Vector3D::new(c, s, 0.0),
+                Vector3D::new(-s, c, 0.0),
+                Vector3D::unit_z(),
+            ],
+            [1.0, 1.0, 1.0],
+        )
+        .unwrap();
+
+        let penetration = epa_penetration(&a, &b).unwrap();
+        let manifold = contact_obb_obb(&a, &b).unwrap();
+        assert_close(penetration.depth, manifold.max_depth(), 1e-6);
+        assert!(penetration.normal.dot(&manifold.normal) > 0.999);
+    }
+
+    #[test]
+    fn test_rounded_box_distance() {
+        let rounded = MinkowskiSum::new(
+            cube(Point3D::origin(), 1.0),
+            Sphere::new(Point3D::origin(), 0.5).unwrap(),
+        );
+        let point = Point3D::new(3.0, 0.0, 0.0);
+        let closest = gjk_distance(&rounded, &point).unwrap();
+        assert_close(closest.distance, 1.5, 1e-9);
+
+        let corner = Point3D::new(2.0, 2.0, 2.0);
+        let closest = gjk_distance(&rounded, &corner).unwrap();
+        assert_close(closest.distance, 3.0_f64.sqrt() - 0.5, 1e-6);
+    }
+
+    #[test]
+    fn test_point_set_and_trait_objects() {
+        let tetrahedron = ConvexPointSet::new(vec![
+            Point3D::origin(),
+            Point3D::new(1.0, 0.0, 0.0),
+            Point3D::new(0.0, 1.0, 0.0),
+            Point3D::new(0.0, 0.0, 1.0),
+        ])
+        .unwrap();
+        let sphere = Sphere::new(Point3D::new(2.0, 2.0, 2.0), 1.0).unwrap();
+
+        let shapes: Vec<&dyn SupportMap> = vec![&tetrahedron, &sphere];
+        let closest = gjk_distance(shapes[0], shapes[1]).unwrap();
+        let expected =
+            Point3D::new(2.0, 2.0, 2.0).distance_to(&Point3D::new(1.0 / 3.0, 1.0 / 3.0, 1.0 / 3.0))
+                - 1.0;
+        assert_close(closest.distance, expected, 1e-6);
+    }
+}
@@ -0,0 +1,88 @@
+use crate::primitives::{Point3D, Ray, Vector3D};
+
+/// Where a ray meets a surface, as returned by every ray query.
+///
+/// `normal` is a unit vector facing against the ray, whichever side was hit;
+/// `front_face` is true when the ray arrived from outside, so
+/// [`RayHit::outward_normal`] recovers the surface's own normal.
+///
+/// Spheres fill `uv` with longitude and latitude in `[0, 1]`, boxes with
+/// the position across the hit face from its min corner, and triangles with
+/// the weights of `b` and `c`. Only triangles fill `barycentric`, with the
+/// weights of `a`, `b` and `c`. Planes fill neither.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RayHit {
+    /// Ray parameter of the hit.
+    pub t: f64,
+    pub point: Point3D,
+    pub normal: Vector3D,
+    pub front_face: bool,
+    pub uv: Option<(f64, f64)>,
+    pub barycentric: Option<(f64, f64, f64)>,
+}
+
+impl RayHit {
+    /// Builds a hit record from the surface's outward normal. The stored
+    /// `normal` always faces against the ray; `front_face` tells whether the
+    /// ray arrived from the outside (grazing hits count as outside).
+    pub fn new(ray: &Ray, t: f64, outward_normal: Vector3D) -> Self {
+        let front_face = ray.direction.dot(&outward_normal) <= 0.0;
+        let normal = if front_face {
+            outward_normal
+        } else {
+            -outward_normal
+        };
+        Self {
+            t,
+            point: ray.point_at(t),
+            normal,
+            front_face,
+            uv: None,
+            barycentric: None,
+        }
+    }
+
+    #[inline]
+    pub fn with_uv(mut self, u: f64, v: f64) -> Self {
+        self.uv = Some((u, v));
+        self
+    }
+
+    #[inline]
+    pub fn with_barycentric(mut self, u: f64, v: f64, w: f64) -> Self {
+        self.barycentric = Some((u, v, w));
+        self
+    }
+
+    #[inline]
+    pub fn outward_normal(&self) -> Vector3D {
+        if self.front_face {
+            self.normal
+        } else {
+            -self.normal
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_front_face_keeps_normal() {
+        let ray = Ray::new(Point3D::new(0.0, 0.0, 5.0), Vector3D::new(0.0, 0.0, -1.0)).unwrap();
+        let hit = RayHit::new(&ray, 5.0, Vector3D::unit_z());
+        assert!(hit.front_face);
+        assert_eq!(hit.normal, Vector3D::unit_z());
+        assert_eq!(hit.point, Point3D::origin());
+    }
+
+    #[test]
+    fn test_back_face_flips_normal() {
+        let ray = Ray::new(Point3D::new(0.0, 0.0, -5.0), Vector3D::new(0.0, 0.0, 1.0)).unwrap();
+        let hit = RayHit::new(&ray, 5.0, Vector3D::unit_z());
+        assert!(!hit.front_face);
+        assert_eq!(hit.normal, Vector3D::new(0.0, 0.0, -1.0));
+        assert_eq!(hit.outward_normal(), Vector3D::unit_z());
+    }
+}
@@ -0,0 +1,723 @@
+use std::collections::HashMap;
+
+use super::support::SupportMap;
+use crate::error::GeometryError;
+use crate::primitives::{Point3D, Triangle, Vector3D};
+
+/// Convex hull of a point cloud as a closed triangle mesh. Every face in
+/// `faces` indexes into `vertices` and is wound counter-clockwise when seen
+/// from outside, so `(b - a) × (c - a)` is the outward normal.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ConvexHull {
+    vertices: Vec<Point3D>,
+    faces: Vec<[usize; 3]>,
+}
+
+#[derive(Debug, Clone)]
+struct HullFace {
+    indices: [usize; 3],
+    normal: Vector3D,
+    offset: f64,
+    outside: Vec<usize>,
+    alive: bool,
+}
+
+impl HullFace {
+    fn new(points: &[Point3D], indices: [usize; 3], interior: &Point3D) -> Self {
+        let [a, b, c] = indices;
+        let mut indices = indices;
+        let mut normal = (points[b] - points[a])
+            .cross(&(points[c] - points[a]))
+            .normalize()
+            .unwrap_or(Vector3D::zero());
+        if normal.dot(&(points[a] - *interior)) < 0.0 {
+            indices = [a, c, b];
+            normal = -normal;
+        }
+        Self {
+            indices,
+            normal,
+            offset: normal.dot(&(points[indices[0]] - Point3D::origin())),
+            outside: Vec::new(),
+            alive: true,
+        }
+    }
+
+    #[inline]
+    fn distance(&self, point: &Point3D) -> f64 {
+        self.normal.dot(&(*point - Point3D::origin())) - self.offset
+    }
+}
+
+impl ConvexHull {
+    /// Builds the hull with Quickhull. Only the corners of the hull become
+    /// vertices: duplicate and interior points, and points lying on a hull
+    /// face or edge, are discarded. Coplanar points give a flat hull, the
+    /// polygon of [`convex_hull_2d`] triangulated once facing each way, with
+    /// zero volume. Fails with `GeometryError::DegenerateCase` when the
+    /// points are identical or collinear.
+    pub fn from_points(points: &[Point3D]) -> Result<Self, GeometryError> {
+        if points.is_empty() {
+            return Err(GeometryError::InvalidParameter(
+                "Cannot build convex hull from empty point list".to_string(),
+            ));
+        }
+
+        let tolerance = hull_tolerance(points);
+        let (i0, i1, i2) = initial_triangle(points, tolerance)?;
+        let normal = (points[i1] - points[i0]).cross(&(points[i2] - points[i0]));
+        let normal = normal.normalize()?;
+        let i3 = (0..points.len())
+            .max_by(|&x, &y| {
+                let dx = normal.dot(&(points[x] - points[i0])).abs();
+                let dy = normal.dot(&(points[y] - points[i0])).abs();
+                dx.total_cmp(&dy)
+            })
+            .unwrap_or(i0);
+        if normal.dot(&(points[i3] - points[i0])).abs() <= tolerance {
+            return Self::flat(points, i0, i1, &normal, tolerance);
+        }
+
+        let origin = Point3D::origin();
+        let interior = origin
+            + ((points[i0] - origin)
+                + (points[i1] - origin)
+                + (points[i2] - origin)
+                + (points[i3] - origin))
+                / 4.0;
+
+        let mut faces: Vec<HullFace> = [[i0, i1, i2], [i0, i1, i3], [i0, i2, i3], [i1, i2, i3]]
+            .into_iter()
+            .map(|indices| HullFace::new(points, indices, &interior))
+            .collect();
+
+        for (index, point) in points.iter().enumerate() {
+            if let Some(face) = faces.iter_mut().find(|f| f.distance(point) > tolerance) {
+                face.outside.push(index);
+            }
+        }
+
+        while let Some(current) = faces.iter().position(|f| f.alive && !f.outside.is_empty()) {
+            let eye = *faces[current]
+                .outside
+                .iter()
+                .max_by(|&&x, &&y| {
+                    let face = &faces[current];
+                    face.distance(&points[x])
+                        .total_cmp(&face.distance(&points[y]))
+                })
+                .expect("outside set is not empty");
+            let eye_point = points[eye];
+
+            let visible: Vec<usize> = (0..faces.len())
+                .filter(|&f| faces[f].alive && faces[f].distance(&eye_point) > tolerance)
+                .collect();
+
+            // Horizon edges are the edges of visible faces whose twin belongs
+            // to a face that stays.
+            let mut horizon: Vec<(usize, usize)> = Vec::new();
+            for &f in &visible {
+                let [a, b, c] = faces[f].indices;
+                for edge in [(a, b), (b, c), (c, a)] {
+                    if let Some(pos) = horizon.iter().position(|&e| e == (edge.1, edge.0)) {
+                        horizon.swap_remove(pos);
+                    } else {
+                        horizon.push(edge);
+                    }
+                }
+            }
+
+            let mut orphans: Vec<usize> = Vec::new();
+            for &f in &visible {
+                faces[f].alive = false;
+                orphans.append(&mut faces[f].outside);
+            }
+
+            let first_new = faces.len();
+            for (a, b) in horizon {
+                faces.push(HullFace::new(points, [a, b, eye], &interior));
+            }
+
+            for index in orphans {
+                if index == eye {
+                    continue;
+                }
+                let point = &points[index];
+                if let Some(face) = faces[first_new..]
+                    .iter_mut()
+                    .find(|f| f.distance(point) > tolerance)
+                {
+                    face.outside.push(index);
+                }
+            }
+        }
+
+        let mut remap = vec![usize::MAX; points.len()];
+        let mut vertices = Vec::new();
+        let mut hull_faces = Vec::new();
+        for face in merge_coplanar(points, &faces, tolerance) {
+            let mut mapped = [0; 3];
+            for (slot, &index) in mapped.iter_mut().zip(face.iter()) {
+                if remap[index] == usize::MAX {
+                    remap[index] = vertices.len();
+                    vertices.push(points[index]);
+                }
+                *slot = remap[index];
+            }
+            hull_faces.push(mapped);
+        }
+
+        Ok(Self {
+            vertices,
+            faces: hull_faces,
+        })
+    }
+
+    /// Hull of coplanar points in the plane through `points[i0]` and
+    /// `points[i1]` with the unit normal `normal`.
+    fn flat(
+        points: &[Point3D],
+        i0: usize,
+        i1: usize,
+        normal: &Vector3D,
+        tolerance: f64,
+    ) -> Result<Self, GeometryError> {
+        let origin = points[i0];
+        let u = (points[i1] - origin).normalize()?;
+        let v = normal.cross(&u);
+        let indices: Vec<usize> = (0..points.len()).collect();
+        let corners = planar_hull(points, &indices, &origin, &u, &v, tolerance);
+
+        let mut faces = Vec::with_capacity(2 * corners.len());
+        for i in 1..corners.len() - 1 {
+            faces.push([0, i, i + 1]);
+            faces.push([0, i + 1, i]);
+        }
+        Ok(Self {
+            vertices: corners.into_iter().map(|i| points[i]).collect(),
+            faces,
+        })
+    }
+
+    #[inline]
+    pub fn vertices(&self) -> &[Point3D] {
+        &self.vertices
+    }
+
+    #[inline]
+    pub fn faces(&self) -> &[[usize; 3]] {
+        &self.faces
+    }
+
+    /// Hull faces as triangles, skipping any sliver too thin to construct.
+    pub fn triangles(&self) -> Vec<Triangle> {
+        self.faces
+            .iter()
+            .filter_map(|&[a, b, c]| {
+                Triangle::new(self.vertices[a], self.vertices[b], self.vertices[c]).ok()
+            })
+            .collect()
+    }
+
+    pub fn volume(&self) -> f64 {
+        let reference = self.vertices[0];
+        self.faces
+            .iter()
+            .map(|&[a, b, c]| {
+                let pa = self.vertices[a] - reference;
+                let pb = self.vertices[b] - reference;
+                let pc = self.vertices[c] - reference;
+                pa.dot(&pb.cross(&pc)) / 6.0
+            })
+            .sum()
+    }
+
+    /// Area of all faces; both sides count for a flat hull.
+    pub fn surface_area(&self) -> f64 {
+        self.faces
+            .iter()
+            .map(|&[a, b, c]| {
+                let ab = self.vertices[b] - self.vertices[a];
+                let ac = self.vertices[c] - self.vertices[a];
+                ab.cross(&ac).magnitude() / 2.0
+            })
+            .sum()
+    }
+
+    /// Whether the hull came from coplanar points and encloses no volume.
+    pub fn is_flat(&self) -> bool {
+        let tolerance = hull_tolerance(&self.vertices);
+        let [a, b, c] = self.faces[0];
+        let pa = self.vertices[a];
+        let Ok(normal) = (self.vertices[b] - pa)
+            .cross(&(self.vertices[c] - pa))
+            .normalize()
+        else {
+            return false;
+        };
+        self.vertices
+            .iter()
+            .all(|p| normal.dot(&(*p - pa)).abs() <= tolerance)
+    }
+
+    pub fn contains_point(&self, point: &Point3D) -> bool {
+        let tolerance = hull_tolerance(&self.vertices);
+        if self.is_flat() {
+            // Every face plane is the same one, so test the polygon itself.
+            return self
+                .triangles()
+                .iter()
+                .any(|t| t.closest_point(point).distance_to(point) <= tolerance);
+        }
+        self.faces.iter().all(|&[a, b, c]| {
+            let pa = self.vertices[a];
+            let normal = (self.vertices[b] - pa).cross(&(self.vertices[c] - pa));
+            match normal.normalize() {
+                Ok(n) => n.dot(&(*point - pa)) <= tolerance,
+                Err(_) => true,
+            }
+        })
+    }
+}
+
+impl SupportMap for ConvexHull {
+    fn support(&self, direction: &Vector3D) -> Point3D {
+        let origin = Point3D::origin();
+        self.vertices
+            .iter()
+            .copied()
+            .max_by(|p, q| {
+                (*p - origin)
+                    .dot(direction)
+                    .total_cmp(&(*q - origin).dot(direction))
+            })
+            .expect("hull has vertices")
+    }
+}
+
+/// Convex hull of coplanar points as a convex polygon with consistent
+/// winding; points lying on its edges are dropped. Fails with
+/// `GeometryError::DegenerateCase` for identical or collinear input and
+/// `GeometryError::InvalidParameter` if the points are not coplanar.
+pub fn convex_hull_2d(points: &[Point3D]) -> Result<Vec<Point3D>, GeometryError> {
+    if points.is_empty() {
+        return Err(GeometryError::InvalidParameter(
+            "Cannot build convex hull from empty point list".to_string(),
+        ));
+    }
+
+    let tolerance = hull_tolerance(points);
+    let (i0, i1, i2) = initial_triangle(points, tolerance)?;
+    let origin = points[i0];
+    let u = (points[i1] - origin).normalize()?;
+    let normal = u.cross(&(points[i2] - origin)).normalize()?;
+    let v = normal.cross(&u);
+
+    if points
+        .iter()
+        .any(|p| normal.dot(&(*p - origin)).abs() > tolerance)
+    {
+        return Err(GeometryError::InvalidParameter(
+            "Points are not coplanar".to_string(),
+        ));
+    }
+
+    let indices: Vec<usize> = (0..points.len()).collect();
+    let corners = planar_hull(points, &indices, &origin, &u, &v, tolerance);
+    Ok(corners.into_iter().map(|i| points[i]).collect())
+}
+
+/// Corners of the planar hull of `indices`, counter-clockwise about `u × v`.
+/// Points on the hull edges are not corners.
+fn planar_hull(
+    points: &[Point3D],
+    indices: &[usize],
+    origin: &Point3D,
+    u: &Vector3D,
+    v: &Vector3D,
+    tolerance: f64,
+) -> Vec<usize> {
+    let mut projected: Vec<(f64, f64, usize)> = indices
+        .iter()
+        .map(|&i| {
+            let d = points[i] - *origin;
+            (d.dot(u), d.dot(v), i)
+        })
+        .collect();
+    projected.sort_by(|a, b| a.0.total_cmp(&b.0).then(a.1.total_cmp(&b.1)));
+
+    // Andrew's monotone chain.
+    let cross = |o: &(f64, f64, usize), a: &(f64, f64, usize), b: &(f64, f64, usize)| {
+        (a.0 - o.0) * (b.1 - o.1) - (a.1 - o.1) * (b.0 - o.0)
+    };
+    let area_tolerance = tolerance * tolerance.max(1.0);
+    let mut hull: Vec<(f64, f64, usize)> = Vec::with_capacity(projected.len() * 2);
+    for p in &projected {
+        while hull.len() >= 2
+            && cross(&hull[hull.len() - 2], &hull[hull.len() - 1], p) <= area_tolerance
+        {
+            hull.pop();
+        }
+        hull.push(*p);
+    }
+    let lower_len = hull.len() + 1;
+    for p in projected.iter().rev().skip(1) {
+        while hull.len() >= lower_len
+            && cross(&hull[hull.len() - 2], &hull[hull.len() - 1], p) <= area_tolerance
+        {
+            hull.pop();
+        }
+        hull.push(*p);
+    }
+    hull.pop();
+
+    hull.into_iter().map(|(_, _, i)| i).collect()
+}
+
+/// Retriangulates each group of coplanar hull faces over the corners of its
+/// polygon, so points that Quickhull picked up on a face or edge before the
+/// face was complete do not survive as vertices.
+fn merge_coplanar(points: &[Point3D], faces: &[HullFace], tolerance: f64) -> Vec<[usize; 3]> {
+    let alive: Vec<&HullFace> = faces.iter().filter(|f| f.alive).collect();
+    let mut edges = HashMap::new();
+    for (f, face) in alive.iter().enumerate() {
+        let [a, b, c] = face.indices;
+        for edge in [(a, b), (b, c), (c, a)] {
+            edges.insert(edge, f);
+        }
+    }
+
+    let mut grouped = vec![false; alive.len()];
+    let mut triangles = Vec::with_capacity(alive.len());
+    for seed in 0..alive.len() {
+        if grouped[seed] {
+            continue;
+        }
+        grouped[seed] = true;
+        let plane = alive[seed];
+        let mut group = vec![seed];
+        let mut next = 0;
+        while next < group.len() {
+            let [a, b, c] = alive[group[next]].indices;
+            next += 1;
+            for (from, to) in [(a, b), (b, c), (c, a)] {
+                let Some(&f) = edges.get(&(to, from)) else {
+                    continue;
+                };
+                let coplanar = alive[f].normal.dot(&plane.normal) > 0.0
+                    && alive[f]
+                        .indices
+                        .iter()
+                        .all(|&i| plane.distance(&points[i]).abs() <= tolerance);
+                if !grouped[f] && coplanar {
+                    grouped[f] = true;
+                    group.push(f);
+                }
+            }
+        }
+
+        if group.len() == 1 {
+            triangles.push(plane.indices);
+            continue;
+        }
+        let mut indices: Vec<usize> = group.iter().flat_map(|&f| alive[f].indices).collect();
+        indices.sort_unstable();
+        indices.dedup();
+        let [a, b, _] = plane.indices;
+        let origin = points[a];
+        let Ok(u) = (points[b] - origin).normalize() else {
+            triangles.extend(group.iter().map(|&f| alive[f].indices));
+            continue;
+        };
+        let v = plane.normal.cross(&u);
+        let corners = planar_hull(points, &indices, &origin, &u, &v, tolerance);
+        for pair in corners[1..].windows(2) {
+            triangles.push([corners[0], pair[0], pair[1]]);
+        }
+    }
+    triangles
+}
+
+/// Absolute tolerance scaled to the magnitude of the input coordinates.
+fn hull_tolerance(points: &[Point3D]) -> f64 {
+    let scale = points
+        .iter()
+        .map(|p| p.x.abs().max(p.y.abs()).max(p.z.abs()))
+        .fold(1.0, f64::max);
+    1e-9 * scale
+}
+
+/// Indices of a well-spread, non-collinear triple of points.
+fn initial_triangle(
+    points: &[Point3D],
+    tolerance: f64,
+) -> Result<(usize, usize, usize), GeometryError> {
+    let mut extremes = [0usize; 6];
+    for (i, p) in points.iter().enumerate() {
+        let coords = [p.x, p.y, p.z];
+        for axis in 0..3 {
+            let min = &points[extremes[2 * axis]];
+            let max = &points[extremes[2 * axis + 1]];
+            if coords[axis] < [min.x, min.y, min.z][axis] {
+                extremes[2 * axis] = i;
+            }
+            if coords[axis] > [max.x, max.y, max.z][axis] {
+                extremes[2 * axis + 1] = i;
+            }
+        }
+    }
+
+    let mut i0 = extremes[0];
+    let mut i1 = extremes[1];
+    let mut best = -1.0;
+    for &a in &extremes {
+        for &b in &extremes {
+            let distance = points[a].distance_squared_to(&points[b]);
+            if distance > best {
+                best = distance;
+                i0 = a;
+                i1 = b;
+            }
+        }
+    }
+    if best.sqrt() <= tolerance {
+        return Err(GeometryError::DegenerateCase(
+            "All points are identical, cannot build convex hull".to_string(),
+        ));
+    }
+
+    let line = points[i1] - points[i0];
+    let line_length = line.magnitude();
+    let distance_to_line = |p: &Point3D| line.cross(&(*p - points[i0])).magnitude() / line_length;
+    let i2 = (0..points.len())
+        .max_by(|&x, &y| distance_to_line(&points[x]).total_cmp(&distance_to_line(&points[y])))
+        .unwrap_or(i0);
+    if distance_to_line(&points[i2]) <= tolerance {
+        return Err(GeometryError::DegenerateCase(
+            "Points are collinear, cannot build convex hull".to_string(),
+        ));
+    }
+
+    Ok((i0, i1, i2))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+
+    fn cube_points() -> Vec<Point3D> {
+        let mut points = Vec::new();
+        for &x in &[-1.0, 1.0] {
+            for &y in &[-1.0, 1.0] {
+                for &z in &[-1.0, 1.0] {
+                    points.push(Point3D::new(x, y, z));
+                }
+            }
+        }
+        points
+    }
+
+    fn assert_closed_and_outward(hull: &ConvexHull) {
+        let vertices = hull.vertices();
+        let count = vertices.len() as f64;
+        let origin = Point3D::origin();
+        let centroid = vertices
+            .iter()
+            .fold(origin, |acc, p| acc + (*p - origin) / count);
+
+        let mut edges = HashSet::new();
+        for &[a, b, c] in hull.faces() {
+            let normal = (vertices[b] - vertices[a]).cross(&(vertices[c] - vertices[a]));
+            assert!(normal.dot(&(vertices[a] - centroid)) > 0.0);
+            for edge in [(a, b), (b, c), (c, a)] {
+                assert!(edges.insert(edge), "edge used twice in the same direction");
+            }
+        }
+        for &(a, b) in &edges {
+            assert!(edges.contains(&(b, a)), "mesh is not closed");
+        }
+    }
+
+    #[test]
+    fn test_tetrahedron() {
+        let points = [
+            Point3D::origin(),
+            Point3D::new(1.0, 0.0, 0.0),
+            Point3D::new(0.0, 1.0, 0.0),
+            Point3D::new(0.0, 0.0, 1.0),
+        ];
+        let hull = ConvexHull::from_points(&points).unwrap();
+        assert!(!hull.is_flat());
+        assert_eq!(hull.vertices().len(), 4);
+        assert_eq!(hull.faces().len(), 4);
+        assert!((hull.volume() - 1.0 / 6.0).abs() < 1e-12);
+        assert_closed_and_outward(&hull);
+    }
+
+    #[test]
+    fn test_cube_with_interior_coplanar_and_duplicate_points() {
+        let mut points = cube_points();
+        points.extend(cube_points());
+        points.push(Point3D::origin());
+        points.push(Point3D::new(0.5, -0.3, 0.2));
+        points.push(Point3D::new(1.0, 0.0, 0.0));
+        points.push(Point3D::new(0.0, 1.0, 0.5));
+        points.push(Point3D::new(1.0, 1.0, 0.0));
+
+        let hull = ConvexHull::from_points(&points).unwrap();
+        assert_eq!(hull.vertices().len(), 8);
+        assert_eq!(hull.faces().len(), 12);
+        assert!((hull.volume() - 8.0).abs() < 1e-9);
+        assert!((hull.surface_area() - 24.0).abs() < 1e-9);
+        assert_eq!(hull.triangles().len(), 12);
+        assert_closed_and_outward(&hull);
+    }
+
+    #[test]
+    fn test_sphere_cloud() {
+        let n = 200;
+        let golden = std::f64::consts::PI * (3.0 - 5.0_f64.sqrt());
+        let mut points: Vec<Point3D> = (0..n)
+            .map(|i| {
+                let y = 1.0 - 2.0 * (i as f64 + 0.5) / n as f64;
+                let r = (1.0 - y * y).sqrt();
+                let theta = golden * i as f64;
+                Point3D::new(r * theta.cos(), y, r * theta.sin())
+            })
+            .collect();
+        points.push(Point3D::new(0.1, 0.2, -0.1));
+
+        let hull = ConvexHull::from_points(&points).unwrap();
+        assert_eq!(hull.vertices().len(), n);
+        assert_eq!(hull.faces().len(), 2 * n - 4);
+        let sphere_volume = 4.0 / 3.0 * std::f64::consts::PI;
+        assert!(hull.volume() < sphere_volume && hull.volume() > 0.95 * sphere_volume);
+        assert!(points.iter().all(|p| hull.contains_point(p)));
+        assert!(!hull.contains_point(&Point3D::new(1.1, 0.0, 0.0)));
+        assert_closed_and_outward(&hull);
+    }
+
+    #[test]
+    fn test_surface_points_are_dropped() {
+        let n = 64;
+        let rim = |i: usize, z: f64| {
+            let angle = std::f64::consts::TAU * i as f64 / n as f64;
+            Point3D::new(angle.cos(), angle.sin(), z)
+        };
+        let mut points = Vec::new();
+        for i in 0..n {
+            points.push(rim(i, 0.0));
+            points.push(rim(i, 1.0));
+            // On the side edges and inside the side quads.
+            points.push(rim(i, 0.5));
+            points.push(Point3D::midpoint(&rim(i, 0.25), &rim(i + 1, 0.25)));
+        }
+        for z in [0.0, 1.0] {
+            points.push(Point3D::new(0.0, 0.0, z));
+            points.push(Point3D::new(0.3, -0.2, z));
+        }
+
+        let hull = ConvexHull::from_points(&points).unwrap();
+        assert_eq!(hull.vertices().len(), 2 * n);
+        assert_eq!(hull.faces().len(), 4 * n - 4);
+        let prism_volume = n as f64 / 2.0 * (std::f64::consts::TAU / n as f64).sin();
+        assert!((hull.volume() - prism_volume).abs() < 1e-9);
+        assert_closed_and_outward(&hull);
+    }
+
+    #[test]
+    fn test_degenerate_input() {
+        assert!(matches!(
+            ConvexHull::from_points(&[]),
+            Err(GeometryError::InvalidParameter(_))
+        ));
+        assert!(matches!(
+            ConvexHull::from_points(&[Point3D::origin(), Point3D::origin()]),
+            Err(GeometryError::DegenerateCase(_))
+        ));
+        let collinear: Vec<Point3D> = (0..5)
+            .map(|i| Point3D::new(i as f64, 2.0 * i as f64, 0.0))
+            .collect();
+        assert!(matches!(
+            ConvexHull::from_points(&collinear),
+            Err(GeometryError::DegenerateCase(_))
+        ));
+        assert!(matches!(
+            convex_hull_2d(&collinear),
+            Err(GeometryError::DegenerateCase(_))
+        ));
+    }
+
+    #[test]
+    fn test_planar_fallback() {
+        let points = [
+            Point3D::new(0.0, 0.0, 2.0),
+            Point3D::new(2.0, 0.0, 2.0),
+            Point3D::new(1.0, 1.0, 2.0),
+            Point3D::new(2.0, 2.0, 2.0),
+            Point3D::new(1.0, 0.0, 2.0),
+            Point3D::new(0.0, 2.0, 2.0),
+            Point3D::new(2.0, 2.0, 2.0),
+        ];
+        let polygon = convex_hull_2d(&points).unwrap();
+        assert_eq!(polygon.len(), 4);
+        for corner in [
+            Point3D::new(0.0, 0.0, 2.0),
+            Point3D::new(2.0, 0.0, 2.0),
+            Point3D::new(2.0, 2.0, 2.0),
+            Point3D::new(0.0, 2.0, 2.0),
+        ] {
+            assert!(polygon.contains(&corner));
+        }
+
+        let turn = |i: usize| {
+            let a = polygon[i];
+            let b = polygon[(i + 1) % 4];
+            let c = polygon[(i + 2) % 4];
+            (b - a).cross(&(c - b))
+        };
+        for i in 1..4 {
+            assert!(turn(i).dot(&turn(0)) > 0.0);
+        }
+
+        let hull = ConvexHull::from_points(&points).unwrap();
+        assert!(hull.is_flat());
+        assert_eq!(hull.vertices().len(), 4);
+        for corner in &polygon {
+            assert!(hull.vertices().contains(corner));
+        }
+        assert_eq!(hull.faces().len(), 4);
+        assert_eq!(hull.volume(), 0.0);
+        assert!((hull.surface_area() - 8.0).abs() < 1e-12);
+        let edges: HashSet<(usize, usize)> = hull
+            .faces()
+            .iter()
+            .flat_map(|&[a, b, c]| [(a, b), (b, c), (c, a)])
+            .collect();
+        assert!(edges.iter().all(|&(a, b)| edges.contains(&(b, a))));
+        assert!(hull.contains_point(&Point3D::new(1.0, 0.5, 2.0)));
+        assert!(!hull.contains_point(&Point3D::new(3.0, 1.0, 2.0)));
+        assert!(!hull.contains_point(&Point3D::new(1.0, 1.0, 2.1)));
+        assert_eq!(
+            hull.support(&Vector3D::new(1.0, 1.0, 0.0)),
+            Point3D::new(2.0, 2.0, 2.0)
+        );
+
+        let mut lifted = points.to_vec();
+        lifted.push(Point3D::new(1.0, 1.0, 3.0));
+        assert!(matches!(
+            convex_hull_2d(&lifted),
+            Err(GeometryError::InvalidParameter(_))
+        ));
+    }
+
+    #[test]
+    fn test_support_map() {
+        let hull = ConvexHull::from_points(&cube_points()).unwrap();
+        assert_eq!(
+            hull.support(&Vector3D::new(1.0, 2.0, -3.0)),
+            Point3D::new(1.0, 1.0, -1.0)
+        );
+    }
+}
@@ -1,8 +1,20 @@
-use crate::primitives::{Plane, Point3D, Ray, Sphere, Triangle, Vector3D, AABB};
-use crate::utils::approx_zero;
+use std::f64::consts::PI;
+
+use super::hit::RayHit;
+use crate::primitives::{Plane, Ray, Sphere, Triangle, Vector3D, AABB};
+use crate::utils::{approx_eq, approx_zero};
 
 #[inline]
-pub fn ray_plane_intersection(ray: &Ray, plane: &Plane) -> Option<Point3D> {
+pub fn ray_plane_intersection(ray: &Ray, plane: &Plane) -> Option<RayHit> {
+    ray_plane_intersection_in_range(ray, plane, ray.t_min, ray.t_max)
+}
+
+pub fn ray_plane_intersection_in_range(
+    ray: &Ray,
+    plane: &Plane,
+    t_min: f64,
+    t_max: f64,
+) -> Option<RayHit> {
     let denom = plane.normal.dot(&ray.direction);
 
     if approx_zero(denom) {
@@ -12,15 +24,51 @@ pub fn ray_plane_intersection(ray: &Ray, plane: &Plane) -> Option<Point3D> {
     let origin_as_vec = Vector3D::new(ray.origin.x, ray.origin.y, ray.origin.z);
     let t = -(plane.normal.dot(&origin_as_vec) + plane.d) / denom;
 
-    if t < 0.0 {
+    if t < t_min || t > t_max {
         return None;
     }
 
-    Some(ray.point_at(t))
+    Some(RayHit::new(ray, t, plane.normal))
+}
+
+pub fn ray_plane_intersections(ray: &Ray, plane: &Plane) -> Vec<RayHit> {
+    ray_plane_intersection(ray, plane).into_iter().collect()
+}
+
+#[inline]
+pub fn ray_sphere_intersection(ray: &Ray, sphere: &Sphere) -> Option<RayHit> {
+    ray_sphere_intersection_in_range(ray, sphere, ray.t_min, ray.t_max)
+}
+
+pub fn ray_sphere_intersection_in_range(
+    ray: &Ray,
+    sphere: &Sphere,
+    t_min: f64,
+    t_max: f64,
+) -> Option<RayHit> {
+    let (t1, t2) = ray_sphere_roots(ray, sphere)?;
+
+    [t1, t2]
+        .into_iter()
+        .find(|t| *t >= t_min && *t <= t_max)
+        .map(|t| sphere_hit(ray, sphere, t))
+}
+
+pub fn ray_sphere_intersections(ray: &Ray, sphere: &Sphere) -> Vec<RayHit> {
+    let Some((t1, t2)) = ray_sphere_roots(ray, sphere) else {
+        return Vec::new();
+    };
+    let roots: &[f64] = if approx_eq(t1, t2) { &[t1] } else { &[t1, t2] };
+
+    roots
+        .iter()
+        .filter(|t| ray.contains_t(**t))
+        .map(|t| sphere_hit(ray, sphere, *t))
+        .collect()
 }
 
 #[inline]
-pub fn ray_sphere_intersection(ray: &Ray, sphere: &Sphere) -> Option<(f64, f64)> {
+pub fn ray_sphere_roots(ray: &Ray, sphere: &Sphere) -> Option<(f64, f64)> {
     let oc = ray.origin - sphere.center;
 
     let a = ray.direction.dot(&ray.direction);
@@ -41,7 +89,16 @@ pub fn ray_sphere_intersection(ray: &Ray, sphere: &Sphere) -> Option<(f64, f64)>
 }
 
 #[inline]
-pub fn ray_triangle_intersection(ray: &Ray, triangle: &Triangle) -> Option<f64> {
+pub fn ray_triangle_intersection(ray: &Ray, triangle: &Triangle) -> Option<RayHit> {
+    ray_triangle_intersection_in_range(ray, triangle, ray.t_min, ray.t_max)
+}
+
+pub fn ray_triangle_intersection_in_range(
+    ray: &Ray,
+    triangle: &Triangle,
+    t_min: f64,
+    t_max: f64,
+) -> Option<RayHit> {
     let edge1 = triangle.b - triangle.a;
     let edge2 = triangle.c - triangle.a;
 
@@ -69,70 +126,143 @@ pub fn ray_triangle_intersection(ray: &Ray, triangle: &Triangle) -> Option<f64>
 
     let t = f * edge2.dot(&q);
 
-    if t > 0.0 {
-        Some(t)
-    } else {
-        None
+    if t < t_min || t > t_max {
+        return None;
     }
+
+    Some(
+        RayHit::new(ray, t, triangle.normal())
+            .with_uv(u, v)
+            .with_barycentric(1.0 - u - v, u, v),
+    )
+}
+
+pub fn ray_triangle_intersections(ray: &Ray, triangle: &Triangle) -> Vec<RayHit> {
+    ray_triangle_intersection(ray, triangle)
+        .into_iter()
+        .collect()
 }
 
 #[inline]
-pub fn ray_aabb_intersection(ray: &Ray, aabb: &AABB) -> Option<(f64, f64)> {
-    let mut tmin = f64::NEG_INFINITY;
-    let mut tmax = f64::INFINITY;
+pub fn ray_aabb_intersection(ray: &Ray, aabb: &AABB) -> Option<RayHit> {
+    ray_aabb_intersection_in_range(ray, aabb, ray.t_min, ray.t_max)
+}
 
-    for i in 0..3 {
-        let origin_component = match i {
-            0 => ray.origin.x,
-            1 => ray.origin.y,
-            _ => ray.origin.z,
-        };
-
-        let dir_component = match i {
-            0 => ray.direction.x,
-            1 => ray.direction.y,
-            _ => ray.direction.z,
-        };
-
-        let min_component = match i {
-            0 => aabb.min.x,
-            1 => aabb.min.y,
-            _ => aabb.min.z,
-        };
-
-        let max_component = match i {
-            0 => aabb.max.x,
-            1 => aabb.max.y,
-            _ => aabb.max.z,
-        };
-
-        if approx_zero(dir_component) {
-            if origin_component < min_component || origin_component > max_component {
-                return None;
-            }
-        } else {
-            let inv_d = 1.0 / dir_component;
-            let mut t0 = (min_component - origin_component) * inv_d;
-            let mut t1 = (max_component - origin_component) * inv_d;
+pub fn ray_aabb_intersection_in_range(
+    ray: &Ray,
+    aabb: &AABB,
+    t_min: f64,
+    t_max: f64,
+) -> Option<RayHit> {
+    let (enter, exit) = ray_aabb_slabs(ray, aabb)?;
+
+    [enter, exit]
+        .iter()
+        .find(|crossing| crossing.t >= t_min && crossing.t <= t_max)
+        .map(|crossing| aabb_hit(ray, aabb, crossing))
+}
 
-            if t0 > t1 {
-                std::mem::swap(&mut t0, &mut t1);
-            }
+pub fn ray_aabb_intersections(ray: &Ray, aabb: &AABB) -> Vec<RayHit> {
+    let Some((enter, exit)) = ray_aabb_slabs(ray, aabb) else {
+        return Vec::new();
+    };
+    let crossings: &[Crossing] = if approx_eq(enter.t, exit.t) {
+        &[enter]
+    } else {
+        &[enter, exit]
+    };
+
+    crossings
+        .iter()
+        .filter(|crossing| ray.contains_t(crossing.t))
+        .map(|crossing| aabb_hit(ray, aabb, crossing))
+        .collect()
+}
+
+/// Returns the part of the ray's `[t_min, t_max]` interval that lies inside
+/// the box, or `None` when they do not overlap.
+#[inline]
+pub fn ray_aabb_interval(ray: &Ray, aabb: &AABB) -> Option<(f64, f64)> {
+    let (enter, exit) = ray_aabb_slabs(ray, aabb)?;
+    let enter = enter.t.max(ray.t_min);
+    let exit = exit.t.min(ray.t_max);
+
+    if enter > exit {
+        return None;
+    }
 
-            tmin = tmin.max(t0);
-            tmax = tmax.min(t1);
+    Some((enter, exit))
+}
 
-            if tmin > tmax {
+/// Where a ray crosses a box face: the ray parameter, the axis of the face
+/// and the sign of its outward normal along that axis.
+#[derive(Debug, Clone, Copy)]
+struct Crossing {
+    t: f64,
+    axis: usize,
+    sign: f64,
+}
+
+/// Entry and exit crossings of the ray's line through the box, each taken
+/// from the slab that set it. When slabs tie, as on edges, the face the ray
+/// crosses most steeply wins.
+fn ray_aabb_slabs(ray: &Ray, aabb: &AABB) -> Option<(Crossing, Crossing)> {
+    let mut enter = Crossing {
+        t: f64::NEG_INFINITY,
+        axis: 0,
+        sign: -1.0,
+    };
+    let mut exit = Crossing {
+        t: f64::INFINITY,
+        axis: 0,
+        sign: 1.0,
+    };
+    let origin = [ray.origin.x, ray.origin.y, ray.origin.z];
+    let direction = [ray.direction.x, ray.direction.y, ray.direction.z];
+    let min = [aabb.min.x, aabb.min.y, aabb.min.z];
+    let max = [aabb.max.x, aabb.max.y, aabb.max.z];
+    let steeper = |t: f64, axis: usize, current: &Crossing| {
+        approx_eq(t, current.t) && direction[axis].abs() > direction[current.axis].abs()
+    };
+
+    for i in 0..3 {
+        if approx_zero(direction[i]) {
+            if origin[i] < min[i] || origin[i] > max[i] {
                 return None;
             }
+            continue;
         }
-    }
 
-    if tmax < 0.0 {
-        return None;
+        let inv_d = 1.0 / direction[i];
+        let mut t0 = (min[i] - origin[i]) * inv_d;
+        let mut t1 = (max[i] - origin[i]) * inv_d;
+        // Moving along +axis the ray enters through the min face.
+        let entry_sign = if inv_d > 0.0 { -1.0 } else { 1.0 };
+        if t0 > t1 {
+            std::mem::swap(&mut t0, &mut t1);
+        }
+
+        if t0 > enter.t || steeper(t0, i, &enter) {
+            enter = Crossing {
+                t: t0,
+                axis: i,
+                sign: entry_sign,
+            };
+        }
+        if t1 < exit.t || steeper(t1, i, &exit) {
+            exit = Crossing {
+                t: t1,
+                axis: i,
+                sign: -entry_sign,
+            };
+        }
+
+        if enter.t > exit.t {
+            return None;
+        }
     }
 
-    Some((tmin, tmax))
+    Some((enter, exit))
 }
 
 #[inline]
@@ -147,6 +277,48 @@ pub fn sphere_sphere_intersection(s1: &Sphere, s2: &Sphere) -> bool {
     distance_squared <= sum_radii * sum_radii
 }
 
+fn sphere_hit(ray: &Ray, sphere: &Sphere, t: f64) -> RayHit {
+    let point = ray.point_at(t);
+    let outward = (point - sphere.center) / sphere.radius;
+    let (u, v) = sphere_uv(&outward);
+
+    RayHit::new(ray, t, outward).with_uv(u, v)
+}
+
+fn aabb_hit(ray: &Ray, aabb: &AABB, crossing: &Crossing) -> RayHit {
+    let point = ray.point_at(crossing.t);
+    let outward = match crossing.axis {
+        0 => Vector3D::new(crossing.sign, 0.0, 0.0),
+        1 => Vector3D::new(0.0, crossing.sign, 0.0),
+        _ => Vector3D::new(0.0, 0.0, crossing.sign),
+    };
+
+    // Position across the box from its min corner, 0 along flat axes.
+    let fraction = |p: f64, min: f64, max: f64| {
+        if max > min {
+            (p - min) / (max - min)
+        } else {
+            0.0
+        }
+    };
+    let x = fraction(point.x, aabb.min.x, aabb.max.x);
+    let y = fraction(point.y, aabb.min.y, aabb.max.y);
+    let z = fraction(point.z, aabb.min.z, aabb.max.z);
+    let (u, v) = match crossing.axis {
+        0 => (z, y),
+        1 => (x, z),
+        _ => (x, y),
+    };
+
+    RayHit::new(ray, crossing.t, outward).with_uv(u, v)
+}
+
+fn sphere_uv(outward: &Vector3D) -> (f64, f64) {
+    let theta = (-outward.y).clamp(-1.0, 1.0).acos();
+    let phi = (-outward.z).atan2(outward.x) + PI;
+    (phi / (2.0 * PI), theta / PI)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -163,8 +335,11 @@ mod tests {
         let intersection = ray_plane_intersection(&ray, &plane);
         assert!(intersection.is_some());
 
-        let point = intersection.unwrap();
-        assert!(approx_eq(point.z, 5.0));
+        let hit = intersection.unwrap();
+        assert!(approx_eq(hit.t, 5.0));
+        assert!(approx_eq(hit.point.z, 5.0));
+        assert!(!hit.front_face);
+        assert_eq!(hit.normal, Vector3D::new(0.0, 0.0, -1.0));
     }
 
     #[test]
@@ -186,7 +361,31 @@ mod tests {
         let intersection = ray_sphere_intersection(&ray, &sphere);
         assert!(intersection.is_some());
 
-        let (t1, t2) = intersection.unwrap();
+        let hit = intersection.unwrap();
+        assert!(approx_eq(hit.t, 4.0));
+        assert_eq!(hit.point, Point3D::new(0.0, 0.0, 4.0));
+        assert_eq!(hit.normal, Vector3D::new(0.0, 0.0, -1.0));
+        assert!(hit.front_face);
+        assert!(hit.uv.is_some());
+    }
+
+    #[test]
+    fn test_ray_sphere_intersection_from_inside() {
+        let ray = Ray::new(Point3D::origin(), Vector3D::new(1.0, 0.0, 0.0)).unwrap();
+        let sphere = Sphere::new(Point3D::origin(), 2.0).unwrap();
+
+        let hit = ray_sphere_intersection(&ray, &sphere).unwrap();
+        assert!(approx_eq(hit.t, 2.0));
+        assert!(!hit.front_face);
+        assert_eq!(hit.normal, Vector3D::new(-1.0, 0.0, 0.0));
+    }
+
+    #[test]
+    fn test_ray_sphere_roots() {
+        let ray = Ray::new(Point3D::origin(), Vector3D::new(0.0, 0.0, 1.0)).unwrap();
+        let sphere = Sphere::new(Point3D::new(0.0, 0.0, 5.0), 1.0).unwrap();
+
+        let (t1, t2) = ray_sphere_roots(&ray, &sphere).unwrap();
         assert!(approx_eq(t1, 4.0));
         assert!(approx_eq(t2, 6.0));
     }
@@ -212,7 +411,17 @@ mod tests {
 
         let intersection = ray_triangle_intersection(&ray, &triangle);
         assert!(intersection.is_some());
-        assert!(approx_eq(intersection.unwrap(), 1.0));
+
+        let hit = intersection.unwrap();
+        assert!(approx_eq(hit.t, 1.0));
+        assert_eq!(hit.point, Point3D::new(0.5, 0.5, 0.0));
+        assert!(!hit.front_face);
+        assert_eq!(hit.normal, Vector3D::new(0.0, 0.0, -1.0));
+
+        let (w, u, v) = hit.barycentric.unwrap();
+        assert!(approx_eq(w, 0.0));
+        assert!(approx_eq(u, 0.5));
+        assert!(approx_eq(v, 0.5));
     }
 
     #[test]
@@ -237,7 +446,23 @@ mod tests {
         let intersection = ray_aabb_intersection(&ray, &aabb);
         assert!(intersection.is_some());
 
-        let (tmin, tmax) = intersection.unwrap();
+        let hit = intersection.unwrap();
+        assert!(approx_eq(hit.t, 1.0));
+        assert_eq!(hit.point, Point3D::new(1.0, 0.0, 0.0));
+        assert_eq!(hit.normal, Vector3D::new(-1.0, 0.0, 0.0));
+        assert!(hit.front_face);
+
+        let (u, v) = hit.uv.unwrap();
+        assert!(approx_eq(u, 0.5));
+        assert!(approx_eq(v, 0.5));
+    }
+
+    #[test]
+    fn test_ray_aabb_interval() {
+        let ray = Ray::new(Point3D::origin(), Vector3D::new(1.0, 0.0, 0.0)).unwrap();
+        let aabb = AABB::new(Point3D::new(1.0, -1.0, -1.0), Point3D::new(3.0, 1.0, 1.0)).unwrap();
+
+        let (tmin, tmax) = ray_aabb_interval(&ray, &aabb).unwrap();
         assert!(approx_eq(tmin, 1.0));
         assert!(approx_eq(tmax, 3.0));
     }
@@ -251,6 +476,172 @@ mod tests {
         assert!(intersection.is_none());
     }
 
+    fn unit_box() -> AABB {
+        AABB::new(Point3D::new(-1.0, -1.0, -1.0), Point3D::new(1.0, 1.0, 1.0)).unwrap()
+    }
+
+    #[test]
+    fn test_ray_sphere_behind_origin() {
+        let ray = Ray::new(Point3D::new(0.0, 0.0, 5.0), Vector3D::unit_z()).unwrap();
+        let sphere = Sphere::new(Point3D::origin(), 1.0).unwrap();
+
+        assert!(ray_sphere_intersection(&ray, &sphere).is_none());
+        assert!(ray_sphere_intersections(&ray, &sphere).is_empty());
+    }
+
+    #[test]
+    fn test_ray_sphere_in_range() {
+        let ray = Ray::new(Point3D::new(0.0, 0.0, -5.0), Vector3D::unit_z()).unwrap();
+        let sphere = Sphere::new(Point3D::origin(), 1.0).unwrap();
+
+        assert!(ray_sphere_intersection_in_range(&ray, &sphere, 0.0, 3.9).is_none());
+
+        let exit = ray_sphere_intersection_in_range(&ray, &sphere, 4.5, 10.0).unwrap();
+        assert!(approx_eq(exit.t, 6.0));
+        assert!(!exit.front_face);
+
+        let bounded = ray.with_interval(0.0, 5.0).unwrap();
+        let hits = ray_sphere_intersections(&bounded, &sphere);
+        assert_eq!(hits.len(), 1);
+        assert!(approx_eq(hits[0].t, 4.0));
+    }
+
+    #[test]
+    fn test_ray_sphere_all_hits() {
+        let ray = Ray::new(Point3D::new(0.0, 0.0, -5.0), Vector3D::unit_z()).unwrap();
+        let sphere = Sphere::new(Point3D::origin(), 1.0).unwrap();
+
+        let hits = ray_sphere_intersections(&ray, &sphere);
+        assert_eq!(hits.len(), 2);
+        assert!(approx_eq(hits[0].t, 4.0));
+        assert!(hits[0].front_face);
+        assert!(approx_eq(hits[1].t, 6.0));
+        assert!(!hits[1].front_face);
+
+        let inside = Ray::new(Point3D::origin(), Vector3D::unit_z()).unwrap();
+        assert_eq!(ray_sphere_intersections(&inside, &sphere).len(), 1);
+    }
+
+    #[test]
+    fn test_ray_sphere_grazing() {
+        let ray = Ray::new(Point3D::new(-5.0, 1.0, 0.0), Vector3D::unit_x()).unwrap();
+        let sphere = Sphere::new(Point3D::origin(), 1.0).unwrap();
+
+        let hits = ray_sphere_intersections(&ray, &sphere);
+        assert_eq!(hits.len(), 1);
+        assert!(approx_eq(hits[0].t, 5.0));
+        assert_eq!(hits[0].normal, Vector3D::unit_y());
+    }
+
+    #[test]
+    fn test_ray_triangle_accepts_t_zero() {
+        let ray = Ray::new(Point3D::new(0.25, 0.25, 0.0), Vector3D::unit_z()).unwrap();
+        let triangle = Triangle::new(
+            Point3D::new(0.0, 0.0, 0.0),
+            Point3D::new(1.0, 0.0, 0.0),
+            Point3D::new(0.0, 1.0, 0.0),
+        )
+        .unwrap();
+
+        let hit = ray_triangle_intersection(&ray, &triangle).unwrap();
+        assert_eq!(hit.t, 0.0);
+
+        let offset = ray.with_interval(1e-6, f64::INFINITY).unwrap();
+        assert!(ray_triangle_intersection(&offset, &triangle).is_none());
+    }
+
+    #[test]
+    fn test_ray_triangle_edge_on_misses() {
+        let ray = Ray::new(Point3D::new(-1.0, 0.25, 0.0), Vector3D::unit_x()).unwrap();
+        let triangle = Triangle::new(
+            Point3D::new(0.0, 0.0, 0.0),
+            Point3D::new(1.0, 0.0, 0.0),
+            Point3D::new(0.0, 1.0, 0.0),
+        )
+        .unwrap();
+
+        assert!(ray_triangle_intersection(&ray, &triangle).is_none());
+    }
+
+    #[test]
+    fn test_ray_plane_in_range() {
+        let ray = Ray::new(Point3D::origin(), Vector3D::unit_z()).unwrap();
+        let plane =
+            Plane::from_point_normal(&Point3D::new(0.0, 0.0, 5.0), &Vector3D::unit_z()).unwrap();
+
+        assert!(ray_plane_intersection_in_range(&ray, &plane, 0.0, 4.0).is_none());
+        assert!(ray_plane_intersection_in_range(&ray, &plane, 0.0, 5.0).is_some());
+        assert_eq!(ray_plane_intersections(&ray, &plane).len(), 1);
+    }
+
+    #[test]
+    fn test_ray_aabb_from_inside() {
+        let ray = Ray::new(Point3D::origin(), Vector3D::unit_x()).unwrap();
+
+        let hit = ray_aabb_intersection(&ray, &unit_box()).unwrap();
+        assert!(approx_eq(hit.t, 1.0));
+        assert!(!hit.front_face);
+        assert_eq!(hit.normal, Vector3D::new(-1.0, 0.0, 0.0));
+
+        let (enter, exit) = ray_aabb_interval(&ray, &unit_box()).unwrap();
+        assert_eq!(enter, 0.0);
+        assert!(approx_eq(exit, 1.0));
+    }
+
+    #[test]
+    fn test_ray_aabb_behind_origin() {
+        let ray = Ray::new(Point3D::new(5.0, 0.0, 0.0), Vector3D::unit_x()).unwrap();
+
+        assert!(ray_aabb_intersection(&ray, &unit_box()).is_none());
+        assert!(ray_aabb_interval(&ray, &unit_box()).is_none());
+    }
+
+    #[test]
+    fn test_ray_aabb_all_hits() {
+        let ray = Ray::new(Point3D::new(-5.0, 0.0, 0.0), Vector3D::unit_x()).unwrap();
+
+        let hits = ray_aabb_intersections(&ray, &unit_box());
+        assert_eq!(hits.len(), 2);
+        assert!(approx_eq(hits[0].t, 4.0));
+        assert!(hits[0].front_face);
+        assert!(approx_eq(hits[1].t, 6.0));
+        assert!(!hits[1].front_face);
+        assert_eq!(hits[1].normal, Vector3D::new(-1.0, 0.0, 0.0));
+
+        let exit = ray_aabb_intersection_in_range(&ray, &unit_box(), 5.0, 10.0).unwrap();
+        assert!(approx_eq(exit.t, 6.0));
+    }
+
+    #[test]
+    fn test_ray_aabb_grazing_face() {
+        let ray = Ray::new(Point3D::new(-5.0, 1.0, 0.0), Vector3D::unit_x()).unwrap();
+
+        let hit = ray_aabb_intersection(&ray, &unit_box()).unwrap();
+        assert!(approx_eq(hit.t, 4.0));
+        assert!(hit.front_face);
+        assert_eq!(hit.normal, Vector3D::new(-1.0, 0.0, 0.0));
+    }
+
+    #[test]
+    fn test_ray_aabb_flat_box() {
+        let ray = Ray::new(Point3D::new(0.5, 0.5, 5.0), Vector3D::new(0.0, 0.0, -1.0)).unwrap();
+        let flat = AABB::from_points(&[Point3D::origin(), Point3D::new(2.0, 2.0, 0.0)]).unwrap();
+
+        let hit = ray_aabb_intersection(&ray, &flat).unwrap();
+        assert!(approx_eq(hit.t, 5.0));
+        assert!(hit.front_face);
+        assert_eq!(hit.normal, Vector3D::unit_z());
+        assert_eq!(hit.uv, Some((0.25, 0.25)));
+        assert_eq!(ray_aabb_intersections(&ray, &flat).len(), 1);
+
+        // Sideways onto a box flat in y: the flat axis maps to uv 0.
+        let ray = Ray::new(Point3D::new(-5.0, 0.0, 1.0), Vector3D::unit_x()).unwrap();
+        let flat = AABB::from_points(&[Point3D::origin(), Point3D::new(2.0, 0.0, 2.0)]).unwrap();
+        let hit = ray_aabb_intersection(&ray, &flat).unwrap();
+        assert_eq!(hit.normal, Vector3D::new(-1.0, 0.0, 0.0));
+        assert_eq!(hit.uv, Some((0.5, 0.0)));
+    }
+
     #[test]
     fn test_aabb_aabb_intersection_true() {
         let a = AABB::new(Point3D::new(0.0, 0.0, 0.0), Point3D::new(2.0, 2.0, 2.0)).unwrap();
@@ -1,10 +1,32 @@
+mod contact;
 mod distance;
+mod gjk;
+mod hit;
+mod hull;
 mod intersection;
+mod support;
+mod sweep;
 mod transform;
 
+pub use contact::{
+    contact_aabb_aabb, contact_capsule_capsule, contact_capsule_sphere, contact_obb_obb,
+    contact_sphere_aabb, contact_sphere_sphere, contact_sphere_triangle, Contact, Manifold,
+};
 pub use distance::{chebyshev_distance, manhattan_distance};
+pub use gjk::{epa_penetration, gjk_distance, gjk_intersects, ClosestPoints, Penetration};
+pub use hit::RayHit;
+pub use hull::{convex_hull_2d, ConvexHull};
 pub use intersection::{
-    aabb_aabb_intersection, ray_aabb_intersection, ray_plane_intersection, ray_sphere_intersection,
-    ray_triangle_intersection, sphere_sphere_intersection,
+    aabb_aabb_intersection, ray_aabb_intersection, ray_aabb_intersection_in_range,
+    ray_aabb_intersections, ray_aabb_interval, ray_plane_intersection,
+    ray_plane_intersection_in_range, ray_plane_intersections, ray_sphere_intersection,
+    ray_sphere_intersection_in_range, ray_sphere_intersections, ray_sphere_roots,
+    ray_triangle_intersection, ray_triangle_intersection_in_range, ray_triangle_intersections,
+    sphere_sphere_intersection,
+};
+pub use support::{ConvexPointSet, MinkowskiSum, SupportMap};
+pub use sweep::{
+    sweep_aabb_aabb, sweep_sphere_aabb, sweep_sphere_plane, sweep_sphere_sphere,
+    sweep_sphere_triangle, TimeOfImpact,
 };
 pub use transform::Transformable;
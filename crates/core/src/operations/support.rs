@@ -0,0 +1,245 @@
+use crate::error::GeometryError;
+use crate::primitives::{Capsule, LineSegment, Point3D, Sphere, Triangle, Vector3D, AABB, OBB};
+
+/// A convex shape described by its support function: the point of the shape
+/// that lies furthest along `direction`. `direction` need not be normalized
+/// and may be zero, in which case any point of the shape is acceptable.
+pub trait SupportMap {
+    fn support(&self, direction: &Vector3D) -> Point3D;
+}
+
+impl<T: SupportMap + ?Sized> SupportMap for &T {
+    #[inline]
+    fn support(&self, direction: &Vector3D) -> Point3D {
+        (**self).support(direction)
+    }
+}
+
+impl SupportMap for Point3D {
+    #[inline]
+    fn support(&self, _direction: &Vector3D) -> Point3D {
+        *self
+    }
+}
+
+impl SupportMap for LineSegment {
+    #[inline]
+    fn support(&self, direction: &Vector3D) -> Point3D {
+        furthest(&[self.start, self.end], direction)
+    }
+}
+
+impl SupportMap for Triangle {
+    #[inline]
+    fn support(&self, direction: &Vector3D) -> Point3D {
+        furthest(&[self.a, self.b, self.c], direction)
+    }
+}
+
+impl SupportMap for Sphere {
+    #[inline]
+    fn support(&self, direction: &Vector3D) -> Point3D {
+        let unit = direction.normalize().unwrap_or(Vector3D::unit_x());
+        self.center + unit * self.radius
+    }
+}
+
+impl SupportMap for AABB {
+    #[inline]
+    fn support(&self, direction: &Vector3D) -> Point3D {
+        Point3D::new(
+            if direction.x >= 0.0 {
+                self.max.x
+            } else {
+                self.min.x
+            },
+            if direction.y >= 0.0 {
+                self.max.y
+            } else {
+                self.min.y
+            },
+            if direction.z >= 0.0 {
+                self.max.z
+            } else {
+                self.min.z
+            },
+        )
+    }
+}
+
+impl SupportMap for OBB {
+    fn support(&self, direction: &Vector3D) -> Point3D {
+        let mut point = self.center;
+        for (axis, extent) in self.axes.iter().zip(self.half_extents) {
+            let sign = if axis.dot(direction) >= 0.0 {
+                1.0
+            } else {
+                -1.0
+            };
+            point = point + *axis * (sign * extent);
+        }
+        point
+    }
+}
+
+impl SupportMap for Capsule {
+    #[inline]
+    fn support(&self, direction: &Vector3D) -> Point3D {
+        let unit = direction.normalize().unwrap_or(Vector3D::unit_x());
+        furthest(&[self.start, self.end], direction) + unit * self.radius
+    }
+}
+
+/// The convex hull of a set of points, represented implicitly by the points
+/// themselves.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ConvexPointSet {
+    points: Vec<Point3D>,
+}
+
+impl ConvexPointSet {
+    pub fn new(points: Vec<Point3D>) -> Result<Self, GeometryError> {
+        if points.is_empty() {
+            return Err(GeometryError::InvalidParameter(
+                "Cannot create convex point set from empty point list".to_string(),
+            ));
+        }
+        Ok(Self { points })
+    }
+
+    #[inline]
+    pub fn points(&self) -> &[Point3D] {
+        &self.points
+    }
+}
+
+impl SupportMap for ConvexPointSet {
+    #[inline]
+    fn support(&self, direction: &Vector3D) -> Point3D {
+        furthest(&self.points, direction)
+    }
+}
+
+/// Minkowski sum of two convex shapes. The second shape is offset by its
+/// position relative to the origin, so a box plus a sphere centred at the
+/// origin gives a box with rounded edges and corners.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MinkowskiSum<A, B> {
+    pub a: A,
+    pub b: B,
+}
+
+impl<A: SupportMap, B: SupportMap> MinkowskiSum<A, B> {
+    pub fn new(a: A, b: B) -> Self {
+        Self { a, b }
+    }
+}
+
+impl<A: SupportMap, B: SupportMap> SupportMap for MinkowskiSum<A, B> {
+    #[inline]
+    fn support(&self, direction: &Vector3D) -> Point3D {
+        self.a.support(direction) + (self.b.support(direction) - Point3D::origin())
+    }
+}
+
+fn furthest(points: &[Point3D], direction: &Vector3D) -> Point3D {
+    let origin = Point3D::origin();
+    let mut best = points[0];
+    let mut best_dot = (best - origin).dot(direction);
+    for point in &points[1..] {
+        let dot = (*point - origin).dot(direction);
+        if dot > best_dot {
+            best = *point;
+            best_dot = dot;
+        }
+    }
+    best
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_primitive_supports() {
+        let d = Vector3D::new(1.0, -1.0, 0.5);
+
+        let segment =
+            LineSegment::new(Point3D::new(-1.0, 0.0, 0.0), Point3D::new(1.0, 0.0, 0.0)).unwrap();
+        assert_eq!(segment.support(&d), Point3D::new(1.0, 0.0, 0.0));
+
+        let aabb = AABB::new(Point3D::new(-1.0, -2.0, -3.0), Point3D::new(1.0, 2.0, 3.0)).unwrap();
+        assert_eq!(aabb.support(&d), Point3D::new(1.0, -2.0, 3.0));
+        assert_eq!(
+            OBB::from_aabb(&aabb).support(&d),
+            Point3D::new(1.0, -2.0, 3.0)
+        );
+
+        let sphere = Sphere::new(Point3D::new(1.0, 1.0, 1.0), 2.0).unwrap();
+        assert_eq!(
+            sphere.support(&Vector3D::unit_y()),
+            Point3D::new(1.0, 3.0, 1.0)
+        );
+
+        let triangle = Triangle::new(
+            Point3D::origin(),
+            Point3D::new(1.0, 0.0, 0.0),
+            Point3D::new(0.0, 1.0, 0.0),
+        )
+        .unwrap();
+        assert_eq!(
+            triangle.support(&Vector3D::unit_y()),
+            Point3D::new(0.0, 1.0, 0.0)
+        );
+
+        let point = Point3D::new(4.0, 5.0, 6.0);
+        assert_eq!(point.support(&d), point);
+    }
+
+    #[test]
+    fn test_capsule_support() {
+        let capsule = Capsule::new(Point3D::origin(), Point3D::new(0.0, 3.0, 0.0), 0.5).unwrap();
+        assert_eq!(
+            capsule.support(&Vector3D::unit_y()),
+            Point3D::new(0.0, 3.5, 0.0)
+        );
+        assert_eq!(
+            capsule.support(&Vector3D::unit_x()),
+            Point3D::new(0.5, 0.0, 0.0)
+        );
+    }
+
+    #[test]
+    fn test_convex_point_set() {
+        assert!(ConvexPointSet::new(Vec::new()).is_err());
+        let set = ConvexPointSet::new(vec![
+            Point3D::origin(),
+            Point3D::new(2.0, 0.0, 0.0),
+            Point3D::new(0.0, 3.0, 0.0),
+        ])
+        .unwrap();
+        assert_eq!(set.points().len(), 3);
+        assert_eq!(
+            set.support(&Vector3D::new(1.0, 1.0, 0.0)),
+            Point3D::new(0.0, 3.0, 0.0)
+        );
+    }
+
+    #[test]
+    fn test_minkowski_sum_rounded_box() {
+        let aabb = AABB::new(Point3D::new(-1.0, -1.0, -1.0), Point3D::new(1.0, 1.0, 1.0)).unwrap();
+        let rounding = Sphere::new(Point3D::origin(), 0.5).unwrap();
+        let rounded = MinkowskiSum::new(aabb, rounding);
+
+        assert_eq!(
+            rounded.support(&Vector3D::unit_x()),
+            Point3D::new(1.5, 1.0, 1.0)
+        );
+        let corner = rounded.support(&Vector3D::new(1.0, 1.0, 1.0));
+        let offset = 0.5 / 3.0_f64.sqrt();
+        assert_eq!(
+            corner,
+            Point3D::new(1.0 + offset, 1.0 + offset, 1.0 + offset)
+        );
+    }
+}
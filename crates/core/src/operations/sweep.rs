@@ -0,0 +1,438 @@
+use super::intersection::{
+    ray_aabb_intersection, ray_sphere_intersection, ray_triangle_intersection,
+};
+use crate::primitives::{Plane, Point3D, Ray, Sphere, Triangle, Vector3D, AABB};
+use crate::utils::{approx_zero, EPSILON};
+
+/// First contact between two shapes moving linearly over one frame.
+///
+/// `time` is the fraction of the frame in `[0, 1]` at which the shapes touch,
+/// `point` is the contact point at that moment and `normal` points from the
+/// obstacle towards the moving shape.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TimeOfImpact {
+    pub time: f64,
+    pub point: Point3D,
+    pub normal: Vector3D,
+}
+
+pub fn sweep_sphere_plane(
+    sphere: &Sphere,
+    velocity: &Vector3D,
+    plane: &Plane,
+) -> Option<TimeOfImpact> {
+    let distance = plane.distance_to_point(&sphere.center);
+    let side = if distance < 0.0 { -1.0 } else { 1.0 };
+    let normal = plane.normal * side;
+
+    if distance.abs() <= sphere.radius {
+        return Some(TimeOfImpact {
+            time: 0.0,
+            point: plane.closest_point(&sphere.center),
+            normal,
+        });
+    }
+
+    let approach = plane.normal.dot(velocity);
+    if approx_zero(approach) || approach * side >= 0.0 {
+        return None;
+    }
+
+    let time = (side * sphere.radius - distance) / approach;
+    if time > 1.0 {
+        return None;
+    }
+
+    let center = sphere.center + *velocity * time;
+    Some(TimeOfImpact {
+        time,
+        point: center + normal * -sphere.radius,
+        normal,
+    })
+}
+
+pub fn sweep_sphere_sphere(
+    a: &Sphere,
+    velocity_a: &Vector3D,
+    b: &Sphere,
+    velocity_b: &Vector3D,
+) -> Option<TimeOfImpact> {
+    let relative = *velocity_a - *velocity_b;
+    let combined = Sphere::new(b.center, a.radius + b.radius).ok()?;
+
+    let time = if combined.contains_point(&a.center) {
+        0.0
+    } else {
+        let ray = sweep_ray(&a.center, &relative)?;
+        ray_sphere_intersection(&ray, &combined)?.t / relative.magnitude()
+    };
+
+    let center_a = a.center + *velocity_a * time;
+    let center_b = b.center + *velocity_b * time;
+    let normal = (center_a - center_b)
+        .normalize()
+        .or_else(|_| (-relative).normalize())
+        .unwrap_or(Vector3D::unit_z());
+
+    Some(TimeOfImpact {
+        time,
+        point: center_b + normal * b.radius,
+        normal,
+    })
+}
+
+pub fn sweep_sphere_aabb(
+    sphere: &Sphere,
+    velocity: &Vector3D,
+    aabb: &AABB,
+) -> Option<TimeOfImpact> {
+    let time = if aabb.distance_to_point(&sphere.center) <= sphere.radius {
+        0.0
+    } else {
+        let ray = sweep_ray(&sphere.center, velocity)?;
+        let r = sphere.radius;
+        let (min, max) = (aabb.min, aabb.max);
+
+        // The box inflated by the radius is the union of three slabs, twelve
+        // edge cylinders and eight corner spheres.
+        let slabs = [
+            Vector3D::new(r, 0.0, 0.0),
+            Vector3D::new(0.0, r, 0.0),
+            Vector3D::new(0.0, 0.0, r),
+        ];
+        let mut nearest = slabs
+            .iter()
+            .filter_map(|grow| AABB::new(min + -*grow, max + *grow).ok())
+            .filter_map(|slab| ray_aabb_intersection(&ray, &slab))
+            .map(|hit| hit.t)
+            .fold(f64::INFINITY, f64::min);
+
+        let corners = aabb_corners(aabb);
+        for corner in &corners {
+            nearest = nearest.min(ray_sphere_t(&ray, corner, r));
+        }
+        for (i, j) in AABB_EDGES {
+            nearest = nearest.min(ray_cylinder_t(&ray, &corners[i], &corners[j], r));
+        }
+
+        if !nearest.is_finite() {
+            return None;
+        }
+        nearest / velocity.magnitude()
+    };
+
+    let center = sphere.center + *velocity * time;
+    let point = aabb.closest_point(&center);
+    Some(TimeOfImpact {
+        time,
+        point,
+        normal: contact_normal(&center, &point, velocity),
+    })
+}
+
+pub fn sweep_sphere_triangle(
+    sphere: &Sphere,
+    velocity: &Vector3D,
+    triangle: &Triangle,
+) -> Option<TimeOfImpact> {
+    let time = if triangle.distance_to_point(&sphere.center) <= sphere.radius {
+        0.0
+    } else {
+        let ray = sweep_ray(&sphere.center, velocity)?;
+        let r = sphere.radius;
+        let offset = triangle.normal() * r;
+
+        // The triangle inflated by the radius is the union of its two offset
+        // faces, three edge cylinders and three vertex spheres.
+        let mut nearest = [offset, -offset]
+            .iter()
+            .filter_map(|shift| {
+                Triangle::new(
+                    triangle.a + *shift,
+                    triangle.b + *shift,
+                    triangle.c + *shift,
+                )
+                .ok()
+            })
+            .filter_map(|face| ray_triangle_intersection(&ray, &face))
+            .map(|hit| hit.t)
+            .fold(f64::INFINITY, f64::min);
+
+        let vertices = [triangle.a, triangle.b, triangle.c];
+        for (i, vertex) in vertices.iter().enumerate() {
+            let next = &vertices[(i + 1) % 3];
+            nearest = nearest.min(ray_sphere_t(&ray, vertex, r));
+            nearest = nearest.min(ray_cylinder_t(&ray, vertex, next, r));
+        }
+
+        if !nearest.is_finite() {
+            return None;
+        }
+        nearest / velocity.magnitude()
+    };
+
+    let center = sphere.center + *velocity * time;
+    let point = triangle.closest_point(&center);
+    Some(TimeOfImpact {
+        time,
+        point,
+        normal: contact_normal(&center, &point, velocity),
+    })
+}
+
+pub fn sweep_aabb_aabb(
+    a: &AABB,
+    velocity_a: &Vector3D,
+    b: &AABB,
+    velocity_b: &Vector3D,
+) -> Option<TimeOfImpact> {
+    let relative = *velocity_a - *velocity_b;
+    let half = a.size() * 0.5;
+    let expanded = AABB::new(b.min + -half, b.max + half).ok()?;
+    let center = a.center();
+
+    let (time, normal) = if a.intersects(b) {
+        let normal = (center - b.center())
+            .normalize()
+            .or_else(|_| (-relative).normalize())
+            .unwrap_or(Vector3D::unit_z());
+        (0.0, normal)
+    } else {
+        let ray = sweep_ray(&center, &relative)?;
+        let hit = ray_aabb_intersection(&ray, &expanded)?;
+        (hit.t / relative.magnitude(), hit.normal)
+    };
+
+    let moved_a = translated(a, &(*velocity_a * time));
+    let moved_b = translated(b, &(*velocity_b * time));
+    let overlap_min = Point3D::new(
+        moved_a.min.x.max(moved_b.min.x),
+        moved_a.min.y.max(moved_b.min.y),
+        moved_a.min.z.max(moved_b.min.z),
+    );
+    let overlap_max = Point3D::new(
+        moved_a.max.x.min(moved_b.max.x),
+        moved_a.max.y.min(moved_b.max.y),
+        moved_a.max.z.min(moved_b.max.z),
+    );
+
+    Some(TimeOfImpact {
+        time,
+        point: overlap_min.midpoint(&overlap_max),
+        normal,
+    })
+}
+
+const AABB_EDGES: [(usize, usize); 12] = [
+    (0, 1),
+    (1, 2),
+    (2, 3),
+    (3, 0),
+    (4, 5),
+    (5, 6),
+    (6, 7),
+    (7, 4),
+    (0, 4),
+    (1, 5),
+    (2, 6),
+    (3, 7),
+];
+
+fn aabb_corners(aabb: &AABB) -> [Point3D; 8] {
+    let (min, max) = (aabb.min, aabb.max);
+    [
+        Point3D::new(min.x, min.y, min.z),
+        Point3D::new(max.x, min.y, min.z),
+        Point3D::new(max.x, max.y, min.z),
+        Point3D::new(min.x, max.y, min.z),
+        Point3D::new(min.x, min.y, max.z),
+        Point3D::new(max.x, min.y, max.z),
+        Point3D::new(max.x, max.y, max.z),
+        Point3D::new(min.x, max.y, max.z),
+    ]
+}
+
+fn translated(aabb: &AABB, offset: &Vector3D) -> AABB {
+    AABB {
+        min: aabb.min + *offset,
+        max: aabb.max + *offset,
+    }
+}
+
+/// A ray along `velocity` whose interval covers exactly one frame of motion.
+fn sweep_ray(origin: &Point3D, velocity: &Vector3D) -> Option<Ray> {
+    Ray::with_range(*origin, *velocity, 0.0, velocity.magnitude()).ok()
+}
+
+fn contact_normal(center: &Point3D, contact: &Point3D, velocity: &Vector3D) -> Vector3D {
+    (*center - *contact)
+        .normalize()
+        .or_else(|_| (-*velocity).normalize())
+        .unwrap_or(Vector3D::unit_z())
+}
+
+fn ray_sphere_t(ray: &Ray, center: &Point3D, radius: f64) -> f64 {
+    Sphere::new(*center, radius)
+        .ok()
+        .and_then(|sphere| ray_sphere_intersection(ray, &sphere))
+        .map_or(f64::INFINITY, |hit| hit.t)
+}
+
+/// Entry parameter of the ray into the side of the finite cylinder `p..q`.
+fn ray_cylinder_t(ray: &Ray, p: &Point3D, q: &Point3D, radius: f64) -> f64 {
+    let d = *q - *p;
+    let m = ray.origin - *p;
+    let n = ray.direction;
+
+    let md = m.dot(&d);
+    let nd = n.dot(&d);
+    let dd = d.dot(&d);
+
+    let a = dd - nd * nd;
+    if a.abs() < EPSILON {
+        return f64::INFINITY;
+    }
+
+    let b = dd * m.dot(&n) - nd * md;
+    let c = dd * (m.dot(&m) - radius * radius) - md * md;
+    let discriminant = b * b - a * c;
+    if discriminant < 0.0 {
+        return f64::INFINITY;
+    }
+
+    let t = (-b - discriminant.sqrt()) / a;
+    let along = md + t * nd;
+    if !ray.contains_t(t) || along < 0.0 || along > dd {
+        return f64::INFINITY;
+    }
+    t
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::approx_eq;
+
+    fn unit_box() -> AABB {
+        AABB::new(Point3D::new(-1.0, -1.0, -1.0), Point3D::new(1.0, 1.0, 1.0)).unwrap()
+    }
+
+    #[test]
+    fn test_sphere_plane_impact() {
+        let sphere = Sphere::new(Point3D::new(0.0, 5.0, 0.0), 1.0).unwrap();
+        let floor = Plane::from_point_normal(&Point3D::origin(), &Vector3D::unit_y()).unwrap();
+
+        let toi = sweep_sphere_plane(&sphere, &Vector3D::new(0.0, -8.0, 0.0), &floor).unwrap();
+        assert!(approx_eq(toi.time, 0.5));
+        assert_eq!(toi.point, Point3D::origin());
+        assert_eq!(toi.normal, Vector3D::unit_y());
+
+        assert!(sweep_sphere_plane(&sphere, &Vector3D::new(0.0, -2.0, 0.0), &floor).is_none());
+        assert!(sweep_sphere_plane(&sphere, &Vector3D::new(0.0, 8.0, 0.0), &floor).is_none());
+    }
+
+    #[test]
+    fn test_sphere_plane_from_below() {
+        let sphere = Sphere::new(Point3D::new(0.0, -5.0, 0.0), 1.0).unwrap();
+        let floor = Plane::from_point_normal(&Point3D::origin(), &Vector3D::unit_y()).unwrap();
+
+        let toi = sweep_sphere_plane(&sphere, &Vector3D::new(0.0, 8.0, 0.0), &floor).unwrap();
+        assert!(approx_eq(toi.time, 0.5));
+        assert_eq!(toi.normal, Vector3D::new(0.0, -1.0, 0.0));
+    }
+
+    #[test]
+    fn test_sphere_sphere_impact() {
+        let a = Sphere::new(Point3D::new(-10.0, 0.0, 0.0), 1.0).unwrap();
+        let b = Sphere::new(Point3D::new(10.0, 0.0, 0.0), 1.0).unwrap();
+        let va = Vector3D::new(10.0, 0.0, 0.0);
+        let vb = Vector3D::new(-10.0, 0.0, 0.0);
+
+        let toi = sweep_sphere_sphere(&a, &va, &b, &vb).unwrap();
+        assert!(approx_eq(toi.time, 0.9));
+        assert_eq!(toi.point, Point3D::origin());
+        assert_eq!(toi.normal, Vector3D::new(-1.0, 0.0, 0.0));
+
+        assert!(sweep_sphere_sphere(&a, &Vector3D::zero(), &b, &Vector3D::zero()).is_none());
+    }
+
+    #[test]
+    fn test_sphere_sphere_already_touching() {
+        let a = Sphere::new(Point3D::origin(), 1.0).unwrap();
+        let b = Sphere::new(Point3D::new(1.5, 0.0, 0.0), 1.0).unwrap();
+
+        let toi = sweep_sphere_sphere(&a, &Vector3D::zero(), &b, &Vector3D::zero()).unwrap();
+        assert_eq!(toi.time, 0.0);
+    }
+
+    #[test]
+    fn test_sphere_aabb_face() {
+        let sphere = Sphere::new(Point3D::new(-5.0, 0.0, 0.0), 1.0).unwrap();
+
+        let toi = sweep_sphere_aabb(&sphere, &Vector3D::new(6.0, 0.0, 0.0), &unit_box()).unwrap();
+        assert!(approx_eq(toi.time, 0.5));
+        assert_eq!(toi.point, Point3D::new(-1.0, 0.0, 0.0));
+        assert_eq!(toi.normal, Vector3D::new(-1.0, 0.0, 0.0));
+    }
+
+    #[test]
+    fn test_sphere_aabb_edge_and_miss() {
+        let sphere = Sphere::new(Point3D::new(-5.0, 1.5, 0.0), 1.0).unwrap();
+        let velocity = Vector3D::new(10.0, 0.0, 0.0);
+
+        let toi = sweep_sphere_aabb(&sphere, &velocity, &unit_box()).unwrap();
+        let expected = (4.0 - 0.75_f64.sqrt()) / 10.0;
+        assert!((toi.time - expected).abs() < 1e-9);
+        assert_eq!(toi.point, Point3D::new(-1.0, 1.0, 0.0));
+
+        let high = Sphere::new(Point3D::new(-5.0, 2.5, 0.0), 1.0).unwrap();
+        assert!(sweep_sphere_aabb(&high, &velocity, &unit_box()).is_none());
+    }
+
+    #[test]
+    fn test_sphere_tunnels_through_thin_triangle() {
+        let triangle = Triangle::new(
+            Point3D::new(-5.0, -5.0, 0.0),
+            Point3D::new(5.0, -5.0, 0.0),
+            Point3D::new(0.0, 5.0, 0.0),
+        )
+        .unwrap();
+        let sphere = Sphere::new(Point3D::new(0.0, 0.0, 3.0), 0.5).unwrap();
+        let velocity = Vector3D::new(0.0, 0.0, -10.0);
+
+        let toi = sweep_sphere_triangle(&sphere, &velocity, &triangle).unwrap();
+        assert!(approx_eq(toi.time, 0.25));
+        assert_eq!(toi.point, Point3D::origin());
+        assert_eq!(toi.normal, Vector3D::unit_z());
+    }
+
+    #[test]
+    fn test_sphere_triangle_vertex() {
+        let triangle = Triangle::new(
+            Point3D::new(0.0, 0.0, 0.0),
+            Point3D::new(1.0, 0.0, 0.0),
+            Point3D::new(0.0, 1.0, 0.0),
+        )
+        .unwrap();
+        let sphere = Sphere::new(Point3D::new(-3.0, 0.0, 0.0), 1.0).unwrap();
+
+        let toi = sweep_sphere_triangle(&sphere, &Vector3D::new(4.0, 0.0, 0.0), &triangle).unwrap();
+        assert!(approx_eq(toi.time, 0.5));
+        assert_eq!(toi.point, Point3D::origin());
+        assert_eq!(toi.normal, Vector3D::new(-1.0, 0.0, 0.0));
+    }
+
+    #[test]
+    fn test_aabb_aabb_impact() {
+        let a = AABB::new(Point3D::new(-6.0, -1.0, -1.0), Point3D::new(-4.0, 1.0, 1.0)).unwrap();
+        let velocity = Vector3D::new(6.0, 0.0, 0.0);
+
+        let toi = sweep_aabb_aabb(&a, &velocity, &unit_box(), &Vector3D::zero()).unwrap();
+        assert!(approx_eq(toi.time, 0.5));
+        assert_eq!(toi.normal, Vector3D::new(-1.0, 0.0, 0.0));
+        assert_eq!(toi.point, Point3D::new(-1.0, 0.0, 0.0));
+
+        let slow = Vector3D::new(2.0, 0.0, 0.0);
+        assert!(sweep_aabb_aabb(&a, &slow, &unit_box(), &Vector3D::zero()).is_none());
+    }
+}
@@ -1,5 +1,5 @@
 use crate::error::GeometryError;
-use crate::primitives::{Point3D, Vector3D};
+use crate::primitives::{Point3D, Sphere, Vector3D};
 
 #[derive(Debug, Clone, Copy)]
 pub struct AABB {
@@ -72,6 +72,13 @@ impl AABB {
         self.min.distance_to(&self.max)
     }
 
+    /// Sphere through opposite corners. Fails with
+    /// `GeometryError::DegenerateCase` when the box is a single point.
+    #[inline]
+    pub fn bounding_sphere(&self) -> Result<Sphere, GeometryError> {
+        Sphere::from_points(&[self.min, self.max])
+    }
+
     #[inline]
     pub fn contains_point(&self, point: &Point3D) -> bool {
         point.x >= self.min.x
@@ -82,6 +89,20 @@ impl AABB {
             && point.z <= self.max.z
     }
 
+    #[inline]
+    pub fn closest_point(&self, point: &Point3D) -> Point3D {
+        Point3D::new(
+            point.x.clamp(self.min.x, self.max.x),
+            point.y.clamp(self.min.y, self.max.y),
+            point.z.clamp(self.min.z, self.max.z),
+        )
+    }
+
+    #[inline]
+    pub fn distance_to_point(&self, point: &Point3D) -> f64 {
+        self.closest_point(point).distance_to(point)
+    }
+
     #[inline]
     pub fn intersects(&self, other: &AABB) -> bool {
         self.min.x <= other.max.x
@@ -187,6 +208,24 @@ mod tests {
         assert_eq!(aabb.volume(), 24.0);
     }
 
+    #[test]
+    fn test_bounding_sphere() {
+        let aabb = AABB::new(Point3D::new(0.0, 0.0, 0.0), Point3D::new(2.0, 2.0, 2.0)).unwrap();
+        let sphere = aabb.bounding_sphere().unwrap();
+        assert_eq!(sphere.center, Point3D::new(1.0, 1.0, 1.0));
+        assert!((sphere.radius - 3.0_f64.sqrt()).abs() < 1e-10);
+
+        let corner = Point3D::new(1.0, 2.0, 3.0);
+        let point = AABB {
+            min: corner,
+            max: corner,
+        };
+        assert!(matches!(
+            point.bounding_sphere(),
+            Err(GeometryError::DegenerateCase(_))
+        ));
+    }
+
     #[test]
     fn test_contains_point() {
         let aabb = AABB::new(Point3D::new(0.0, 0.0, 0.0), Point3D::new(1.0, 1.0, 1.0)).unwrap();
@@ -194,6 +233,20 @@ mod tests {
         assert!(!aabb.contains_point(&Point3D::new(2.0, 0.5, 0.5)));
     }
 
+    #[test]
+    fn test_closest_point() {
+        let aabb = AABB::new(Point3D::new(0.0, 0.0, 0.0), Point3D::new(1.0, 1.0, 1.0)).unwrap();
+        assert_eq!(
+            aabb.closest_point(&Point3D::new(2.0, 0.5, -1.0)),
+            Point3D::new(1.0, 0.5, 0.0)
+        );
+        assert_eq!(
+            aabb.closest_point(&Point3D::new(0.5, 0.5, 0.5)),
+            Point3D::new(0.5, 0.5, 0.5)
+        );
+        assert_eq!(aabb.distance_to_point(&Point3D::new(3.0, 0.5, 0.5)), 2.0);
+    }
+
     #[test]
     fn test_intersects() {
         let aabb1 = AABB::new(Point3D::new(0.0, 0.0, 0.0), Point3D::new(1.0, 1.0, 1.0)).unwrap();
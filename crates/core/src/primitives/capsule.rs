@@ -0,0 +1,137 @@
+use std::f64::consts::PI;
+
+use crate::error::GeometryError;
+use crate::primitives::line_segment::closest_points_between;
+use crate::primitives::{Point3D, Vector3D, AABB};
+use crate::utils::{approx_eq, approx_zero, clamp};
+
+/// A segment swept by a sphere. `start == end` is allowed and describes a
+/// sphere.
+#[derive(Debug, Clone, Copy)]
+pub struct Capsule {
+    pub start: Point3D,
+    pub end: Point3D,
+    pub radius: f64,
+}
+
+impl Capsule {
+    pub fn new(start: Point3D, end: Point3D, radius: f64) -> Result<Self, GeometryError> {
+        if radius <= 0.0 {
+            return Err(GeometryError::InvalidConstruction(
+                "Capsule radius must be positive".to_string(),
+            ));
+        }
+        Ok(Self { start, end, radius })
+    }
+
+    #[inline]
+    pub fn axis(&self) -> Vector3D {
+        self.end - self.start
+    }
+
+    #[inline]
+    pub fn length(&self) -> f64 {
+        self.start.distance_to(&self.end)
+    }
+
+    #[inline]
+    pub fn center(&self) -> Point3D {
+        self.start.midpoint(&self.end)
+    }
+
+    #[inline]
+    pub fn volume(&self) -> f64 {
+        PI * self.radius.powi(2) * (self.length() + (4.0 / 3.0) * self.radius)
+    }
+
+    #[inline]
+    pub fn surface_area(&self) -> f64 {
+        2.0 * PI * self.radius * (self.length() + 2.0 * self.radius)
+    }
+
+    pub fn closest_point_on_axis(&self, point: &Point3D) -> Point3D {
+        let axis = self.axis();
+        let len_sq = axis.magnitude_squared();
+        if approx_zero(len_sq) {
+            return self.start;
+        }
+        let t = clamp((*point - self.start).dot(&axis) / len_sq, 0.0, 1.0);
+        self.start + axis * t
+    }
+
+    pub fn closest_points_between_axes(&self, other: &Capsule) -> (Point3D, Point3D) {
+        closest_points_between(&self.start, &self.end, &other.start, &other.end)
+    }
+
+    #[inline]
+    pub fn contains_point(&self, point: &Point3D) -> bool {
+        self.closest_point_on_axis(point).distance_squared_to(point) <= self.radius * self.radius
+    }
+
+    #[inline]
+    pub fn distance_to_point(&self, point: &Point3D) -> f64 {
+        (self.closest_point_on_axis(point).distance_to(point) - self.radius).max(0.0)
+    }
+
+    pub fn bounding_box(&self) -> AABB {
+        let r = self.radius;
+        AABB {
+            min: Point3D::new(
+                self.start.x.min(self.end.x) - r,
+                self.start.y.min(self.end.y) - r,
+                self.start.z.min(self.end.z) - r,
+            ),
+            max: Point3D::new(
+                self.start.x.max(self.end.x) + r,
+                self.start.y.max(self.end.y) + r,
+                self.start.z.max(self.end.z) + r,
+            ),
+        }
+    }
+}
+
+impl PartialEq for Capsule {
+    fn eq(&self, other: &Self) -> bool {
+        self.start == other.start && self.end == other.end && approx_eq(self.radius, other.radius)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_capsule_creation() {
+        let capsule = Capsule::new(Point3D::origin(), Point3D::new(0.0, 2.0, 0.0), 0.5).unwrap();
+        assert_eq!(capsule.length(), 2.0);
+        assert_eq!(capsule.center(), Point3D::new(0.0, 1.0, 0.0));
+        assert!(Capsule::new(Point3D::origin(), Point3D::origin(), 1.0).is_ok());
+        assert!(Capsule::new(Point3D::origin(), Point3D::origin(), 0.0).is_err());
+    }
+
+    #[test]
+    fn test_volume_and_area() {
+        let capsule = Capsule::new(Point3D::origin(), Point3D::new(0.0, 0.0, 2.0), 1.0).unwrap();
+        let expected_volume = PI * 2.0 + (4.0 / 3.0) * PI;
+        assert!((capsule.volume() - expected_volume).abs() < 1e-10);
+        let expected_area = 2.0 * PI * 2.0 + 4.0 * PI;
+        assert!((capsule.surface_area() - expected_area).abs() < 1e-10);
+    }
+
+    #[test]
+    fn test_contains_and_distance() {
+        let capsule = Capsule::new(Point3D::origin(), Point3D::new(4.0, 0.0, 0.0), 1.0).unwrap();
+        assert!(capsule.contains_point(&Point3D::new(2.0, 0.5, 0.0)));
+        assert!(capsule.contains_point(&Point3D::new(-0.9, 0.0, 0.0)));
+        assert!(!capsule.contains_point(&Point3D::new(2.0, 1.5, 0.0)));
+        assert!((capsule.distance_to_point(&Point3D::new(6.0, 0.0, 0.0)) - 1.0).abs() < 1e-10);
+    }
+
+    #[test]
+    fn test_bounding_box() {
+        let capsule = Capsule::new(Point3D::origin(), Point3D::new(4.0, 2.0, 0.0), 1.0).unwrap();
+        let bbox = capsule.bounding_box();
+        assert_eq!(bbox.min, Point3D::new(-1.0, -1.0, -1.0));
+        assert_eq!(bbox.max, Point3D::new(5.0, 3.0, 1.0));
+    }
+}
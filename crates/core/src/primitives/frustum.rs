@@ -0,0 +1,311 @@
+use crate::error::GeometryError;
+use crate::primitives::{LineSegment, Plane, Point3D, Sphere, Triangle, Vector3D, AABB};
+
+/// Result of testing a shape against a [`Frustum`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Containment {
+    Inside,
+    Outside,
+    Intersecting,
+}
+
+/// Convex viewing volume bounded by six planes whose normals point inwards.
+///
+/// `planes` are ordered left, right, bottom, top, near, far. `corners` list
+/// the near face then the far face, each as bottom-left, bottom-right,
+/// top-right, top-left when seen from the eye.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Frustum {
+    pub planes: [Plane; 6],
+    pub corners: [Point3D; 8],
+}
+
+impl Frustum {
+    pub const LEFT: usize = 0;
+    pub const RIGHT: usize = 1;
+    pub const BOTTOM: usize = 2;
+    pub const TOP: usize = 3;
+    pub const NEAR: usize = 4;
+    pub const FAR: usize = 5;
+
+    /// Builds the frustum spanned by eight corners in the order described on
+    /// [`Frustum`]. Fails if any face collapses to a line or a point.
+    pub fn from_corners(corners: [Point3D; 8]) -> Result<Self, GeometryError> {
+        let sum = corners
+            .iter()
+            .fold(Vector3D::zero(), |acc, c| acc + (*c - Point3D::origin()));
+        let centroid = Point3D::origin() + sum / 8.0;
+
+        // Three corners of each face; the fourth only matters for the
+        // orientation check through the centroid.
+        let faces = [
+            [0, 4, 7],
+            [1, 2, 6],
+            [0, 1, 5],
+            [3, 7, 6],
+            [0, 1, 2],
+            [4, 5, 6],
+        ];
+        let mut planes = [Plane {
+            normal: Vector3D::unit_z(),
+            d: 0.0,
+        }; 6];
+        for (plane, [a, b, c]) in planes.iter_mut().zip(faces) {
+            let face = Plane::from_three_points(&corners[a], &corners[b], &corners[c])?;
+            *plane = if face.distance_to_point(&centroid) < 0.0 {
+                face.flip_normal()
+            } else {
+                face
+            };
+        }
+
+        Ok(Self { planes, corners })
+    }
+
+    pub fn contains_point(&self, point: &Point3D) -> bool {
+        self.planes
+            .iter()
+            .all(|plane| plane.distance_to_point(point) >= 0.0)
+    }
+
+    pub fn classify_point(&self, point: &Point3D) -> Containment {
+        if self.contains_point(point) {
+            Containment::Inside
+        } else {
+            Containment::Outside
+        }
+    }
+
+    pub fn classify_sphere(&self, sphere: &Sphere) -> Containment {
+        let mut result = Containment::Inside;
+        for plane in &self.planes {
+            let distance = plane.distance_to_point(&sphere.center);
+            if distance < -sphere.radius {
+                return Containment::Outside;
+            }
+            if distance < sphere.radius {
+                result = Containment::Intersecting;
+            }
+        }
+        result
+    }
+
+    /// Classifies a box. Boxes near a frustum edge may be reported as
+    /// intersecting even though they are just outside, never the reverse.
+    pub fn classify_aabb(&self, aabb: &AABB) -> Containment {
+        let mut result = Containment::Inside;
+        for plane in &self.planes {
+            let normal = plane.normal;
+            let farthest = Point3D::new(
+                if normal.x >= 0.0 {
+                    aabb.max.x
+                } else {
+                    aabb.min.x
+                },
+                if normal.y >= 0.0 {
+                    aabb.max.y
+                } else {
+                    aabb.min.y
+                },
+                if normal.z >= 0.0 {
+                    aabb.max.z
+                } else {
+                    aabb.min.z
+                },
+            );
+            if plane.distance_to_point(&farthest) < 0.0 {
+                return Containment::Outside;
+            }
+            let nearest = Point3D::new(
+                if normal.x >= 0.0 {
+                    aabb.min.x
+                } else {
+                    aabb.max.x
+                },
+                if normal.y >= 0.0 {
+                    aabb.min.y
+                } else {
+                    aabb.max.y
+                },
+                if normal.z >= 0.0 {
+                    aabb.min.z
+                } else {
+                    aabb.max.z
+                },
+            );
+            if plane.distance_to_point(&nearest) < 0.0 {
+                result = Containment::Intersecting;
+            }
+        }
+
+        // The box faces can also separate it from the frustum.
+        if result == Containment::Intersecting {
+            let min = [aabb.min.x, aabb.min.y, aabb.min.z];
+            let max = [aabb.max.x, aabb.max.y, aabb.max.z];
+            for axis in 0..3 {
+                let coords = self.corners.map(|c| [c.x, c.y, c.z][axis]);
+                if coords.iter().all(|&v| v < min[axis]) || coords.iter().all(|&v| v > max[axis]) {
+                    return Containment::Outside;
+                }
+            }
+        }
+        result
+    }
+
+    /// Classifies a triangle by its vertices. Like [`Frustum::classify_aabb`]
+    /// this is conservative near frustum edges.
+    pub fn classify_triangle(&self, triangle: &Triangle) -> Containment {
+        self.classify_points(&[triangle.a, triangle.b, triangle.c])
+    }
+
+    pub fn classify_segment(&self, segment: &LineSegment) -> Containment {
+        self.classify_points(&[segment.start, segment.end])
+    }
+
+    fn classify_points(&self, points: &[Point3D]) -> Containment {
+        let mut result = Containment::Inside;
+        for plane in &self.planes {
+            let inside = points
+                .iter()
+                .filter(|p| plane.distance_to_point(p) >= 0.0)
+                .count();
+            if inside == 0 {
+                return Containment::Outside;
+            }
+            if inside < points.len() {
+                result = Containment::Intersecting;
+            }
+        }
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Frustum of a camera at the origin looking down -z with a 90 degree
+    /// field of view, near 1 and far 10.
+    fn frustum() -> Frustum {
+        let corner = |d: f64, sx: f64, sy: f64| Point3D::new(sx * d, sy * d, -d);
+        Frustum::from_corners([
+            corner(1.0, -1.0, -1.0),
+            corner(1.0, 1.0, -1.0),
+            corner(1.0, 1.0, 1.0),
+            corner(1.0, -1.0, 1.0),
+            corner(10.0, -1.0, -1.0),
+            corner(10.0, 1.0, -1.0),
+            corner(10.0, 1.0, 1.0),
+            corner(10.0, -1.0, 1.0),
+        ])
+        .unwrap()
+    }
+
+    #[test]
+    fn test_planes_point_inwards() {
+        let frustum = frustum();
+        let center = Point3D::new(0.0, 0.0, -5.0);
+        for plane in &frustum.planes {
+            assert!(plane.distance_to_point(&center) > 0.0);
+        }
+        assert!(frustum.planes[Frustum::NEAR].normal == Vector3D::new(0.0, 0.0, -1.0));
+        assert!(frustum.planes[Frustum::FAR].normal == Vector3D::new(0.0, 0.0, 1.0));
+    }
+
+    #[test]
+    fn test_degenerate_corners() {
+        assert!(Frustum::from_corners([Point3D::origin(); 8]).is_err());
+    }
+
+    #[test]
+    fn test_classify_point() {
+        let frustum = frustum();
+        assert_eq!(
+            frustum.classify_point(&Point3D::new(0.0, 0.0, -5.0)),
+            Containment::Inside
+        );
+        assert_eq!(
+            frustum.classify_point(&Point3D::new(0.0, 0.0, 5.0)),
+            Containment::Outside
+        );
+        assert_eq!(
+            frustum.classify_point(&Point3D::new(3.0, 0.0, -2.0)),
+            Containment::Outside
+        );
+    }
+
+    #[test]
+    fn test_classify_sphere() {
+        let frustum = frustum();
+        let inside = Sphere::new(Point3D::new(0.0, 0.0, -5.0), 1.0).unwrap();
+        let straddling = Sphere::new(Point3D::new(0.0, 0.0, -10.0), 1.0).unwrap();
+        let behind = Sphere::new(Point3D::new(0.0, 0.0, 3.0), 1.0).unwrap();
+        assert_eq!(frustum.classify_sphere(&inside), Containment::Inside);
+        assert_eq!(
+            frustum.classify_sphere(&straddling),
+            Containment::Intersecting
+        );
+        assert_eq!(frustum.classify_sphere(&behind), Containment::Outside);
+    }
+
+    #[test]
+    fn test_classify_aabb() {
+        let frustum = frustum();
+        let inside =
+            AABB::new(Point3D::new(-1.0, -1.0, -6.0), Point3D::new(1.0, 1.0, -4.0)).unwrap();
+        let straddling =
+            AABB::new(Point3D::new(-1.0, -1.0, -2.0), Point3D::new(1.0, 1.0, 0.0)).unwrap();
+        let beside = AABB::new(
+            Point3D::new(20.0, -1.0, -6.0),
+            Point3D::new(22.0, 1.0, -4.0),
+        )
+        .unwrap();
+        // Overlaps the left and top planes' half-spaces individually but sits
+        // beyond the frustum's corner edge; only the box-face test rejects it.
+        let corner =
+            AABB::new(Point3D::new(-3.0, 2.5, -3.0), Point3D::new(-2.5, 3.0, -2.0)).unwrap();
+        assert_eq!(frustum.classify_aabb(&inside), Containment::Inside);
+        assert_eq!(
+            frustum.classify_aabb(&straddling),
+            Containment::Intersecting
+        );
+        assert_eq!(frustum.classify_aabb(&beside), Containment::Outside);
+        assert_ne!(frustum.classify_aabb(&corner), Containment::Inside);
+    }
+
+    #[test]
+    fn test_classify_triangle_and_segment() {
+        let frustum = frustum();
+        let inside = Triangle::new(
+            Point3D::new(-1.0, -1.0, -5.0),
+            Point3D::new(1.0, -1.0, -5.0),
+            Point3D::new(0.0, 1.0, -5.0),
+        )
+        .unwrap();
+        let crossing = Triangle::new(
+            Point3D::new(0.0, 0.0, -5.0),
+            Point3D::new(50.0, 0.0, -5.0),
+            Point3D::new(0.0, 1.0, -5.0),
+        )
+        .unwrap();
+        let behind = Triangle::new(
+            Point3D::new(-1.0, -1.0, 2.0),
+            Point3D::new(1.0, -1.0, 2.0),
+            Point3D::new(0.0, 1.0, 2.0),
+        )
+        .unwrap();
+        assert_eq!(frustum.classify_triangle(&inside), Containment::Inside);
+        assert_eq!(
+            frustum.classify_triangle(&crossing),
+            Containment::Intersecting
+        );
+        assert_eq!(frustum.classify_triangle(&behind), Containment::Outside);
+
+        let segment =
+            LineSegment::new(Point3D::new(0.0, 0.0, 5.0), Point3D::new(0.0, 0.0, -5.0)).unwrap();
+        assert_eq!(
+            frustum.classify_segment(&segment),
+            Containment::Intersecting
+        );
+    }
+}
@@ -1,6 +1,6 @@
 use crate::error::GeometryError;
-use crate::primitives::{Point3D, Vector3D};
-use crate::utils::clamp;
+use crate::primitives::{Point3D, Sphere, Vector3D};
+use crate::utils::{approx_zero, clamp};
 
 #[derive(Debug, Clone, Copy)]
 pub struct LineSegment {
@@ -33,6 +33,13 @@ impl LineSegment {
         self.start.midpoint(&self.end)
     }
 
+    /// Sphere with the segment as a diameter. Fails with
+    /// `GeometryError::DegenerateCase` when the endpoints coincide.
+    #[inline]
+    pub fn bounding_sphere(&self) -> Result<Sphere, GeometryError> {
+        Sphere::from_points(&[self.start, self.end])
+    }
+
     #[inline]
     pub fn point_at(&self, t: f64) -> Point3D {
         let dir = self.direction();
@@ -59,6 +66,56 @@ impl LineSegment {
         let closest = self.closest_point(point);
         point.distance_to(&closest)
     }
+
+    pub fn closest_points_to_segment(&self, other: &LineSegment) -> (Point3D, Point3D) {
+        closest_points_between(&self.start, &self.end, &other.start, &other.end)
+    }
+}
+
+/// Closest points between segments `p1..q1` and `p2..q2`; either segment may
+/// be degenerate (a single point).
+pub(crate) fn closest_points_between(
+    p1: &Point3D,
+    q1: &Point3D,
+    p2: &Point3D,
+    q2: &Point3D,
+) -> (Point3D, Point3D) {
+    let d1 = *q1 - *p1;
+    let d2 = *q2 - *p2;
+    let r = *p1 - *p2;
+    let a = d1.magnitude_squared();
+    let e = d2.magnitude_squared();
+    let f = d2.dot(&r);
+
+    let (s, t) = if approx_zero(a) && approx_zero(e) {
+        (0.0, 0.0)
+    } else if approx_zero(a) {
+        (0.0, clamp(f / e, 0.0, 1.0))
+    } else {
+        let c = d1.dot(&r);
+        if approx_zero(e) {
+            (clamp(-c / a, 0.0, 1.0), 0.0)
+        } else {
+            let b = d1.dot(&d2);
+            let denom = a * e - b * b;
+            let mut s = if denom > 0.0 {
+                clamp((b * f - c * e) / denom, 0.0, 1.0)
+            } else {
+                0.0
+            };
+            let mut t = (b * s + f) / e;
+            if t < 0.0 {
+                t = 0.0;
+                s = clamp(-c / a, 0.0, 1.0);
+            } else if t > 1.0 {
+                t = 1.0;
+                s = clamp((b - c) / a, 0.0, 1.0);
+            }
+            (s, t)
+        }
+    };
+
+    (*p1 + d1 * s, *p2 + d2 * t)
 }
 
 impl PartialEq for LineSegment {
@@ -100,6 +157,40 @@ mod tests {
         assert_eq!(seg.midpoint(), Point3D::new(1.0, 1.0, 1.0));
     }
 
+    #[test]
+    fn test_closest_points_to_segment() {
+        let a =
+            LineSegment::new(Point3D::new(-1.0, 0.0, 0.0), Point3D::new(1.0, 0.0, 0.0)).unwrap();
+        let b =
+            LineSegment::new(Point3D::new(0.5, -1.0, 2.0), Point3D::new(0.5, 1.0, 2.0)).unwrap();
+        let (pa, pb) = a.closest_points_to_segment(&b);
+        assert_eq!(pa, Point3D::new(0.5, 0.0, 0.0));
+        assert_eq!(pb, Point3D::new(0.5, 0.0, 2.0));
+
+        let c = LineSegment::new(Point3D::new(3.0, 1.0, 0.0), Point3D::new(5.0, 1.0, 0.0)).unwrap();
+        let (pa, pc) = a.closest_points_to_segment(&c);
+        assert_eq!(pa, Point3D::new(1.0, 0.0, 0.0));
+        assert_eq!(pc, Point3D::new(3.0, 1.0, 0.0));
+    }
+
+    #[test]
+    fn test_bounding_sphere() {
+        let segment =
+            LineSegment::new(Point3D::new(0.0, 0.0, 0.0), Point3D::new(0.0, 4.0, 0.0)).unwrap();
+        let sphere = segment.bounding_sphere().unwrap();
+        assert_eq!(sphere.center, Point3D::new(0.0, 2.0, 0.0));
+        assert_eq!(sphere.radius, 2.0);
+
+        let point = LineSegment {
+            start: segment.start,
+            end: segment.start,
+        };
+        assert!(matches!(
+            point.bounding_sphere(),
+            Err(GeometryError::DegenerateCase(_))
+        ));
+    }
+
     #[test]
     fn test_point_at() {
         let seg =
@@ -1,5 +1,8 @@
 mod aabb;
+mod capsule;
+mod frustum;
 mod line_segment;
+mod obb;
 mod plane;
 mod point;
 mod ray;
@@ -8,7 +11,11 @@ mod triangle;
 mod vector;
 
 pub use aabb::AABB;
+pub use capsule::Capsule;
+pub use frustum::{Containment, Frustum};
+pub(crate) use line_segment::closest_points_between;
 pub use line_segment::LineSegment;
+pub use obb::OBB;
 pub use plane::Plane;
 pub use point::Point3D;
 pub use ray::Ray;
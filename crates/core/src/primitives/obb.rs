@@ -0,0 +1,211 @@
+use crate::error::GeometryError;
+use crate::primitives::{Point3D, Vector3D, AABB};
+use crate::utils::EPSILON;
+
+/// Oriented bounding box: a centre, three orthonormal axes and the half
+/// extent along each axis.
+#[derive(Debug, Clone, Copy)]
+pub struct OBB {
+    pub center: Point3D,
+    pub axes: [Vector3D; 3],
+    pub half_extents: [f64; 3],
+}
+
+impl OBB {
+    pub fn new(
+        center: Point3D,
+        axes: [Vector3D; 3],
+        half_extents: [f64; 3],
+    ) -> Result<Self, GeometryError> {
+        if half_extents.iter().any(|&e| e <= 0.0) {
+            return Err(GeometryError::InvalidConstruction(
+                "OBB half extents must be positive".to_string(),
+            ));
+        }
+
+        let axes = [
+            axes[0].normalize()?,
+            axes[1].normalize()?,
+            axes[2].normalize()?,
+        ];
+        let tolerance = EPSILON.sqrt();
+        if axes[0].dot(&axes[1]).abs() > tolerance
+            || axes[1].dot(&axes[2]).abs() > tolerance
+            || axes[2].dot(&axes[0]).abs() > tolerance
+        {
+            return Err(GeometryError::InvalidConstruction(
+                "OBB axes must be mutually perpendicular".to_string(),
+            ));
+        }
+
+        Ok(Self {
+            center,
+            axes,
+            half_extents,
+        })
+    }
+
+    pub fn from_aabb(aabb: &AABB) -> Self {
+        let size = aabb.size();
+        Self {
+            center: aabb.center(),
+            axes: [Vector3D::unit_x(), Vector3D::unit_y(), Vector3D::unit_z()],
+            half_extents: [size.x / 2.0, size.y / 2.0, size.z / 2.0],
+        }
+    }
+
+    #[inline]
+    pub fn volume(&self) -> f64 {
+        8.0 * self.half_extents[0] * self.half_extents[1] * self.half_extents[2]
+    }
+
+    /// Corners ordered by the sign bits of the local coordinates: bit 0 is
+    /// axis 0, bit 1 axis 1, bit 2 axis 2 (a set bit means the positive side).
+    pub fn corners(&self) -> [Point3D; 8] {
+        let mut corners = [self.center; 8];
+        for (i, corner) in corners.iter_mut().enumerate() {
+            for axis in 0..3 {
+                let sign = if i & (1 << axis) != 0 { 1.0 } else { -1.0 };
+                *corner = *corner + self.axes[axis] * (sign * self.half_extents[axis]);
+            }
+        }
+        corners
+    }
+
+    #[inline]
+    pub fn to_local(&self, point: &Point3D) -> Vector3D {
+        let d = *point - self.center;
+        Vector3D::new(
+            d.dot(&self.axes[0]),
+            d.dot(&self.axes[1]),
+            d.dot(&self.axes[2]),
+        )
+    }
+
+    pub fn contains_point(&self, point: &Point3D) -> bool {
+        let local = self.to_local(point);
+        local.x.abs() <= self.half_extents[0]
+            && local.y.abs() <= self.half_extents[1]
+            && local.z.abs() <= self.half_extents[2]
+    }
+
+    pub fn closest_point(&self, point: &Point3D) -> Point3D {
+        let local = self.to_local(point);
+        let coords = [local.x, local.y, local.z];
+        let mut result = self.center;
+        for ((axis, extent), coord) in self.axes.iter().zip(self.half_extents).zip(coords) {
+            result = result + *axis * coord.clamp(-extent, extent);
+        }
+        result
+    }
+
+    #[inline]
+    pub fn distance_to_point(&self, point: &Point3D) -> f64 {
+        self.closest_point(point).distance_to(point)
+    }
+
+    /// Half the width of the box's projection onto `direction` (unit length).
+    #[inline]
+    pub fn projected_radius(&self, direction: &Vector3D) -> f64 {
+        (0..3)
+            .map(|axis| self.half_extents[axis] * self.axes[axis].dot(direction).abs())
+            .sum()
+    }
+
+    pub fn bounding_box(&self) -> AABB {
+        let extent = Vector3D::new(
+            self.projected_radius(&Vector3D::unit_x()),
+            self.projected_radius(&Vector3D::unit_y()),
+            self.projected_radius(&Vector3D::unit_z()),
+        );
+        AABB {
+            min: self.center + -extent,
+            max: self.center + extent,
+        }
+    }
+}
+
+impl PartialEq for OBB {
+    fn eq(&self, other: &Self) -> bool {
+        self.center == other.center
+            && self.axes == other.axes
+            && self
+                .half_extents
+                .iter()
+                .zip(other.half_extents.iter())
+                .all(|(a, b)| (a - b).abs() < EPSILON)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rotated_z(angle: f64) -> [Vector3D; 3] {
+        let (s, c) = angle.sin_cos();
+        [
+            Vector3D::new(c, s, 0.0),
+            Vector3D::new(-s, c, 0.0),
+            Vector3D::unit_z(),
+        ]
+    }
+
+    #[test]
+    fn test_obb_creation() {
+        let obb = OBB::new(Point3D::origin(), rotated_z(0.3), [1.0, 2.0, 3.0]).unwrap();
+        assert_eq!(obb.volume(), 48.0);
+        assert!(OBB::new(Point3D::origin(), rotated_z(0.3), [1.0, 0.0, 3.0]).is_err());
+
+        let skewed = [
+            Vector3D::unit_x(),
+            Vector3D::new(1.0, 1.0, 0.0),
+            Vector3D::unit_z(),
+        ];
+        assert!(OBB::new(Point3D::origin(), skewed, [1.0, 1.0, 1.0]).is_err());
+    }
+
+    #[test]
+    fn test_from_aabb() {
+        let aabb = AABB::new(Point3D::new(0.0, 0.0, 0.0), Point3D::new(2.0, 4.0, 6.0)).unwrap();
+        let obb = OBB::from_aabb(&aabb);
+        assert_eq!(obb.center, Point3D::new(1.0, 2.0, 3.0));
+        assert_eq!(obb.half_extents, [1.0, 2.0, 3.0]);
+        assert_eq!(obb.bounding_box().min, aabb.min);
+        assert_eq!(obb.bounding_box().max, aabb.max);
+    }
+
+    #[test]
+    fn test_contains_and_closest_point() {
+        let obb = OBB::new(
+            Point3D::origin(),
+            rotated_z(std::f64::consts::FRAC_PI_4),
+            [1.0, 1.0, 1.0],
+        )
+        .unwrap();
+        assert!(obb.contains_point(&Point3D::new(1.3, 0.0, 0.0)));
+        assert!(!obb.contains_point(&Point3D::new(1.0, 1.0, 0.0)));
+
+        let closest = obb.closest_point(&Point3D::new(3.0, 0.0, 0.0));
+        let expected = 2.0_f64.sqrt();
+        assert_eq!(closest, Point3D::new(expected, 0.0, 0.0));
+    }
+
+    #[test]
+    fn test_corners_and_bounding_box() {
+        let obb = OBB::new(
+            Point3D::origin(),
+            rotated_z(std::f64::consts::FRAC_PI_4),
+            [1.0, 1.0, 1.0],
+        )
+        .unwrap();
+        let corners = obb.corners();
+        assert_eq!(corners.len(), 8);
+        for corner in &corners {
+            assert!((corner.distance_to(&Point3D::origin()) - 3.0_f64.sqrt()).abs() < 1e-10);
+        }
+
+        let bbox = obb.bounding_box();
+        let expected = 2.0_f64.sqrt();
+        assert_eq!(bbox.max, Point3D::new(expected, expected, 1.0));
+    }
+}
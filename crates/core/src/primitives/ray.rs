@@ -1,10 +1,17 @@
-use crate::error::Result;
+use crate::error::{GeometryError, Result};
 use crate::primitives::{Point3D, Vector3D};
+use crate::utils::approx_eq;
 
+/// A half-line `origin + t * direction` with a unit direction.
+///
+/// Every intersection query only reports hits whose `t` lies in the closed
+/// interval `[t_min, t_max]`, which defaults to `[0, +inf)`.
 #[derive(Debug, Clone, Copy)]
 pub struct Ray {
     pub origin: Point3D,
     pub direction: Vector3D,
+    pub t_min: f64,
+    pub t_max: f64,
 }
 
 impl Ray {
@@ -13,9 +20,38 @@ impl Ray {
         Ok(Self {
             origin,
             direction: normalized_direction,
+            t_min: 0.0,
+            t_max: f64::INFINITY,
         })
     }
 
+    pub fn with_range(
+        origin: Point3D,
+        direction: Vector3D,
+        t_min: f64,
+        t_max: f64,
+    ) -> Result<Self> {
+        Self::new(origin, direction)?.with_interval(t_min, t_max)
+    }
+
+    pub fn with_interval(&self, t_min: f64, t_max: f64) -> Result<Self> {
+        if t_min.is_nan() || t_max.is_nan() || t_min > t_max {
+            return Err(GeometryError::InvalidParameter(
+                "Ray interval requires t_min <= t_max".to_string(),
+            ));
+        }
+        Ok(Self {
+            t_min,
+            t_max,
+            ..*self
+        })
+    }
+
+    #[inline]
+    pub fn contains_t(&self, t: f64) -> bool {
+        t >= self.t_min && t <= self.t_max
+    }
+
     #[inline]
     pub fn point_at(&self, t: f64) -> Point3D {
         Point3D {
@@ -28,7 +64,12 @@ impl Ray {
 
 impl PartialEq for Ray {
     fn eq(&self, other: &Self) -> bool {
-        self.origin == other.origin && self.direction == other.direction
+        // Exact comparison first so infinite bounds match.
+        let same = |a: f64, b: f64| a == b || approx_eq(a, b);
+        self.origin == other.origin
+            && self.direction == other.direction
+            && same(self.t_min, other.t_min)
+            && same(self.t_max, other.t_max)
     }
 }
 
@@ -83,10 +124,36 @@ mod tests {
         assert!(result.is_err());
     }
 
+    #[test]
+    fn test_ray_default_interval() {
+        let ray = Ray::new(Point3D::origin(), Vector3D::unit_x()).unwrap();
+        assert_eq!(ray.t_min, 0.0);
+        assert_eq!(ray.t_max, f64::INFINITY);
+        assert!(ray.contains_t(0.0));
+        assert!(!ray.contains_t(-1e-6));
+    }
+
+    #[test]
+    fn test_ray_with_range() {
+        let ray = Ray::with_range(Point3D::origin(), Vector3D::unit_x(), 1.0, 2.0).unwrap();
+        assert!(ray.contains_t(1.0));
+        assert!(ray.contains_t(2.0));
+        assert!(!ray.contains_t(2.5));
+
+        assert!(Ray::with_range(Point3D::origin(), Vector3D::unit_x(), 2.0, 1.0).is_err());
+        assert!(ray.with_interval(f64::NAN, 1.0).is_err());
+    }
+
     #[test]
     fn test_ray_equality() {
         let ray1 = Ray::new(Point3D::new(0.0, 0.0, 0.0), Vector3D::new(1.0, 0.0, 0.0)).unwrap();
         let ray2 = Ray::new(Point3D::new(0.0, 0.0, 0.0), Vector3D::new(2.0, 0.0, 0.0)).unwrap();
         assert_eq!(ray1, ray2);
+        assert_ne!(ray1, ray1.with_interval(0.0, 5.0).unwrap());
+        assert_ne!(ray1, ray1.with_interval(1.0, f64::INFINITY).unwrap());
+        assert_eq!(
+            ray1.with_interval(0.0, 5.0).unwrap(),
+            ray2.with_interval(0.0, 5.0).unwrap()
+        );
     }
 }
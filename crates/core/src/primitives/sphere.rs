@@ -1,8 +1,8 @@
 use std::f64::consts::PI;
 
 use crate::error::GeometryError;
-use crate::primitives::Point3D;
-use crate::utils::approx_eq;
+use crate::primitives::{Point3D, Vector3D, AABB};
+use crate::utils::{approx_eq, approx_zero};
 
 #[derive(Debug, Clone, Copy)]
 pub struct Sphere {
@@ -39,6 +39,216 @@ impl Sphere {
     pub fn distance_to_point(&self, point: &Point3D) -> f64 {
         (self.center.distance_to(point) - self.radius).max(0.0)
     }
+
+    #[inline]
+    pub fn bounding_box(&self) -> AABB {
+        let extent = Vector3D::new(self.radius, self.radius, self.radius);
+        AABB {
+            min: self.center + -extent,
+            max: self.center + extent,
+        }
+    }
+
+    /// Exact minimum enclosing sphere (Welzl). Points are visited in a fixed
+    /// pseudo-random order, so the result is deterministic and the expected
+    /// running time is linear.
+    pub fn from_points(points: &[Point3D]) -> Result<Self, GeometryError> {
+        check_bounding_input(points)?;
+
+        let mut order: Vec<Point3D> = points.to_vec();
+        shuffle(&mut order);
+
+        let mut ball = Ball::point(order[0]);
+        for i in 1..order.len() {
+            if ball.contains(&order[i]) {
+                continue;
+            }
+            ball = Ball::point(order[i]);
+            for j in 0..i {
+                if ball.contains(&order[j]) {
+                    continue;
+                }
+                ball = Ball::diameter(&order[i], &order[j]);
+                for k in 0..j {
+                    if ball.contains(&order[k]) {
+                        continue;
+                    }
+                    ball = Ball::circumscribe_3(&order[i], &order[j], &order[k]);
+                    for l in 0..k {
+                        if !ball.contains(&order[l]) {
+                            ball = Ball::circumscribe_4(&order[i], &order[j], &order[k], &order[l]);
+                        }
+                    }
+                }
+            }
+        }
+
+        ball.into_sphere()
+    }
+
+    /// Ritter's approximate bounding sphere: a single pass that is typically
+    /// within 5-20% of the optimal radius.
+    pub fn from_points_approx(points: &[Point3D]) -> Result<Self, GeometryError> {
+        check_bounding_input(points)?;
+
+        let farthest_from = |from: &Point3D| {
+            *points
+                .iter()
+                .max_by(|a, b| {
+                    from.distance_squared_to(a)
+                        .total_cmp(&from.distance_squared_to(b))
+                })
+                .expect("points are not empty")
+        };
+        let y = farthest_from(&points[0]);
+        let z = farthest_from(&y);
+
+        let mut ball = Ball::diameter(&y, &z);
+        for point in points {
+            let distance = ball.center.distance_to(point);
+            if distance > ball.radius {
+                let radius = (ball.radius + distance) / 2.0;
+                ball.center =
+                    ball.center + (*point - ball.center) * ((radius - ball.radius) / distance);
+                ball.radius = radius;
+            }
+        }
+
+        ball.into_sphere()
+    }
+
+    /// Smallest sphere enclosing both spheres.
+    pub fn union(&self, other: &Sphere) -> Sphere {
+        let offset = other.center - self.center;
+        let distance = offset.magnitude();
+        if distance + other.radius <= self.radius {
+            return *self;
+        }
+        if distance + self.radius <= other.radius {
+            return *other;
+        }
+
+        let radius = (distance + self.radius + other.radius) / 2.0;
+        Sphere {
+            center: self.center + offset * ((radius - self.radius) / distance),
+            radius,
+        }
+    }
+}
+
+fn check_bounding_input(points: &[Point3D]) -> Result<(), GeometryError> {
+    if points.is_empty() {
+        return Err(GeometryError::InvalidParameter(
+            "Cannot create sphere from empty point list".to_string(),
+        ));
+    }
+    if points.iter().all(|p| *p == points[0]) {
+        return Err(GeometryError::DegenerateCase(
+            "All points are identical, cannot create sphere with volume".to_string(),
+        ));
+    }
+    Ok(())
+}
+
+/// Fisher-Yates shuffle driven by a fixed xorshift seed.
+fn shuffle(points: &mut [Point3D]) {
+    let mut state: u64 = 0x9E37_79B9_7F4A_7C15;
+    for i in (1..points.len()).rev() {
+        state ^= state << 13;
+        state ^= state >> 7;
+        state ^= state << 17;
+        let j = (state % (i as u64 + 1)) as usize;
+        points.swap(i, j);
+    }
+}
+
+/// Sphere that may have zero radius, used while building bounding spheres.
+#[derive(Debug, Clone, Copy)]
+struct Ball {
+    center: Point3D,
+    radius: f64,
+}
+
+impl Ball {
+    fn point(center: Point3D) -> Self {
+        Self {
+            center,
+            radius: 0.0,
+        }
+    }
+
+    fn diameter(a: &Point3D, b: &Point3D) -> Self {
+        Self {
+            center: a.midpoint(b),
+            radius: a.distance_to(b) / 2.0,
+        }
+    }
+
+    fn contains(&self, point: &Point3D) -> bool {
+        self.center.distance_to(point) <= self.radius * (1.0 + 1e-12) + 1e-12
+    }
+
+    /// Smallest sphere with `a`, `b` and `c` on its boundary.
+    fn circumscribe_3(a: &Point3D, b: &Point3D, c: &Point3D) -> Self {
+        let ab = *b - *a;
+        let ac = *c - *a;
+        let normal = ab.cross(&ac);
+        let denom = 2.0 * normal.magnitude_squared();
+        if approx_zero(denom) {
+            return Self::widest(&[*a, *b, *c]);
+        }
+        let offset = (normal.cross(&ab) * ac.magnitude_squared()
+            + ac.cross(&normal) * ab.magnitude_squared())
+            / denom;
+        Self {
+            center: *a + offset,
+            radius: offset.magnitude(),
+        }
+    }
+
+    /// Sphere through four points; coplanar input falls back to the smallest
+    /// three-point sphere that holds all four.
+    fn circumscribe_4(a: &Point3D, b: &Point3D, c: &Point3D, d: &Point3D) -> Self {
+        let ab = *b - *a;
+        let ac = *c - *a;
+        let ad = *d - *a;
+        let det = ab.dot(&ac.cross(&ad));
+        if approx_zero(det) {
+            let points = [*a, *b, *c, *d];
+            return [(0, 1, 2), (0, 1, 3), (0, 2, 3), (1, 2, 3)]
+                .iter()
+                .map(|&(i, j, k)| Self::circumscribe_3(&points[i], &points[j], &points[k]))
+                .filter(|ball| points.iter().all(|p| ball.contains(p)))
+                .min_by(|x, y| x.radius.total_cmp(&y.radius))
+                .unwrap_or_else(|| Self::widest(&points));
+        }
+        let offset: Vector3D = (ac.cross(&ad) * ab.magnitude_squared()
+            + ad.cross(&ab) * ac.magnitude_squared()
+            + ab.cross(&ac) * ad.magnitude_squared())
+            / (2.0 * det);
+        Self {
+            center: *a + offset,
+            radius: offset.magnitude(),
+        }
+    }
+
+    /// Diameter sphere of the two points furthest apart.
+    fn widest(points: &[Point3D]) -> Self {
+        let mut best = Self::point(points[0]);
+        for (i, p) in points.iter().enumerate() {
+            for q in &points[i + 1..] {
+                let candidate = Self::diameter(p, q);
+                if candidate.radius > best.radius {
+                    best = candidate;
+                }
+            }
+        }
+        best
+    }
+
+    fn into_sphere(self) -> Result<Sphere, GeometryError> {
+        Sphere::new(self.center, self.radius)
+    }
 }
 
 impl PartialEq for Sphere {
@@ -84,4 +294,106 @@ mod tests {
         assert!(sphere.contains_point(&Point3D::new(3.0, 0.0, 0.0)));
         assert!(!sphere.contains_point(&Point3D::new(6.0, 0.0, 0.0)));
     }
+
+    #[test]
+    fn test_from_points_exact() {
+        let points = [
+            Point3D::new(-1.0, 0.0, 0.0),
+            Point3D::new(1.0, 0.0, 0.0),
+            Point3D::new(0.0, 0.5, 0.0),
+            Point3D::new(0.0, 0.0, 0.3),
+        ];
+        let sphere = Sphere::from_points(&points).unwrap();
+        assert_eq!(sphere.center, Point3D::origin());
+        assert!((sphere.radius - 1.0).abs() < 1e-10);
+    }
+
+    #[test]
+    fn test_from_points_cube_and_tetrahedron() {
+        let mut corners = Vec::new();
+        for &x in &[0.0, 2.0] {
+            for &y in &[0.0, 2.0] {
+                for &z in &[0.0, 2.0] {
+                    corners.push(Point3D::new(x, y, z));
+                }
+            }
+        }
+        corners.push(Point3D::new(1.0, 1.0, 1.0));
+        let sphere = Sphere::from_points(&corners).unwrap();
+        assert_eq!(sphere.center, Point3D::new(1.0, 1.0, 1.0));
+        assert!((sphere.radius - 3.0_f64.sqrt()).abs() < 1e-10);
+
+        let tetrahedron = [
+            Point3D::new(1.0, 1.0, 1.0),
+            Point3D::new(1.0, -1.0, -1.0),
+            Point3D::new(-1.0, 1.0, -1.0),
+            Point3D::new(-1.0, -1.0, 1.0),
+        ];
+        let sphere = Sphere::from_points(&tetrahedron).unwrap();
+        assert_eq!(sphere.center, Point3D::origin());
+        assert!((sphere.radius - 3.0_f64.sqrt()).abs() < 1e-10);
+    }
+
+    #[test]
+    fn test_from_points_contains_all_and_beats_ritter() {
+        let points: Vec<Point3D> = (0..500)
+            .map(|i| {
+                let t = i as f64;
+                Point3D::new(
+                    (t * 0.37).sin() * 3.0,
+                    (t * 0.11).cos() * 2.0,
+                    (t * 0.53).sin() * (t * 0.07).cos(),
+                )
+            })
+            .collect();
+        let exact = Sphere::from_points(&points).unwrap();
+        let approx = Sphere::from_points_approx(&points).unwrap();
+        for p in &points {
+            assert!(exact.center.distance_to(p) <= exact.radius + 1e-9);
+            assert!(approx.center.distance_to(p) <= approx.radius + 1e-9);
+        }
+        assert!(exact.radius <= approx.radius + 1e-9);
+    }
+
+    #[test]
+    fn test_from_points_invalid() {
+        assert!(matches!(
+            Sphere::from_points(&[]),
+            Err(GeometryError::InvalidParameter(_))
+        ));
+        assert!(matches!(
+            Sphere::from_points(&[Point3D::origin(), Point3D::origin()]),
+            Err(GeometryError::DegenerateCase(_))
+        ));
+        assert!(Sphere::from_points_approx(&[Point3D::origin()]).is_err());
+    }
+
+    #[test]
+    fn test_from_collinear_points() {
+        let points: Vec<Point3D> = (0..10).map(|i| Point3D::new(i as f64, 0.0, 0.0)).collect();
+        let sphere = Sphere::from_points(&points).unwrap();
+        assert_eq!(sphere.center, Point3D::new(4.5, 0.0, 0.0));
+        assert!((sphere.radius - 4.5).abs() < 1e-10);
+    }
+
+    #[test]
+    fn test_bounding_box() {
+        let sphere = Sphere::new(Point3D::new(1.0, 2.0, 3.0), 0.5).unwrap();
+        let bbox = sphere.bounding_box();
+        assert_eq!(bbox.min, Point3D::new(0.5, 1.5, 2.5));
+        assert_eq!(bbox.max, Point3D::new(1.5, 2.5, 3.5));
+    }
+
+    #[test]
+    fn test_union() {
+        let a = Sphere::new(Point3D::origin(), 1.0).unwrap();
+        let b = Sphere::new(Point3D::new(4.0, 0.0, 0.0), 1.0).unwrap();
+        let union = a.union(&b);
+        assert_eq!(union.center, Point3D::new(2.0, 0.0, 0.0));
+        assert!((union.radius - 3.0).abs() < 1e-10);
+
+        let inner = Sphere::new(Point3D::new(0.5, 0.0, 0.0), 0.2).unwrap();
+        assert_eq!(a.union(&inner), a);
+        assert_eq!(inner.union(&a), a);
+    }
 }
@@ -1,5 +1,5 @@
 use crate::error::GeometryError;
-use crate::primitives::{Plane, Point3D, Vector3D, AABB};
+use crate::primitives::{Plane, Point3D, Sphere, Vector3D, AABB};
 use crate::utils::approx_zero;
 
 #[derive(Debug, Clone, Copy)]
@@ -72,6 +72,12 @@ impl Triangle {
         })
     }
 
+    /// Smallest sphere enclosing the vertices. Fails with
+    /// `GeometryError::DegenerateCase` when all three vertices coincide.
+    pub fn bounding_sphere(&self) -> Result<Sphere, GeometryError> {
+        Sphere::from_points(&[self.a, self.b, self.c])
+    }
+
     pub fn barycentric_coords(&self, point: &Point3D) -> (f64, f64, f64) {
         let v0 = Vector3D::from_points(&self.a, &self.b);
         let v1 = Vector3D::from_points(&self.a, &self.c);
@@ -96,6 +102,60 @@ impl Triangle {
         (u, v, w)
     }
 
+    pub fn closest_point(&self, point: &Point3D) -> Point3D {
+        let ab = self.b - self.a;
+        let ac = self.c - self.a;
+        let ap = *point - self.a;
+
+        let d1 = ab.dot(&ap);
+        let d2 = ac.dot(&ap);
+        if d1 <= 0.0 && d2 <= 0.0 {
+            return self.a;
+        }
+
+        let bp = *point - self.b;
+        let d3 = ab.dot(&bp);
+        let d4 = ac.dot(&bp);
+        if d3 >= 0.0 && d4 <= d3 {
+            return self.b;
+        }
+
+        let vc = d1 * d4 - d3 * d2;
+        if vc <= 0.0 && d1 >= 0.0 && d3 <= 0.0 {
+            let v = d1 / (d1 - d3);
+            return self.a + ab * v;
+        }
+
+        let cp = *point - self.c;
+        let d5 = ab.dot(&cp);
+        let d6 = ac.dot(&cp);
+        if d6 >= 0.0 && d5 <= d6 {
+            return self.c;
+        }
+
+        let vb = d5 * d2 - d1 * d6;
+        if vb <= 0.0 && d2 >= 0.0 && d6 <= 0.0 {
+            let w = d2 / (d2 - d6);
+            return self.a + ac * w;
+        }
+
+        let va = d3 * d6 - d5 * d4;
+        if va <= 0.0 && (d4 - d3) >= 0.0 && (d5 - d6) >= 0.0 {
+            let w = (d4 - d3) / ((d4 - d3) + (d5 - d6));
+            return self.b + (self.c - self.b) * w;
+        }
+
+        let denom = 1.0 / (va + vb + vc);
+        let v = vb * denom;
+        let w = vc * denom;
+        self.a + ab * v + ac * w
+    }
+
+    #[inline]
+    pub fn distance_to_point(&self, point: &Point3D) -> f64 {
+        self.closest_point(point).distance_to(point)
+    }
+
     pub fn contains_point(&self, point: &Point3D) -> bool {
         let plane = self.to_plane();
         if !plane.contains_point(point) {
@@ -149,6 +209,64 @@ mod tests {
         assert_eq!(tri.area(), 2.0);
     }
 
+    #[test]
+    fn test_closest_point() {
+        let tri = Triangle::new(
+            Point3D::new(0.0, 0.0, 0.0),
+            Point3D::new(2.0, 0.0, 0.0),
+            Point3D::new(0.0, 2.0, 0.0),
+        )
+        .unwrap();
+        assert_eq!(
+            tri.closest_point(&Point3D::new(0.5, 0.5, 3.0)),
+            Point3D::new(0.5, 0.5, 0.0)
+        );
+        assert_eq!(
+            tri.closest_point(&Point3D::new(-1.0, -1.0, 0.0)),
+            Point3D::new(0.0, 0.0, 0.0)
+        );
+        assert_eq!(
+            tri.closest_point(&Point3D::new(1.0, -1.0, 0.0)),
+            Point3D::new(1.0, 0.0, 0.0)
+        );
+        assert_eq!(
+            tri.closest_point(&Point3D::new(2.0, 2.0, 0.0)),
+            Point3D::new(1.0, 1.0, 0.0)
+        );
+        assert_eq!(tri.distance_to_point(&Point3D::new(0.5, 0.5, 3.0)), 3.0);
+    }
+
+    #[test]
+    fn test_bounding_sphere() {
+        let acute = Triangle::new(
+            Point3D::new(-1.0, 0.0, 0.0),
+            Point3D::new(1.0, 0.0, 0.0),
+            Point3D::new(0.0, 1.5, 0.0),
+        )
+        .unwrap();
+        let sphere = acute.bounding_sphere().unwrap();
+        for vertex in [acute.a, acute.b, acute.c] {
+            assert!((sphere.center.distance_to(&vertex) - sphere.radius).abs() < 1e-10);
+        }
+
+        let obtuse = Triangle::new(
+            Point3D::new(-2.0, 0.0, 0.0),
+            Point3D::new(2.0, 0.0, 0.0),
+            Point3D::new(0.0, 0.5, 0.0),
+        )
+        .unwrap();
+        let sphere = obtuse.bounding_sphere().unwrap();
+        assert_eq!(sphere.center, Point3D::origin());
+        assert!((sphere.radius - 2.0).abs() < 1e-10);
+
+        let a = Point3D::new(1.0, 2.0, 3.0);
+        let point = Triangle { a, b: a, c: a };
+        assert!(matches!(
+            point.bounding_sphere(),
+            Err(GeometryError::DegenerateCase(_))
+        ));
+    }
+
     #[test]
     fn test_centroid() {
         let tri = Triangle::new(
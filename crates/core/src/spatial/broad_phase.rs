@@ -0,0 +1,627 @@
+use std::cmp::Ordering;
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::hash::Hash;
+
+use crate::primitives::AABB;
+
+/// Above this many boxes inserted since the last update, sorting and
+/// sweeping everything is cheaper than moving each new box into place.
+const FULL_SORT_THRESHOLD: usize = 32;
+
+/// Change in the overlap state of a pair between two calls to
+/// [`SweepAndPrune::update_pairs`]. Pairs are always ordered `(low, high)`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PairEvent<H> {
+    Added(H, H),
+    Removed(H, H),
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Endpoint<H> {
+    value: f64,
+    handle: H,
+    is_min: bool,
+}
+
+impl<H> Endpoint<H> {
+    /// Minimum endpoints sort before maximum ones at equal values, so boxes
+    /// that only touch still count as overlapping, as in `AABB::intersects`.
+    fn cmp_position(&self, other: &Self) -> Ordering {
+        self.value
+            .total_cmp(&other.value)
+            .then(other.is_min.cmp(&self.is_min))
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Slot<H> {
+    aabb: AABB,
+    /// Indices of the box's endpoints in the sorted list.
+    min: usize,
+    max: usize,
+    /// Boxes whose x intervals overlap this one.
+    partners: BTreeSet<H>,
+    /// Inserted since the last update; its endpoints are still at the end
+    /// of the list and count as lying beyond every other endpoint.
+    fresh: bool,
+    moved: bool,
+}
+
+/// Incremental sweep-and-prune broad phase over user handles.
+///
+/// Box endpoints along the x axis stay sorted between frames. Only boxes
+/// that were inserted or moved are walked into place, and each swap of a
+/// minimum with a maximum endpoint is where two boxes start or stop
+/// overlapping on x; overlaps on y and z are then rechecked for the moved
+/// boxes alone. Since objects move little from one frame to the next, a
+/// frame costs about as much as the boxes that moved and the pairs that
+/// changed. Call [`SweepAndPrune::update_pairs`] once per frame after
+/// inserting, moving and removing boxes to refresh the candidate pairs and
+/// learn which pairs started or stopped overlapping.
+#[derive(Debug, Clone)]
+pub struct SweepAndPrune<H> {
+    slots: HashMap<H, Slot<H>>,
+    endpoints: Vec<Endpoint<H>>,
+    pairs: BTreeSet<(H, H)>,
+    /// Boxes inserted or moved since the last update, in that order.
+    dirty: Vec<H>,
+    /// Boxes removed since the last update, with their x partners.
+    removed: Vec<(H, BTreeSet<H>)>,
+    /// Pairs touched during an update, with whether they were in `pairs`
+    /// before it.
+    changed: BTreeMap<(H, H), bool>,
+}
+
+impl<H: Copy + Ord + Hash> Default for SweepAndPrune<H> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<H: Copy + Ord + Hash> SweepAndPrune<H> {
+    pub fn new() -> Self {
+        Self {
+            slots: HashMap::new(),
+            endpoints: Vec::new(),
+            pairs: BTreeSet::new(),
+            dirty: Vec::new(),
+            removed: Vec::new(),
+            changed: BTreeMap::new(),
+        }
+    }
+
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.slots.len()
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.slots.is_empty()
+    }
+
+    #[inline]
+    pub fn contains(&self, handle: &H) -> bool {
+        self.slots.contains_key(handle)
+    }
+
+    #[inline]
+    pub fn get(&self, handle: &H) -> Option<AABB> {
+        self.slots.get(handle).map(|slot| slot.aabb)
+    }
+
+    /// Adds `handle` with bounds `aabb`, returning the previous bounds if the
+    /// handle was already present.
+    pub fn insert(&mut self, handle: H, aabb: AABB) -> Option<AABB> {
+        if let Some(slot) = self.slots.get(&handle) {
+            let previous = slot.aabb;
+            self.update(handle, aabb);
+            return Some(previous);
+        }
+        let min = self.endpoints.len();
+        for (value, is_min) in [(aabb.min.x, true), (aabb.max.x, false)] {
+            self.endpoints.push(Endpoint {
+                value,
+                handle,
+                is_min,
+            });
+        }
+        self.slots.insert(
+            handle,
+            Slot {
+                aabb,
+                min,
+                max: min + 1,
+                partners: BTreeSet::new(),
+                fresh: true,
+                moved: false,
+            },
+        );
+        self.dirty.push(handle);
+        None
+    }
+
+    /// Moves an existing box. Returns `false` if `handle` is unknown.
+    pub fn update(&mut self, handle: H, aabb: AABB) -> bool {
+        let Some(slot) = self.slots.get_mut(&handle) else {
+            return false;
+        };
+        slot.aabb = aabb;
+        if !slot.fresh && !slot.moved {
+            slot.moved = true;
+            self.dirty.push(handle);
+        }
+        true
+    }
+
+    /// Removes a box. Pairs it was part of are reported as removed by the
+    /// next [`SweepAndPrune::update_pairs`], which also drops its endpoints.
+    pub fn remove(&mut self, handle: &H) -> Option<AABB> {
+        let slot = self.slots.remove(handle)?;
+        self.removed.push((*handle, slot.partners));
+        Some(slot.aabb)
+    }
+
+    /// Candidate pairs found by the last [`SweepAndPrune::update_pairs`], in
+    /// ascending order.
+    pub fn pairs(&self) -> impl Iterator<Item = (H, H)> + '_ {
+        self.pairs.iter().copied()
+    }
+
+    #[inline]
+    pub fn pair_count(&self) -> usize {
+        self.pairs.len()
+    }
+
+    pub fn contains_pair(&self, a: H, b: H) -> bool {
+        self.pairs.contains(&ordered(a, b))
+    }
+
+    /// Moves inserted and updated boxes into place, updating the pairs as
+    /// they pass other boxes, and returns how the pair set changed since
+    /// the previous call: removals first, then additions, each in
+    /// ascending pair order.
+    pub fn update_pairs(&mut self) -> Vec<PairEvent<H>> {
+        self.drop_removed();
+
+        let dirty = std::mem::take(&mut self.dirty);
+        let fresh = dirty
+            .iter()
+            .filter(|handle| self.slots.get(handle).is_some_and(|slot| slot.fresh))
+            .count();
+        if fresh > FULL_SORT_THRESHOLD {
+            self.rebuild();
+        } else {
+            // New boxes only move left from the end of the list, so they go
+            // first, while everything before them is still sorted.
+            let (fresh, moved): (Vec<H>, Vec<H>) = dirty
+                .iter()
+                .partition(|handle| self.slots.get(handle).is_some_and(|slot| slot.fresh));
+            for handle in fresh.iter().chain(&moved) {
+                self.place(*handle);
+            }
+            for handle in fresh.iter().chain(&moved) {
+                self.recheck_partners(*handle);
+            }
+        }
+
+        let changed = std::mem::take(&mut self.changed);
+        let mut events: Vec<PairEvent<H>> = changed
+            .iter()
+            .filter(|&(pair, &was)| was && !self.pairs.contains(pair))
+            .map(|(&(a, b), _)| PairEvent::Removed(a, b))
+            .collect();
+        events.extend(
+            changed
+                .iter()
+                .filter(|&(pair, &was)| !was && self.pairs.contains(pair))
+                .map(|(&(a, b), _)| PairEvent::Added(a, b)),
+        );
+        events
+    }
+
+    /// Forgets removed boxes and compacts the endpoint list once for all of
+    /// them.
+    fn drop_removed(&mut self) {
+        if self.removed.is_empty() {
+            return;
+        }
+        for (handle, partners) in std::mem::take(&mut self.removed) {
+            for partner in partners {
+                if let Some(slot) = self.slots.get_mut(&partner) {
+                    slot.partners.remove(&handle);
+                }
+                self.set_pair(handle, partner, false);
+            }
+        }
+        // A handle removed and inserted again keeps only its new endpoints.
+        let slots = &self.slots;
+        let mut index = 0;
+        self.endpoints.retain(|endpoint| {
+            let live = slots.get(&endpoint.handle).is_some_and(|slot| {
+                let own = if endpoint.is_min { slot.min } else { slot.max };
+                own == index
+            });
+            index += 1;
+            live
+        });
+        for (index, endpoint) in self.endpoints.iter().enumerate() {
+            let slot = self.slots.get_mut(&endpoint.handle).expect("live endpoint");
+            if endpoint.is_min {
+                slot.min = index;
+            } else {
+                slot.max = index;
+            }
+        }
+    }
+
+    /// Sorts and sweeps every box from scratch.
+    fn rebuild(&mut self) {
+        for endpoint in &mut self.endpoints {
+            let slot = &self.slots[&endpoint.handle];
+            endpoint.value = if endpoint.is_min {
+                slot.aabb.min.x
+            } else {
+                slot.aabb.max.x
+            };
+        }
+        self.endpoints.sort_by(Endpoint::cmp_position);
+
+        let mut partners: HashMap<H, BTreeSet<H>> = HashMap::new();
+        let mut active: Vec<H> = Vec::new();
+        for (index, endpoint) in self.endpoints.iter().enumerate() {
+            let slot = self.slots.get_mut(&endpoint.handle).expect("live endpoint");
+            slot.fresh = false;
+            slot.moved = false;
+            if endpoint.is_min {
+                slot.min = index;
+                for other in &active {
+                    partners.entry(endpoint.handle).or_default().insert(*other);
+                    partners.entry(*other).or_default().insert(endpoint.handle);
+                }
+                active.push(endpoint.handle);
+            } else {
+                slot.max = index;
+                if let Some(position) = active.iter().position(|h| *h == endpoint.handle) {
+                    active.swap_remove(position);
+                }
+            }
+        }
+
+        let mut pairs = BTreeSet::new();
+        for (handle, slot) in &mut self.slots {
+            slot.partners = partners.remove(handle).unwrap_or_default();
+            for other in &slot.partners {
+                if handle < other {
+                    pairs.insert((*handle, *other));
+                }
+            }
+        }
+        pairs.retain(|(a, b)| overlaps_yz(&self.slots[a].aabb, &self.slots[b].aabb));
+        for pair in self.pairs.symmetric_difference(&pairs) {
+            self.changed
+                .entry(*pair)
+                .or_insert_with(|| self.pairs.contains(pair));
+        }
+        self.pairs = pairs;
+    }
+
+    /// Walks the endpoints of `handle` to their sorted positions.
+    fn place(&mut self, handle: H) {
+        let Some(slot) = self.slots.get_mut(&handle) else {
+            return;
+        };
+        let (min, max) = (slot.min, slot.max);
+        let moving_right = slot.aabb.min.x > self.endpoints[min].value;
+        // Anything after a new box is another new box not yet in place.
+        let end = if slot.fresh {
+            max + 1
+        } else {
+            self.endpoints.len()
+        };
+        self.endpoints[min].value = slot.aabb.min.x;
+        self.endpoints[max].value = slot.aabb.max.x;
+        slot.fresh = false;
+        slot.moved = false;
+
+        // Lead with the endpoint in front so the two never cross.
+        if moving_right {
+            self.sift(max, end);
+            let min = self.slots[&handle].min;
+            self.sift(min, end);
+        } else {
+            self.sift(min, end);
+            let max = self.slots[&handle].max;
+            self.sift(max, end);
+        }
+    }
+
+    /// Swaps the endpoint at `index` towards its sorted position among the
+    /// endpoints before `end`.
+    fn sift(&mut self, mut index: usize, end: usize) {
+        while index > 0
+            && self.endpoints[index - 1].cmp_position(&self.endpoints[index]) == Ordering::Greater
+        {
+            self.swap(index - 1);
+            index -= 1;
+        }
+        while index + 1 < end
+            && self.endpoints[index].cmp_position(&self.endpoints[index + 1]) == Ordering::Greater
+        {
+            self.swap(index);
+            index += 1;
+        }
+    }
+
+    /// Swaps the endpoints at `left` and `left + 1`. A minimum moving in
+    /// front of a maximum starts an overlap on x; a maximum moving in front
+    /// of a minimum ends one.
+    fn swap(&mut self, left: usize) {
+        self.endpoints.swap(left, left + 1);
+        let ahead = self.endpoints[left];
+        let behind = self.endpoints[left + 1];
+        for (endpoint, index) in [(ahead, left), (behind, left + 1)] {
+            let slot = self.slots.get_mut(&endpoint.handle).expect("live endpoint");
+            if endpoint.is_min {
+                slot.min = index;
+            } else {
+                slot.max = index;
+            }
+        }
+
+        let (a, b) = (ahead.handle, behind.handle);
+        match (ahead.is_min, behind.is_min) {
+            (true, false) => {
+                self.slots
+                    .get_mut(&a)
+                    .expect("live endpoint")
+                    .partners
+                    .insert(b);
+                self.slots
+                    .get_mut(&b)
+                    .expect("live endpoint")
+                    .partners
+                    .insert(a);
+            }
+            (false, true) => {
+                self.slots
+                    .get_mut(&a)
+                    .expect("live endpoint")
+                    .partners
+                    .remove(&b);
+                self.slots
+                    .get_mut(&b)
+                    .expect("live endpoint")
+                    .partners
+                    .remove(&a);
+                self.set_pair(a, b, false);
+            }
+            _ => {}
+        }
+    }
+
+    /// Brings the pairs of `handle` with its x partners in line with their
+    /// overlap on y and z.
+    fn recheck_partners(&mut self, handle: H) {
+        let Some(slot) = self.slots.get(&handle) else {
+            return;
+        };
+        let aabb = slot.aabb;
+        let updates: Vec<(H, bool)> = slot
+            .partners
+            .iter()
+            .map(|other| (*other, overlaps_yz(&aabb, &self.slots[other].aabb)))
+            .collect();
+        for (other, overlapping) in updates {
+            self.set_pair(handle, other, overlapping);
+        }
+    }
+
+    fn set_pair(&mut self, a: H, b: H, overlapping: bool) {
+        let pair = ordered(a, b);
+        let was = self.pairs.contains(&pair);
+        if was == overlapping {
+            return;
+        }
+        self.changed.entry(pair).or_insert(was);
+        if overlapping {
+            self.pairs.insert(pair);
+        } else {
+            self.pairs.remove(&pair);
+        }
+    }
+}
+
+#[inline]
+fn overlaps_yz(a: &AABB, b: &AABB) -> bool {
+    a.min.y <= b.max.y && a.max.y >= b.min.y && a.min.z <= b.max.z && a.max.z >= b.min.z
+}
+
+#[inline]
+fn ordered<H: Ord>(a: H, b: H) -> (H, H) {
+    if a <= b {
+        (a, b)
+    } else {
+        (b, a)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::primitives::{Point3D, Vector3D};
+
+    fn unit_box(x: f64, y: f64, z: f64) -> AABB {
+        let min = Point3D::new(x, y, z);
+        AABB::new(min, min + Vector3D::new(1.0, 1.0, 1.0)).unwrap()
+    }
+
+    #[test]
+    fn test_pairs_and_events() {
+        let mut sap = SweepAndPrune::new();
+        sap.insert(1u32, unit_box(0.0, 0.0, 0.0));
+        sap.insert(2, unit_box(0.5, 0.5, 0.5));
+        sap.insert(3, unit_box(5.0, 0.0, 0.0));
+        // Overlaps on x but not on y.
+        sap.insert(4, unit_box(0.2, 3.0, 0.0));
+
+        assert_eq!(sap.update_pairs(), vec![PairEvent::Added(1, 2)]);
+        assert!(sap.contains_pair(2, 1));
+        assert!(sap.update_pairs().is_empty());
+
+        assert!(sap.update(3, unit_box(1.0, 1.0, 1.0)));
+        assert!(!sap.update(9, unit_box(0.0, 0.0, 0.0)));
+        assert_eq!(
+            sap.update_pairs(),
+            vec![PairEvent::Added(1, 3), PairEvent::Added(2, 3)]
+        );
+
+        sap.update(1, unit_box(-5.0, 0.0, 0.0));
+        sap.remove(&2);
+        assert_eq!(
+            sap.update_pairs(),
+            vec![
+                PairEvent::Removed(1, 2),
+                PairEvent::Removed(1, 3),
+                PairEvent::Removed(2, 3),
+            ]
+        );
+        assert_eq!(sap.pair_count(), 0);
+        assert_eq!(sap.len(), 3);
+    }
+
+    #[test]
+    fn test_few_changes_per_frame_match_brute_force() {
+        let mut sap = SweepAndPrune::new();
+        let mut current: HashMap<usize, AABB> = HashMap::new();
+        let position = |i: usize, frame: usize| {
+            let t = i as f64 * 1.9 + frame as f64 * 0.3;
+            Point3D::new(
+                8.0 * t.cos() - i as f64 * 0.5,
+                2.0 * (0.7 * t).sin(),
+                1.5 * (1.1 * t).cos(),
+            )
+        };
+
+        for frame in 0..40 {
+            // New boxes arrive right to left, a few per frame.
+            if frame < 10 {
+                for i in [3 * frame + 2, 3 * frame + 1, 3 * frame] {
+                    let p = position(i, frame);
+                    let aabb = unit_box(p.x, p.y, p.z);
+                    assert!(sap.insert(i, aabb).is_none());
+                    current.insert(i, aabb);
+                }
+            }
+            for i in (frame % 4..30).step_by(4) {
+                if current.contains_key(&i) {
+                    let p = position(i, frame);
+                    let aabb = unit_box(p.x, p.y, p.z);
+                    sap.update(i, aabb);
+                    current.insert(i, aabb);
+                }
+            }
+            if frame % 7 == 3 {
+                let i = frame % 30;
+                if sap.remove(&i).is_some() {
+                    current.remove(&i);
+                }
+            }
+            if frame % 7 == 5 {
+                // Back again, somewhere else.
+                let i = (frame - 2) % 30;
+                let aabb = unit_box(-(i as f64), 0.0, 0.0);
+                sap.insert(i, aabb);
+                current.insert(i, aabb);
+            }
+
+            let before: BTreeSet<(usize, usize)> = sap.pairs().collect();
+            let events = sap.update_pairs();
+            let after: BTreeSet<(usize, usize)> = sap.pairs().collect();
+
+            let expected: BTreeSet<(usize, usize)> = current
+                .iter()
+                .flat_map(|(&a, box_a)| {
+                    current
+                        .iter()
+                        .filter(move |&(&b, box_b)| a < b && box_a.intersects(box_b))
+                        .map(move |(&b, _)| (a, b))
+                })
+                .collect();
+            assert_eq!(after, expected, "frame {}", frame);
+
+            let mut replayed = before;
+            for event in events {
+                match event {
+                    PairEvent::Added(a, b) => assert!(replayed.insert((a, b))),
+                    PairEvent::Removed(a, b) => assert!(replayed.remove(&(a, b))),
+                }
+            }
+            assert_eq!(replayed, after);
+        }
+    }
+
+    #[test]
+    fn test_touching_boxes_overlap() {
+        let mut sap = SweepAndPrune::new();
+        sap.insert("a", unit_box(0.0, 0.0, 0.0));
+        sap.insert("b", unit_box(1.0, 0.0, 0.0));
+        sap.update_pairs();
+        assert_eq!(sap.pairs().collect::<Vec<_>>(), vec![("a", "b")]);
+    }
+
+    #[test]
+    fn test_matches_brute_force_over_frames() {
+        let mut sap = SweepAndPrune::new();
+        let n = 120;
+        let position = |i: usize, frame: usize| {
+            let t = i as f64 * 0.7 + frame as f64 * 0.05;
+            Point3D::new(10.0 * t.sin(), 4.0 * (1.3 * t).cos(), 3.0 * (0.9 * t).sin())
+        };
+        let mut current = HashMap::new();
+
+        for frame in 0..10 {
+            for i in 0..n {
+                if frame > 5 && i == 7 {
+                    assert!(!sap.update(i, unit_box(0.0, 0.0, 0.0)));
+                    continue;
+                }
+                let p = position(i, frame);
+                let aabb = unit_box(p.x, p.y, p.z);
+                if frame == 0 {
+                    sap.insert(i, aabb);
+                } else {
+                    sap.update(i, aabb);
+                }
+                current.insert(i, aabb);
+            }
+            if frame == 5 {
+                sap.remove(&7);
+                current.remove(&7);
+            }
+
+            let before: BTreeSet<(usize, usize)> = sap.pairs().collect();
+            let events = sap.update_pairs();
+            let after: BTreeSet<(usize, usize)> = sap.pairs().collect();
+
+            let mut expected = BTreeSet::new();
+            for (&a, box_a) in &current {
+                for (&b, box_b) in &current {
+                    if a < b && box_a.intersects(box_b) {
+                        expected.insert((a, b));
+                    }
+                }
+            }
+            assert_eq!(after, expected);
+
+            let mut replayed = before;
+            for event in events {
+                match event {
+                    PairEvent::Added(a, b) => assert!(replayed.insert((a, b))),
+                    PairEvent::Removed(a, b) => assert!(replayed.remove(&(a, b))),
+                }
+            }
+            assert_eq!(replayed, after);
+        }
+    }
+}
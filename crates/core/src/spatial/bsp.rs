@@ -0,0 +1,259 @@
+use crate::primitives::{Plane, Point3D, Vector3D};
+use crate::utils::EPSILON;
+
+#[derive(Debug, Clone)]
+struct Node<T> {
+    plane: Plane,
+    polygons: Vec<(Vec<Point3D>, T)>,
+    front: Option<usize>,
+    back: Option<usize>,
+}
+
+/// Binary space partitioning tree over planar polygons, for drawing them in
+/// visibility order.
+///
+/// Each node splits space by the plane of one polygon; polygons crossing it
+/// are cut in two, each half keeping a copy of the original's value. The
+/// traversals then list every fragment back to front, so painting them in
+/// that order is correct even for intersecting or cyclically overlapping
+/// polygons.
+#[derive(Debug, Clone)]
+pub struct BspTree<T> {
+    nodes: Vec<Node<T>>,
+    root: Option<usize>,
+    len: usize,
+}
+
+impl<T: Clone> BspTree<T> {
+    /// Builds a tree over `(vertices, value)` polygons, split in the order
+    /// given. Polygons with fewer than three vertices or no area are
+    /// dropped.
+    pub fn new<I: IntoIterator<Item = (Vec<Point3D>, T)>>(polygons: I) -> Self {
+        let mut tree = Self {
+            nodes: Vec::new(),
+            root: None,
+            len: 0,
+        };
+        let polygons: Vec<_> = polygons
+            .into_iter()
+            .filter_map(|(vertices, value)| {
+                let plane = polygon_plane(&vertices)?;
+                Some((vertices, plane, value))
+            })
+            .collect();
+        tree.root = tree.build(polygons);
+        tree
+    }
+
+    /// Number of polygon fragments after splitting.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Number of splitting planes.
+    #[inline]
+    pub fn node_count(&self) -> usize {
+        self.nodes.len()
+    }
+
+    /// Fragments ordered from farthest to nearest as seen from `eye`.
+    pub fn back_to_front(&self, eye: &Point3D) -> Vec<(&[Point3D], &T)> {
+        self.ordered(|plane| plane.distance_to_point(eye) > 0.0)
+    }
+
+    /// Fragments ordered from farthest to nearest for a parallel projection
+    /// looking along `direction`.
+    pub fn back_to_front_along(&self, direction: &Vector3D) -> Vec<(&[Point3D], &T)> {
+        self.ordered(|plane| plane.normal.dot(direction) < 0.0)
+    }
+
+    fn ordered<F: Fn(&Plane) -> bool>(&self, viewer_in_front: F) -> Vec<(&[Point3D], &T)> {
+        let mut result = Vec::with_capacity(self.len);
+        let mut stack = Vec::new();
+        // Each entry is a node plus whether its far side is already drawn.
+        stack.extend(self.root.map(|root| (root, false)));
+        while let Some((index, far_done)) = stack.pop() {
+            let node = &self.nodes[index];
+            let in_front = viewer_in_front(&node.plane);
+            let (far, near) = if in_front {
+                (node.back, node.front)
+            } else {
+                (node.front, node.back)
+            };
+            if far_done {
+                result.extend(
+                    node.polygons
+                        .iter()
+                        .map(|(vertices, value)| (vertices.as_slice(), value)),
+                );
+                stack.extend(near.map(|child| (child, false)));
+            } else {
+                stack.push((index, true));
+                stack.extend(far.map(|child| (child, false)));
+            }
+        }
+        result
+    }
+
+    fn build(&mut self, polygons: Vec<(Vec<Point3D>, Plane, T)>) -> Option<usize> {
+        let mut polygons = polygons.into_iter();
+        let (vertices, plane, value) = polygons.next()?;
+
+        let mut coplanar = vec![(vertices, value)];
+        let mut front = Vec::new();
+        let mut back = Vec::new();
+        for (vertices, own_plane, value) in polygons {
+            let distances: Vec<f64> = vertices
+                .iter()
+                .map(|v| plane.distance_to_point(v))
+                .collect();
+            let has_front = distances.iter().any(|&d| d > EPSILON);
+            let has_back = distances.iter().any(|&d| d < -EPSILON);
+            match (has_front, has_back) {
+                (false, false) => coplanar.push((vertices, value)),
+                (true, false) => front.push((vertices, own_plane, value)),
+                (false, true) => back.push((vertices, own_plane, value)),
+                (true, true) => {
+                    let (front_part, back_part) = split_polygon(&vertices, &distances);
+                    if front_part.len() >= 3 {
+                        front.push((front_part, own_plane, value.clone()));
+                    }
+                    if back_part.len() >= 3 {
+                        back.push((back_part, own_plane, value));
+                    }
+                }
+            }
+        }
+
+        self.len += coplanar.len();
+        let index = self.nodes.len();
+        self.nodes.push(Node {
+            plane,
+            polygons: coplanar,
+            front: None,
+            back: None,
+        });
+        let front = self.build(front);
+        let back = self.build(back);
+        self.nodes[index].front = front;
+        self.nodes[index].back = back;
+        Some(index)
+    }
+}
+
+/// Plane through a polygon using Newell's method, which tolerates collinear
+/// runs of vertices left behind by earlier splits.
+fn polygon_plane(vertices: &[Point3D]) -> Option<Plane> {
+    if vertices.len() < 3 {
+        return None;
+    }
+    let mut normal = Vector3D::zero();
+    for (i, current) in vertices.iter().enumerate() {
+        let next = &vertices[(i + 1) % vertices.len()];
+        normal = normal
+            + Vector3D::new(
+                (current.y - next.y) * (current.z + next.z),
+                (current.z - next.z) * (current.x + next.x),
+                (current.x - next.x) * (current.y + next.y),
+            );
+    }
+    Plane::from_point_normal(&vertices[0], &normal).ok()
+}
+
+/// Cuts a polygon along the plane its vertices are `distances` from.
+/// Vertices on the plane go to both halves.
+fn split_polygon(vertices: &[Point3D], distances: &[f64]) -> (Vec<Point3D>, Vec<Point3D>) {
+    let mut front = Vec::new();
+    let mut back = Vec::new();
+    for (i, current) in vertices.iter().enumerate() {
+        let j = (i + 1) % vertices.len();
+        let (dc, dn) = (distances[i], distances[j]);
+        if dc >= -EPSILON {
+            front.push(*current);
+        }
+        if dc <= EPSILON {
+            back.push(*current);
+        }
+        if (dc > EPSILON && dn < -EPSILON) || (dc < -EPSILON && dn > EPSILON) {
+            let t = dc / (dc - dn);
+            let crossing = *current + (vertices[j] - *current) * t;
+            front.push(crossing);
+            back.push(crossing);
+        }
+    }
+    (front, back)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn square(z: f64) -> Vec<Point3D> {
+        vec![
+            Point3D::new(-1.0, -1.0, z),
+            Point3D::new(1.0, -1.0, z),
+            Point3D::new(1.0, 1.0, z),
+            Point3D::new(-1.0, 1.0, z),
+        ]
+    }
+
+    #[test]
+    fn test_parallel_polygons_order() {
+        let tree = BspTree::new([(square(0.0), 'b'), (square(2.0), 'a'), (square(-2.0), 'c')]);
+        assert_eq!(tree.len(), 3);
+
+        let from_above: Vec<char> = tree
+            .back_to_front(&Point3D::new(0.0, 0.0, 10.0))
+            .into_iter()
+            .map(|(_, v)| *v)
+            .collect();
+        assert_eq!(from_above, vec!['c', 'b', 'a']);
+
+        let from_below: Vec<char> = tree
+            .back_to_front_along(&Vector3D::new(0.0, 0.0, 1.0))
+            .into_iter()
+            .map(|(_, v)| *v)
+            .collect();
+        assert_eq!(from_below, vec!['a', 'b', 'c']);
+    }
+
+    #[test]
+    fn test_intersecting_polygons_are_split() {
+        let vertical = vec![
+            Point3D::new(0.0, -1.0, -1.0),
+            Point3D::new(0.0, 1.0, -1.0),
+            Point3D::new(0.0, 1.0, 1.0),
+            Point3D::new(0.0, -1.0, 1.0),
+        ];
+        let tree = BspTree::new([(square(0.0), 0), (vertical, 1)]);
+        assert_eq!(tree.len(), 3);
+        assert_eq!(tree.node_count(), 3);
+
+        let order = tree.back_to_front(&Point3D::new(0.5, 0.0, 10.0));
+        // The lower half of the vertical polygon is hidden behind the square,
+        // so it is drawn first; the upper half covers the square.
+        assert_eq!(*order[0].1, 1);
+        assert!(order[0].0.iter().all(|p| p.z <= 0.0));
+        assert_eq!(*order[1].1, 0);
+        assert_eq!(*order[2].1, 1);
+        assert!(order[2].0.iter().all(|p| p.z >= 0.0));
+    }
+
+    #[test]
+    fn test_degenerate_polygons_are_dropped() {
+        let line = vec![
+            Point3D::new(0.0, 0.0, 0.0),
+            Point3D::new(1.0, 0.0, 0.0),
+            Point3D::new(2.0, 0.0, 0.0),
+        ];
+        let tree = BspTree::new([(line, ()), (vec![Point3D::origin()], ())]);
+        assert!(tree.is_empty());
+        assert!(tree.back_to_front(&Point3D::origin()).is_empty());
+    }
+}
@@ -0,0 +1,583 @@
+use super::traits::{Bounded, RayCast};
+use crate::operations::RayHit;
+use crate::primitives::{Point3D, Ray, Sphere, Vector3D, AABB};
+
+const MAX_LEAF_SIZE: usize = 4;
+const SAH_BINS: usize = 16;
+const TRAVERSAL_COST: f64 = 1.0;
+
+#[derive(Debug, Clone, Copy)]
+enum NodeKind {
+    Leaf { start: usize, count: usize },
+    Interior { left: usize, right: usize },
+}
+
+#[derive(Debug, Clone, Copy)]
+struct BvhNode {
+    bounds: AABB,
+    kind: NodeKind,
+}
+
+/// Bounding volume hierarchy over owned items, built with the binned surface
+/// area heuristic.
+///
+/// Items keep the index they had in the input vector. Queries that return
+/// several items list them in ascending index order, and ray queries break
+/// ties on equal `t` towards the lower index, so results never depend on the
+/// tree layout.
+#[derive(Debug, Clone)]
+pub struct Bvh<T> {
+    items: Vec<T>,
+    indices: Vec<usize>,
+    nodes: Vec<BvhNode>,
+}
+
+impl<T: Bounded> Bvh<T> {
+    pub fn build(items: Vec<T>) -> Self {
+        let mut bvh = Self {
+            indices: (0..items.len()).collect(),
+            items,
+            nodes: Vec::new(),
+        };
+        bvh.rebuild();
+        bvh
+    }
+
+    /// Rebuilds the tree from scratch; prefer [`Bvh::refit`] when items only
+    /// moved a little.
+    pub fn rebuild(&mut self) {
+        self.nodes.clear();
+        self.indices = (0..self.items.len()).collect();
+        if self.items.is_empty() {
+            return;
+        }
+
+        let bounds: Vec<AABB> = self.items.iter().map(|item| item.bounding_box()).collect();
+        let centroids: Vec<Point3D> = bounds.iter().map(|b| b.center()).collect();
+
+        self.nodes.push(BvhNode {
+            bounds: bounds[0],
+            kind: NodeKind::Leaf {
+                start: 0,
+                count: self.items.len(),
+            },
+        });
+
+        let mut stack = vec![(0usize, 0usize, self.items.len())];
+        while let Some((node, start, end)) = stack.pop() {
+            let slice = &mut self.indices[start..end];
+            let node_bounds = union_all(slice.iter().map(|&i| &bounds[i]));
+            self.nodes[node].bounds = node_bounds;
+
+            let count = end - start;
+            if count <= MAX_LEAF_SIZE {
+                continue;
+            }
+
+            let mid = match sah_split(slice, &bounds, &centroids, &node_bounds) {
+                Some(mid) => start + mid,
+                None => continue,
+            };
+
+            let left = self.nodes.len();
+            let right = left + 1;
+            for (child_start, child_end) in [(start, mid), (mid, end)] {
+                self.nodes.push(BvhNode {
+                    bounds: node_bounds,
+                    kind: NodeKind::Leaf {
+                        start: child_start,
+                        count: child_end - child_start,
+                    },
+                });
+            }
+            self.nodes[node].kind = NodeKind::Interior { left, right };
+            stack.push((right, mid, end));
+            stack.push((left, start, mid));
+        }
+    }
+
+    /// Recomputes node bounds bottom-up after items moved, keeping the tree
+    /// topology. Queries stay correct but may slow down if items moved far.
+    pub fn refit(&mut self) {
+        // Children are always stored after their parent.
+        for node in (0..self.nodes.len()).rev() {
+            let bounds = match self.nodes[node].kind {
+                NodeKind::Leaf { start, count } => {
+                    let mut members = self.indices[start..start + count].iter();
+                    let first = *members.next().expect("leaves are never empty");
+                    members.fold(self.items[first].bounding_box(), |acc, &i| {
+                        acc.union(&self.items[i].bounding_box())
+                    })
+                }
+                NodeKind::Interior { left, right } => {
+                    self.nodes[left].bounds.union(&self.nodes[right].bounds)
+                }
+            };
+            self.nodes[node].bounds = bounds;
+        }
+    }
+
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    #[inline]
+    pub fn items(&self) -> &[T] {
+        &self.items
+    }
+
+    #[inline]
+    pub fn get(&self, index: usize) -> Option<&T> {
+        self.items.get(index)
+    }
+
+    /// Mutable access to an item. Call [`Bvh::refit`] once all edits are done.
+    #[inline]
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        self.items.get_mut(index)
+    }
+
+    #[inline]
+    pub fn into_items(self) -> Vec<T> {
+        self.items
+    }
+
+    pub fn bounds(&self) -> Option<AABB> {
+        self.nodes.first().map(|node| node.bounds)
+    }
+
+    /// Number of nodes in the tree, mostly useful for diagnostics.
+    #[inline]
+    pub fn node_count(&self) -> usize {
+        self.nodes.len()
+    }
+
+    /// Indices of items whose bounding box overlaps `aabb`.
+    pub fn query_aabb(&self, aabb: &AABB) -> Vec<usize> {
+        self.query(|bounds| bounds.intersects(aabb))
+    }
+
+    /// Indices of items whose bounding box overlaps `sphere`.
+    pub fn query_sphere(&self, sphere: &Sphere) -> Vec<usize> {
+        self.query(|bounds| bounds.distance_to_point(&sphere.center) <= sphere.radius)
+    }
+
+    fn query<F: Fn(&AABB) -> bool>(&self, overlaps: F) -> Vec<usize> {
+        let mut result = Vec::new();
+        if self.nodes.is_empty() {
+            return result;
+        }
+
+        let mut stack = vec![0usize];
+        while let Some(node) = stack.pop() {
+            let node = &self.nodes[node];
+            if !overlaps(&node.bounds) {
+                continue;
+            }
+            match node.kind {
+                NodeKind::Leaf { start, count } => {
+                    for &index in &self.indices[start..start + count] {
+                        if overlaps(&self.items[index].bounding_box()) {
+                            result.push(index);
+                        }
+                    }
+                }
+                NodeKind::Interior { left, right } => {
+                    stack.push(right);
+                    stack.push(left);
+                }
+            }
+        }
+
+        result.sort_unstable();
+        result
+    }
+}
+
+impl<T: Bounded + RayCast> Bvh<T> {
+    /// Nearest hit along the ray, with the index of the item that was hit.
+    pub fn closest_hit(&self, ray: &Ray) -> Option<(usize, RayHit)> {
+        self.traverse(ray, false)
+    }
+
+    /// Some hit along the ray, stopping at the first one found. Cheaper than
+    /// [`Bvh::closest_hit`] for occlusion tests; the reported hit is
+    /// deterministic for a given tree but not necessarily the nearest.
+    pub fn any_hit(&self, ray: &Ray) -> Option<(usize, RayHit)> {
+        self.traverse(ray, true)
+    }
+
+    fn traverse(&self, ray: &Ray, stop_at_first: bool) -> Option<(usize, RayHit)> {
+        if self.nodes.is_empty() {
+            return None;
+        }
+
+        let inverse = inverse_direction(ray);
+        let mut best: Option<(usize, RayHit)> = None;
+        let mut stack = vec![0usize];
+
+        while let Some(node) = stack.pop() {
+            let t_max = best.map_or(ray.t_max, |(_, hit)| hit.t);
+            let node = &self.nodes[node];
+            if slab_entry(ray, &inverse, &node.bounds, t_max).is_none() {
+                continue;
+            }
+
+            match node.kind {
+                NodeKind::Leaf { start, count } => {
+                    for &index in &self.indices[start..start + count] {
+                        let t_max = best.map_or(ray.t_max, |(_, hit)| hit.t);
+                        let narrowed = Ray { t_max, ..*ray };
+                        if let Some(hit) = self.items[index].cast_ray(&narrowed) {
+                            let closer = match best {
+                                None => true,
+                                Some((best_index, best_hit)) => {
+                                    hit.t < best_hit.t
+                                        || (hit.t == best_hit.t && index < best_index)
+                                }
+                            };
+                            if closer {
+                                best = Some((index, hit));
+                                if stop_at_first {
+                                    return best;
+                                }
+                            }
+                        }
+                    }
+                }
+                NodeKind::Interior { left, right } => {
+                    // Visit the nearer child first so `best` tightens early.
+                    let t_left = slab_entry(ray, &inverse, &self.nodes[left].bounds, t_max);
+                    let t_right = slab_entry(ray, &inverse, &self.nodes[right].bounds, t_max);
+                    match (t_left, t_right) {
+                        (Some(l), Some(r)) if r < l => {
+                            stack.push(left);
+                            stack.push(right);
+                        }
+                        (Some(_), Some(_)) => {
+                            stack.push(right);
+                            stack.push(left);
+                        }
+                        (Some(_), None) => stack.push(left),
+                        (None, Some(_)) => stack.push(right),
+                        (None, None) => {}
+                    }
+                }
+            }
+        }
+
+        best
+    }
+}
+
+impl<T: Bounded> FromIterator<T> for Bvh<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        Bvh::build(iter.into_iter().collect())
+    }
+}
+
+/// Entry distance of the ray into `aabb` within `[ray.t_min, t_max]`.
+fn slab_entry(ray: &Ray, inverse: &Vector3D, aabb: &AABB, t_max: f64) -> Option<f64> {
+    slab_interval(ray, inverse, aabb, t_max).map(|(near, _)| near)
+}
+
+/// Entry and exit distances of the ray through `aabb`, clamped to
+/// `[ray.t_min, t_max]`. `inverse` holds the reciprocal of each direction
+/// component.
+pub(super) fn slab_interval(
+    ray: &Ray,
+    inverse: &Vector3D,
+    aabb: &AABB,
+    t_max: f64,
+) -> Option<(f64, f64)> {
+    let mut near = ray.t_min;
+    let mut far = t_max;
+    for (origin, inv, min, max) in [
+        (ray.origin.x, inverse.x, aabb.min.x, aabb.max.x),
+        (ray.origin.y, inverse.y, aabb.min.y, aabb.max.y),
+        (ray.origin.z, inverse.z, aabb.min.z, aabb.max.z),
+    ] {
+        let t1 = (min - origin) * inv;
+        let t2 = (max - origin) * inv;
+        // `min`/`max` discard the NaN produced by 0 * inf on a slab boundary.
+        near = near.max(t1.min(t2));
+        far = far.min(t1.max(t2));
+    }
+    (near <= far).then_some((near, far))
+}
+
+#[inline]
+pub(super) fn inverse_direction(ray: &Ray) -> Vector3D {
+    Vector3D::new(
+        1.0 / ray.direction.x,
+        1.0 / ray.direction.y,
+        1.0 / ray.direction.z,
+    )
+}
+
+fn union_all<'a, I: Iterator<Item = &'a AABB>>(mut boxes: I) -> AABB {
+    let first = *boxes.next().expect("at least one box");
+    boxes.fold(first, |acc, b| acc.union(b))
+}
+
+/// Picks the cheapest binned SAH split of `indices` and partitions them in
+/// place (stable), returning the split position. `None` keeps a leaf.
+fn sah_split(
+    indices: &mut [usize],
+    bounds: &[AABB],
+    centroids: &[Point3D],
+    node_bounds: &AABB,
+) -> Option<usize> {
+    let count = indices.len();
+    let centroid_bounds = indices.iter().skip(1).fold(
+        (centroids[indices[0]], centroids[indices[0]]),
+        |(lo, hi), &i| {
+            let c = centroids[i];
+            (
+                Point3D::new(lo.x.min(c.x), lo.y.min(c.y), lo.z.min(c.z)),
+                Point3D::new(hi.x.max(c.x), hi.y.max(c.y), hi.z.max(c.z)),
+            )
+        },
+    );
+    let lo = [
+        centroid_bounds.0.x,
+        centroid_bounds.0.y,
+        centroid_bounds.0.z,
+    ];
+    let hi = [
+        centroid_bounds.1.x,
+        centroid_bounds.1.y,
+        centroid_bounds.1.z,
+    ];
+    let coord = |p: &Point3D, axis: usize| [p.x, p.y, p.z][axis];
+    let bin_of = |i: usize, axis: usize| {
+        let extent = hi[axis] - lo[axis];
+        let b = ((coord(&centroids[i], axis) - lo[axis]) / extent * SAH_BINS as f64) as usize;
+        b.min(SAH_BINS - 1)
+    };
+
+    let parent_area = node_bounds.surface_area().max(f64::MIN_POSITIVE);
+    let mut best: Option<(f64, usize, usize)> = None;
+    for axis in 0..3 {
+        if hi[axis] - lo[axis] <= 0.0 {
+            continue;
+        }
+
+        let mut bin_counts = [0usize; SAH_BINS];
+        let mut bin_bounds: [Option<AABB>; SAH_BINS] = [None; SAH_BINS];
+        for &i in indices.iter() {
+            let b = bin_of(i, axis);
+            bin_counts[b] += 1;
+            bin_bounds[b] = Some(match bin_bounds[b] {
+                Some(existing) => existing.union(&bounds[i]),
+                None => bounds[i],
+            });
+        }
+
+        let mut right_area = [0.0; SAH_BINS];
+        let mut right_count = [0usize; SAH_BINS];
+        let mut acc: Option<AABB> = None;
+        let mut n = 0;
+        for b in (1..SAH_BINS).rev() {
+            if let Some(bb) = bin_bounds[b] {
+                acc = Some(acc.map_or(bb, |a| a.union(&bb)));
+            }
+            n += bin_counts[b];
+            right_area[b] = acc.map_or(0.0, |a| a.surface_area());
+            right_count[b] = n;
+        }
+
+        let mut acc: Option<AABB> = None;
+        let mut n = 0;
+        for split in 1..SAH_BINS {
+            if let Some(bb) = bin_bounds[split - 1] {
+                acc = Some(acc.map_or(bb, |a| a.union(&bb)));
+            }
+            n += bin_counts[split - 1];
+            if n == 0 || right_count[split] == 0 {
+                continue;
+            }
+            let left_area = acc.map_or(0.0, |a| a.surface_area());
+            let cost = TRAVERSAL_COST
+                + (left_area * n as f64 + right_area[split] * right_count[split] as f64)
+                    / parent_area;
+            if best.is_none_or(|(c, _, _)| cost < c) {
+                best = Some((cost, axis, split));
+            }
+        }
+    }
+
+    match best {
+        Some((cost, axis, split)) if cost < count as f64 || count > 4 * MAX_LEAF_SIZE => {
+            let (left, right): (Vec<usize>, Vec<usize>) =
+                indices.iter().partition(|&&i| bin_of(i, axis) < split);
+            let mid = left.len();
+            indices[..mid].copy_from_slice(&left);
+            indices[mid..].copy_from_slice(&right);
+            Some(mid)
+        }
+        Some(_) => None,
+        // All centroids coincide: split by position to keep leaves small.
+        None if count > 4 * MAX_LEAF_SIZE => Some(count / 2),
+        None => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::primitives::Triangle;
+
+    fn grid_triangles(n: usize) -> Vec<Triangle> {
+        let mut triangles = Vec::new();
+        for i in 0..n {
+            for j in 0..n {
+                let x = i as f64;
+                let y = j as f64;
+                triangles.push(
+                    Triangle::new(
+                        Point3D::new(x, y, 0.0),
+                        Point3D::new(x + 1.0, y, 0.0),
+                        Point3D::new(x, y + 1.0, 0.0),
+                    )
+                    .unwrap(),
+                );
+            }
+        }
+        triangles
+    }
+
+    fn brute_force_closest<T: RayCast>(items: &[T], ray: &Ray) -> Option<(usize, RayHit)> {
+        let mut best: Option<(usize, RayHit)> = None;
+        for (i, item) in items.iter().enumerate() {
+            if let Some(hit) = item.cast_ray(ray) {
+                if best.is_none_or(|(_, b)| hit.t < b.t) {
+                    best = Some((i, hit));
+                }
+            }
+        }
+        best
+    }
+
+    #[test]
+    fn test_empty_bvh() {
+        let bvh: Bvh<Sphere> = Bvh::build(Vec::new());
+        assert!(bvh.is_empty());
+        assert!(bvh.bounds().is_none());
+        let ray = Ray::new(Point3D::origin(), Vector3D::unit_x()).unwrap();
+        assert!(bvh.closest_hit(&ray).is_none());
+        assert!(bvh
+            .query_aabb(&AABB::new(Point3D::origin(), Point3D::new(1.0, 1.0, 1.0)).unwrap())
+            .is_empty());
+    }
+
+    #[test]
+    fn test_closest_hit_matches_brute_force() {
+        let triangles = grid_triangles(20);
+        let bvh = Bvh::build(triangles.clone());
+        assert_eq!(bvh.len(), 400);
+        assert!(bvh.node_count() > 1);
+
+        for k in 0..50 {
+            let t = k as f64 * 0.37;
+            let origin = Point3D::new(10.0 + 8.0 * t.sin(), 10.0 + 8.0 * t.cos(), 5.0);
+            let direction = Vector3D::new(0.3 * t.cos(), -0.2 * t.sin(), -1.0);
+            let ray = Ray::new(origin, direction).unwrap();
+
+            let expected = brute_force_closest(&triangles, &ray);
+            let actual = bvh.closest_hit(&ray);
+            match (expected, actual) {
+                (Some((_, e)), Some((i, a))) => {
+                    assert!((e.t - a.t).abs() < 1e-12);
+                    assert!(triangles[i].cast_ray(&ray).is_some());
+                }
+                (None, None) => {}
+                other => panic!("mismatch: {:?}", other),
+            }
+        }
+    }
+
+    #[test]
+    fn test_closest_hit_prefers_nearest_and_lowest_index() {
+        let spheres = vec![
+            Sphere::new(Point3D::new(0.0, 0.0, -10.0), 1.0).unwrap(),
+            Sphere::new(Point3D::new(0.0, 0.0, -5.0), 1.0).unwrap(),
+            Sphere::new(Point3D::new(0.0, 0.0, -5.0), 1.0).unwrap(),
+            Sphere::new(Point3D::new(0.0, 5.0, -5.0), 1.0).unwrap(),
+        ];
+        let bvh = Bvh::build(spheres);
+        let ray = Ray::new(Point3D::origin(), Vector3D::new(0.0, 0.0, -1.0)).unwrap();
+        let (index, hit) = bvh.closest_hit(&ray).unwrap();
+        assert_eq!(index, 1);
+        assert!((hit.t - 4.0).abs() < 1e-12);
+
+        let (index, _) = bvh.any_hit(&ray).unwrap();
+        assert!(index <= 2);
+
+        let short =
+            Ray::with_range(Point3D::origin(), Vector3D::new(0.0, 0.0, -1.0), 0.0, 3.0).unwrap();
+        assert!(bvh.closest_hit(&short).is_none());
+        assert!(bvh.any_hit(&short).is_none());
+    }
+
+    #[test]
+    fn test_overlap_queries() {
+        let bvh: Bvh<Triangle> = grid_triangles(10).into_iter().collect();
+        let region = AABB::new(Point3D::new(2.5, 2.5, -1.0), Point3D::new(3.5, 3.5, 1.0)).unwrap();
+        let hits = bvh.query_aabb(&region);
+        let expected: Vec<usize> = (0..100)
+            .filter(|&i| bvh.items()[i].bounding_box().intersects(&region))
+            .collect();
+        assert_eq!(hits, expected);
+        assert!(hits.windows(2).all(|w| w[0] < w[1]));
+
+        let probe = Sphere::new(Point3D::new(5.0, 5.0, 0.5), 0.6).unwrap();
+        let hits = bvh.query_sphere(&probe);
+        assert!(!hits.is_empty());
+        for &i in &hits {
+            assert!(
+                bvh.items()[i]
+                    .bounding_box()
+                    .distance_to_point(&probe.center)
+                    <= 0.6
+            );
+        }
+    }
+
+    #[test]
+    fn test_refit_after_motion() {
+        let spheres: Vec<Sphere> = (0..32)
+            .map(|i| Sphere::new(Point3D::new(i as f64 * 3.0, 0.0, 0.0), 1.0).unwrap())
+            .collect();
+        let mut bvh = Bvh::build(spheres);
+
+        bvh.get_mut(7).unwrap().center = Point3D::new(0.0, 50.0, 0.0);
+        bvh.refit();
+
+        let ray = Ray::new(Point3D::new(0.0, 100.0, 0.0), Vector3D::new(0.0, -1.0, 0.0)).unwrap();
+        let (index, hit) = bvh.closest_hit(&ray).unwrap();
+        assert_eq!(index, 7);
+        assert!((hit.t - 49.0).abs() < 1e-12);
+        assert!(bvh
+            .bounds()
+            .unwrap()
+            .contains_point(&Point3D::new(0.0, 51.0, 0.0)));
+    }
+
+    #[test]
+    fn test_identical_items_still_split() {
+        let boxes: Vec<AABB> = (0..100)
+            .map(|_| AABB::new(Point3D::origin(), Point3D::new(1.0, 1.0, 1.0)).unwrap())
+            .collect();
+        let bvh = Bvh::build(boxes);
+        assert!(bvh.node_count() > 1);
+        let ray = Ray::new(Point3D::new(0.5, 0.5, 5.0), Vector3D::new(0.0, 0.0, -1.0)).unwrap();
+        assert_eq!(bvh.closest_hit(&ray).unwrap().0, 0);
+    }
+}
@@ -0,0 +1,31 @@
+//! Deterministic, evenly scattered inputs for the spatial index tests.
+
+use crate::primitives::{Point3D, Vector3D, AABB};
+
+/// `n` points spread over the cube from `min` with edge `extent`, using
+/// fractional parts of irrational-looking multiples so runs repeat exactly.
+pub(crate) fn scattered_points(n: usize, min: Point3D, extent: f64) -> Vec<Point3D> {
+    (0..n)
+        .map(|i| {
+            let t = i as f64;
+            min + Vector3D::new(
+                (t * 0.618).fract() * extent,
+                (t * 0.414).fract() * extent,
+                (t * 0.732).fract() * extent,
+            )
+        })
+        .collect()
+}
+
+/// `n` boxes between 0.5 and 3.5 units wide (half that in z), with their
+/// minimum corners placed as in [`scattered_points`].
+pub(crate) fn scattered_boxes(n: usize, min: Point3D, extent: f64) -> Vec<AABB> {
+    scattered_points(n, min, extent)
+        .into_iter()
+        .enumerate()
+        .map(|(i, corner)| {
+            let size = 0.5 + (i as f64 * 0.27).fract() * 3.0;
+            AABB::new(corner, corner + Vector3D::new(size, size, size * 0.5)).unwrap()
+        })
+        .collect()
+}
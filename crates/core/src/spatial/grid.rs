@@ -0,0 +1,386 @@
+use std::collections::{HashMap, HashSet};
+use std::hash::Hash;
+
+use super::bvh::{inverse_direction, slab_interval};
+use super::octree::{outside_any_plane, sort_hits};
+use crate::error::GeometryError;
+use crate::primitives::{Plane, Point3D, Ray, Sphere, AABB};
+
+type CellKey = [i64; 3];
+
+/// Unbounded uniform grid (spatial hash) over user handles, each stored with
+/// its `AABB` in every cell the box overlaps.
+///
+/// Works best when items are no larger than a few cells. Queries listing
+/// several handles return them in ascending order.
+#[derive(Debug, Clone)]
+pub struct UniformGrid<H> {
+    cell_size: f64,
+    cells: HashMap<CellKey, Vec<H>>,
+    entries: HashMap<H, AABB>,
+    /// Range of cells that have ever held an item while the grid was
+    /// non-empty; bounds ray traversal.
+    occupied: Option<(CellKey, CellKey)>,
+}
+
+impl<H: Copy + Ord + Hash> UniformGrid<H> {
+    pub fn new(cell_size: f64) -> Result<Self, GeometryError> {
+        if !(cell_size.is_finite() && cell_size > 0.0) {
+            return Err(GeometryError::InvalidParameter(
+                "Grid cell size must be positive and finite".to_string(),
+            ));
+        }
+        Ok(Self {
+            cell_size,
+            cells: HashMap::new(),
+            entries: HashMap::new(),
+            occupied: None,
+        })
+    }
+
+    #[inline]
+    pub fn cell_size(&self) -> f64 {
+        self.cell_size
+    }
+
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    #[inline]
+    pub fn contains(&self, handle: &H) -> bool {
+        self.entries.contains_key(handle)
+    }
+
+    #[inline]
+    pub fn get(&self, handle: &H) -> Option<AABB> {
+        self.entries.get(handle).copied()
+    }
+
+    /// Number of non-empty cells.
+    #[inline]
+    pub fn cell_count(&self) -> usize {
+        self.cells.len()
+    }
+
+    /// Adds `handle` with bounds `aabb`, returning the previous bounds if the
+    /// handle was already present.
+    pub fn insert(&mut self, handle: H, aabb: AABB) -> Option<AABB> {
+        let previous = self.remove(&handle);
+        let (lo, hi) = self.cell_range(&aabb);
+        for_each_cell(lo, hi, |key| {
+            self.cells.entry(key).or_default().push(handle)
+        });
+        self.entries.insert(handle, aabb);
+        self.occupied = Some(match self.occupied {
+            Some((min, max)) => (
+                std::array::from_fn(|i| min[i].min(lo[i])),
+                std::array::from_fn(|i| max[i].max(hi[i])),
+            ),
+            None => (lo, hi),
+        });
+        previous
+    }
+
+    pub fn remove(&mut self, handle: &H) -> Option<AABB> {
+        let aabb = self.entries.remove(handle)?;
+        let (lo, hi) = self.cell_range(&aabb);
+        for_each_cell(lo, hi, |key| {
+            if let Some(items) = self.cells.get_mut(&key) {
+                if let Some(position) = items.iter().position(|h| h == handle) {
+                    items.swap_remove(position);
+                }
+                if items.is_empty() {
+                    self.cells.remove(&key);
+                }
+            }
+        });
+        if self.entries.is_empty() {
+            self.occupied = None;
+        }
+        Some(aabb)
+    }
+
+    /// Moves an existing item to new bounds. Returns `false` if `handle` is
+    /// unknown. Moves that stay within the same cells only update the stored
+    /// bounds.
+    pub fn update(&mut self, handle: H, aabb: AABB) -> bool {
+        let range = self.cell_range(&aabb);
+        let cell_size = self.cell_size;
+        let Some(current) = self.entries.get_mut(&handle) else {
+            return false;
+        };
+        if (
+            cell_of(&current.min, cell_size),
+            cell_of(&current.max, cell_size),
+        ) == range
+        {
+            *current = aabb;
+        } else {
+            self.insert(handle, aabb);
+        }
+        true
+    }
+
+    /// Handles whose bounds overlap `aabb`.
+    pub fn query_aabb(&self, aabb: &AABB) -> Vec<H> {
+        let (lo, hi) = self.cell_range(aabb);
+        self.collect(lo, hi, |bounds| bounds.intersects(aabb))
+    }
+
+    /// Handles whose bounds overlap `sphere`.
+    pub fn query_sphere(&self, sphere: &Sphere) -> Vec<H> {
+        let (lo, hi) = self.cell_range(&sphere.bounding_box());
+        self.collect(lo, hi, |bounds| {
+            bounds.distance_to_point(&sphere.center) <= sphere.radius
+        })
+    }
+
+    /// Handles whose bounds are not entirely behind any of `planes`. Plane
+    /// normals point into the volume, as for a view frustum.
+    pub fn query_frustum(&self, planes: &[Plane]) -> Vec<H> {
+        let mut result: Vec<H> = self
+            .cells
+            .iter()
+            .filter(|(key, _)| !outside_any_plane(&self.cell_bounds(**key), planes))
+            .flat_map(|(_, items)| items.iter().copied())
+            .filter(|handle| !outside_any_plane(&self.entries[handle], planes))
+            .collect();
+        result.sort_unstable();
+        result.dedup();
+        result
+    }
+
+    /// Handles whose bounds the ray passes through within `[t_min, t_max]`,
+    /// with the distance at which the ray enters them, nearest first. Cells
+    /// are walked in ray order with a 3D-DDA.
+    pub fn query_ray(&self, ray: &Ray) -> Vec<(H, f64)> {
+        let mut hits = Vec::new();
+        let Some((lo, hi)) = self.occupied else {
+            return hits;
+        };
+        let inverse = inverse_direction(ray);
+        let region = AABB {
+            min: self.cell_bounds(lo).min,
+            max: self.cell_bounds(hi).max,
+        };
+        let Some((t_enter, t_exit)) = slab_interval(ray, &inverse, &region, ray.t_max) else {
+            return hits;
+        };
+
+        let size = self.cell_size;
+        let start = ray.point_at(t_enter);
+        let origin = [ray.origin.x, ray.origin.y, ray.origin.z];
+        let direction = [ray.direction.x, ray.direction.y, ray.direction.z];
+        let start_cell = cell_of(&start, size);
+        let mut cell: CellKey = std::array::from_fn(|i| start_cell[i].clamp(lo[i], hi[i]));
+        let step: [i64; 3] = std::array::from_fn(|i| match direction[i] {
+            d if d > 0.0 => 1,
+            d if d < 0.0 => -1,
+            _ => 0,
+        });
+        let mut t_next: [f64; 3] = std::array::from_fn(|i| match step[i] {
+            1 => ((cell[i] + 1) as f64 * size - origin[i]) / direction[i],
+            -1 => (cell[i] as f64 * size - origin[i]) / direction[i],
+            _ => f64::INFINITY,
+        });
+        let t_delta: [f64; 3] = std::array::from_fn(|i| (size / direction[i]).abs());
+
+        let mut seen = HashSet::new();
+        loop {
+            if let Some(items) = self.cells.get(&cell) {
+                for handle in items {
+                    if !seen.insert(*handle) {
+                        continue;
+                    }
+                    let bounds = &self.entries[handle];
+                    if let Some((t, _)) = slab_interval(ray, &inverse, bounds, ray.t_max) {
+                        hits.push((*handle, t));
+                    }
+                }
+            }
+
+            let axis = (0..3)
+                .min_by(|&a, &b| t_next[a].total_cmp(&t_next[b]))
+                .unwrap_or(0);
+            if t_next[axis] > t_exit {
+                break;
+            }
+            cell[axis] += step[axis];
+            if cell[axis] < lo[axis] || cell[axis] > hi[axis] {
+                break;
+            }
+            t_next[axis] += t_delta[axis];
+        }
+
+        sort_hits(&mut hits);
+        hits
+    }
+
+    fn collect<F: Fn(&AABB) -> bool>(&self, lo: CellKey, hi: CellKey, overlaps: F) -> Vec<H> {
+        let mut result = Vec::new();
+        for_each_cell(lo, hi, |key| {
+            if let Some(items) = self.cells.get(&key) {
+                result.extend(
+                    items
+                        .iter()
+                        .filter(|handle| overlaps(&self.entries[*handle]))
+                        .copied(),
+                );
+            }
+        });
+        result.sort_unstable();
+        result.dedup();
+        result
+    }
+
+    fn cell_range(&self, aabb: &AABB) -> (CellKey, CellKey) {
+        (
+            cell_of(&aabb.min, self.cell_size),
+            cell_of(&aabb.max, self.cell_size),
+        )
+    }
+
+    fn cell_bounds(&self, key: CellKey) -> AABB {
+        let corner = |offset: i64| {
+            Point3D::new(
+                (key[0] + offset) as f64 * self.cell_size,
+                (key[1] + offset) as f64 * self.cell_size,
+                (key[2] + offset) as f64 * self.cell_size,
+            )
+        };
+        AABB {
+            min: corner(0),
+            max: corner(1),
+        }
+    }
+}
+
+#[inline]
+fn cell_of(point: &Point3D, cell_size: f64) -> CellKey {
+    [
+        (point.x / cell_size).floor() as i64,
+        (point.y / cell_size).floor() as i64,
+        (point.z / cell_size).floor() as i64,
+    ]
+}
+
+fn for_each_cell<F: FnMut(CellKey)>(lo: CellKey, hi: CellKey, mut f: F) {
+    for x in lo[0]..=hi[0] {
+        for y in lo[1]..=hi[1] {
+            for z in lo[2]..=hi[2] {
+                f([x, y, z]);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::primitives::Vector3D;
+    use crate::spatial::fixtures::scattered_boxes;
+
+    fn boxes(n: usize) -> Vec<AABB> {
+        scattered_boxes(n, Point3D::new(-20.0, -20.0, -20.0), 40.0)
+    }
+
+    fn filled(items: &[AABB]) -> UniformGrid<usize> {
+        let mut grid = UniformGrid::new(2.0).unwrap();
+        for (i, aabb) in items.iter().enumerate() {
+            grid.insert(i, *aabb);
+        }
+        grid
+    }
+
+    #[test]
+    fn test_invalid_cell_size() {
+        assert!(UniformGrid::<u32>::new(0.0).is_err());
+        assert!(UniformGrid::<u32>::new(-1.0).is_err());
+        assert!(UniformGrid::<u32>::new(f64::NAN).is_err());
+    }
+
+    #[test]
+    fn test_insert_remove_update() {
+        let mut grid = UniformGrid::new(1.0).unwrap();
+        let a = AABB::new(Point3D::new(0.2, 0.2, 0.2), Point3D::new(1.5, 0.8, 0.8)).unwrap();
+        let b = AABB::new(Point3D::new(10.0, 0.0, 0.0), Point3D::new(10.5, 0.5, 0.5)).unwrap();
+        grid.insert("crate", a);
+        assert_eq!(grid.cell_count(), 2);
+
+        let nudged = AABB::new(Point3D::new(0.3, 0.2, 0.2), Point3D::new(1.6, 0.8, 0.8)).unwrap();
+        assert!(grid.update("crate", nudged));
+        assert_eq!(grid.get(&"crate"), Some(nudged));
+        assert!(grid.update("crate", b));
+        assert_eq!(grid.cell_count(), 1);
+        assert!(grid.query_aabb(&a).is_empty());
+        assert_eq!(grid.query_aabb(&b), vec!["crate"]);
+        assert!(!grid.update("barrel", a));
+
+        assert_eq!(grid.remove(&"crate"), Some(b));
+        assert!(grid.is_empty());
+        assert_eq!(grid.cell_count(), 0);
+    }
+
+    #[test]
+    fn test_queries_match_brute_force() {
+        let items = boxes(300);
+        let grid = filled(&items);
+
+        let region =
+            AABB::new(Point3D::new(-5.0, -8.0, -3.0), Point3D::new(6.0, 4.0, 9.0)).unwrap();
+        let expected: Vec<usize> = (0..items.len())
+            .filter(|&i| items[i].intersects(&region))
+            .collect();
+        assert_eq!(grid.query_aabb(&region), expected);
+
+        let sphere = Sphere::new(Point3D::new(3.0, -2.0, 1.0), 7.5).unwrap();
+        let expected: Vec<usize> = (0..items.len())
+            .filter(|&i| items[i].distance_to_point(&sphere.center) <= sphere.radius)
+            .collect();
+        assert_eq!(grid.query_sphere(&sphere), expected);
+
+        let planes = [
+            Plane::from_point_normal(&Point3D::new(0.0, 0.0, 0.0), &Vector3D::unit_y()).unwrap(),
+            Plane::from_point_normal(&Point3D::new(0.0, 0.0, 5.0), &-Vector3D::unit_z()).unwrap(),
+        ];
+        let expected: Vec<usize> = (0..items.len())
+            .filter(|&i| items[i].max.y >= 0.0 && items[i].min.z <= 5.0)
+            .collect();
+        assert_eq!(grid.query_frustum(&planes), expected);
+    }
+
+    #[test]
+    fn test_ray_traversal_matches_brute_force() {
+        let items = boxes(300);
+        let grid = filled(&items);
+
+        for k in 0..40 {
+            let t = k as f64 * 0.41;
+            let origin = Point3D::new(30.0 * t.cos(), 30.0 * t.sin(), 25.0 * (t * 0.7).cos());
+            let direction = Point3D::new(t.sin(), 0.5 * t.cos(), -0.3) - origin;
+            let ray = Ray::new(origin, direction).unwrap();
+            let inverse = inverse_direction(&ray);
+
+            let mut expected: Vec<(usize, f64)> = items
+                .iter()
+                .enumerate()
+                .filter_map(|(i, b)| {
+                    slab_interval(&ray, &inverse, b, ray.t_max).map(|(t, _)| (i, t))
+                })
+                .collect();
+            sort_hits(&mut expected);
+            assert_eq!(grid.query_ray(&ray), expected);
+        }
+
+        let axis_ray = Ray::new(Point3D::new(-30.0, 0.5, 0.5), Vector3D::unit_x()).unwrap();
+        let hits = grid.query_ray(&axis_ray);
+        assert!(hits.windows(2).all(|w| w[0].1 <= w[1].1));
+    }
+}
@@ -0,0 +1,421 @@
+use std::cmp::Ordering;
+use std::collections::BinaryHeap;
+
+use crate::primitives::Point3D;
+
+/// Distance function used by [`KdTree`]. Any metric works as long as it is
+/// never smaller than the difference along a single axis, which holds for
+/// every Lp norm. Plain functions such as
+/// [`manhattan_distance`](crate::operations::manhattan_distance) and
+/// [`chebyshev_distance`](crate::operations::chebyshev_distance) implement it
+/// directly.
+pub trait DistanceMetric {
+    fn distance(&self, a: &Point3D, b: &Point3D) -> f64;
+}
+
+impl<F: Fn(&Point3D, &Point3D) -> f64> DistanceMetric for F {
+    #[inline]
+    fn distance(&self, a: &Point3D, b: &Point3D) -> f64 {
+        self(a, b)
+    }
+}
+
+/// The straight-line metric, `Point3D::distance_to`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Euclidean;
+
+impl DistanceMetric for Euclidean {
+    #[inline]
+    fn distance(&self, a: &Point3D, b: &Point3D) -> f64 {
+        a.distance_to(b)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Neighbor {
+    pub index: usize,
+    pub point: Point3D,
+    pub distance: f64,
+}
+
+/// Candidate ordered by distance, then by index so ties resolve towards the
+/// earlier input point.
+#[derive(Debug, Clone, Copy)]
+struct Candidate {
+    distance: f64,
+    index: usize,
+}
+
+impl PartialEq for Candidate {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Candidate {}
+
+impl PartialOrd for Candidate {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Candidate {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.distance
+            .total_cmp(&other.distance)
+            .then(self.index.cmp(&other.index))
+    }
+}
+
+/// Static k-d tree over a point cloud, stored implicitly: each sub-range of
+/// `order` keeps its median in the middle and splits along `axes[mid]`.
+///
+/// Results report the point's index in the input vector and are sorted by
+/// distance, ties broken by index.
+#[derive(Debug, Clone)]
+pub struct KdTree<M = Euclidean> {
+    points: Vec<Point3D>,
+    order: Vec<usize>,
+    axes: Vec<u8>,
+    metric: M,
+}
+
+impl KdTree<Euclidean> {
+    pub fn new(points: Vec<Point3D>) -> Self {
+        Self::with_metric(points, Euclidean)
+    }
+}
+
+impl<M: DistanceMetric> KdTree<M> {
+    pub fn with_metric(points: Vec<Point3D>, metric: M) -> Self {
+        let mut order: Vec<usize> = (0..points.len()).collect();
+        let mut axes = vec![0u8; points.len()];
+        build(&points, &mut order, &mut axes);
+        Self {
+            points,
+            order,
+            axes,
+            metric,
+        }
+    }
+
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.points.len()
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.points.is_empty()
+    }
+
+    #[inline]
+    pub fn points(&self) -> &[Point3D] {
+        &self.points
+    }
+
+    #[inline]
+    pub fn metric(&self) -> &M {
+        &self.metric
+    }
+
+    pub fn nearest(&self, query: &Point3D) -> Option<Neighbor> {
+        self.k_nearest(query, 1).into_iter().next()
+    }
+
+    /// The `k` closest points, nearest first.
+    pub fn k_nearest(&self, query: &Point3D, k: usize) -> Vec<Neighbor> {
+        if k == 0 {
+            return Vec::new();
+        }
+        let mut heap = BinaryHeap::with_capacity(k + 1);
+        self.search_k(query, k, 1.0, 0, self.order.len(), &mut heap);
+        self.finish(heap.into_vec())
+    }
+
+    /// A neighbour whose distance is within a factor `1 + epsilon` of the
+    /// true nearest distance. Larger `epsilon` prunes more of the tree.
+    pub fn approx_nearest(&self, query: &Point3D, epsilon: f64) -> Option<Neighbor> {
+        let mut heap = BinaryHeap::with_capacity(2);
+        self.search_k(
+            query,
+            1,
+            1.0 + epsilon.max(0.0),
+            0,
+            self.order.len(),
+            &mut heap,
+        );
+        self.finish(heap.into_vec()).into_iter().next()
+    }
+
+    /// Every point within `radius` of `query` (inclusive), nearest first.
+    pub fn within_radius(&self, query: &Point3D, radius: f64) -> Vec<Neighbor> {
+        let mut found = Vec::new();
+        self.search_radius(query, radius, 0, self.order.len(), &mut found);
+        self.finish(found)
+    }
+
+    fn search_k(
+        &self,
+        query: &Point3D,
+        k: usize,
+        slack: f64,
+        lo: usize,
+        hi: usize,
+        heap: &mut BinaryHeap<Candidate>,
+    ) {
+        if lo >= hi {
+            return;
+        }
+        let mid = lo + (hi - lo) / 2;
+        let index = self.order[mid];
+        let point = &self.points[index];
+
+        let candidate = Candidate {
+            distance: self.metric.distance(query, point),
+            index,
+        };
+        if heap.len() < k {
+            heap.push(candidate);
+        } else if heap.peek().is_some_and(|worst| candidate < *worst) {
+            heap.pop();
+            heap.push(candidate);
+        }
+
+        let axis = self.axes[mid] as usize;
+        let delta = coord(query, axis) - coord(point, axis);
+        let (near, far) = if delta < 0.0 {
+            ((lo, mid), (mid + 1, hi))
+        } else {
+            ((mid + 1, hi), (lo, mid))
+        };
+
+        self.search_k(query, k, slack, near.0, near.1, heap);
+        let worth_visiting = heap.len() < k
+            || heap
+                .peek()
+                .is_some_and(|worst| delta.abs() * slack <= worst.distance);
+        if worth_visiting {
+            self.search_k(query, k, slack, far.0, far.1, heap);
+        }
+    }
+
+    fn search_radius(
+        &self,
+        query: &Point3D,
+        radius: f64,
+        lo: usize,
+        hi: usize,
+        found: &mut Vec<Candidate>,
+    ) {
+        if lo >= hi {
+            return;
+        }
+        let mid = lo + (hi - lo) / 2;
+        let index = self.order[mid];
+        let point = &self.points[index];
+
+        let distance = self.metric.distance(query, point);
+        if distance <= radius {
+            found.push(Candidate { distance, index });
+        }
+
+        let axis = self.axes[mid] as usize;
+        let delta = coord(query, axis) - coord(point, axis);
+        if delta <= radius {
+            self.search_radius(query, radius, lo, mid, found);
+        }
+        if -delta <= radius {
+            self.search_radius(query, radius, mid + 1, hi, found);
+        }
+    }
+
+    fn finish(&self, mut candidates: Vec<Candidate>) -> Vec<Neighbor> {
+        candidates.sort();
+        candidates
+            .into_iter()
+            .map(|c| Neighbor {
+                index: c.index,
+                point: self.points[c.index],
+                distance: c.distance,
+            })
+            .collect()
+    }
+}
+
+impl FromIterator<Point3D> for KdTree<Euclidean> {
+    fn from_iter<I: IntoIterator<Item = Point3D>>(iter: I) -> Self {
+        KdTree::new(iter.into_iter().collect())
+    }
+}
+
+#[inline]
+fn coord(point: &Point3D, axis: usize) -> f64 {
+    match axis {
+        0 => point.x,
+        1 => point.y,
+        _ => point.z,
+    }
+}
+
+/// Splits `order` at its median along the axis of widest spread, recursing
+/// on both halves. Equal coordinates fall back to index order, so the layout
+/// is deterministic.
+fn build(points: &[Point3D], order: &mut [usize], axes: &mut [u8]) {
+    if order.len() <= 1 {
+        return;
+    }
+
+    let mut lo = [f64::INFINITY; 3];
+    let mut hi = [f64::NEG_INFINITY; 3];
+    for &i in order.iter() {
+        for axis in 0..3 {
+            let c = coord(&points[i], axis);
+            lo[axis] = lo[axis].min(c);
+            hi[axis] = hi[axis].max(c);
+        }
+    }
+    let axis = (0..3)
+        .max_by(|&a, &b| (hi[a] - lo[a]).total_cmp(&(hi[b] - lo[b])).then(b.cmp(&a)))
+        .unwrap_or(0);
+
+    let mid = order.len() / 2;
+    order.select_nth_unstable_by(mid, |&a, &b| {
+        coord(&points[a], axis)
+            .total_cmp(&coord(&points[b], axis))
+            .then(a.cmp(&b))
+    });
+    axes[mid] = axis as u8;
+
+    let (left, right) = order.split_at_mut(mid);
+    let (left_axes, right_axes) = axes.split_at_mut(mid);
+    build(points, left, left_axes);
+    build(points, &mut right[1..], &mut right_axes[1..]);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::operations::{chebyshev_distance, manhattan_distance};
+    use crate::spatial::fixtures::scattered_points;
+
+    fn cloud(n: usize) -> Vec<Point3D> {
+        scattered_points(n, Point3D::origin(), 10.0)
+    }
+
+    fn brute_force<M: DistanceMetric>(
+        points: &[Point3D],
+        query: &Point3D,
+        metric: &M,
+    ) -> Vec<Candidate> {
+        let mut all: Vec<Candidate> = points
+            .iter()
+            .enumerate()
+            .map(|(index, p)| Candidate {
+                distance: metric.distance(query, p),
+                index,
+            })
+            .collect();
+        all.sort();
+        all
+    }
+
+    #[test]
+    fn test_empty_tree() {
+        let tree = KdTree::new(Vec::new());
+        assert!(tree.is_empty());
+        assert!(tree.nearest(&Point3D::origin()).is_none());
+        assert!(tree.within_radius(&Point3D::origin(), 1.0).is_empty());
+    }
+
+    #[test]
+    fn test_nearest_and_k_nearest_match_brute_force() {
+        let points = cloud(500);
+        let tree = KdTree::new(points.clone());
+        for q in cloud(20)
+            .iter()
+            .map(|p| Point3D::new(p.z, p.x + 0.5, p.y - 0.5))
+        {
+            let expected = brute_force(&points, &q, &Euclidean);
+            let nearest = tree.nearest(&q).unwrap();
+            assert_eq!(nearest.index, expected[0].index);
+
+            let knn = tree.k_nearest(&q, 7);
+            let indices: Vec<usize> = knn.iter().map(|n| n.index).collect();
+            let expected: Vec<usize> = expected.iter().take(7).map(|c| c.index).collect();
+            assert_eq!(indices, expected);
+        }
+    }
+
+    #[test]
+    fn test_radius_search() {
+        let points = cloud(300);
+        let tree: KdTree = points.iter().copied().collect();
+        let query = Point3D::new(5.0, 5.0, 5.0);
+        let found = tree.within_radius(&query, 2.0);
+        let expected: Vec<usize> = brute_force(&points, &query, &Euclidean)
+            .into_iter()
+            .filter(|c| c.distance <= 2.0)
+            .map(|c| c.index)
+            .collect();
+        assert_eq!(found.iter().map(|n| n.index).collect::<Vec<_>>(), expected);
+        assert!(found.windows(2).all(|w| w[0].distance <= w[1].distance));
+    }
+
+    #[test]
+    fn test_pluggable_metrics() {
+        let points = cloud(200);
+        let query = Point3D::new(3.0, 7.0, 1.0);
+
+        let manhattan = KdTree::with_metric(points.clone(), manhattan_distance);
+        let expected = brute_force(&points, &query, &manhattan_distance);
+        let found = manhattan.k_nearest(&query, 5);
+        assert_eq!(
+            found.iter().map(|n| n.index).collect::<Vec<_>>(),
+            expected.iter().take(5).map(|c| c.index).collect::<Vec<_>>()
+        );
+        assert_eq!(
+            found[0].distance,
+            manhattan_distance(&query, &found[0].point)
+        );
+
+        let chebyshev = KdTree::with_metric(points.clone(), chebyshev_distance);
+        let expected = brute_force(&points, &query, &chebyshev_distance);
+        let within = chebyshev.within_radius(&query, 1.5);
+        assert_eq!(
+            within.iter().map(|n| n.index).collect::<Vec<_>>(),
+            expected
+                .iter()
+                .filter(|c| c.distance <= 1.5)
+                .map(|c| c.index)
+                .collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn test_approx_nearest_bound() {
+        let points = cloud(1000);
+        let tree = KdTree::new(points.clone());
+        let query = Point3D::new(4.2, 6.1, 2.7);
+        let exact = tree.nearest(&query).unwrap();
+        let approx = tree.approx_nearest(&query, 0.5).unwrap();
+        assert!(approx.distance <= exact.distance * 1.5 + 1e-12);
+        assert_eq!(tree.approx_nearest(&query, 0.0).unwrap().index, exact.index);
+    }
+
+    #[test]
+    fn test_duplicates_resolve_to_lowest_index() {
+        let points = vec![
+            Point3D::new(1.0, 1.0, 1.0),
+            Point3D::new(0.0, 0.0, 0.0),
+            Point3D::new(0.0, 0.0, 0.0),
+            Point3D::new(0.0, 0.0, 0.0),
+        ];
+        let tree = KdTree::new(points);
+        let knn = tree.k_nearest(&Point3D::new(0.1, 0.0, 0.0), 2);
+        assert_eq!(knn[0].index, 1);
+        assert_eq!(knn[1].index, 2);
+        assert_eq!(tree.k_nearest(&Point3D::origin(), 10).len(), 4);
+    }
+}
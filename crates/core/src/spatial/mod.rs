@@ -0,0 +1,17 @@
+mod broad_phase;
+mod bsp;
+mod bvh;
+#[cfg(test)]
+mod fixtures;
+mod grid;
+mod kdtree;
+mod octree;
+mod traits;
+
+pub use broad_phase::{PairEvent, SweepAndPrune};
+pub use bsp::BspTree;
+pub use bvh::Bvh;
+pub use grid::UniformGrid;
+pub use kdtree::{DistanceMetric, Euclidean, KdTree, Neighbor};
+pub use octree::Octree;
+pub use traits::{Bounded, RayCast};
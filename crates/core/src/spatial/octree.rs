@@ -0,0 +1,369 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+
+use super::bvh::{inverse_direction, slab_interval};
+use crate::operations::SupportMap;
+use crate::primitives::{Plane, Point3D, Ray, Sphere, Vector3D, AABB};
+
+/// Children slots holding this value are empty; the root can never be a child.
+const NO_CHILD: usize = 0;
+
+#[derive(Debug, Clone)]
+struct OctreeNode<H> {
+    center: Point3D,
+    half_size: f64,
+    children: [usize; 8],
+    items: Vec<H>,
+}
+
+impl<H> OctreeNode<H> {
+    fn new(center: Point3D, half_size: f64) -> Self {
+        Self {
+            center,
+            half_size,
+            children: [NO_CHILD; 8],
+            items: Vec::new(),
+        }
+    }
+
+    /// The cell grown by half its size on every side, which is where items
+    /// stored in this node may reach.
+    fn loose_bounds(&self) -> AABB {
+        let reach = Vector3D::new(1.0, 1.0, 1.0) * (2.0 * self.half_size);
+        AABB {
+            min: self.center + -reach,
+            max: self.center + reach,
+        }
+    }
+}
+
+/// Loose octree over user handles, each stored with its `AABB`.
+///
+/// An item lives in the deepest cell that contains its centre and is at
+/// least twice its size, so inserting, removing and moving an item never
+/// touches more than one node. Items centred outside `bounds` stay in the
+/// root and are still found by every query. Queries listing several handles
+/// return them in ascending order.
+#[derive(Debug, Clone)]
+pub struct Octree<H> {
+    nodes: Vec<OctreeNode<H>>,
+    entries: HashMap<H, (AABB, usize)>,
+    max_depth: usize,
+}
+
+impl<H: Copy + Ord + Hash> Octree<H> {
+    /// Creates an empty tree whose root cell is the cube enclosing `bounds`.
+    pub fn new(bounds: AABB, max_depth: usize) -> Self {
+        let size = bounds.size();
+        let half_size = size.x.max(size.y).max(size.z) * 0.5;
+        Self {
+            nodes: vec![OctreeNode::new(bounds.center(), half_size)],
+            entries: HashMap::new(),
+            max_depth,
+        }
+    }
+
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    #[inline]
+    pub fn contains(&self, handle: &H) -> bool {
+        self.entries.contains_key(handle)
+    }
+
+    #[inline]
+    pub fn get(&self, handle: &H) -> Option<AABB> {
+        self.entries.get(handle).map(|&(aabb, _)| aabb)
+    }
+
+    /// Number of allocated nodes, mostly useful for diagnostics.
+    #[inline]
+    pub fn node_count(&self) -> usize {
+        self.nodes.len()
+    }
+
+    /// Adds `handle` with bounds `aabb`, returning the previous bounds if the
+    /// handle was already present.
+    pub fn insert(&mut self, handle: H, aabb: AABB) -> Option<AABB> {
+        let previous = self.remove(&handle);
+        let node = self.place(&aabb);
+        self.nodes[node].items.push(handle);
+        self.entries.insert(handle, (aabb, node));
+        previous
+    }
+
+    pub fn remove(&mut self, handle: &H) -> Option<AABB> {
+        let (aabb, node) = self.entries.remove(handle)?;
+        self.detach(node, handle);
+        Some(aabb)
+    }
+
+    /// Moves an existing item to new bounds. Returns `false` if `handle` is
+    /// unknown.
+    pub fn update(&mut self, handle: H, aabb: AABB) -> bool {
+        let Some(&(_, node)) = self.entries.get(&handle) else {
+            return false;
+        };
+        let target = self.place(&aabb);
+        if target != node {
+            self.detach(node, &handle);
+            self.nodes[target].items.push(handle);
+        }
+        self.entries.insert(handle, (aabb, target));
+        true
+    }
+
+    /// Handles whose bounds overlap `aabb`.
+    pub fn query_aabb(&self, aabb: &AABB) -> Vec<H> {
+        self.query(|bounds| bounds.intersects(aabb))
+    }
+
+    /// Handles whose bounds overlap `sphere`.
+    pub fn query_sphere(&self, sphere: &Sphere) -> Vec<H> {
+        self.query(|bounds| bounds.distance_to_point(&sphere.center) <= sphere.radius)
+    }
+
+    /// Handles whose bounds are not entirely behind any of `planes`. Plane
+    /// normals point into the volume, as for a view frustum.
+    pub fn query_frustum(&self, planes: &[Plane]) -> Vec<H> {
+        self.query(|bounds| !outside_any_plane(bounds, planes))
+    }
+
+    /// Handles whose bounds the ray passes through within `[t_min, t_max]`,
+    /// with the distance at which the ray enters them, nearest first.
+    pub fn query_ray(&self, ray: &Ray) -> Vec<(H, f64)> {
+        let inverse = inverse_direction(ray);
+        let mut hits = Vec::new();
+        self.visit(
+            |bounds| slab_interval(ray, &inverse, bounds, ray.t_max).is_some(),
+            |handle, bounds| {
+                if let Some((t, _)) = slab_interval(ray, &inverse, bounds, ray.t_max) {
+                    hits.push((handle, t));
+                }
+            },
+        );
+        sort_hits(&mut hits);
+        hits
+    }
+
+    fn query<F: Fn(&AABB) -> bool>(&self, overlaps: F) -> Vec<H> {
+        let mut result = Vec::new();
+        self.visit(&overlaps, |handle, bounds| {
+            if overlaps(bounds) {
+                result.push(handle);
+            }
+        });
+        result.sort_unstable();
+        result
+    }
+
+    /// Walks every node whose loose bounds pass `enter`, handing each stored
+    /// item to `visit_item`. The root is always entered because it also
+    /// holds items centred outside the tree.
+    fn visit<E, V>(&self, enter: E, mut visit_item: V)
+    where
+        E: Fn(&AABB) -> bool,
+        V: FnMut(H, &AABB),
+    {
+        let mut stack = vec![0usize];
+        while let Some(index) = stack.pop() {
+            let node = &self.nodes[index];
+            if index != 0 && !enter(&node.loose_bounds()) {
+                continue;
+            }
+            for handle in &node.items {
+                visit_item(*handle, &self.entries[handle].0);
+            }
+            stack.extend(node.children.iter().filter(|&&c| c != NO_CHILD));
+        }
+    }
+
+    /// Finds (creating as needed) the node that should hold `aabb`.
+    fn place(&mut self, aabb: &AABB) -> usize {
+        let center = aabb.center();
+        let size = aabb.size();
+        let extent = size.x.max(size.y).max(size.z) * 0.5;
+
+        let root = &self.nodes[0];
+        let offset = center - root.center;
+        if offset.x.abs().max(offset.y.abs()).max(offset.z.abs()) > root.half_size {
+            return 0;
+        }
+
+        let mut node = 0;
+        for _ in 0..self.max_depth {
+            let parent = &self.nodes[node];
+            let child_half = parent.half_size * 0.5;
+            if extent > child_half {
+                break;
+            }
+
+            let parent_center = parent.center;
+            let octant = (center.x >= parent_center.x) as usize
+                | ((center.y >= parent_center.y) as usize) << 1
+                | ((center.z >= parent_center.z) as usize) << 2;
+            let mut child = parent.children[octant];
+            if child == NO_CHILD {
+                let shift = |bit: usize| {
+                    if octant & bit != 0 {
+                        child_half
+                    } else {
+                        -child_half
+                    }
+                };
+                let child_center = parent_center + Vector3D::new(shift(1), shift(2), shift(4));
+                child = self.nodes.len();
+                self.nodes.push(OctreeNode::new(child_center, child_half));
+                self.nodes[node].children[octant] = child;
+            }
+            node = child;
+        }
+        node
+    }
+
+    fn detach(&mut self, node: usize, handle: &H) {
+        let items = &mut self.nodes[node].items;
+        if let Some(position) = items.iter().position(|h| h == handle) {
+            items.swap_remove(position);
+        }
+    }
+}
+
+/// True when `aabb` lies entirely on the negative side of one of `planes`.
+pub(super) fn outside_any_plane(aabb: &AABB, planes: &[Plane]) -> bool {
+    planes
+        .iter()
+        .any(|plane| plane.distance_to_point(&aabb.support(&plane.normal)) < 0.0)
+}
+
+/// Orders ray hits by entry distance, then by handle.
+pub(super) fn sort_hits<H: Ord>(hits: &mut [(H, f64)]) {
+    hits.sort_unstable_by(|a, b| a.1.total_cmp(&b.1).then(a.0.cmp(&b.0)));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::spatial::fixtures::scattered_boxes;
+
+    fn world() -> AABB {
+        AABB::new(Point3D::new(0.0, 0.0, 0.0), Point3D::new(64.0, 64.0, 64.0)).unwrap()
+    }
+
+    fn boxes(n: usize) -> Vec<AABB> {
+        scattered_boxes(n, Point3D::origin(), 60.0)
+    }
+
+    #[test]
+    fn test_insert_remove_update() {
+        let mut tree = Octree::new(world(), 6);
+        let a = AABB::new(Point3D::new(1.0, 1.0, 1.0), Point3D::new(2.0, 2.0, 2.0)).unwrap();
+        let b = AABB::new(
+            Point3D::new(50.0, 50.0, 50.0),
+            Point3D::new(51.0, 51.0, 51.0),
+        )
+        .unwrap();
+        assert!(tree.insert(7u32, a).is_none());
+        assert!(tree.node_count() > 1);
+        assert_eq!(tree.insert(7, a), Some(a));
+        assert_eq!(tree.len(), 1);
+
+        assert!(tree.update(7, b));
+        assert!(!tree.update(8, b));
+        assert!(tree.query_aabb(&a).is_empty());
+        assert_eq!(tree.query_aabb(&b), vec![7]);
+
+        assert_eq!(tree.remove(&7), Some(b));
+        assert!(tree.remove(&7).is_none());
+        assert!(tree.is_empty());
+        assert!(tree.query_aabb(&b).is_empty());
+    }
+
+    #[test]
+    fn test_queries_match_brute_force() {
+        let items = boxes(400);
+        let mut tree = Octree::new(world(), 8);
+        for (i, aabb) in items.iter().enumerate() {
+            tree.insert(i, *aabb);
+        }
+
+        let region = AABB::new(
+            Point3D::new(10.0, 20.0, 5.0),
+            Point3D::new(30.0, 28.0, 40.0),
+        )
+        .unwrap();
+        let expected: Vec<usize> = (0..items.len())
+            .filter(|&i| items[i].intersects(&region))
+            .collect();
+        assert_eq!(tree.query_aabb(&region), expected);
+
+        let sphere = Sphere::new(Point3D::new(32.0, 32.0, 32.0), 9.0).unwrap();
+        let expected: Vec<usize> = (0..items.len())
+            .filter(|&i| items[i].distance_to_point(&sphere.center) <= sphere.radius)
+            .collect();
+        assert!(!expected.is_empty());
+        assert_eq!(tree.query_sphere(&sphere), expected);
+    }
+
+    #[test]
+    fn test_items_outside_bounds() {
+        let mut tree = Octree::new(world(), 4);
+        let far = AABB::new(Point3D::new(100.0, 0.0, 0.0), Point3D::new(101.0, 1.0, 1.0)).unwrap();
+        let huge = AABB::new(
+            Point3D::new(-10.0, -10.0, -10.0),
+            Point3D::new(80.0, 80.0, 80.0),
+        )
+        .unwrap();
+        tree.insert(1, far);
+        tree.insert(2, huge);
+        assert_eq!(tree.query_aabb(&far), vec![1]);
+        let strip = AABB::new(Point3D::new(75.0, 0.0, 0.0), Point3D::new(101.0, 1.0, 1.0)).unwrap();
+        assert_eq!(tree.query_aabb(&strip), vec![1, 2]);
+    }
+
+    #[test]
+    fn test_ray_query_orders_by_entry() {
+        let mut tree = Octree::new(world(), 6);
+        for i in 0..5u32 {
+            let x = 10.0 * i as f64 + 2.0;
+            let aabb =
+                AABB::new(Point3D::new(x, 9.0, 9.0), Point3D::new(x + 1.0, 11.0, 11.0)).unwrap();
+            tree.insert(4 - i, aabb);
+        }
+        let ray = Ray::new(Point3D::new(0.0, 10.0, 10.0), Vector3D::unit_x()).unwrap();
+        let hits = tree.query_ray(&ray);
+        assert_eq!(
+            hits.iter().map(|h| h.0).collect::<Vec<_>>(),
+            vec![4, 3, 2, 1, 0]
+        );
+        assert!((hits[0].1 - 2.0).abs() < 1e-12);
+
+        let short = Ray::with_range(ray.origin, ray.direction, 0.0, 15.0).unwrap();
+        assert_eq!(tree.query_ray(&short).len(), 2);
+    }
+
+    #[test]
+    fn test_frustum_query() {
+        let items = boxes(200);
+        let mut tree = Octree::new(world(), 6);
+        for (i, aabb) in items.iter().enumerate() {
+            tree.insert(i, *aabb);
+        }
+        // The slab 20 <= x <= 30.
+        let planes = [
+            Plane::from_point_normal(&Point3D::new(20.0, 0.0, 0.0), &Vector3D::unit_x()).unwrap(),
+            Plane::from_point_normal(&Point3D::new(30.0, 0.0, 0.0), &-Vector3D::unit_x()).unwrap(),
+        ];
+        let expected: Vec<usize> = (0..items.len())
+            .filter(|&i| items[i].max.x >= 20.0 && items[i].min.x <= 30.0)
+            .collect();
+        assert_eq!(tree.query_frustum(&planes), expected);
+    }
+}
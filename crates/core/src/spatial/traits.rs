@@ -0,0 +1,103 @@
+use crate::operations::{
+    ray_aabb_intersection, ray_sphere_intersection, ray_triangle_intersection, RayHit,
+};
+use crate::primitives::{Capsule, LineSegment, Point3D, Ray, Sphere, Triangle, AABB, OBB};
+
+/// Anything that can report an axis-aligned box enclosing it.
+pub trait Bounded {
+    fn bounding_box(&self) -> AABB;
+}
+
+/// Anything a ray can hit. Implementations honour the ray's `[t_min, t_max]`
+/// interval and report the nearest hit inside it.
+pub trait RayCast {
+    fn cast_ray(&self, ray: &Ray) -> Option<RayHit>;
+}
+
+impl Bounded for AABB {
+    #[inline]
+    fn bounding_box(&self) -> AABB {
+        *self
+    }
+}
+
+impl Bounded for Sphere {
+    #[inline]
+    fn bounding_box(&self) -> AABB {
+        Sphere::bounding_box(self)
+    }
+}
+
+impl Bounded for Triangle {
+    #[inline]
+    fn bounding_box(&self) -> AABB {
+        Triangle::bounding_box(self)
+    }
+}
+
+impl Bounded for LineSegment {
+    #[inline]
+    fn bounding_box(&self) -> AABB {
+        AABB {
+            min: Point3D::new(
+                self.start.x.min(self.end.x),
+                self.start.y.min(self.end.y),
+                self.start.z.min(self.end.z),
+            ),
+            max: Point3D::new(
+                self.start.x.max(self.end.x),
+                self.start.y.max(self.end.y),
+                self.start.z.max(self.end.z),
+            ),
+        }
+    }
+}
+
+impl Bounded for Capsule {
+    #[inline]
+    fn bounding_box(&self) -> AABB {
+        Capsule::bounding_box(self)
+    }
+}
+
+impl Bounded for OBB {
+    #[inline]
+    fn bounding_box(&self) -> AABB {
+        OBB::bounding_box(self)
+    }
+}
+
+impl<T: Bounded + ?Sized> Bounded for &T {
+    #[inline]
+    fn bounding_box(&self) -> AABB {
+        (**self).bounding_box()
+    }
+}
+
+impl RayCast for AABB {
+    #[inline]
+    fn cast_ray(&self, ray: &Ray) -> Option<RayHit> {
+        ray_aabb_intersection(ray, self)
+    }
+}
+
+impl RayCast for Sphere {
+    #[inline]
+    fn cast_ray(&self, ray: &Ray) -> Option<RayHit> {
+        ray_sphere_intersection(ray, self)
+    }
+}
+
+impl RayCast for Triangle {
+    #[inline]
+    fn cast_ray(&self, ray: &Ray) -> Option<RayHit> {
+        ray_triangle_intersection(ray, self)
+    }
+}
+
+impl<T: RayCast + ?Sized> RayCast for &T {
+    #[inline]
+    fn cast_ray(&self, ray: &Ray) -> Option<RayHit> {
+        (**self).cast_ray(ray)
+    }
+}
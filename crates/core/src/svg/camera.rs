@@ -1,4 +1,46 @@
-use crate::primitives::{Point3D, Vector3D};
+use std::f64::consts::{FRAC_PI_2, FRAC_PI_3, PI};
+
+use super::projection::{
+    multiply_matrices, multiply_matrix_point, perspective_divide, screen_to_ndc,
+};
+use crate::error::GeometryError;
+use crate::primitives::{Frustum, Point3D, Ray, Sphere, Vector3D, AABB};
+use crate::utils::clamp;
+
+/// Parallel projections have no depth limit; their frustum ends here.
+const UNBOUNDED_FAR: f64 = 1.0e6;
+
+/// Furthest [`Camera::orbit`] goes above or below the target, in degrees.
+const MAX_ORBIT_ELEVATION: f64 = 89.0;
+
+/// Widest half-angle, in radians, at which [`Camera::fit_to_sphere`] frames
+/// a sphere with a fisheye or equirectangular camera. Wider lenses would
+/// otherwise end up almost touching it.
+const MAX_FIT_HALF_ANGLE: f64 = FRAC_PI_3;
+
+/// Ellipse in two dimensions, as the outline of a projected sphere: the
+/// points `center + axes[0] * cos(t) + axes[1] * sin(t)`. The two axes are
+/// conjugate semi-diameters, not necessarily perpendicular; see
+/// [`ProjectedEllipse::principal_axes`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ProjectedEllipse {
+    pub center: (f64, f64),
+    pub axes: [(f64, f64); 2],
+}
+
+impl ProjectedEllipse {
+    /// Semi-major and semi-minor axis lengths, and the angle of the major
+    /// axis from the `x` axis towards the `y` axis, in radians.
+    pub fn principal_axes(&self) -> (f64, f64, f64) {
+        // The outline is the image of the unit circle under the matrix with
+        // the axes as columns, A; A * A^T has the squared radii as its
+        // eigenvalues.
+        let [(ax, ay), (bx, by)] = self.axes;
+        let (major, minor, angle) =
+            symmetric_eigen(ax * ax + bx * bx, ax * ay + bx * by, ay * ay + by * by);
+        (major.max(0.0).sqrt(), minor.max(0.0).sqrt(), angle)
+    }
+}
 
 #[derive(Debug, Clone)]
 pub enum Camera {
@@ -18,6 +60,48 @@ pub enum Camera {
         width: f64,
         height: f64,
     },
+    /// Parallel projection whose depth axis is drawn receding at `angle`
+    /// degrees from the screen's x axis, scaled by `depth_scale`. The plane
+    /// through `target` facing the camera keeps its true shape.
+    Oblique {
+        position: Point3D,
+        target: Point3D,
+        up: Vector3D,
+        width: f64,
+        height: f64,
+        angle: f64,
+        depth_scale: f64,
+    },
+    /// Perspective projection through the asymmetric window `left..right`,
+    /// `bottom..top` on the near plane.
+    OffAxis {
+        position: Point3D,
+        target: Point3D,
+        up: Vector3D,
+        left: f64,
+        right: f64,
+        bottom: f64,
+        top: f64,
+        near: f64,
+        far: f64,
+    },
+    /// Equidistant fisheye: the angle away from the viewing direction maps
+    /// linearly to the distance from the image centre. `fov` spans the image
+    /// height and may be up to 360 degrees.
+    Fisheye {
+        position: Point3D,
+        target: Point3D,
+        up: Vector3D,
+        fov: f64,
+        aspect: f64,
+    },
+    /// Full 360 by 180 degree panorama with longitude across the image and
+    /// latitude up it, centred on the viewing direction.
+    Equirectangular {
+        position: Point3D,
+        target: Point3D,
+        up: Vector3D,
+    },
 }
 
 impl Camera {
@@ -29,8 +113,12 @@ impl Camera {
         aspect: f64,
         near: f64,
         far: f64,
-    ) -> Self {
-        Camera::Perspective {
+    ) -> Result<Self, GeometryError> {
+        validate_view(&position, &target, &up)?;
+        validate_fov(fov)?;
+        validate_aspect(aspect)?;
+        validate_depth_range(near, far)?;
+        Ok(Camera::Perspective {
             position,
             target,
             up,
@@ -38,7 +126,7 @@ impl Camera {
             aspect,
             near,
             far,
-        }
+        })
     }
 
     pub fn orthographic(
@@ -47,31 +135,515 @@ impl Camera {
         up: Vector3D,
         width: f64,
         height: f64,
-    ) -> Self {
-        Camera::Orthographic {
+    ) -> Result<Self, GeometryError> {
+        validate_view(&position, &target, &up)?;
+        validate_extent(width, height)?;
+        Ok(Camera::Orthographic {
             position,
             target,
             up,
             width,
             height,
+        })
+    }
+
+    /// Orthographic camera looking at `target` from `yaw` degrees around
+    /// `up` and `elevation` degrees above the ground plane. Yaw starts at the
+    /// world axis following `up`'s dominant one in x, y, z order (x when z is
+    /// up, z when y is up) and turns towards the axis after that.
+    pub fn trimetric(
+        target: Point3D,
+        up: Vector3D,
+        yaw: f64,
+        elevation: f64,
+        width: f64,
+        height: f64,
+    ) -> Result<Self, GeometryError> {
+        let (ground_x, ground_y, up_unit) = ground_axes(&up)?;
+        let (yaw, elevation) = (yaw.to_radians(), elevation.to_radians());
+        let direction = ground_x * (elevation.cos() * yaw.cos())
+            + ground_y * (elevation.cos() * yaw.sin())
+            + up_unit * elevation.sin();
+        let position = target + direction * width.max(height);
+        Self::orthographic(position, target, up, width, height)
+    }
+
+    /// Trimetric view foreshortening all three world axes equally.
+    pub fn isometric(
+        target: Point3D,
+        up: Vector3D,
+        width: f64,
+        height: f64,
+    ) -> Result<Self, GeometryError> {
+        let elevation = (1.0 / 2f64.sqrt()).atan().to_degrees();
+        Self::trimetric(target, up, 45.0, elevation, width, height)
+    }
+
+    /// Trimetric view with the up axis and one ground axis at equal scale
+    /// and the other ground axis (the one yaw starts from) at half that, as
+    /// in DIN ISO 5456-3.
+    pub fn dimetric(
+        target: Point3D,
+        up: Vector3D,
+        width: f64,
+        height: f64,
+    ) -> Result<Self, GeometryError> {
+        let yaw = 1f64.atan2(7f64.sqrt()).to_degrees();
+        let elevation = (1.0 / 3.0f64).asin().to_degrees();
+        Self::trimetric(target, up, yaw, elevation, width, height)
+    }
+
+    pub fn oblique(
+        position: Point3D,
+        target: Point3D,
+        up: Vector3D,
+        width: f64,
+        height: f64,
+        angle: f64,
+        depth_scale: f64,
+    ) -> Result<Self, GeometryError> {
+        validate_view(&position, &target, &up)?;
+        validate_extent(width, height)?;
+        if !(angle.is_finite() && depth_scale >= 0.0 && depth_scale.is_finite()) {
+            return Err(GeometryError::InvalidParameter(
+                "Oblique angle must be finite and depth scale non-negative".to_string(),
+            ));
         }
+        Ok(Camera::Oblique {
+            position,
+            target,
+            up,
+            width,
+            height,
+            angle,
+            depth_scale,
+        })
     }
 
-    pub fn view_matrix(&self) -> [[f64; 4]; 4] {
-        let (position, target, up) = match self {
+    /// Oblique projection drawing depth at full scale and 45 degrees.
+    pub fn cavalier(
+        position: Point3D,
+        target: Point3D,
+        up: Vector3D,
+        width: f64,
+        height: f64,
+    ) -> Result<Self, GeometryError> {
+        Self::oblique(position, target, up, width, height, 45.0, 1.0)
+    }
+
+    /// Oblique projection drawing depth at half scale and 45 degrees.
+    pub fn cabinet(
+        position: Point3D,
+        target: Point3D,
+        up: Vector3D,
+        width: f64,
+        height: f64,
+    ) -> Result<Self, GeometryError> {
+        Self::oblique(position, target, up, width, height, 45.0, 0.5)
+    }
+
+    /// Off-axis perspective camera. `window` is `[left, right, bottom, top]`
+    /// on the near plane, in camera right/up units.
+    pub fn off_axis(
+        position: Point3D,
+        target: Point3D,
+        up: Vector3D,
+        window: [f64; 4],
+        near: f64,
+        far: f64,
+    ) -> Result<Self, GeometryError> {
+        validate_view(&position, &target, &up)?;
+        let [left, right, bottom, top] = window;
+        if !(left < right && bottom < top) || window.iter().any(|v| !v.is_finite()) {
+            return Err(GeometryError::InvalidParameter(
+                "Off-axis window must have left < right and bottom < top".to_string(),
+            ));
+        }
+        validate_depth_range(near, far)?;
+        Ok(Camera::OffAxis {
+            position,
+            target,
+            up,
+            left,
+            right,
+            bottom,
+            top,
+            near,
+            far,
+        })
+    }
+
+    pub fn fisheye(
+        position: Point3D,
+        target: Point3D,
+        up: Vector3D,
+        fov: f64,
+        aspect: f64,
+    ) -> Result<Self, GeometryError> {
+        validate_view(&position, &target, &up)?;
+        validate_fisheye_fov(fov)?;
+        validate_aspect(aspect)?;
+        Ok(Camera::Fisheye {
+            position,
+            target,
+            up,
+            fov,
+            aspect,
+        })
+    }
+
+    pub fn equirectangular(
+        position: Point3D,
+        target: Point3D,
+        up: Vector3D,
+    ) -> Result<Self, GeometryError> {
+        validate_view(&position, &target, &up)?;
+        Ok(Camera::Equirectangular {
+            position,
+            target,
+            up,
+        })
+    }
+
+    #[inline]
+    pub fn position(&self) -> Point3D {
+        *self.view().0
+    }
+
+    #[inline]
+    pub fn target(&self) -> Point3D {
+        *self.view().1
+    }
+
+    #[inline]
+    pub fn up(&self) -> Vector3D {
+        *self.view().2
+    }
+
+    /// Whether straight lines stay straight under this projection. Fisheye
+    /// and equirectangular cameras bend them.
+    #[inline]
+    pub fn is_linear(&self) -> bool {
+        !matches!(
+            self,
+            Camera::Fisheye { .. } | Camera::Equirectangular { .. }
+        )
+    }
+
+    /// Whether all viewing rays are parallel rather than meeting at the eye,
+    /// as for orthographic and oblique cameras.
+    #[inline]
+    pub fn is_parallel_projection(&self) -> bool {
+        matches!(self, Camera::Orthographic { .. } | Camera::Oblique { .. })
+    }
+
+    /// Points the camera at a new target, keeping its position.
+    pub fn look_at(&mut self, new_target: Point3D) -> Result<(), GeometryError> {
+        let (position, target, up) = self.view_mut();
+        validate_view(position, &new_target, up)?;
+        *target = new_target;
+        Ok(())
+    }
+
+    /// Rotates the camera around its target: `yaw` degrees about the up
+    /// vector, then `pitch` degrees upwards. The elevation is clamped just
+    /// short of the poles so the view never becomes parallel to `up`.
+    pub fn orbit(&mut self, yaw: f64, pitch: f64) {
+        let (position, target, up) = self.view_mut();
+        let Ok(up) = up.normalize() else {
+            return;
+        };
+
+        let offset = rotate(&(*position - *target), &up, yaw.to_radians());
+        let distance = offset.magnitude();
+        let Ok(axis) = offset.cross(&up).normalize() else {
+            return;
+        };
+        let elevation = clamp(offset.dot(&up) / distance, -1.0, 1.0).asin();
+        let limit = MAX_ORBIT_ELEVATION.to_radians();
+        let raised = clamp(elevation + pitch.to_radians(), -limit, limit);
+
+        *position = *target + rotate(&offset, &axis, raised - elevation);
+    }
+
+    /// Slides the camera and its target sideways (`dx`) and vertically
+    /// (`dy`) in the view plane, in world units.
+    pub fn pan(&mut self, dx: f64, dy: f64) {
+        let (_, right, camera_up, _) = self.basis();
+        let delta = right * dx + camera_up * dy;
+        let (position, target, _) = self.view_mut();
+        *position = *position + delta;
+        *target = *target + delta;
+    }
+
+    /// Moves the camera `distance` units towards its target (away from it
+    /// when negative). Fails if that would reach or pass the target.
+    pub fn dolly(&mut self, distance: f64) -> Result<(), GeometryError> {
+        let (position, target, _) = self.view_mut();
+        let offset = *target - *position;
+        if distance >= offset.magnitude() {
+            return Err(GeometryError::InvalidParameter(
+                "Cannot dolly the camera onto or past its target".to_string(),
+            ));
+        }
+        *position = *position + offset.normalize()? * distance;
+        Ok(())
+    }
+
+    /// Magnifies the view by `factor` without moving the camera: narrows
+    /// the field of view of perspective and fisheye cameras, or shrinks the
+    /// visible window of the others. Equirectangular cameras always show
+    /// the full panorama and cannot zoom. Fails, leaving the camera
+    /// unchanged, if the new field of view or window would be invalid.
+    pub fn zoom(&mut self, factor: f64) -> Result<(), GeometryError> {
+        if !(factor > 0.0 && factor.is_finite()) {
+            return Err(GeometryError::InvalidParameter(
+                "Zoom factor must be positive".to_string(),
+            ));
+        }
+        match self {
+            Camera::Perspective { fov, .. } => {
+                let half = (fov.to_radians() / 2.0).tan() / factor;
+                let zoomed = (2.0 * half.atan()).to_degrees();
+                validate_fov(zoomed)?;
+                *fov = zoomed;
+            }
+            Camera::Orthographic { width, height, .. } | Camera::Oblique { width, height, .. } => {
+                validate_extent(*width / factor, *height / factor)?;
+                *width /= factor;
+                *height /= factor;
+            }
+            Camera::OffAxis {
+                left,
+                right,
+                bottom,
+                top,
+                ..
+            } => {
+                for edge in [left, right, bottom, top] {
+                    *edge /= factor;
+                }
+            }
+            Camera::Fisheye { fov, .. } => {
+                validate_fisheye_fov(*fov / factor)?;
+                *fov /= factor;
+            }
+            Camera::Equirectangular { .. } => {
+                return Err(GeometryError::InvalidParameter(
+                    "Equirectangular cameras cannot zoom".to_string(),
+                ));
+            }
+        }
+        Ok(())
+    }
+
+    /// Re-aims the camera at the sphere, keeping the viewing direction, and
+    /// backs off until the whole sphere is in view. Perspective near and far
+    /// planes are moved if needed; parallel projections resize their window.
+    /// Fails for a sphere without a positive, finite radius, such as the
+    /// bounding sphere of a single point, leaving the camera unchanged.
+    pub fn fit_to_sphere(&mut self, sphere: &Sphere) -> Result<(), GeometryError> {
+        let center = sphere.center;
+        let radius = sphere.radius;
+        if !(radius > 0.0 && radius.is_finite()) {
+            return Err(GeometryError::InvalidParameter(
+                "Cannot fit the camera to a sphere without a positive radius".to_string(),
+            ));
+        }
+
+        let (_, right, camera_up, forward) = self.basis();
+        let (left, right_edge, bottom, top) = self.window_at(1.0);
+        let (shear_x, shear_y) = self.shear();
+        match self {
             Camera::Perspective {
                 position,
                 target,
                 up,
+                near,
+                far,
                 ..
-            } => (position, target, up),
+            }
+            | Camera::OffAxis {
+                position,
+                target,
+                up,
+                near,
+                far,
+                ..
+            } => {
+                // The sphere goes on the ray through the middle of the
+                // window, far enough along it to clear all four sides.
+                let axis = forward
+                    + right * ((left + right_edge) / 2.0)
+                    + camera_up * ((bottom + top) / 2.0);
+                let half_width = (right_edge - left) / 2.0;
+                let half_height = (top - bottom) / 2.0;
+                let depth = radius
+                    * [
+                        left.hypot(1.0) / half_width,
+                        right_edge.hypot(1.0) / half_width,
+                        bottom.hypot(1.0) / half_height,
+                        top.hypot(1.0) / half_height,
+                    ]
+                    .into_iter()
+                    .fold(0.0, f64::max);
+                let fitted_position = center + axis * -depth;
+                let fitted_target = fitted_position + forward * depth;
+                // Keep the near plane well in front of the sphere.
+                let fitted_near = near.min((depth - radius) / 2.0);
+                let fitted_far = far.max(depth + radius);
+                validate_view(&fitted_position, &fitted_target, up)?;
+                validate_depth_range(fitted_near, fitted_far)?;
+                *position = fitted_position;
+                *target = fitted_target;
+                *near = fitted_near;
+                *far = fitted_far;
+            }
             Camera::Orthographic {
                 position,
                 target,
                 up,
+                width,
+                height,
+            }
+            | Camera::Oblique {
+                position,
+                target,
+                up,
+                width,
+                height,
                 ..
+            } => {
+                let aspect = *width / *height;
+                let half_x = radius * shear_x.hypot(1.0);
+                let half_y = radius * shear_y.hypot(1.0);
+                let fitted_width = (2.0 * half_x).max(2.0 * half_y * aspect);
+                let fitted_height = fitted_width / aspect;
+                // Parallel views start at the camera, so stay outside.
+                let fitted_position = center + forward * (-2.0 * radius);
+                validate_view(&fitted_position, &center, up)?;
+                validate_extent(fitted_width, fitted_height)?;
+                *width = fitted_width;
+                *height = fitted_height;
+                *target = center;
+                *position = fitted_position;
+            }
+            Camera::Fisheye {
+                position,
+                target,
+                up,
+                fov,
+                aspect,
+            } => {
+                let half = (fov.to_radians() / 2.0 * aspect.min(1.0)).min(MAX_FIT_HALF_ANGLE);
+                let fitted_position = center + forward * -(radius / half.sin());
+                validate_view(&fitted_position, &center, up)?;
+                *target = center;
+                *position = fitted_position;
+            }
+            Camera::Equirectangular {
+                position,
+                target,
+                up,
+            } => {
+                let fitted_position = center + forward * -(radius / MAX_FIT_HALF_ANGLE.sin());
+                validate_view(&fitted_position, &center, up)?;
+                *target = center;
+                *position = fitted_position;
+            }
+        }
+        Ok(())
+    }
+
+    /// Like [`Camera::fit_to_sphere`] for the box's bounding sphere, so it
+    /// fails for a box that is a single point.
+    pub fn fit_to_aabb(&mut self, aabb: &AABB) -> Result<(), GeometryError> {
+        self.fit_to_sphere(&aabb.bounding_sphere()?)
+    }
+
+    fn view(&self) -> (&Point3D, &Point3D, &Vector3D) {
+        match self {
+            Camera::Perspective {
+                position,
+                target,
+                up,
+                ..
+            }
+            | Camera::Orthographic {
+                position,
+                target,
+                up,
+                ..
+            }
+            | Camera::Oblique {
+                position,
+                target,
+                up,
+                ..
+            }
+            | Camera::OffAxis {
+                position,
+                target,
+                up,
+                ..
+            }
+            | Camera::Fisheye {
+                position,
+                target,
+                up,
+                ..
+            }
+            | Camera::Equirectangular {
+                position,
+                target,
+                up,
             } => (position, target, up),
-        };
+        }
+    }
+
+    fn view_mut(&mut self) -> (&mut Point3D, &mut Point3D, &mut Vector3D) {
+        match self {
+            Camera::Perspective {
+                position,
+                target,
+                up,
+                ..
+            }
+            | Camera::Orthographic {
+                position,
+                target,
+                up,
+                ..
+            }
+            | Camera::Oblique {
+                position,
+                target,
+                up,
+                ..
+            }
+            | Camera::OffAxis {
+                position,
+                target,
+                up,
+                ..
+            }
+            | Camera::Fisheye {
+                position,
+                target,
+                up,
+                ..
+            }
+            | Camera::Equirectangular {
+                position,
+                target,
+                up,
+            } => (position, target, up),
+        }
+    }
+
+    /// Eye position and the camera's right, up and forward unit vectors.
+    fn basis(&self) -> (Point3D, Vector3D, Vector3D, Vector3D) {
+        let (position, target, up) = self.view();
 
         let forward = Vector3D::new(
             target.x - position.x,
@@ -87,6 +659,12 @@ impl Camera {
             .unwrap_or_else(|_| Vector3D::new(1.0, 0.0, 0.0));
         let camera_up = right.cross(&forward);
 
+        (*position, right, camera_up, forward)
+    }
+
+    pub fn view_matrix(&self) -> [[f64; 4]; 4] {
+        let (position, right, camera_up, forward) = self.basis();
+
         [
             [
                 right.x,
@@ -110,6 +688,270 @@ impl Camera {
         ]
     }
 
+    /// Screen offset per unit of depth behind the target plane of an oblique
+    /// camera, along its right and up axes. Zero for other cameras.
+    fn shear(&self) -> (f64, f64) {
+        match self {
+            Camera::Oblique {
+                angle, depth_scale, ..
+            } => {
+                let (sin, cos) = angle.to_radians().sin_cos();
+                (depth_scale * cos, depth_scale * sin)
+            }
+            _ => (0.0, 0.0),
+        }
+    }
+
+    /// Visible window `(left, right, bottom, top)` on the plane `distance`
+    /// in front of the camera, in camera right/up units. Non-linear cameras
+    /// have no flat window and report the unit square.
+    fn window_at(&self, distance: f64) -> (f64, f64, f64, f64) {
+        match self {
+            Camera::Perspective { fov, aspect, .. } => {
+                let half_height = distance * (fov.to_radians() / 2.0).tan();
+                let half_width = half_height * aspect;
+                (-half_width, half_width, -half_height, half_height)
+            }
+            Camera::Orthographic { width, height, .. } => {
+                (-width / 2.0, width / 2.0, -height / 2.0, height / 2.0)
+            }
+            Camera::Oblique {
+                position,
+                target,
+                width,
+                height,
+                ..
+            } => {
+                // Points behind the target plane are drawn shifted along the
+                // receding axis, so the window there shifts the other way.
+                let (shear_x, shear_y) = self.shear();
+                let behind = distance - position.distance_to(target);
+                let (dx, dy) = (-shear_x * behind, -shear_y * behind);
+                (
+                    dx - width / 2.0,
+                    dx + width / 2.0,
+                    dy - height / 2.0,
+                    dy + height / 2.0,
+                )
+            }
+            Camera::OffAxis {
+                left,
+                right,
+                bottom,
+                top,
+                near,
+                ..
+            } => {
+                let scale = distance / near;
+                (left * scale, right * scale, bottom * scale, top * scale)
+            }
+            Camera::Fisheye { .. } | Camera::Equirectangular { .. } => (-1.0, 1.0, -1.0, 1.0),
+        }
+    }
+
+    /// The volume this camera can see. Parallel projections see from their
+    /// position onwards, up to a fixed far distance of one million units.
+    /// Fisheye and equirectangular views are not bounded by planes and
+    /// report [`GeometryError::DegenerateCase`].
+    pub fn frustum(&self) -> Result<Frustum, GeometryError> {
+        let (near, far) = match self {
+            Camera::Perspective { near, far, .. } | Camera::OffAxis { near, far, .. } => {
+                (*near, *far)
+            }
+            Camera::Orthographic { .. } | Camera::Oblique { .. } => (0.0, UNBOUNDED_FAR),
+            Camera::Fisheye { .. } | Camera::Equirectangular { .. } => {
+                return Err(GeometryError::DegenerateCase(
+                    "Non-linear projections have no planar frustum".to_string(),
+                ));
+            }
+        };
+        let corner = |distance: f64, sx: f64, sy: f64| self.unproject(sx, sy, distance);
+        Frustum::from_corners([
+            corner(near, -1.0, -1.0),
+            corner(near, 1.0, -1.0),
+            corner(near, 1.0, 1.0),
+            corner(near, -1.0, 1.0),
+            corner(far, -1.0, -1.0),
+            corner(far, 1.0, -1.0),
+            corner(far, 1.0, 1.0),
+            corner(far, -1.0, 1.0),
+        ])
+    }
+
+    /// Normalized device coordinates of `point`: `x` and `y` in `[-1, 1]`
+    /// across the image, `y` pointing up, for points in view.
+    pub fn project_to_ndc(&self, point: &Point3D) -> (f64, f64) {
+        let (position, right, up, forward) = self.basis();
+        let offset = *point - position;
+        let (x, y, z) = (offset.dot(&right), offset.dot(&up), offset.dot(&forward));
+        match self {
+            Camera::Fisheye { fov, aspect, .. } => {
+                let radius = x.hypot(y).atan2(z) / (fov.to_radians() / 2.0);
+                let azimuth = y.atan2(x);
+                (radius * azimuth.cos() / aspect, radius * azimuth.sin())
+            }
+            Camera::Equirectangular { .. } => (x.atan2(z) / PI, y.atan2(x.hypot(z)) / FRAC_PI_2),
+            _ => {
+                let matrix = multiply_matrices(&self.projection_matrix(), &self.view_matrix());
+                let (ndc_x, ndc_y, _) = perspective_divide(multiply_matrix_point(&matrix, point));
+                (ndc_x, ndc_y)
+            }
+        }
+    }
+
+    /// How far `point` lies in front of the camera along its viewing
+    /// direction, negative behind it. For fisheye and equirectangular
+    /// cameras this is the distance from the camera instead, matching the
+    /// `depth` of [`Camera::unproject`].
+    pub fn view_depth(&self, point: &Point3D) -> f64 {
+        let (position, _, _, forward) = self.basis();
+        if self.is_linear() {
+            (*point - position).dot(&forward)
+        } else {
+            position.distance_to(point)
+        }
+    }
+
+    /// Outline of `sphere` in normalized device coordinates, which is an
+    /// ellipse for linear cameras: the tangent cone from the eye (or
+    /// cylinder, for parallel projections) cut by the image plane. `None`
+    /// for fisheye and equirectangular cameras, and when the sphere reaches
+    /// back to the plane through the eye, where the outline is unbounded.
+    pub fn project_sphere(&self, sphere: &Sphere) -> Option<ProjectedEllipse> {
+        let (position, right, up, forward) = self.basis();
+        let radius = sphere.radius;
+        match self {
+            Camera::Perspective { .. } | Camera::OffAxis { .. } => {
+                let offset = sphere.center - position;
+                let c = [offset.dot(&right), offset.dot(&up), offset.dot(&forward)];
+                if c[2] <= radius {
+                    return None;
+                }
+                // Directions d inside the tangent cone satisfy
+                // (d.c)^2 >= (|c|^2 - r^2) |d|^2. On the image plane at depth
+                // one, d = (u, v, 1) = H (x, y, 1) for device coordinates x, y.
+                let k = offset.magnitude_squared() - radius * radius;
+                let cone: [[f64; 3]; 3] = std::array::from_fn(|i| {
+                    std::array::from_fn(|j| c[i] * c[j] - if i == j { k } else { 0.0 })
+                });
+                let (left, right_edge, bottom, top) = self.window_at(1.0);
+                let h = [
+                    [(right_edge - left) / 2.0, 0.0, (right_edge + left) / 2.0],
+                    [0.0, (top - bottom) / 2.0, (top + bottom) / 2.0],
+                    [0.0, 0.0, 1.0],
+                ];
+                // Conic in device coordinates: H^T * cone * H.
+                let m: [[f64; 3]; 3] = std::array::from_fn(|i| {
+                    std::array::from_fn(|j| {
+                        (0..3)
+                            .map(|a| (0..3).map(|b| h[a][i] * cone[a][b] * h[b][j]).sum::<f64>())
+                            .sum()
+                    })
+                });
+
+                let det = m[0][0] * m[1][1] - m[0][1] * m[1][0];
+                let cx = (-m[1][1] * m[0][2] + m[0][1] * m[1][2]) / det;
+                let cy = (m[1][0] * m[0][2] - m[0][0] * m[1][2]) / det;
+                let value = m[2][2] + m[0][2] * cx + m[1][2] * cy;
+                // The conic is positive inside, so the outline is
+                // (p - center)^T Q (p - center) = 1 for Q as below.
+                let q = [m[0][0] / -value, m[0][1] / -value, m[1][1] / -value];
+                if !(q[0] > 0.0 && q[0] * q[2] - q[1] * q[1] > 0.0) {
+                    return None;
+                }
+                let (large, small, angle) = symmetric_eigen(q[0], q[1], q[2]);
+                let (sin, cos) = angle.sin_cos();
+                Some(ProjectedEllipse {
+                    center: (cx, cy),
+                    axes: [
+                        (cos / large.sqrt(), sin / large.sqrt()),
+                        (-sin / small.sqrt(), cos / small.sqrt()),
+                    ],
+                })
+            }
+            Camera::Orthographic { .. } | Camera::Oblique { .. } => {
+                // Parallel projection is affine, so the outline is the image
+                // of the great circle across the projection direction.
+                let center = self.project_to_ndc(&sphere.center);
+                let along = |axis: Vector3D| {
+                    let (x, y) = self.project_to_ndc(&(sphere.center + axis));
+                    (x - center.0, y - center.1)
+                };
+                let columns = [
+                    along(Vector3D::unit_x()),
+                    along(Vector3D::unit_y()),
+                    along(Vector3D::unit_z()),
+                ];
+                let row_x = Vector3D::new(columns[0].0, columns[1].0, columns[2].0);
+                let row_y = Vector3D::new(columns[0].1, columns[1].1, columns[2].1);
+                let direction = row_x.cross(&row_y);
+                let first = direction.cross(&up).normalize().ok()?;
+                let second = direction.cross(&first).normalize().ok()?;
+                let image = |v: Vector3D| (row_x.dot(&v) * radius, row_y.dot(&v) * radius);
+                Some(ProjectedEllipse {
+                    center,
+                    axes: [image(first), image(second)],
+                })
+            }
+            Camera::Fisheye { .. } | Camera::Equirectangular { .. } => None,
+        }
+    }
+
+    /// World-space point at normalized device coordinates `(x, y)`, each in
+    /// `[-1, 1]` with `y` pointing up, lying `depth` units in front of the
+    /// camera along its viewing direction. For fisheye and equirectangular
+    /// cameras `depth` is the distance from the camera instead.
+    pub fn unproject(&self, x: f64, y: f64, depth: f64) -> Point3D {
+        let (position, right, up, forward) = self.basis();
+        match self {
+            Camera::Fisheye { fov, aspect, .. } => {
+                let x = x * aspect;
+                let angle = x.hypot(y) * fov.to_radians() / 2.0;
+                let azimuth = y.atan2(x);
+                let sideways = right * azimuth.cos() + up * azimuth.sin();
+                position + (forward * angle.cos() + sideways * angle.sin()) * depth
+            }
+            Camera::Equirectangular { .. } => {
+                let longitude = x * PI;
+                let latitude = y * FRAC_PI_2;
+                let level = forward * longitude.cos() + right * longitude.sin();
+                position + (level * latitude.cos() + up * latitude.sin()) * depth
+            }
+            _ => {
+                let (left, right_edge, bottom, top) = self.window_at(depth);
+                let across = left + (x + 1.0) / 2.0 * (right_edge - left);
+                let along = bottom + (y + 1.0) / 2.0 * (top - bottom);
+                position + forward * depth + right * across + up * along
+            }
+        }
+    }
+
+    /// Ray through pixel `(x, y)` of a `width` x `height` image rendered with
+    /// this camera, for picking with the `ray_*_intersection` functions.
+    /// Rays start at the eye, or on the camera plane for parallel
+    /// projections.
+    pub fn screen_to_ray(&self, x: f64, y: f64, width: usize, height: usize) -> Ray {
+        let (ndc_x, ndc_y) = screen_to_ndc(x, y, width, height);
+        let (position, _, _, forward) = self.basis();
+        let origin = if self.is_parallel_projection() {
+            self.unproject(ndc_x, ndc_y, 0.0)
+        } else {
+            position
+        };
+        let direction = (self.unproject(ndc_x, ndc_y, 1.0) - origin)
+            .normalize()
+            .unwrap_or(forward);
+        Ray {
+            origin,
+            direction,
+            t_min: 0.0,
+            t_max: f64::INFINITY,
+        }
+    }
+
+    /// Maps view space to clip space. Fisheye and equirectangular
+    /// projections cannot be written as a matrix and return the identity;
+    /// use [`Camera::project_to_ndc`] for them.
     pub fn projection_matrix(&self) -> [[f64; 4]; 4] {
         match self {
             Camera::Perspective {
@@ -134,6 +976,35 @@ impl Camera {
                     [0.0, 0.0, -1.0, 0.0],
                 ]
             }
+            Camera::OffAxis {
+                left,
+                right,
+                bottom,
+                top,
+                near,
+                far,
+                ..
+            } => [
+                [
+                    2.0 * near / (right - left),
+                    0.0,
+                    (right + left) / (right - left),
+                    0.0,
+                ],
+                [
+                    0.0,
+                    2.0 * near / (top - bottom),
+                    (top + bottom) / (top - bottom),
+                    0.0,
+                ],
+                [
+                    0.0,
+                    0.0,
+                    -(far + near) / (far - near),
+                    -(2.0 * far * near) / (far - near),
+                ],
+                [0.0, 0.0, -1.0, 0.0],
+            ],
             Camera::Orthographic { width, height, .. } => {
                 let right = width / 2.0;
                 let left = -right;
@@ -159,13 +1030,145 @@ impl Camera {
                     [0.0, 0.0, 0.0, 1.0],
                 ]
             }
+            Camera::Oblique {
+                position,
+                target,
+                width,
+                height,
+                ..
+            } => {
+                // View-space depth is -z; shift x and y by the shear times
+                // the depth behind the target plane.
+                let (shear_x, shear_y) = self.shear();
+                let distance = position.distance_to(target);
+                [
+                    [
+                        2.0 / width,
+                        0.0,
+                        -2.0 * shear_x / width,
+                        -2.0 * shear_x * distance / width,
+                    ],
+                    [
+                        0.0,
+                        2.0 / height,
+                        -2.0 * shear_y / height,
+                        -2.0 * shear_y * distance / height,
+                    ],
+                    [0.0, 0.0, -1.0, 0.0],
+                    [0.0, 0.0, 0.0, 1.0],
+                ]
+            }
+            Camera::Fisheye { .. } | Camera::Equirectangular { .. } => [
+                [1.0, 0.0, 0.0, 0.0],
+                [0.0, 1.0, 0.0, 0.0],
+                [0.0, 0.0, 1.0, 0.0],
+                [0.0, 0.0, 0.0, 1.0],
+            ],
         }
     }
 }
 
+/// Eigenvalues of the symmetric matrix `[[a, b], [b, c]]`, larger first,
+/// and the angle of the larger one's eigenvector from the `x` axis.
+fn symmetric_eigen(a: f64, b: f64, c: f64) -> (f64, f64, f64) {
+    let mean = (a + c) / 2.0;
+    let spread = ((a - c) / 2.0).hypot(b);
+    (mean + spread, mean - spread, 0.5 * (2.0 * b).atan2(a - c))
+}
+
+fn validate_view(position: &Point3D, target: &Point3D, up: &Vector3D) -> Result<(), GeometryError> {
+    let forward = (*target - *position).normalize().map_err(|_| {
+        GeometryError::InvalidParameter("Camera position and target must differ".to_string())
+    })?;
+    let up = up.normalize().map_err(|_| {
+        GeometryError::InvalidParameter("Camera up vector must be non-zero".to_string())
+    })?;
+    if forward.is_parallel(&up) {
+        return Err(GeometryError::InvalidParameter(
+            "Camera up vector must not be parallel to the view direction".to_string(),
+        ));
+    }
+    Ok(())
+}
+
+/// Rotates `v` by `angle` radians about the unit vector `axis` (Rodrigues).
+fn rotate(v: &Vector3D, axis: &Vector3D, angle: f64) -> Vector3D {
+    let (sin, cos) = angle.sin_cos();
+    *v * cos + axis.cross(v) * sin + *axis * (axis.dot(v) * (1.0 - cos))
+}
+
+fn validate_fov(fov: f64) -> Result<(), GeometryError> {
+    if fov > 0.0 && fov < 180.0 {
+        Ok(())
+    } else {
+        Err(GeometryError::InvalidParameter(
+            "Camera field of view must be between 0 and 180 degrees".to_string(),
+        ))
+    }
+}
+
+fn validate_fisheye_fov(fov: f64) -> Result<(), GeometryError> {
+    if fov > 0.0 && fov <= 360.0 {
+        Ok(())
+    } else {
+        Err(GeometryError::InvalidParameter(
+            "Fisheye field of view must be between 0 and 360 degrees".to_string(),
+        ))
+    }
+}
+
+fn validate_aspect(aspect: f64) -> Result<(), GeometryError> {
+    if aspect > 0.0 && aspect.is_finite() {
+        Ok(())
+    } else {
+        Err(GeometryError::InvalidParameter(
+            "Camera aspect ratio must be positive".to_string(),
+        ))
+    }
+}
+
+fn validate_depth_range(near: f64, far: f64) -> Result<(), GeometryError> {
+    if near > 0.0 && near < far && near.is_finite() && far.is_finite() {
+        Ok(())
+    } else {
+        Err(GeometryError::InvalidParameter(
+            "Camera near and far planes must be finite, positive and in order".to_string(),
+        ))
+    }
+}
+
+fn validate_extent(width: f64, height: f64) -> Result<(), GeometryError> {
+    if width > 0.0 && height > 0.0 && width.is_finite() && height.is_finite() {
+        Ok(())
+    } else {
+        Err(GeometryError::InvalidParameter(
+            "Camera view width and height must be positive".to_string(),
+        ))
+    }
+}
+
+/// Unit `up` and two unit axes spanning the ground plane perpendicular to
+/// it: the world axis after `up`'s dominant one (in x, y, z order) made
+/// perpendicular to `up`, then `up` crossed with that.
+fn ground_axes(up: &Vector3D) -> Result<(Vector3D, Vector3D, Vector3D), GeometryError> {
+    let up = up.normalize()?;
+    let magnitudes = [up.x.abs(), up.y.abs(), up.z.abs()];
+    let dominant = (1..3).fold(0, |best, i| {
+        if magnitudes[i] > magnitudes[best] {
+            i
+        } else {
+            best
+        }
+    });
+    let axis = [Vector3D::unit_x(), Vector3D::unit_y(), Vector3D::unit_z()][(dominant + 1) % 3];
+    let ground_x = (axis - up * axis.dot(&up)).normalize()?;
+    Ok((ground_x, up.cross(&ground_x), up))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::primitives::Containment;
 
     #[test]
     fn test_perspective_camera_creation() {
@@ -177,7 +1180,8 @@ mod tests {
             16.0 / 9.0,
             0.1,
             100.0,
-        );
+        )
+        .unwrap();
 
         match camera {
             Camera::Perspective { fov, aspect, .. } => {
@@ -196,7 +1200,8 @@ mod tests {
             Vector3D::new(0.0, 1.0, 0.0),
             10.0,
             10.0,
-        );
+        )
+        .unwrap();
 
         match camera {
             Camera::Orthographic { width, height, .. } => {
@@ -217,7 +1222,8 @@ mod tests {
             1.0,
             0.1,
             100.0,
-        );
+        )
+        .unwrap();
 
         let view_matrix = camera.view_matrix();
         assert_eq!(view_matrix[3][3], 1.0);
@@ -233,21 +1239,610 @@ mod tests {
             1.0,
             0.1,
             100.0,
-        );
+        )
+        .unwrap();
 
         let proj_matrix = camera.projection_matrix();
         assert_eq!(proj_matrix[3][2], -1.0);
     }
 
     #[test]
-    fn test_projection_matrix_orthographic() {
+    fn test_perspective_frustum() {
+        let camera = Camera::perspective(
+            Point3D::new(0.0, 0.0, 5.0),
+            Point3D::new(0.0, 0.0, 0.0),
+            Vector3D::new(0.0, 1.0, 0.0),
+            90.0,
+            2.0,
+            1.0,
+            100.0,
+        )
+        .unwrap();
+
+        let frustum = camera.frustum().unwrap();
+        assert_eq!(frustum.corners[0], Point3D::new(-2.0, -1.0, 4.0));
+        assert_eq!(frustum.corners[6], Point3D::new(200.0, 100.0, -95.0));
+        assert!(frustum.contains_point(&Point3D::origin()));
+        assert!(!frustum.contains_point(&Point3D::new(0.0, 0.0, 6.0)));
+        assert!(!frustum.contains_point(&Point3D::new(0.0, 5.5, 0.0)));
+    }
+
+    #[test]
+    fn test_orthographic_frustum() {
         let camera = Camera::orthographic(
             Point3D::new(0.0, 0.0, 5.0),
             Point3D::new(0.0, 0.0, 0.0),
             Vector3D::new(0.0, 1.0, 0.0),
             10.0,
+            4.0,
+        )
+        .unwrap();
+
+        let frustum = camera.frustum().unwrap();
+        assert!(frustum.contains_point(&Point3D::new(4.9, 1.9, -50.0)));
+        assert!(!frustum.contains_point(&Point3D::new(0.0, 2.1, 0.0)));
+        assert!(!frustum.contains_point(&Point3D::new(0.0, 0.0, 6.0)));
+    }
+
+    #[test]
+    fn test_unproject_inverts_projection() {
+        use crate::svg::projection::project_point;
+
+        let cameras = [
+            Camera::perspective(
+                Point3D::new(3.0, 4.0, 5.0),
+                Point3D::new(0.0, 0.0, 0.0),
+                Vector3D::new(0.0, 1.0, 0.0),
+                50.0,
+                4.0 / 3.0,
+                0.1,
+                100.0,
+            )
+            .unwrap(),
+            Camera::orthographic(
+                Point3D::new(3.0, 4.0, 5.0),
+                Point3D::new(0.0, 0.0, 0.0),
+                Vector3D::new(0.0, 0.0, 1.0),
+                8.0,
+                6.0,
+            )
+            .unwrap(),
+            Camera::cabinet(
+                Point3D::new(3.0, 4.0, 5.0),
+                Point3D::new(0.0, 0.0, 0.0),
+                Vector3D::new(0.0, 1.0, 0.0),
+                8.0,
+                6.0,
+            )
+            .unwrap(),
+            Camera::off_axis(
+                Point3D::new(3.0, 4.0, 5.0),
+                Point3D::new(0.0, 0.0, 0.0),
+                Vector3D::new(0.0, 1.0, 0.0),
+                [-0.02, 0.08, -0.05, 0.01],
+                0.1,
+                100.0,
+            )
+            .unwrap(),
+            Camera::fisheye(
+                Point3D::new(3.0, 4.0, 5.0),
+                Point3D::new(0.0, 0.0, 0.0),
+                Vector3D::new(0.0, 1.0, 0.0),
+                200.0,
+                4.0 / 3.0,
+            )
+            .unwrap(),
+            Camera::equirectangular(
+                Point3D::new(3.0, 4.0, 5.0),
+                Point3D::new(0.0, 0.0, 0.0),
+                Vector3D::new(0.0, 1.0, 0.0),
+            )
+            .unwrap(),
+        ];
+        for camera in &cameras {
+            let world = Point3D::new(0.5, -0.25, 1.0);
+            let (sx, sy) = project_point(&world, camera, 800, 600);
+            let ray = camera.screen_to_ray(sx, sy, 800, 600);
+            let t = (world - ray.origin).dot(&ray.direction);
+            assert!(ray.point_at(t).distance_to(&world) < 1e-9);
+
+            let (ndc_x, ndc_y) = screen_to_ndc(sx, sy, 800, 600);
+            let depth = camera.view_depth(&world);
+            assert!(camera.unproject(ndc_x, ndc_y, depth).distance_to(&world) < 1e-9);
+        }
+    }
+
+    #[test]
+    fn test_screen_center_ray() {
+        let camera = Camera::perspective(
+            Point3D::new(0.0, 0.0, 5.0),
+            Point3D::new(0.0, 0.0, 0.0),
+            Vector3D::new(0.0, 1.0, 0.0),
+            60.0,
+            1.0,
+            0.1,
+            100.0,
+        )
+        .unwrap();
+        let ray = camera.screen_to_ray(400.0, 300.0, 800, 600);
+        assert_eq!(ray.origin, Point3D::new(0.0, 0.0, 5.0));
+        assert_eq!(ray.direction, Vector3D::new(0.0, 0.0, -1.0));
+
+        // Screen y grows downwards, world up is +y.
+        let upper = camera.screen_to_ray(400.0, 0.0, 800, 600);
+        assert!(upper.direction.y > 0.0);
+    }
+
+    fn front_camera() -> Camera {
+        Camera::perspective(
+            Point3D::new(0.0, 0.0, 5.0),
+            Point3D::new(0.0, 0.0, 0.0),
+            Vector3D::new(0.0, 1.0, 0.0),
+            60.0,
+            1.0,
+            0.1,
+            100.0,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_invalid_parameters() {
+        let eye = Point3D::new(0.0, 0.0, 5.0);
+        let origin = Point3D::origin();
+        let up = Vector3D::unit_y();
+        assert!(Camera::perspective(eye, origin, up, 0.0, 1.0, 0.1, 100.0).is_err());
+        assert!(Camera::perspective(eye, origin, up, 180.0, 1.0, 0.1, 100.0).is_err());
+        assert!(Camera::perspective(eye, origin, up, 60.0, 1.0, 10.0, 10.0).is_err());
+        assert!(Camera::perspective(eye, origin, up, 60.0, 1.0, 0.0, 10.0).is_err());
+        assert!(Camera::perspective(eye, origin, up, 60.0, 1.0, 0.1, f64::INFINITY).is_err());
+        assert!(Camera::perspective(eye, origin, up, 60.0, 1.0, 0.1, f64::NAN).is_err());
+        assert!(Camera::perspective(eye, eye, up, 60.0, 1.0, 0.1, 100.0).is_err());
+        assert!(
+            Camera::perspective(eye, origin, Vector3D::unit_z(), 60.0, 1.0, 0.1, 100.0).is_err()
+        );
+        assert!(Camera::orthographic(eye, origin, Vector3D::zero(), 4.0, 4.0).is_err());
+        assert!(Camera::orthographic(eye, origin, up, 0.0, 4.0).is_err());
+    }
+
+    #[test]
+    fn test_orbit() {
+        let mut camera = front_camera();
+        camera.orbit(90.0, 0.0);
+        assert_eq!(camera.position(), Point3D::new(5.0, 0.0, 0.0));
+        assert_eq!(camera.target(), Point3D::origin());
+
+        camera.orbit(0.0, 45.0);
+        let position = camera.position();
+        assert!((position.distance_to(&Point3D::origin()) - 5.0).abs() < 1e-9);
+        assert!((position.y - 5.0 * 45f64.to_radians().sin()).abs() < 1e-9);
+
+        // Pitching over the pole stops short of it.
+        camera.orbit(0.0, 120.0);
+        let elevation = (camera.position().y / 5.0).asin().to_degrees();
+        assert!((elevation - MAX_ORBIT_ELEVATION).abs() < 1e-9);
+        assert!(camera.frustum().is_ok());
+    }
+
+    #[test]
+    fn test_pan_dolly_zoom() {
+        let mut camera = front_camera();
+        camera.pan(1.0, 2.0);
+        assert_eq!(camera.position(), Point3D::new(1.0, 2.0, 5.0));
+        assert_eq!(camera.target(), Point3D::new(1.0, 2.0, 0.0));
+
+        camera.dolly(3.0).unwrap();
+        assert_eq!(camera.position(), Point3D::new(1.0, 2.0, 2.0));
+        assert!(camera.dolly(2.0).is_err());
+        camera.dolly(-1.0).unwrap();
+        assert_eq!(camera.position(), Point3D::new(1.0, 2.0, 3.0));
+
+        camera.zoom(2.0).unwrap();
+        match camera {
+            Camera::Perspective { fov, .. } => {
+                let expected = (2.0 * (30f64.to_radians().tan() / 2.0).atan()).to_degrees();
+                assert!((fov - expected).abs() < 1e-9);
+            }
+            _ => panic!("Expected perspective camera"),
+        }
+        assert!(camera.zoom(0.0).is_err());
+
+        let mut ortho = Camera::orthographic(
+            Point3D::new(0.0, 0.0, 5.0),
+            Point3D::origin(),
+            Vector3D::unit_y(),
+            8.0,
+            4.0,
+        )
+        .unwrap();
+        ortho.zoom(4.0).unwrap();
+        match ortho {
+            Camera::Orthographic { width, height, .. } => assert_eq!((width, height), (2.0, 1.0)),
+            _ => panic!("Expected orthographic camera"),
+        }
+    }
+
+    #[test]
+    fn test_look_at() {
+        let mut camera = front_camera();
+        camera.look_at(Point3D::new(1.0, 0.0, 0.0)).unwrap();
+        assert_eq!(camera.target(), Point3D::new(1.0, 0.0, 0.0));
+        assert!(camera.look_at(Point3D::new(0.0, 3.0, 5.0)).is_err());
+        assert!(camera.look_at(Point3D::new(0.0, 0.0, 5.0)).is_err());
+        assert_eq!(camera.target(), Point3D::new(1.0, 0.0, 0.0));
+    }
+
+    #[test]
+    fn test_fit_to_sphere_and_aabb() {
+        let sphere = Sphere::new(Point3D::new(10.0, 0.0, 0.0), 2.0).unwrap();
+        let mut camera = front_camera();
+        camera.fit_to_sphere(&sphere).unwrap();
+        assert_eq!(camera.target(), sphere.center);
+        assert_eq!(camera.position(), Point3D::new(10.0, 0.0, 4.0));
+        // The sphere touches the top and bottom planes.
+        let frustum = camera.frustum().unwrap();
+        for plane in &frustum.planes {
+            assert!(plane.distance_to_point(&sphere.center) >= sphere.radius - 1e-9);
+        }
+
+        let aabb = AABB::new(Point3D::new(-1.0, -1.0, -1.0), Point3D::new(1.0, 1.0, 1.0)).unwrap();
+        let mut ortho = Camera::orthographic(
+            Point3D::new(0.0, 0.0, 5.0),
+            Point3D::origin(),
+            Vector3D::unit_y(),
+            2.0,
+            1.0,
+        )
+        .unwrap();
+        ortho.fit_to_aabb(&aabb).unwrap();
+        let frustum = ortho.frustum().unwrap();
+        assert_eq!(frustum.classify_aabb(&aabb), Containment::Inside);
+
+        // A single point has nothing to frame.
+        let corner = Point3D::new(1.0, 2.0, 3.0);
+        let point = AABB {
+            min: corner,
+            max: corner,
+        };
+        let before = ortho.clone();
+        assert!(ortho.fit_to_aabb(&point).is_err());
+        assert_eq!(ortho.position(), before.position());
+        let mut camera = front_camera();
+        assert!(camera.fit_to_aabb(&point).is_err());
+        assert_eq!(camera.position(), front_camera().position());
+    }
+
+    #[test]
+    fn test_fit_to_small_sphere_pulls_near_plane_in() {
+        let sphere = Sphere::new(Point3D::origin(), 0.01).unwrap();
+        let mut camera = Camera::perspective(
+            Point3D::new(0.0, 0.0, 10.0),
+            Point3D::origin(),
+            Vector3D::unit_y(),
+            90.0,
+            1.0,
+            1.0,
+            100.0,
+        )
+        .unwrap();
+        camera.fit_to_sphere(&sphere).unwrap();
+        let frustum = camera.frustum().unwrap();
+        for plane in &frustum.planes {
+            assert!(plane.distance_to_point(&sphere.center) >= sphere.radius - 1e-9);
+        }
+    }
+
+    /// Screen-space lengths of the unit world axes under `camera`.
+    fn axis_lengths(camera: &Camera) -> [f64; 3] {
+        let (ox, oy) = camera.project_to_ndc(&Point3D::origin());
+        [Vector3D::unit_x(), Vector3D::unit_y(), Vector3D::unit_z()].map(|axis| {
+            let (x, y) = camera.project_to_ndc(&(Point3D::origin() + axis));
+            (x - ox).hypot(y - oy)
+        })
+    }
+
+    #[test]
+    fn test_axonometric_presets() {
+        let up = Vector3D::unit_z();
+        let isometric = Camera::isometric(Point3D::origin(), up, 10.0, 10.0).unwrap();
+        let [x, y, z] = axis_lengths(&isometric);
+        assert!((x - y).abs() < 1e-12 && (y - z).abs() < 1e-12);
+        let corner = 10.0 / 3f64.sqrt();
+        assert_eq!(isometric.position(), Point3D::new(corner, corner, corner));
+
+        let dimetric = Camera::dimetric(Point3D::origin(), up, 10.0, 10.0).unwrap();
+        let [x, y, z] = axis_lengths(&dimetric);
+        assert!((y - z).abs() < 1e-12);
+        assert!((x - y / 2.0).abs() < 1e-12);
+
+        let trimetric = Camera::trimetric(
+            Point3D::origin(),
+            Vector3D::unit_y(),
+            30.0,
+            20.0,
+            10.0,
+            10.0,
+        )
+        .unwrap();
+        let [x, y, z] = axis_lengths(&trimetric);
+        assert!((x - y).abs() > 1e-3 && (y - z).abs() > 1e-3 && (x - z).abs() > 1e-3);
+
+        assert!(Camera::trimetric(Point3D::origin(), up, 0.0, 90.0, 10.0, 10.0).is_err());
+    }
+
+    #[test]
+    fn test_oblique_projection() {
+        let camera = Camera::cabinet(
+            Point3D::new(0.0, 0.0, 5.0),
+            Point3D::origin(),
+            Vector3D::unit_y(),
+            10.0,
+            10.0,
+        )
+        .unwrap();
+
+        // The target plane keeps its true shape.
+        let (x, y) = camera.project_to_ndc(&Point3D::new(1.0, 1.0, 0.0));
+        assert!((x - 0.2).abs() < 1e-12 && (y - 0.2).abs() < 1e-12);
+
+        // One unit deeper shifts half a unit up and right at 45 degrees.
+        let shift = 0.5 * 45f64.to_radians().cos() * 2.0 / 10.0;
+        let (x, y) = camera.project_to_ndc(&Point3D::new(0.0, 0.0, -1.0));
+        assert!((x - shift).abs() < 1e-12 && (y - shift).abs() < 1e-12);
+
+        let cavalier = Camera::cavalier(
+            Point3D::new(0.0, 0.0, 5.0),
+            Point3D::origin(),
+            Vector3D::unit_y(),
+            10.0,
+            10.0,
+        )
+        .unwrap();
+        let [x, y, z] = axis_lengths(&cavalier);
+        assert!((x - y).abs() < 1e-12 && (y - z).abs() < 1e-12);
+
+        assert!(Camera::oblique(
+            Point3D::new(0.0, 0.0, 5.0),
+            Point3D::origin(),
+            Vector3D::unit_y(),
+            10.0,
             10.0,
+            45.0,
+            -1.0,
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_off_axis() {
+        let eye = Point3D::new(0.0, 0.0, 5.0);
+        let up = Vector3D::unit_y();
+        let symmetric = Camera::off_axis(
+            eye,
+            Point3D::origin(),
+            up,
+            [-0.1, 0.1, -0.1, 0.1],
+            0.1,
+            100.0,
+        )
+        .unwrap();
+        let perspective =
+            Camera::perspective(eye, Point3D::origin(), up, 90.0, 1.0, 0.1, 100.0).unwrap();
+        let (a, b) = (
+            symmetric.projection_matrix(),
+            perspective.projection_matrix(),
         );
+        for (row_a, row_b) in a.iter().zip(&b) {
+            for (x, y) in row_a.iter().zip(row_b) {
+                assert!((x - y).abs() < 1e-12);
+            }
+        }
+
+        // Shifting the window right moves the target left on screen.
+        let shifted = Camera::off_axis(
+            eye,
+            Point3D::origin(),
+            up,
+            [0.0, 0.2, -0.1, 0.1],
+            0.1,
+            100.0,
+        )
+        .unwrap();
+        let (x, y) = shifted.project_to_ndc(&Point3D::origin());
+        assert!((x + 1.0).abs() < 1e-12 && y.abs() < 1e-12);
+        assert!(shifted
+            .frustum()
+            .unwrap()
+            .contains_point(&Point3D::new(1.0, 0.0, 0.0)));
+
+        assert!(Camera::off_axis(
+            eye,
+            Point3D::origin(),
+            up,
+            [0.1, -0.1, -0.1, 0.1],
+            0.1,
+            100.0
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_fisheye_and_equirectangular() {
+        let eye = Point3D::new(0.0, 0.0, 5.0);
+        let up = Vector3D::unit_y();
+        let fisheye = Camera::fisheye(eye, Point3D::origin(), up, 180.0, 2.0).unwrap();
+        assert!(!fisheye.is_linear());
+        // 45 degrees to the right is halfway to the image circle, which is
+        // squeezed horizontally by the aspect ratio.
+        let (x, y) = fisheye.project_to_ndc(&Point3D::new(1.0, 0.0, 4.0));
+        assert!((x - 0.25).abs() < 1e-12 && y.abs() < 1e-12);
+
+        let panorama = Camera::equirectangular(eye, Point3D::origin(), up).unwrap();
+        let (x, y) = panorama.project_to_ndc(&Point3D::new(3.0, 0.0, 5.0));
+        assert!((x - 0.5).abs() < 1e-12 && y.abs() < 1e-12);
+        let (x, _) = panorama.project_to_ndc(&Point3D::new(0.0, 0.0, 8.0));
+        assert!((x.abs() - 1.0).abs() < 1e-12);
+        let (_, y) = panorama.project_to_ndc(&Point3D::new(0.0, 2.0, 5.0));
+        assert!((y - 1.0).abs() < 1e-12);
+
+        assert!(matches!(
+            panorama.frustum(),
+            Err(GeometryError::DegenerateCase(_))
+        ));
+        assert!(fisheye.frustum().is_err());
+        assert!(panorama.clone().zoom(2.0).is_err());
+        assert!(Camera::fisheye(eye, Point3D::origin(), up, 400.0, 1.0).is_err());
+
+        let mut zoomed = fisheye;
+        zoomed.zoom(2.0).unwrap();
+        assert!(matches!(zoomed, Camera::Fisheye { fov, .. } if fov == 90.0));
+        assert!(matches!(
+            zoomed.zoom(0.1),
+            Err(GeometryError::InvalidParameter(_))
+        ));
+        assert!(matches!(zoomed, Camera::Fisheye { fov, .. } if fov == 90.0));
+    }
+
+    #[test]
+    fn test_fit_new_projections() {
+        let sphere = Sphere::new(Point3D::new(10.0, 0.0, 0.0), 2.0).unwrap();
+        let eye = Point3D::new(0.0, 0.0, 5.0);
+        let up = Vector3D::unit_y();
+        let cameras = [
+            Camera::off_axis(
+                eye,
+                Point3D::origin(),
+                up,
+                [0.0, 0.2, -0.05, 0.1],
+                0.1,
+                20.0,
+            )
+            .unwrap(),
+            Camera::cabinet(eye, Point3D::origin(), up, 4.0, 2.0).unwrap(),
+        ];
+        for mut camera in cameras {
+            camera.fit_to_sphere(&sphere).unwrap();
+            let frustum = camera.frustum().unwrap();
+            for plane in &frustum.planes {
+                assert!(plane.distance_to_point(&sphere.center) >= sphere.radius - 1e-9);
+            }
+        }
+
+        let mut fisheye = Camera::fisheye(eye, Point3D::origin(), up, 40.0, 1.0).unwrap();
+        fisheye.fit_to_sphere(&sphere).unwrap();
+        let edge = sphere.center + Vector3D::unit_y() * sphere.radius;
+        let (_, y) = fisheye.project_to_ndc(&edge);
+        assert!(y <= 1.0 + 1e-9);
+        assert_eq!(fisheye.target(), sphere.center);
+    }
+
+    /// Largest value of |A^-1 (p - center)| over points p of the sphere's
+    /// surface, where A has the ellipse's axes as columns. Exactly one for
+    /// the true outline.
+    fn outline_extent(camera: &Camera, sphere: &Sphere, ellipse: &ProjectedEllipse) -> f64 {
+        let [(ax, ay), (bx, by)] = ellipse.axes;
+        let det = ax * by - bx * ay;
+        let mut extent: f64 = 0.0;
+        for i in 0..=180 {
+            let polar = PI * i as f64 / 180.0;
+            for j in 0..360 {
+                let azimuth = PI * j as f64 / 180.0;
+                let normal = Vector3D::new(
+                    polar.sin() * azimuth.cos(),
+                    polar.sin() * azimuth.sin(),
+                    polar.cos(),
+                );
+                let (x, y) = camera.project_to_ndc(&(sphere.center + normal * sphere.radius));
+                let (dx, dy) = (x - ellipse.center.0, y - ellipse.center.1);
+                let (u, v) = ((by * dx - bx * dy) / det, (ax * dy - ay * dx) / det);
+                extent = extent.max(u.hypot(v));
+            }
+        }
+        extent
+    }
+
+    #[test]
+    fn test_project_sphere() {
+        let eye = Point3D::new(0.0, 0.0, 5.0);
+        let up = Vector3D::unit_y();
+        let perspective =
+            Camera::perspective(eye, Point3D::origin(), up, 60.0, 1.0, 0.1, 100.0).unwrap();
+        let centered = Sphere::new(Point3D::origin(), 1.0).unwrap();
+        let (major, minor, _) = perspective
+            .project_sphere(&centered)
+            .unwrap()
+            .principal_axes();
+        let expected = (0.2f64.asin().tan()) / (PI / 6.0).tan();
+        assert!((major - expected).abs() < 1e-12 && (minor - expected).abs() < 1e-12);
+
+        // Looking along the x axis used to collapse the outline.
+        let sideways = Camera::perspective(
+            Point3D::new(5.0, 0.0, 0.0),
+            Point3D::origin(),
+            up,
+            60.0,
+            1.0,
+            0.1,
+            100.0,
+        )
+        .unwrap();
+        let (major, _, _) = sideways.project_sphere(&centered).unwrap().principal_axes();
+        assert!((major - expected).abs() < 1e-12);
+
+        // Off to the side, the outline stretches away from the centre.
+        let aside = Sphere::new(Point3D::new(3.0, 3.0, 0.0), 1.0).unwrap();
+        let ellipse = perspective.project_sphere(&aside).unwrap();
+        let (major, minor, angle) = ellipse.principal_axes();
+        assert!(major > minor * 1.1);
+        assert!((angle.abs() - PI / 4.0).abs() < 1e-9);
+        let (x, y) = perspective.project_to_ndc(&aside.center);
+        assert!(ellipse.center.0 > x && ellipse.center.1 > y);
+
+        let cameras = [
+            perspective.clone(),
+            Camera::off_axis(
+                eye,
+                Point3D::origin(),
+                up,
+                [0.0, 0.2, -0.1, 0.1],
+                0.1,
+                100.0,
+            )
+            .unwrap(),
+            Camera::orthographic(eye, Point3D::origin(), up, 10.0, 4.0).unwrap(),
+            Camera::isometric(Point3D::origin(), up, 10.0, 10.0).unwrap(),
+            Camera::cabinet(eye, Point3D::origin(), up, 10.0, 10.0).unwrap(),
+        ];
+        for camera in &cameras {
+            let ellipse = camera.project_sphere(&aside).unwrap();
+            let extent = outline_extent(camera, &aside, &ellipse);
+            assert!((extent - 1.0).abs() < 1e-3, "{:?}", camera);
+        }
+
+        let (major, minor, angle) = cameras[2]
+            .project_sphere(&centered)
+            .unwrap()
+            .principal_axes();
+        assert!((major - 0.5).abs() < 1e-12 && (minor - 0.2).abs() < 1e-12);
+        assert!((angle.abs() - FRAC_PI_2).abs() < 1e-12);
+
+        // Reaching behind the eye, or through a curved lens.
+        let touching = Sphere::new(Point3D::new(0.0, 0.0, 3.0), 2.5).unwrap();
+        assert!(perspective.project_sphere(&touching).is_none());
+        let fisheye = Camera::fisheye(eye, Point3D::origin(), up, 180.0, 1.0).unwrap();
+        assert!(fisheye.project_sphere(&centered).is_none());
+    }
+
+    #[test]
+    fn test_projection_matrix_orthographic() {
+        let camera = Camera::orthographic(
+            Point3D::new(0.0, 0.0, 5.0),
+            Point3D::new(0.0, 0.0, 0.0),
+            Vector3D::new(0.0, 1.0, 0.0),
+            10.0,
+            10.0,
+        )
+        .unwrap();
 
         let proj_matrix = camera.projection_matrix();
         assert_eq!(proj_matrix[3][3], 1.0);
@@ -0,0 +1,87 @@
+use super::style::{xml_id, Style};
+
+/// Named group of elements drawn by [`SVGRenderer`](super::SVGRenderer),
+/// written as a `<g>` that Inkscape and Illustrator open as a layer.
+///
+/// Elements added while the layer is current take their unset style
+/// fields from `style`, and their ids are prefixed with the layer's id so
+/// the same key can be reused across layers.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Layer {
+    pub name: String,
+    /// Hidden layers are still written, with `display="none"`, so they can
+    /// be shown again in an editor or from a script.
+    pub visible: bool,
+    /// Defaults for the layer's elements. Its id is not used.
+    pub style: Style,
+    /// SVG transform of the group in screen space, such as
+    /// `"translate(10 0)"`.
+    pub transform: Option<String>,
+}
+
+impl Layer {
+    pub fn new(name: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            visible: true,
+            style: Style::default(),
+            transform: None,
+        }
+    }
+
+    pub fn with_visible(mut self, visible: bool) -> Self {
+        self.visible = visible;
+        self
+    }
+
+    pub fn with_style(mut self, style: Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    pub fn with_transform(mut self, transform: &str) -> Self {
+        self.transform = Some(transform.to_string());
+        self
+    }
+
+    /// Id of the layer's group: its name with characters not allowed in an
+    /// XML id replaced by `-`.
+    pub fn id(&self) -> String {
+        xml_id(&self.name)
+    }
+
+    /// `style` for an element of this layer: unset fields taken from the
+    /// layer's style, and the id prefixed with the layer's.
+    pub(crate) fn element_style(&self, style: &Style) -> Style {
+        let mut style = style.with_defaults(&Style {
+            id: None,
+            ..self.style.clone()
+        });
+        style.id = style
+            .id
+            .map(|key| format!("{}-{}", self.id(), xml_id(&key)));
+        style
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_layer_ids_and_styles() {
+        let layer = Layer::new("Hidden Edges").with_style(
+            Style::new("#808080", 1.0)
+                .with_class("hidden")
+                .with_id("ignored"),
+        );
+        assert_eq!(layer.id(), "Hidden-Edges");
+        assert_eq!(Layer::new("3d view").id(), "_3d-view");
+
+        let style = layer.element_style(&Style::default().with_id("box #1").with_stroke("#ff0000"));
+        assert_eq!(style.id.as_deref(), Some("Hidden-Edges-box--1"));
+        assert_eq!(style.stroke.as_deref(), Some("#ff0000"));
+        assert_eq!(style.class.as_deref(), Some("hidden"));
+        assert_eq!(layer.element_style(&Style::default()).id, None);
+    }
+}
@@ -0,0 +1,186 @@
+use crate::error::GeometryError;
+use crate::primitives::{Point3D, Vector3D};
+
+/// Light source for shading triangles in [`SVGRenderer`](super::SVGRenderer).
+///
+/// Colours are RGB components in `[0, 1]`. Point lights do not fall off
+/// with distance.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Light {
+    /// Light reaching every surface equally, whichever way it faces.
+    Ambient { color: [f64; 3], intensity: f64 },
+    /// Parallel light travelling along the unit vector `direction`, as from
+    /// the sun.
+    Directional {
+        direction: Vector3D,
+        color: [f64; 3],
+        intensity: f64,
+    },
+    /// Light shining in all directions from `position`.
+    Point {
+        position: Point3D,
+        color: [f64; 3],
+        intensity: f64,
+    },
+}
+
+impl Light {
+    /// `color` is a `#rgb` or `#rrggbb` hex string.
+    pub fn ambient(color: &str, intensity: f64) -> Result<Self, GeometryError> {
+        Ok(Light::Ambient {
+            color: light_color(color)?,
+            intensity: validate_intensity(intensity)?,
+        })
+    }
+
+    pub fn directional(
+        direction: Vector3D,
+        color: &str,
+        intensity: f64,
+    ) -> Result<Self, GeometryError> {
+        Ok(Light::Directional {
+            direction: direction.normalize()?,
+            color: light_color(color)?,
+            intensity: validate_intensity(intensity)?,
+        })
+    }
+
+    pub fn point(position: Point3D, color: &str, intensity: f64) -> Result<Self, GeometryError> {
+        Ok(Light::Point {
+            position,
+            color: light_color(color)?,
+            intensity: validate_intensity(intensity)?,
+        })
+    }
+
+    /// Light received by a surface at `point` facing along the unit vector
+    /// `normal` (Lambert's cosine law), per RGB component.
+    pub fn illuminance(&self, point: &Point3D, normal: &Vector3D) -> [f64; 3] {
+        let (color, intensity, cosine) = match self {
+            Light::Ambient { color, intensity } => (color, intensity, 1.0),
+            Light::Directional {
+                direction,
+                color,
+                intensity,
+            } => (color, intensity, -normal.dot(direction)),
+            Light::Point {
+                position,
+                color,
+                intensity,
+            } => {
+                let cosine = (*position - *point)
+                    .normalize()
+                    .map_or(0.0, |to_light| normal.dot(&to_light));
+                (color, intensity, cosine)
+            }
+        };
+        color.map(|c| c * intensity * cosine.max(0.0))
+    }
+}
+
+/// `base` lit by `lights` at a surface through `point` facing `normal`, as
+/// a `#rrggbb` string. `None` if `base` is not a hex colour.
+pub(crate) fn shade_color(
+    base: &str,
+    lights: &[Light],
+    point: &Point3D,
+    normal: &Vector3D,
+) -> Option<String> {
+    let base = parse_color(base)?;
+    let mut total = [0.0; 3];
+    for light in lights {
+        let received = light.illuminance(point, normal);
+        for (sum, value) in total.iter_mut().zip(received) {
+            *sum += value;
+        }
+    }
+    Some(format_color([0, 1, 2].map(|i| base[i] * total[i])))
+}
+
+/// RGB components in `[0, 1]` of a `#rgb` or `#rrggbb` string.
+pub(crate) fn parse_color(color: &str) -> Option<[f64; 3]> {
+    let hex = color.strip_prefix('#')?;
+    if !hex.is_ascii() {
+        return None;
+    }
+    let channel = |digits: &str| u8::from_str_radix(digits, 16).ok();
+    let [r, g, b] = match hex.len() {
+        3 => [0, 1, 2].map(|i| channel(&hex[i..=i]).map(|v| v * 17)),
+        6 => [0, 2, 4].map(|i| channel(&hex[i..i + 2])),
+        _ => return None,
+    };
+    Some([r?, g?, b?].map(|v| v as f64 / 255.0))
+}
+
+/// `#rrggbb` string for RGB components, clamped to `[0, 1]`.
+pub(crate) fn format_color(color: [f64; 3]) -> String {
+    let [r, g, b] = color.map(|c| (c.clamp(0.0, 1.0) * 255.0).round() as u8);
+    format!("#{:02x}{:02x}{:02x}", r, g, b)
+}
+
+fn light_color(color: &str) -> Result<[f64; 3], GeometryError> {
+    parse_color(color).ok_or_else(|| {
+        GeometryError::InvalidParameter(format!(
+            "Light color must be a #rgb or #rrggbb hex string, got '{}'",
+            color
+        ))
+    })
+}
+
+fn validate_intensity(intensity: f64) -> Result<f64, GeometryError> {
+    if intensity >= 0.0 && intensity.is_finite() {
+        Ok(intensity)
+    } else {
+        Err(GeometryError::InvalidParameter(
+            "Light intensity must be finite and non-negative".to_string(),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_and_format_color() {
+        assert_eq!(parse_color("#ffffff"), Some([1.0, 1.0, 1.0]));
+        assert_eq!(parse_color("#f00"), Some([1.0, 0.0, 0.0]));
+        assert_eq!(parse_color("#336699").map(format_color).unwrap(), "#336699");
+        assert_eq!(parse_color("red"), None);
+        assert_eq!(parse_color("#12345"), None);
+        assert_eq!(parse_color("#ééé"), None);
+        assert_eq!(format_color([1.5, 0.5, -1.0]), "#ff8000");
+    }
+
+    #[test]
+    fn test_invalid_lights() {
+        assert!(Light::ambient("white", 1.0).is_err());
+        assert!(Light::ambient("#fff", -1.0).is_err());
+        assert!(Light::directional(Vector3D::zero(), "#fff", 1.0).is_err());
+        assert!(Light::point(Point3D::origin(), "#fff", f64::NAN).is_err());
+    }
+
+    #[test]
+    fn test_illuminance() {
+        let normal = Vector3D::unit_z();
+        let origin = Point3D::origin();
+
+        let ambient = Light::ambient("#ffffff", 0.25).unwrap();
+        assert_eq!(ambient.illuminance(&origin, &-normal), [0.25; 3]);
+
+        let overhead = Light::directional(Vector3D::new(0.0, 0.0, -1.0), "#ff0000", 1.0).unwrap();
+        assert_eq!(overhead.illuminance(&origin, &normal), [1.0, 0.0, 0.0]);
+        assert_eq!(overhead.illuminance(&origin, &-normal), [0.0; 3]);
+
+        let slanted = Light::point(Point3D::new(1.0, 0.0, 1.0), "#ffffff", 1.0).unwrap();
+        let received = slanted.illuminance(&origin, &normal);
+        assert!((received[0] - 0.5f64.sqrt()).abs() < 1e-12);
+
+        let lights = [ambient, overhead];
+        assert_eq!(
+            shade_color("#808080", &lights, &origin, &normal).unwrap(),
+            "#a02020"
+        );
+        assert_eq!(shade_color("gray", &lights, &origin, &normal), None);
+    }
+}
@@ -1,7 +1,17 @@
 pub mod camera;
+pub mod layer;
+pub mod lighting;
 pub mod projection;
 pub mod renderer;
+pub mod style;
 
-pub use camera::Camera;
-pub use projection::{multiply_matrices, multiply_matrix_point, perspective_divide, project_point};
-pub use renderer::{SVGElement, SVGRenderer};
+pub use camera::{Camera, ProjectedEllipse};
+pub use layer::Layer;
+pub use lighting::Light;
+pub use projection::{
+    clip_point_inside, clip_polygon, clip_segment, multiply_matrices, multiply_matrix_point,
+    ndc_to_screen, perspective_divide, project_point, screen_to_ndc, ClipPlane, ClipPoint,
+    FAR_CLIP_PLANE, NEAR_CLIP_PLANE, SIDE_CLIP_PLANES,
+};
+pub use renderer::{DepthSort, HiddenLines, SVGElement, SVGRenderer, Shading};
+pub use style::{LineCap, LineJoin, Marker, Style, TextAnchor};
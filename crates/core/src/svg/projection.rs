@@ -32,17 +32,115 @@ pub fn perspective_divide(homogeneous: [f64; 4]) -> (f64, f64, f64) {
 }
 
 pub fn project_point(point: &Point3D, camera: &Camera, width: usize, height: usize) -> (f64, f64) {
-    let view_matrix = camera.view_matrix();
-    let projection_matrix = camera.projection_matrix();
-    let vp_matrix = multiply_matrices(&projection_matrix, &view_matrix);
+    let (ndc_x, ndc_y) = camera.project_to_ndc(point);
+    ndc_to_screen(ndc_x, ndc_y, width, height)
+}
+
+/// Viewport mapping from normalized device coordinates (`y` up) to pixel
+/// coordinates (origin top-left, `y` down).
+pub fn ndc_to_screen(x: f64, y: f64, width: usize, height: usize) -> (f64, f64) {
+    (
+        (x + 1.0) * 0.5 * width as f64,
+        (1.0 - y) * 0.5 * height as f64,
+    )
+}
+
+/// Inverse of the viewport mapping in [`project_point`]: pixel coordinates
+/// (origin top-left, `y` down) to normalized device coordinates in `[-1, 1]`
+/// with `y` up.
+pub fn screen_to_ndc(x: f64, y: f64, width: usize, height: usize) -> (f64, f64) {
+    (x / width as f64 * 2.0 - 1.0, 1.0 - y / height as f64 * 2.0)
+}
+
+/// Homogeneous clip-space point `[x, y, z, w]`.
+pub type ClipPoint = [f64; 4];
+
+/// Clip-space plane `[a, b, c, d]`. A point is inside when
+/// `a * x + b * y + c * z + d * w >= 0`.
+pub type ClipPlane = [f64; 4];
+
+/// Keeps points at or beyond the near plane of a perspective projection.
+pub const NEAR_CLIP_PLANE: ClipPlane = [0.0, 0.0, 1.0, 1.0];
+
+/// Frustum side planes in clip space: left, right, bottom, top.
+pub const SIDE_CLIP_PLANES: [ClipPlane; 4] = [
+    [1.0, 0.0, 0.0, 1.0],
+    [-1.0, 0.0, 0.0, 1.0],
+    [0.0, 1.0, 0.0, 1.0],
+    [0.0, -1.0, 0.0, 1.0],
+];
+
+/// Keeps points at or before the far plane of a perspective projection.
+pub const FAR_CLIP_PLANE: ClipPlane = [0.0, 0.0, -1.0, 1.0];
+
+#[inline]
+fn clip_distance(plane: &ClipPlane, point: &ClipPoint) -> f64 {
+    plane.iter().zip(point).map(|(a, b)| a * b).sum()
+}
+
+#[inline]
+fn lerp_clip(a: &ClipPoint, b: &ClipPoint, t: f64) -> ClipPoint {
+    std::array::from_fn(|i| a[i] + (b[i] - a[i]) * t)
+}
 
-    let homogeneous = multiply_matrix_point(&vp_matrix, point);
-    let (ndc_x, ndc_y, _ndc_z) = perspective_divide(homogeneous);
+/// Whether `point` is on the inside of every plane.
+pub fn clip_point_inside(point: &ClipPoint, planes: &[ClipPlane]) -> bool {
+    planes
+        .iter()
+        .all(|plane| clip_distance(plane, point) >= 0.0)
+}
 
-    let screen_x = (ndc_x + 1.0) * 0.5 * width as f64;
-    let screen_y = (1.0 - ndc_y) * 0.5 * height as f64;
+/// Clips the segment `a`-`b` to the inside of every plane before the
+/// perspective divide. Returns `None` if nothing is left. Unclipped ends
+/// are returned unchanged.
+pub fn clip_segment(
+    a: ClipPoint,
+    b: ClipPoint,
+    planes: &[ClipPlane],
+) -> Option<(ClipPoint, ClipPoint)> {
+    let (mut t0, mut t1) = (0.0f64, 1.0f64);
+    for plane in planes {
+        let (da, db) = (clip_distance(plane, &a), clip_distance(plane, &b));
+        if da < 0.0 && db < 0.0 {
+            return None;
+        }
+        if da < 0.0 {
+            t0 = t0.max(da / (da - db));
+        } else if db < 0.0 {
+            t1 = t1.min(da / (da - db));
+        }
+    }
+    if t0 > t1 {
+        return None;
+    }
+    let start = if t0 > 0.0 { lerp_clip(&a, &b, t0) } else { a };
+    let end = if t1 < 1.0 { lerp_clip(&a, &b, t1) } else { b };
+    Some((start, end))
+}
 
-    (screen_x, screen_y)
+/// Sutherland-Hodgman clipping of a convex or concave polygon against every
+/// plane before the perspective divide. Returns an empty polygon if fewer
+/// than three vertices survive.
+pub fn clip_polygon(points: &[ClipPoint], planes: &[ClipPlane]) -> Vec<ClipPoint> {
+    let mut polygon = points.to_vec();
+    for plane in planes {
+        let mut clipped = Vec::with_capacity(polygon.len() + 1);
+        for (i, current) in polygon.iter().enumerate() {
+            let next = &polygon[(i + 1) % polygon.len()];
+            let (dc, dn) = (clip_distance(plane, current), clip_distance(plane, next));
+            if dc >= 0.0 {
+                clipped.push(*current);
+            }
+            if (dc >= 0.0) != (dn >= 0.0) {
+                clipped.push(lerp_clip(current, next, dc / (dc - dn)));
+            }
+        }
+        polygon = clipped;
+        if polygon.len() < 3 {
+            return Vec::new();
+        }
+    }
+    polygon
 }
 
 #[cfg(test)]
@@ -72,12 +170,12 @@ mod tests {
             [0.0, 0.0, 0.0, 1.0],
         ];
         let result = multiply_matrices(&identity, &identity);
-        for i in 0..4 {
-            for j in 0..4 {
+        for (i, row) in result.iter().enumerate() {
+            for (j, value) in row.iter().enumerate() {
                 if i == j {
-                    assert_eq!(result[i][j], 1.0);
+                    assert_eq!(*value, 1.0);
                 } else {
-                    assert_eq!(result[i][j], 0.0);
+                    assert_eq!(*value, 0.0);
                 }
             }
         }
@@ -102,13 +200,14 @@ mod tests {
             1.0,
             0.1,
             100.0,
-        );
+        )
+        .unwrap();
 
         let point = Point3D::new(0.0, 0.0, 0.0);
         let (screen_x, screen_y) = project_point(&point, &camera, 800, 600);
 
-        assert!(screen_x >= 0.0 && screen_x <= 800.0);
-        assert!(screen_y >= 0.0 && screen_y <= 600.0);
+        assert!((0.0..=800.0).contains(&screen_x));
+        assert!((0.0..=600.0).contains(&screen_y));
     }
 
     #[test]
@@ -119,12 +218,73 @@ mod tests {
             Vector3D::new(0.0, 1.0, 0.0),
             10.0,
             10.0,
-        );
+        )
+        .unwrap();
 
         let point = Point3D::new(0.0, 0.0, 0.0);
         let (screen_x, screen_y) = project_point(&point, &camera, 800, 600);
 
-        assert!(screen_x >= 0.0 && screen_x <= 800.0);
-        assert!(screen_y >= 0.0 && screen_y <= 600.0);
+        assert!((0.0..=800.0).contains(&screen_x));
+        assert!((0.0..=600.0).contains(&screen_y));
+    }
+
+    #[test]
+    fn test_clip_segment() {
+        // Crosses the near plane (z = -w) halfway.
+        let a = [0.0, 0.0, -3.0, 1.0];
+        let b = [0.0, 0.0, 1.0, 1.0];
+        let (start, end) = clip_segment(a, b, &[NEAR_CLIP_PLANE]).unwrap();
+        assert_eq!(start, [0.0, 0.0, -1.0, 1.0]);
+        assert_eq!(end, b);
+
+        assert!(clip_segment(a, [0.0, 0.0, -2.0, 1.0], &[NEAR_CLIP_PLANE]).is_none());
+
+        let wide = clip_segment(
+            [-4.0, 0.0, 0.0, 2.0],
+            [4.0, 0.0, 0.0, 2.0],
+            &SIDE_CLIP_PLANES,
+        )
+        .unwrap();
+        assert_eq!(wide, ([-2.0, 0.0, 0.0, 2.0], [2.0, 0.0, 0.0, 2.0]));
+    }
+
+    #[test]
+    fn test_clip_polygon() {
+        let triangle = [
+            [0.0, 0.0, 0.0, 1.0],
+            [0.0, 0.0, -3.0, 1.0],
+            [1.0, 0.0, 0.0, 1.0],
+        ];
+        let clipped = clip_polygon(&triangle, &[NEAR_CLIP_PLANE]);
+        assert_eq!(clipped.len(), 4);
+        assert!(clipped
+            .iter()
+            .all(|p| clip_point_inside(p, &[NEAR_CLIP_PLANE])));
+
+        let behind = triangle.map(|[x, y, _, w]| [x, y, -2.0, w]);
+        assert!(clip_polygon(&behind, &[NEAR_CLIP_PLANE]).is_empty());
+        assert_eq!(
+            clip_polygon(&triangle, &SIDE_CLIP_PLANES),
+            triangle.to_vec()
+        );
+    }
+
+    #[test]
+    fn test_project_point_fisheye() {
+        let camera = Camera::fisheye(
+            Point3D::new(0.0, 0.0, 5.0),
+            Point3D::new(0.0, 0.0, 0.0),
+            Vector3D::new(0.0, 1.0, 0.0),
+            180.0,
+            1.0,
+        )
+        .unwrap();
+
+        let (x, y) = project_point(&Point3D::new(0.0, 0.0, 0.0), &camera, 800, 600);
+        assert!((x - 400.0).abs() < 1e-9 && (y - 300.0).abs() < 1e-9);
+
+        // 90 degrees off the viewing direction lands on the image circle.
+        let (x, y) = project_point(&Point3D::new(0.0, 3.0, 5.0), &camera, 800, 600);
+        assert!((x - 400.0).abs() < 1e-9 && y.abs() < 1e-9);
     }
 }
@@ -1,89 +1,429 @@
+use std::borrow::Cow;
+use std::collections::BTreeSet;
+use std::f64::consts::TAU;
 use std::fmt;
 use std::io::Write;
+use std::str::FromStr;
 
-use super::camera::Camera;
-use super::projection::project_point;
-use crate::primitives::{LineSegment, Point3D, Sphere, Triangle, AABB};
+use super::camera::{Camera, ProjectedEllipse};
+use super::layer::Layer;
+use super::lighting::{parse_color, shade_color, Light};
+use super::projection::{
+    clip_point_inside, clip_polygon, clip_segment, multiply_matrices, multiply_matrix_point,
+    ndc_to_screen, perspective_divide, project_point, ClipPlane, ClipPoint, FAR_CLIP_PLANE,
+    NEAR_CLIP_PLANE, SIDE_CLIP_PLANES,
+};
+use super::style::{escape_xml, Marker, Style, TextAnchor};
+use crate::error::GeometryError;
+use crate::operations::RayHit;
+use crate::primitives::{
+    Containment, Frustum, LineSegment, Plane, Point3D, Ray, Sphere, Triangle, Vector3D, AABB,
+};
+use crate::spatial::{Bounded, BspTree, Bvh, RayCast};
+use crate::utils::EPSILON;
 
+/// Pieces each straight edge is split into when the camera bends lines.
+const CURVE_SEGMENTS: usize = 24;
+
+/// Points along each edge tested for occlusion in hidden-line mode. Changes
+/// in visibility between two samples are then located by bisection.
+const VISIBILITY_SAMPLES: usize = 64;
+const VISIBILITY_REFINEMENTS: usize = 24;
+
+/// Dash and gap length of hidden lines, per unit of stroke width.
+const HIDDEN_LINE_DASH: (f64, f64) = (4.0, 3.0);
+
+/// Pieces the arc of an angular dimension is drawn with.
+const ARC_SEGMENTS: usize = 32;
+
+/// Pixels between a dimension line and its value.
+const DIMENSION_TEXT_GAP: f64 = 4.0;
+
+/// Font size of legends and tick labels that do not set one, in pixels.
+const LABEL_FONT_SIZE: f64 = 12.0;
+
+/// Distance of axis labels from the origin, per unit of axis length.
+const AXIS_LABEL_DISTANCE: f64 = 1.1;
+
+/// How far unbounded rays reach when the camera has no frustum to clip
+/// them, per unit of the camera's distance from its target and the ray.
+const RAY_REACH: f64 = 10.0;
+
+/// Shape in the output, in pixel coordinates.
 #[derive(Debug, Clone)]
 pub enum SVGElement {
     Circle {
         cx: f64,
         cy: f64,
         r: f64,
-        stroke: String,
-        fill: Option<String>,
-        stroke_width: f64,
+        style: Style,
     },
     Line {
         x1: f64,
         y1: f64,
         x2: f64,
         y2: f64,
-        stroke: String,
-        stroke_width: f64,
+        style: Style,
     },
     Polygon {
         points: Vec<(f64, f64)>,
-        stroke: String,
-        fill: Option<String>,
-        stroke_width: f64,
+        style: Style,
+    },
+    Polyline {
+        points: Vec<(f64, f64)>,
+        style: Style,
+    },
+    /// Ellipse turned `rotation` degrees clockwise about its centre.
+    Ellipse {
+        cx: f64,
+        cy: f64,
+        rx: f64,
+        ry: f64,
+        rotation: f64,
+        style: Style,
+    },
+    /// Line of text with its anchor at `(x, y)` on the baseline. Text is
+    /// filled with the style's fill or, failing that, its stroke colour,
+    /// and not stroked.
+    Text {
+        x: f64,
+        y: f64,
+        text: String,
+        style: Style,
+    },
+    /// Paint for other elements to fill with as `url(#id)`, fading from
+    /// the focus `(fx, fy)` to the circle around `(cx, cy)`. Stops are
+    /// `(offset, color)` with offsets in `[0, 1]`. Draws nothing itself.
+    RadialGradient {
+        id: String,
+        cx: f64,
+        cy: f64,
+        r: f64,
+        fx: f64,
+        fy: f64,
+        stops: Vec<(f64, String)>,
+    },
+    /// Paint for other elements to fill with as `url(#id)`, changing along
+    /// the line from `(x1, y1)` to `(x2, y2)` and constant across it. Stops
+    /// are as for [`SVGElement::RadialGradient`]. Draws nothing itself.
+    LinearGradient {
+        id: String,
+        x1: f64,
+        y1: f64,
+        x2: f64,
+        y2: f64,
+        stops: Vec<(f64, String)>,
     },
 }
 
 impl SVGElement {
+    /// How the element is drawn; `None` for gradients.
+    pub fn style(&self) -> Option<&Style> {
+        match self {
+            SVGElement::Circle { style, .. }
+            | SVGElement::Line { style, .. }
+            | SVGElement::Polygon { style, .. }
+            | SVGElement::Polyline { style, .. }
+            | SVGElement::Ellipse { style, .. }
+            | SVGElement::Text { style, .. } => Some(style),
+            SVGElement::RadialGradient { .. } | SVGElement::LinearGradient { .. } => None,
+        }
+    }
+
     fn to_svg_string(&self) -> String {
+        let points_str = |points: &[(f64, f64)]| {
+            points
+                .iter()
+                .map(|(x, y)| format!("{:.2},{:.2}", x, y))
+                .collect::<Vec<_>>()
+                .join(" ")
+        };
+        let stops_str = |stops: &[(f64, String)]| {
+            stops
+                .iter()
+                .map(|(offset, color)| {
+                    format!(
+                        "<stop offset=\"{:.3}\" stop-color=\"{}\" />",
+                        offset,
+                        escape_xml(color)
+                    )
+                })
+                .collect::<String>()
+        };
         match self {
-            SVGElement::Circle {
+            SVGElement::Circle { cx, cy, r, style } => format!(
+                "<circle cx=\"{:.2}\" cy=\"{:.2}\" r=\"{:.2}\"{}{}",
                 cx,
                 cy,
                 r,
-                stroke,
-                fill,
-                stroke_width,
-            } => {
-                let fill_str = fill
-                    .as_ref()
-                    .map(|f| format!("fill=\"{}\"", f))
-                    .unwrap_or_else(|| "fill=\"none\"".to_string());
-                format!(
-                    "<circle cx=\"{:.2}\" cy=\"{:.2}\" r=\"{:.2}\" stroke=\"{}\" {} stroke-width=\"{:.2}\" />",
-                    cx, cy, r, stroke, fill_str, stroke_width
-                )
-            }
+                style.attributes(),
+                style.close_tag("circle")
+            ),
             SVGElement::Line {
                 x1,
                 y1,
                 x2,
                 y2,
-                stroke,
-                stroke_width,
-            } => {
-                format!(
-                    "<line x1=\"{:.2}\" y1=\"{:.2}\" x2=\"{:.2}\" y2=\"{:.2}\" stroke=\"{}\" stroke-width=\"{:.2}\" />",
-                    x1, y1, x2, y2, stroke, stroke_width
-                )
-            }
-            SVGElement::Polygon {
-                points,
-                stroke,
-                fill,
-                stroke_width,
-            } => {
-                let points_str = points
-                    .iter()
-                    .map(|(x, y)| format!("{:.2},{:.2}", x, y))
-                    .collect::<Vec<_>>()
-                    .join(" ");
-                let fill_str = fill
+                style,
+            } => format!(
+                "<line x1=\"{:.2}\" y1=\"{:.2}\" x2=\"{:.2}\" y2=\"{:.2}\"{}{}",
+                x1,
+                y1,
+                x2,
+                y2,
+                style.attributes(),
+                style.close_tag("line")
+            ),
+            SVGElement::Polygon { points, style } => format!(
+                "<polygon points=\"{}\"{}{}",
+                points_str(points),
+                style.attributes(),
+                style.close_tag("polygon")
+            ),
+            SVGElement::Polyline { points, style } => format!(
+                "<polyline points=\"{}\"{}{}",
+                points_str(points),
+                style.attributes(),
+                style.close_tag("polyline")
+            ),
+            SVGElement::Ellipse {
+                cx,
+                cy,
+                rx,
+                ry,
+                rotation,
+                style,
+            } => format!(
+                "<ellipse cx=\"{:.2}\" cy=\"{:.2}\" rx=\"{:.2}\" ry=\"{:.2}\" transform=\"rotate({:.2} {:.2} {:.2})\"{}{}",
+                cx,
+                cy,
+                rx,
+                ry,
+                rotation,
+                cx,
+                cy,
+                style.attributes(),
+                style.close_tag("ellipse")
+            ),
+            SVGElement::Text { x, y, text, style } => {
+                let paint = Style {
+                    stroke: None,
+                    stroke_width: None,
+                    fill: Some(
+                        style
+                            .fill
+                            .clone()
+                            .unwrap_or_else(|| style.stroke_color().to_string()),
+                    ),
+                    dash_array: None,
+                    marker_start: None,
+                    marker_end: None,
+                    title: None,
+                    ..style.clone()
+                };
+                let title = style
+                    .title
                     .as_ref()
-                    .map(|f| format!("fill=\"{}\"", f))
-                    .unwrap_or_else(|| "fill=\"none\"".to_string());
+                    .map(|title| format!("<title>{}</title>", escape_xml(title)))
+                    .unwrap_or_default();
                 format!(
-                    "<polygon points=\"{}\" stroke=\"{}\" {} stroke-width=\"{:.2}\" />",
-                    points_str, stroke, fill_str, stroke_width
+                    "<text x=\"{:.2}\" y=\"{:.2}\"{}>{}{}</text>",
+                    x,
+                    y,
+                    paint.attributes(),
+                    title,
+                    escape_xml(text)
                 )
             }
+            SVGElement::RadialGradient {
+                id,
+                cx,
+                cy,
+                r,
+                fx,
+                fy,
+                stops,
+            } => format!(
+                "<radialGradient id=\"{}\" gradientUnits=\"userSpaceOnUse\" cx=\"{:.2}\" cy=\"{:.2}\" r=\"{:.2}\" fx=\"{:.2}\" fy=\"{:.2}\">{}</radialGradient>",
+                escape_xml(id), cx, cy, r, fx, fy, stops_str(stops)
+            ),
+            SVGElement::LinearGradient {
+                id,
+                x1,
+                y1,
+                x2,
+                y2,
+                stops,
+            } => format!(
+                "<linearGradient id=\"{}\" gradientUnits=\"userSpaceOnUse\" x1=\"{:.2}\" y1=\"{:.2}\" x2=\"{:.2}\" y2=\"{:.2}\">{}</linearGradient>",
+                escape_xml(id), x1, y1, x2, y2, stops_str(stops)
+            ),
+        }
+    }
+}
+
+/// Order in which [`SVGRenderer`] writes its elements.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum DepthSort {
+    /// Insertion order.
+    #[default]
+    None,
+    /// Farthest first by the view depth of each element's centre (the
+    /// painter's algorithm). Fast, but wrong for intersecting triangles or
+    /// triangles overlapping each other in a cycle.
+    Painter,
+    /// Triangles are ordered with a BSP tree, splitting them where needed so
+    /// that every overlap is drawn correctly. Other elements are slotted in
+    /// between by depth as for [`DepthSort::Painter`].
+    Bsp,
+}
+
+impl FromStr for DepthSort {
+    type Err = GeometryError;
+
+    /// Parses `"none"`, `"painter"` or `"bsp"`, ignoring case.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "none" => Ok(DepthSort::None),
+            "painter" => Ok(DepthSort::Painter),
+            "bsp" => Ok(DepthSort::Bsp),
+            _ => Err(GeometryError::InvalidParameter(format!(
+                "Unknown depth sort mode '{}', expected 'none', 'painter' or 'bsp'",
+                s
+            ))),
+        }
+    }
+}
+
+/// How [`SVGRenderer`] draws the parts of lines and triangle outlines that
+/// lie behind a triangle, box or sphere.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum HiddenLines {
+    /// Everything is drawn as added; no visibility is computed.
+    #[default]
+    Shown,
+    /// Hidden parts are drawn dashed, on top of everything else.
+    Dashed,
+    /// Hidden parts are left out.
+    Removed,
+}
+
+impl FromStr for HiddenLines {
+    type Err = GeometryError;
+
+    /// Parses `"shown"`, `"dashed"` or `"removed"`, ignoring case.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "shown" => Ok(HiddenLines::Shown),
+            "dashed" => Ok(HiddenLines::Dashed),
+            "removed" => Ok(HiddenLines::Removed),
+            _ => Err(GeometryError::InvalidParameter(format!(
+                "Unknown hidden line mode '{}', expected 'shown', 'dashed' or 'removed'",
+                s
+            ))),
+        }
+    }
+}
+
+/// How [`SVGRenderer`] lights the hex fills of triangles and spheres with
+/// its lights. Spheres get a radial gradient in either lit mode.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Shading {
+    /// Fills are drawn as given.
+    #[default]
+    None,
+    /// One colour per triangle, lit at its centroid along its normal.
+    Flat,
+    /// Each vertex is lit along its own normal and the three colours are
+    /// blended across the triangle by a linear gradient. The blend is exact
+    /// at the vertices and runs along the direction the brightness changes
+    /// fastest, which is exact for white lights.
+    Gouraud,
+}
+
+impl FromStr for Shading {
+    type Err = GeometryError;
+
+    /// Parses `"none"`, `"flat"` or `"gouraud"`, ignoring case.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "none" => Ok(Shading::None),
+            "flat" => Ok(Shading::Flat),
+            "gouraud" => Ok(Shading::Gouraud),
+            _ => Err(GeometryError::InvalidParameter(format!(
+                "Unknown shading mode '{}', expected 'none', 'flat' or 'gouraud'",
+                s
+            ))),
+        }
+    }
+}
+
+/// What an element was drawn from, for the passes that redraw it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Source {
+    Face(usize),
+    Edge(usize),
+}
+
+/// Index into `SVGRenderer::layers`, `None` outside any layer.
+type LayerIndex = Option<usize>;
+
+#[derive(Debug, Clone)]
+struct Entry {
+    element: SVGElement,
+    depth: f64,
+    source: Option<Source>,
+    layer: LayerIndex,
+}
+
+/// Element in output order, with what it was drawn from and its layer.
+type Ordered<'a> = (Cow<'a, SVGElement>, Option<Source>, LayerIndex);
+
+impl Entry {
+    fn borrowed(&self) -> Ordered<'_> {
+        (Cow::Borrowed(&self.element), self.source, self.layer)
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Face {
+    triangle: Triangle,
+    style: Style,
+    layer: LayerIndex,
+}
+
+#[derive(Debug, Clone)]
+struct Edge {
+    start: Point3D,
+    end: Point3D,
+    style: Style,
+    layer: LayerIndex,
+}
+
+/// Opaque shape hiding lines behind it in hidden-line mode.
+#[derive(Debug, Clone, Copy)]
+enum Occluder {
+    Triangle(Triangle),
+    Aabb(AABB),
+    Sphere(Sphere),
+}
+
+impl Bounded for Occluder {
+    fn bounding_box(&self) -> AABB {
+        match self {
+            Occluder::Triangle(triangle) => triangle.bounding_box(),
+            Occluder::Aabb(aabb) => *aabb,
+            Occluder::Sphere(sphere) => sphere.bounding_box(),
+        }
+    }
+}
+
+impl RayCast for Occluder {
+    fn cast_ray(&self, ray: &Ray) -> Option<RayHit> {
+        match self {
+            Occluder::Triangle(triangle) => triangle.cast_ray(ray),
+            Occluder::Aabb(aabb) => aabb.cast_ray(ray),
+            Occluder::Sphere(sphere) => sphere.cast_ray(ray),
         }
     }
 }
@@ -92,8 +432,25 @@ pub struct SVGRenderer {
     width: usize,
     height: usize,
     camera: Camera,
+    view_projection: [[f64; 4]; 4],
+    frustum: Option<Frustum>,
+    frustum_culling: bool,
+    frustum_clipping: bool,
+    depth_sort: DepthSort,
+    hidden_lines: HiddenLines,
+    lights: Vec<Light>,
+    shading: Shading,
+    back_face_culling: bool,
     background: Option<String>,
-    elements: Vec<SVGElement>,
+    stylesheet: Option<String>,
+    layers: Vec<Layer>,
+    current_layer: LayerIndex,
+    entries: Vec<Entry>,
+    faces: Vec<Face>,
+    edges: Vec<Edge>,
+    /// Boxes and spheres; triangles are in `faces`.
+    solids: Vec<(Occluder, LayerIndex)>,
+    bounds: Option<AABB>,
 }
 
 impl SVGRenderer {
@@ -101,9 +458,25 @@ impl SVGRenderer {
         Self {
             width,
             height,
+            view_projection: multiply_matrices(&camera.projection_matrix(), &camera.view_matrix()),
+            frustum: camera.frustum().ok(),
+            frustum_culling: true,
+            frustum_clipping: false,
             camera,
+            depth_sort: DepthSort::None,
+            hidden_lines: HiddenLines::Shown,
+            lights: Vec::new(),
+            shading: Shading::None,
+            back_face_culling: false,
             background: None,
-            elements: Vec::new(),
+            stylesheet: None,
+            layers: Vec::new(),
+            current_layer: None,
+            entries: Vec::new(),
+            faces: Vec::new(),
+            edges: Vec::new(),
+            solids: Vec::new(),
+            bounds: None,
         }
     }
 
@@ -111,29 +484,246 @@ impl SVGRenderer {
         self.background = Some(color.to_string());
     }
 
+    /// CSS written into a `<style>` block at the top of the output, for
+    /// restyling elements by the class or id given in their [`Style`].
+    pub fn set_stylesheet(&mut self, css: &str) {
+        self.stylesheet = Some(css.to_string());
+    }
+
+    /// Adds `layer` and draws the elements added from now on into it.
+    /// Layers are stacked in the order they are added, above the elements
+    /// drawn outside any layer. Fails if another layer has the same id.
+    pub fn add_layer(&mut self, layer: Layer) -> Result<(), GeometryError> {
+        let id = layer.id();
+        if self.layers.iter().any(|other| other.id() == id) {
+            return Err(GeometryError::InvalidParameter(format!(
+                "Layer id '{}' is already in use",
+                id
+            )));
+        }
+        self.layers.push(layer);
+        self.current_layer = Some(self.layers.len() - 1);
+        Ok(())
+    }
+
+    /// Draws the elements added from now on into the layer called `name`,
+    /// or outside any layer for `None`.
+    pub fn set_current_layer(&mut self, name: Option<&str>) -> Result<(), GeometryError> {
+        self.current_layer = name.map(|name| self.layer_index(name)).transpose()?;
+        Ok(())
+    }
+
+    pub fn set_layer_visible(&mut self, name: &str, visible: bool) -> Result<(), GeometryError> {
+        let index = self.layer_index(name)?;
+        self.layers[index].visible = visible;
+        Ok(())
+    }
+
+    pub fn layers(&self) -> &[Layer] {
+        &self.layers
+    }
+
+    fn layer_index(&self, name: &str) -> Result<usize, GeometryError> {
+        self.layers
+            .iter()
+            .position(|layer| layer.name == name)
+            .ok_or_else(|| GeometryError::InvalidParameter(format!("Unknown layer '{}'", name)))
+    }
+
+    /// `style` as given to the current layer's elements.
+    fn layer_style(&self, style: &Style) -> Style {
+        match self.current_layer {
+            Some(index) => self.layers[index].element_style(style),
+            None => style.clone(),
+        }
+    }
+
+    /// Enables or disables skipping elements that lie entirely outside the
+    /// camera frustum. Culling is on by default.
+    pub fn set_frustum_culling(&mut self, enabled: bool) {
+        self.frustum_culling = enabled;
+    }
+
+    /// Enables or disables clipping lines and triangles to the image edges
+    /// (and a perspective camera's far plane). Geometry is always clipped
+    /// against the near plane; this is off by default.
+    pub fn set_frustum_clipping(&mut self, enabled: bool) {
+        self.frustum_clipping = enabled;
+    }
+
+    /// Chooses the order elements are written in. Elements keep their
+    /// depth, so this can be changed at any time before rendering.
+    pub fn set_depth_sort(&mut self, mode: DepthSort) {
+        self.depth_sort = mode;
+    }
+
+    /// Chooses how hidden parts of lines and triangle outlines are drawn.
+    /// Every triangle, box and sphere added counts as opaque, whether or
+    /// not it is filled; points and sphere outlines are never hidden.
+    pub fn set_hidden_lines(&mut self, mode: HiddenLines) {
+        self.hidden_lines = mode;
+    }
+
+    pub fn add_light(&mut self, light: Light) {
+        self.lights.push(light);
+    }
+
+    pub fn clear_lights(&mut self) {
+        self.lights.clear();
+    }
+
+    pub fn lights(&self) -> &[Light] {
+        &self.lights
+    }
+
+    /// Chooses how the fill of triangles and spheres added from now on is
+    /// lit with the renderer's lights. Fills that are not `#rgb` or
+    /// `#rrggbb` strings are left as they are; without any lights shaded
+    /// shapes are black. [`Shading::None`] by default.
+    pub fn set_shading(&mut self, mode: Shading) {
+        self.shading = mode;
+    }
+
+    /// Enables or disables dropping triangles added from now on whose
+    /// front, the side `Triangle::normal` points to, faces away from the
+    /// camera. Off by default, in which case both sides are lit alike.
+    pub fn set_back_face_culling(&mut self, enabled: bool) {
+        self.back_face_culling = enabled;
+    }
+
+    /// Number of elements added so far.
+    pub fn element_count(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Elements in insertion order with their view depth, as given by
+    /// [`Camera::view_depth`] for the element's centre. Annotations have a
+    /// depth of negative infinity.
+    pub fn elements(&self) -> impl Iterator<Item = (&SVGElement, f64)> + '_ {
+        self.entries
+            .iter()
+            .map(|entry| (&entry.element, entry.depth))
+    }
+
+    /// Box around every point, segment, triangle, sphere and box added so
+    /// far, whether in view or not. Annotations and the helpers drawing
+    /// axes, grids, ticks and bounds are left out.
+    pub fn scene_bounds(&self) -> Option<AABB> {
+        self.bounds
+    }
+
+    fn extend_bounds(&mut self, aabb: AABB) {
+        self.bounds = Some(match self.bounds {
+            Some(bounds) => bounds.union(&aabb),
+            None => aabb,
+        });
+    }
+
+    /// Clip-space planes geometry is clipped against before projection.
+    /// The first is always the near plane. Non-linear cameras have none.
+    fn clip_planes(&self) -> Vec<ClipPlane> {
+        if !self.camera.is_linear() {
+            return Vec::new();
+        }
+        let parallel = self.camera.is_parallel_projection();
+        // Parallel projections put the camera plane at clip-space z = 0.
+        let mut planes = vec![if parallel {
+            [0.0, 0.0, 1.0, 0.0]
+        } else {
+            NEAR_CLIP_PLANE
+        }];
+        if self.frustum_clipping {
+            planes.extend(SIDE_CLIP_PLANES);
+            if !parallel {
+                planes.push(FAR_CLIP_PLANE);
+            }
+        }
+        planes
+    }
+
+    #[inline]
+    fn to_clip_space(&self, point: &Point3D) -> ClipPoint {
+        multiply_matrix_point(&self.view_projection, point)
+    }
+
+    #[inline]
+    fn clip_to_screen(&self, point: ClipPoint) -> (f64, f64) {
+        let (x, y, _) = perspective_divide(point);
+        ndc_to_screen(x, y, self.width, self.height)
+    }
+
+    fn is_culled<F: Fn(&Frustum) -> Containment>(&self, classify: F) -> bool {
+        self.frustum_culling
+            && self
+                .frustum
+                .as_ref()
+                .is_some_and(|frustum| classify(frustum) == Containment::Outside)
+    }
+
     pub fn add_point(&mut self, point: &Point3D, color: &str, size: f64) {
-        let (x, y) = project_point(point, &self.camera, self.width, self.height);
-        self.elements.push(SVGElement::Circle {
+        self.add_point_with_style(point, size, &Style::new(color, 1.0).with_fill(color));
+    }
+
+    /// Draws `point` as a dot of `radius` pixels.
+    pub fn add_point_with_style(&mut self, point: &Point3D, radius: f64, style: &Style) {
+        self.extend_bounds(AABB {
+            min: *point,
+            max: *point,
+        });
+        let Some((x, y)) = self.project_visible_point(point) else {
+            return;
+        };
+        let element = SVGElement::Circle {
             cx: x,
             cy: y,
-            r: size,
-            stroke: color.to_string(),
-            fill: Some(color.to_string()),
-            stroke_width: 1.0,
-        });
+            r: radius,
+            style: self.layer_style(style),
+        };
+        self.push(element, point, None);
+    }
+
+    /// Screen position of `point`, `None` if it is culled or clipped.
+    fn project_visible_point(&self, point: &Point3D) -> Option<(f64, f64)> {
+        if self.is_culled(|frustum| frustum.classify_point(point)) {
+            return None;
+        }
+        if !clip_point_inside(&self.to_clip_space(point), &self.clip_planes()) {
+            return None;
+        }
+        Some(project_point(point, &self.camera, self.width, self.height))
     }
 
     pub fn add_line_segment(&mut self, segment: &LineSegment, color: &str, width: f64) {
-        let (x1, y1) = project_point(&segment.start, &self.camera, self.width, self.height);
-        let (x2, y2) = project_point(&segment.end, &self.camera, self.width, self.height);
-        self.elements.push(SVGElement::Line {
-            x1,
-            y1,
-            x2,
-            y2,
-            stroke: color.to_string(),
-            stroke_width: width,
+        self.add_line_segment_with_style(segment, &Style::new(color, width));
+    }
+
+    pub fn add_line_segment_with_style(&mut self, segment: &LineSegment, style: &Style) {
+        self.extend_bounds(segment.bounding_box());
+        let style = self.layer_style(style);
+        self.add_edge(segment, &style);
+    }
+
+    /// Draws `segment` in the current layer with `style` as it is.
+    fn add_edge(&mut self, segment: &LineSegment, style: &Style) {
+        if self.is_culled(|frustum| frustum.classify_segment(segment)) {
+            return;
+        }
+        let paths = self.project_path(&[segment.start, segment.end], false);
+        if paths.is_empty() {
+            return;
+        }
+        let edge = self.edges.len();
+        self.edges.push(Edge {
+            start: segment.start,
+            end: segment.end,
+            style: style.clone(),
+            layer: self.current_layer,
         });
+        let center = segment.midpoint();
+        for (index, path) in paths.into_iter().enumerate() {
+            let element = path_element(path, false, piece_style(style, index));
+            self.push(element, &center, Some(Source::Edge(edge)));
+        }
     }
 
     pub fn add_triangle(
@@ -143,170 +733,2136 @@ impl SVGRenderer {
         fill: Option<&str>,
         width: f64,
     ) {
-        let (x1, y1) = project_point(&triangle.a, &self.camera, self.width, self.height);
-        let (x2, y2) = project_point(&triangle.b, &self.camera, self.width, self.height);
-        let (x3, y3) = project_point(&triangle.c, &self.camera, self.width, self.height);
-
-        self.elements.push(SVGElement::Polygon {
-            points: vec![(x1, y1), (x2, y2), (x3, y3)],
-            stroke: stroke.to_string(),
-            fill: fill.map(|s| s.to_string()),
-            stroke_width: width,
+        let mut style = Style::new(stroke, width);
+        style.fill = fill.map(|s| s.to_string());
+        self.add_triangle_with_style(triangle, &style);
+    }
+
+    pub fn add_triangle_with_style(&mut self, triangle: &Triangle, style: &Style) {
+        self.extend_bounds(triangle.bounding_box());
+        let style = self.layer_style(style);
+        self.add_face(triangle, None, style);
+    }
+
+    /// Like [`SVGRenderer::add_triangle_with_style`], with the surface
+    /// normals at `a`, `b` and `c` for [`Shading::Gouraud`], as on a smooth
+    /// mesh. Flat shading and back-face culling still go by
+    /// `Triangle::normal`.
+    pub fn add_triangle_with_normals(
+        &mut self,
+        triangle: &Triangle,
+        normals: [Vector3D; 3],
+        style: &Style,
+    ) {
+        self.extend_bounds(triangle.bounding_box());
+        let style = self.layer_style(style);
+        self.add_face(triangle, Some(normals), style);
+    }
+
+    /// Draws `triangle` in the current layer with `style` as it is, apart
+    /// from shading its fill. `normals` are the vertex normals, if known.
+    fn add_face(&mut self, triangle: &Triangle, normals: Option<[Vector3D; 3]>, mut style: Style) {
+        if self.is_culled(|frustum| frustum.classify_triangle(triangle)) {
+            return;
+        }
+        let center = triangle.centroid();
+        let normal = triangle.normal();
+        let towards_camera = match self.parallel_view_direction() {
+            Some(view) => -view,
+            None => self.camera.position() - center,
+        };
+        let front_facing = normal.dot(&towards_camera) > 0.0;
+        if self.back_face_culling && !front_facing {
+            return;
+        }
+
+        let paths = self.project_path(&[triangle.a, triangle.b, triangle.c], true);
+        if paths.is_empty() {
+            return;
+        }
+        // Light the side facing the camera.
+        let facing = |n: Vector3D| if front_facing { n } else { -n };
+        if let Some(base) = style.fill.clone() {
+            let gradient = match self.shading {
+                Shading::Gouraud => {
+                    let normals = normals
+                        .unwrap_or([normal; 3])
+                        .map(|n| facing(n.normalize().unwrap_or(normal)));
+                    let id = format!("triangle-shade-{}", self.entries.len());
+                    self.triangle_gradient(triangle, &normals, &base, &id)
+                }
+                _ => None,
+            };
+            if let Some(gradient) = gradient {
+                style.fill = Some(format!("url(#{})", gradient.0));
+                self.push(gradient.1, &center, None);
+            } else if self.shading != Shading::None {
+                if let Some(lit) = shade_color(&base, &self.lights, &center, &facing(normal)) {
+                    style.fill = Some(lit);
+                }
+            }
+        }
+
+        let face = self.faces.len();
+        self.faces.push(Face {
+            triangle: *triangle,
+            style: style.clone(),
+            layer: self.current_layer,
         });
+        // An outline torn apart by a panorama seam can only be stroked.
+        let closed = paths.len() == 1;
+        for (index, path) in paths.into_iter().enumerate() {
+            let element = path_element(path, closed, piece_style(&style, index));
+            self.push(element, &center, Some(Source::Face(face)));
+        }
     }
 
     pub fn add_sphere(&mut self, sphere: &Sphere, color: &str, width: f64) {
-        let (cx, cy) = project_point(&sphere.center, &self.camera, self.width, self.height);
+        self.add_sphere_with_style(sphere, &Style::new(color, width));
+    }
+
+    pub fn add_filled_sphere(
+        &mut self,
+        sphere: &Sphere,
+        stroke: &str,
+        fill: Option<&str>,
+        width: f64,
+    ) {
+        let mut style = Style::new(stroke, width);
+        style.fill = fill.map(|s| s.to_string());
+        self.add_sphere_with_style(sphere, &style);
+    }
+
+    /// Draws the outline of `sphere` as seen from the camera, an ellipse
+    /// for linear cameras. With shading enabled, the fill is lit with a
+    /// radial gradient from the sphere's brightest visible point to its
+    /// rim.
+    pub fn add_sphere_with_style(&mut self, sphere: &Sphere, style: &Style) {
+        self.extend_bounds(sphere.bounding_box());
+        if self.is_culled(|frustum| frustum.classify_sphere(sphere)) {
+            return;
+        }
+        let center_in_front = self
+            .clip_planes()
+            .first()
+            .is_none_or(|near| clip_point_inside(&self.to_clip_space(&sphere.center), &[*near]));
+        let ellipse = self
+            .camera
+            .project_sphere(sphere)
+            .filter(|_| center_in_front);
+
+        let outline = match ellipse {
+            Some(ellipse) => {
+                let (width, height) = (self.width as f64, self.height as f64);
+                let (cx, cy) =
+                    ndc_to_screen(ellipse.center.0, ellipse.center.1, self.width, self.height);
+                let screen = ProjectedEllipse {
+                    center: (cx, cy),
+                    axes: ellipse
+                        .axes
+                        .map(|(x, y)| (x * width / 2.0, -y * height / 2.0)),
+                };
+                let (rx, ry, angle) = screen.principal_axes();
+                Outline::Ellipse {
+                    cx,
+                    cy,
+                    rx,
+                    ry,
+                    angle,
+                }
+            }
+            None => {
+                // Spheres cut by the near plane, or seen through a curving
+                // lens: draw the circle where the view grazes the surface.
+                let Some(points) = self.silhouette_circle(sphere) else {
+                    return;
+                };
+                let paths = self.project_path(&points, true);
+                if paths.is_empty() {
+                    return;
+                }
+                Outline::Paths(paths)
+            }
+        };
+        self.solids
+            .push((Occluder::Sphere(*sphere), self.current_layer));
+
+        let mut style = self.layer_style(style);
+        let id = format!("sphere-shade-{}", self.entries.len());
+        let gradient = style
+            .fill
+            .as_deref()
+            .filter(|_| self.shading != Shading::None)
+            .and_then(|base| self.sphere_gradient(sphere, base, &outline, &id));
+        if let Some(gradient) = gradient {
+            style.fill = Some(format!("url(#{})", id));
+            self.push(gradient, &sphere.center, None);
+        }
+
+        match outline {
+            Outline::Ellipse {
+                cx,
+                cy,
+                rx,
+                ry,
+                angle,
+            } => {
+                let element = SVGElement::Ellipse {
+                    cx,
+                    cy,
+                    rx,
+                    ry,
+                    rotation: angle.to_degrees(),
+                    style,
+                };
+                self.push(element, &sphere.center, None);
+            }
+            Outline::Paths(paths) => {
+                let closed = paths.len() == 1;
+                for (index, path) in paths.into_iter().enumerate() {
+                    let element = path_element(path, closed, piece_style(&style, index));
+                    self.push(element, &sphere.center, None);
+                }
+            }
+        }
+    }
+
+    /// Points around the circle along which sight lines from the camera
+    /// touch `sphere`, or `None` if the camera is inside it.
+    fn silhouette_circle(&self, sphere: &Sphere) -> Option<Vec<Point3D>> {
+        let (center, radius, axis) = match self.parallel_view_direction() {
+            Some(view) => (sphere.center, sphere.radius, view),
+            None => {
+                let offset = sphere.center - self.camera.position();
+                let distance = offset.magnitude();
+                if distance <= sphere.radius {
+                    return None;
+                }
+                let ratio = sphere.radius / distance;
+                (
+                    sphere.center + -(offset * ratio * ratio),
+                    sphere.radius * (1.0 - ratio * ratio).sqrt(),
+                    offset,
+                )
+            }
+        };
+        let (first, second) = perpendicular_axes(&axis)?;
+        let count = 2 * CURVE_SEGMENTS;
+        Some(
+            (0..count)
+                .map(|i| {
+                    let (sin, cos) = (TAU * i as f64 / count as f64).sin_cos();
+                    center + (first * cos + second * sin) * radius
+                })
+                .collect(),
+        )
+    }
+
+    /// Linear gradient lighting `base` across `triangle` from the colours at
+    /// its vertices, each lit along the matching unit normal, with its id.
+    /// `None` if `base` is not a hex colour, a vertex is behind the camera,
+    /// the triangle is seen edge-on or its brightness does not change.
+    fn triangle_gradient(
+        &self,
+        triangle: &Triangle,
+        normals: &[Vector3D; 3],
+        base: &str,
+        id: &str,
+    ) -> Option<(String, SVGElement)> {
+        let vertices = [triangle.a, triangle.b, triangle.c];
+        let near = self.clip_planes().first().copied();
+        if near.is_some_and(|near| {
+            vertices
+                .iter()
+                .any(|v| !clip_point_inside(&self.to_clip_space(v), &[near]))
+        }) {
+            return None;
+        }
+        let mut colors = Vec::with_capacity(3);
+        for (vertex, normal) in vertices.iter().zip(normals) {
+            colors.push(shade_color(base, &self.lights, vertex, normal)?);
+        }
+        let brightness: Vec<f64> = colors
+            .iter()
+            .map(|color| parse_color(color).map_or(0.0, |[r, g, b]| r + g + b))
+            .collect();
+        let screen = vertices.map(|v| project_point(&v, &self.camera, self.width, self.height));
+
+        // Screen-space gradient of the brightness interpolated over the
+        // triangle.
+        let (e1, e2) = (
+            (screen[1].0 - screen[0].0, screen[1].1 - screen[0].1),
+            (screen[2].0 - screen[0].0, screen[2].1 - screen[0].1),
+        );
+        let (d1, d2) = (brightness[1] - brightness[0], brightness[2] - brightness[0]);
+        let det = e1.0 * e2.1 - e1.1 * e2.0;
+        if det.abs() < 1e-9 {
+            return None;
+        }
+        let g = ((d1 * e2.1 - d2 * e1.1) / det, (e1.0 * d2 - e2.0 * d1) / det);
+        let length = g.0.hypot(g.1);
+        if length < 1e-9 {
+            return None;
+        }
+
+        let along = |i: usize| (screen[i].0 * g.0 + screen[i].1 * g.1) / length;
+        let mut order = [0, 1, 2];
+        order.sort_by(|&i, &j| along(i).total_cmp(&along(j)));
+        let [first, middle, last] = order;
+        let span = along(last) - along(first);
+        let (x1, y1) = screen[first];
+        let stops = vec![
+            (0.0, colors[first].clone()),
+            (
+                (along(middle) - along(first)) / span,
+                colors[middle].clone(),
+            ),
+            (1.0, colors[last].clone()),
+        ];
+        Some((
+            id.to_string(),
+            SVGElement::LinearGradient {
+                id: id.to_string(),
+                x1,
+                y1,
+                x2: x1 + g.0 / length * span,
+                y2: y1 + g.1 / length * span,
+                stops,
+            },
+        ))
+    }
+
+    /// Radial gradient lighting `base` across the visible half of `sphere`,
+    /// from its brightest point to its rim, in the screen space of
+    /// `outline`. `None` if `base` is not a hex colour.
+    fn sphere_gradient(
+        &self,
+        sphere: &Sphere,
+        base: &str,
+        outline: &Outline,
+        id: &str,
+    ) -> Option<SVGElement> {
+        let towards_camera = match self.parallel_view_direction() {
+            Some(view) => -view,
+            None => self.camera.position() - sphere.center,
+        }
+        .normalize()
+        .ok()?;
+        let towards_light = self
+            .lights
+            .iter()
+            .fold(Vector3D::zero(), |sum, light| match light {
+                Light::Ambient { .. } => sum,
+                Light::Directional {
+                    direction,
+                    intensity,
+                    ..
+                } => sum + -*direction * *intensity,
+                Light::Point {
+                    position,
+                    intensity,
+                    ..
+                } => (*position - sphere.center)
+                    .normalize()
+                    .map_or(sum, |to_light| sum + to_light * *intensity),
+            });
+        // Halfway to the light stays on the visible side; the rim is darkest
+        // opposite the light.
+        let brightest = (towards_light.normalize().unwrap_or(towards_camera) + towards_camera)
+            .normalize()
+            .unwrap_or(towards_camera);
+        let across = towards_light + -(towards_camera * towards_light.dot(&towards_camera));
+        let rim = match (-across).normalize() {
+            Ok(rim) => rim,
+            Err(_) => perpendicular_axes(&towards_camera)?.0,
+        };
+        let surface = |normal: &Vector3D| sphere.center + *normal * sphere.radius;
+        let lit = |normal: &Vector3D| shade_color(base, &self.lights, &surface(normal), normal);
+        let stops = vec![(0.0, lit(&brightest)?), (1.0, lit(&rim)?)];
+
+        let (fx, fy) = project_point(&surface(&brightest), &self.camera, self.width, self.height);
+        let (cx, cy, r, fx, fy) = match outline {
+            Outline::Ellipse {
+                cx, cy, rx, angle, ..
+            } => {
+                // Gradient coordinates are taken before the ellipse's
+                // rotation, so undo it for the focus.
+                let (sin, cos) = angle.sin_cos();
+                let (dx, dy) = (fx - cx, fy - cy);
+                (
+                    *cx,
+                    *cy,
+                    *rx,
+                    cx + dx * cos + dy * sin,
+                    cy - dx * sin + dy * cos,
+                )
+            }
+            Outline::Paths(paths) => {
+                let (cx, cy) = project_point(&sphere.center, &self.camera, self.width, self.height);
+                let r = paths
+                    .iter()
+                    .flatten()
+                    .map(|(x, y)| (x - cx).hypot(y - cy))
+                    .fold(0.0, f64::max);
+                (cx, cy, r, fx, fy)
+            }
+        };
+        Some(SVGElement::RadialGradient {
+            id: id.to_string(),
+            cx,
+            cy,
+            r,
+            fx,
+            fy,
+            stops,
+        })
+    }
+
+    pub fn add_aabb(&mut self, aabb: &AABB, color: &str, width: f64) {
+        self.add_aabb_with_style(aabb, &Style::new(color, width));
+    }
+
+    /// Draws the twelve edges of `aabb`, which take the style's id as for
+    /// pieces of one element.
+    pub fn add_aabb_with_style(&mut self, aabb: &AABB, style: &Style) {
+        self.extend_bounds(*aabb);
+        if self.is_culled(|frustum| frustum.classify_aabb(aabb)) {
+            return;
+        }
+        self.solids
+            .push((Occluder::Aabb(*aabb), self.current_layer));
+        let style = self.layer_style(style);
+        self.add_box_edges(aabb, &style);
+    }
+
+    /// Draws the edges of `aabb` with `style` as it is, leaving out those
+    /// of zero length.
+    fn add_box_edges(&mut self, aabb: &AABB, style: &Style) {
+        let min = &aabb.min;
+        let max = &aabb.max;
+
+        let corners = [
+            Point3D::new(min.x, min.y, min.z),
+            Point3D::new(max.x, min.y, min.z),
+            Point3D::new(max.x, max.y, min.z),
+            Point3D::new(min.x, max.y, min.z),
+            Point3D::new(min.x, min.y, max.z),
+            Point3D::new(max.x, min.y, max.z),
+            Point3D::new(max.x, max.y, max.z),
+            Point3D::new(min.x, max.y, max.z),
+        ];
+
+        let edges = [
+            (0, 1),
+            (1, 2),
+            (2, 3),
+            (3, 0),
+            (4, 5),
+            (5, 6),
+            (6, 7),
+            (7, 4),
+            (0, 4),
+            (1, 5),
+            (2, 6),
+            (3, 7),
+        ];
+
+        for (index, (i, j)) in edges.iter().enumerate() {
+            if let Ok(segment) = LineSegment::new(corners[*i], corners[*j]) {
+                self.add_edge(&segment, &piece_style(style, index));
+            }
+        }
+    }
+
+    pub fn add_ray(&mut self, ray: &Ray, color: &str, width: f64) {
+        self.add_ray_with_style(ray, &Style::new(color, width));
+    }
+
+    /// Draws the part of `ray` between `t_min` and `t_max` that lies in the
+    /// view frustum, with an arrowhead at its far end unless the style sets
+    /// other markers. Only its start counts towards the scene bounds.
+    pub fn add_ray_with_style(&mut self, ray: &Ray, style: &Style) {
+        let start = ray.point_at(ray.t_min);
+        self.extend_bounds(AABB {
+            min: start,
+            max: start,
+        });
+        let Some((near, far)) = self.ray_extent(ray) else {
+            return;
+        };
+        let Ok(segment) = LineSegment::new(ray.point_at(near), ray.point_at(far)) else {
+            return;
+        };
+        let style = self
+            .layer_style(style)
+            .with_defaults(&Style::default().with_marker_end(Marker::Arrow));
+        self.add_edge(&segment, &style);
+    }
+
+    /// Range of `ray` parameters inside the view frustum, `None` if the ray
+    /// misses it. Cameras without a frustum cut unbounded rays off at
+    /// [`RAY_REACH`] times the distance from the camera to its target and
+    /// the start of the ray.
+    fn ray_extent(&self, ray: &Ray) -> Option<(f64, f64)> {
+        let (mut near, mut far) = (ray.t_min, ray.t_max);
+        match &self.frustum {
+            Some(frustum) => {
+                for plane in &frustum.planes {
+                    let distance = plane.distance_to_point(&ray.origin);
+                    let rate = plane.normal.dot(&ray.direction);
+                    if rate.abs() < EPSILON {
+                        if distance < 0.0 {
+                            return None;
+                        }
+                    } else if rate > 0.0 {
+                        near = near.max(-distance / rate);
+                    } else {
+                        far = far.min(-distance / rate);
+                    }
+                }
+            }
+            None if far.is_infinite() => {
+                let eye = self.camera.position();
+                let reach =
+                    eye.distance_to(&self.camera.target()) + eye.distance_to(&ray.point_at(near));
+                far = near + RAY_REACH * reach;
+            }
+            None => {}
+        }
+        (near < far).then_some((near, far))
+    }
+
+    pub fn add_vector(
+        &mut self,
+        origin: &Point3D,
+        vector: &Vector3D,
+        color: &str,
+        width: f64,
+    ) -> Result<(), GeometryError> {
+        self.add_vector_with_style(origin, vector, &Style::new(color, width))
+    }
+
+    /// Draws `vector` as an arrow from `origin`, with an arrowhead at its
+    /// tip unless the style sets other markers. Fails for a zero vector.
+    pub fn add_vector_with_style(
+        &mut self,
+        origin: &Point3D,
+        vector: &Vector3D,
+        style: &Style,
+    ) -> Result<(), GeometryError> {
+        let segment = LineSegment::new(*origin, *origin + *vector)?;
+        self.extend_bounds(segment.bounding_box());
+        let style = self
+            .layer_style(style)
+            .with_defaults(&Style::default().with_marker_end(Marker::Arrow));
+        self.add_edge(&segment, &style);
+        Ok(())
+    }
+
+    pub fn add_plane(
+        &mut self,
+        plane: &Plane,
+        center: &Point3D,
+        size: f64,
+        stroke: &str,
+        fill: Option<&str>,
+        width: f64,
+    ) -> Result<(), GeometryError> {
+        let mut style = Style::new(stroke, width);
+        style.fill = fill.map(|s| s.to_string());
+        self.add_plane_with_style(plane, center, size, &style)
+    }
+
+    /// Draws a square patch of `plane`, `size` across and centred on the
+    /// point of the plane closest to `center`. With a fill, the patch is
+    /// two triangles that hide and are sorted like any others, filled
+    /// without a stroke and taking the style's id; the outline pieces are
+    /// numbered after them.
+    pub fn add_plane_with_style(
+        &mut self,
+        plane: &Plane,
+        center: &Point3D,
+        size: f64,
+        style: &Style,
+    ) -> Result<(), GeometryError> {
+        validate_length("Plane size", size)?;
+        let (u, v) = perpendicular_axes(&plane.normal).ok_or_else(|| {
+            GeometryError::DegenerateCase("Plane normal must not be zero".to_string())
+        })?;
+        let center = plane.closest_point(center);
+        let half = size / 2.0;
+        let corners = [(-1.0, -1.0), (1.0, -1.0), (1.0, 1.0), (-1.0, 1.0)]
+            .map(|(a, b)| center + u * (a * half) + v * (b * half));
+        for corner in &corners {
+            self.extend_bounds(AABB {
+                min: *corner,
+                max: *corner,
+            });
+        }
+
+        let style = self.layer_style(style);
+        let mut piece = 0;
+        if style.fill.is_some() {
+            let fill = Style {
+                stroke: Some("none".to_string()),
+                marker_start: None,
+                marker_end: None,
+                ..style.clone()
+            };
+            for [a, b, c] in [[0, 1, 2], [0, 2, 3]] {
+                let triangle = Triangle::new(corners[a], corners[b], corners[c])?;
+                self.add_face(&triangle, None, piece_style(&fill, piece));
+                piece += 1;
+            }
+        }
+        let outline = Style {
+            fill: None,
+            ..style
+        };
+        for i in 0..corners.len() {
+            let edge = LineSegment::new(corners[i], corners[(i + 1) % corners.len()])?;
+            self.add_edge(&edge, &piece_style(&outline, piece));
+            piece += 1;
+        }
+        Ok(())
+    }
+
+    /// Writes `text` with its baseline through the projection of `point`.
+    /// Like every annotation, the label is never hidden and is drawn in
+    /// front of the other elements of its layer when depth sorting.
+    pub fn add_label(&mut self, point: &Point3D, text: &str, style: &Style) {
+        let style = self.layer_style(style);
+        self.add_text(point, text, style);
+    }
+
+    /// Writes `text` at `point` with `style` as it is.
+    fn add_text(&mut self, point: &Point3D, text: &str, style: Style) {
+        if let Some((x, y)) = self.project_visible_point(point) {
+            self.push_annotation(SVGElement::Text {
+                x,
+                y,
+                text: text.to_string(),
+                style,
+            });
+        }
+    }
+
+    /// Writes `text` `offset` pixels away from the projection of `point`
+    /// and draws a leader line between them, with the style's markers. The
+    /// text starts at the end of the line, or ends there for offsets to the
+    /// left, unless the style sets an anchor.
+    pub fn add_leader_label(
+        &mut self,
+        point: &Point3D,
+        offset: (f64, f64),
+        text: &str,
+        style: &Style,
+    ) {
+        let Some((x1, y1)) = self.project_visible_point(point) else {
+            return;
+        };
+        let style = self.layer_style(style);
+        let (x, y) = (x1 + offset.0, y1 + offset.1);
+        let leader = SVGElement::Line {
+            x1,
+            y1,
+            x2: x,
+            y2: y,
+            style: Style {
+                title: None,
+                ..piece_style(&style, 1)
+            },
+        };
+        self.push_annotation(leader);
+
+        let anchor = if offset.0 < 0.0 {
+            TextAnchor::End
+        } else {
+            TextAnchor::Start
+        };
+        let style = style.with_defaults(&Style::default().with_text_anchor(anchor));
+        self.push_annotation(SVGElement::Text {
+            x,
+            y,
+            text: text.to_string(),
+            style,
+        });
+    }
+
+    /// Dimensions the distance from `start` to `end`: extension lines from
+    /// them to `start + offset` and `end + offset`, a dimension line between
+    /// those with arrowheads unless the style sets other markers, and the
+    /// distance with two decimals above its middle. Returns the distance.
+    pub fn add_linear_dimension(
+        &mut self,
+        start: &Point3D,
+        end: &Point3D,
+        offset: &Vector3D,
+        style: &Style,
+    ) -> f64 {
+        let distance = start.distance_to(end);
+        let (a, b) = (*start + *offset, *end + *offset);
+        self.add_dimension(
+            &[[*start, a], [*end, b]],
+            &[a, b],
+            &a.midpoint(&b),
+            &format!("{:.2}", distance),
+            style,
+        );
+        distance
+    }
+
+    /// Dimensions the angle at `vertex` between the directions to `from`
+    /// and `to`: an arc of `radius` around `vertex` with arrowheads unless
+    /// the style sets other markers, and the angle in degrees with one
+    /// decimal above its middle. Returns the angle in radians.
+    pub fn add_angular_dimension(
+        &mut self,
+        vertex: &Point3D,
+        from: &Point3D,
+        to: &Point3D,
+        radius: f64,
+        style: &Style,
+    ) -> Result<f64, GeometryError> {
+        validate_length("Dimension radius", radius)?;
+        let u = (*from - *vertex).normalize()?;
+        let w = (*to - *vertex).normalize()?;
+        let angle = u.angle(&w);
+        // Straight angles leave the plane of the arc open; pick one.
+        let v = match (w - u * u.dot(&w)).normalize() {
+            Ok(v) => v,
+            Err(_) => perpendicular_axes(&u).expect("u is a unit vector").0,
+        };
+        let arc = |t: f64| *vertex + (u * t.cos() + v * t.sin()) * radius;
+        let points: Vec<Point3D> = (0..=ARC_SEGMENTS)
+            .map(|i| arc(angle * i as f64 / ARC_SEGMENTS as f64))
+            .collect();
+        self.add_dimension(
+            &[],
+            &points,
+            &arc(angle / 2.0),
+            &format!("{:.1}\u{b0}", angle.to_degrees()),
+            style,
+        );
+        Ok(angle)
+    }
+
+    /// Draws a dimension: `extensions` as plain lines, the dimension line
+    /// through `line` with markers at its ends, and `text` centred above
+    /// `label`. The text takes the style's id and the lines are numbered
+    /// after it.
+    fn add_dimension(
+        &mut self,
+        extensions: &[[Point3D; 2]],
+        line: &[Point3D],
+        label: &Point3D,
+        text: &str,
+        style: &Style,
+    ) {
+        let style = self.layer_style(style);
+        let lines = Style {
+            title: None,
+            ..style.clone()
+        };
+        let mut piece = 1;
+        for extension in extensions {
+            let plain = Style {
+                marker_start: None,
+                marker_end: None,
+                ..lines.clone()
+            };
+            piece = self.add_annotation_path(extension, &plain, piece);
+        }
+        let arrows = Style::default()
+            .with_marker_start(Marker::Arrow)
+            .with_marker_end(Marker::Arrow);
+        self.add_annotation_path(line, &lines.with_defaults(&arrows), piece);
+
+        if let Some((x, y)) = self.project_visible_point(label) {
+            let style = style.with_defaults(&Style::default().with_text_anchor(TextAnchor::Middle));
+            self.push_annotation(SVGElement::Text {
+                x,
+                y: y - DIMENSION_TEXT_GAP,
+                text: text.to_string(),
+                style,
+            });
+        }
+    }
+
+    /// Draws a legend box with its top-left corner at `(x, y)` pixels,
+    /// listing each entry's text after a swatch in the entry's style: a
+    /// square for filled styles, a short line otherwise. The box is drawn
+    /// with `style`, filled white and stroked black unless it sets
+    /// otherwise, and the text in the stroke colour at the style's font
+    /// size.
+    pub fn add_legend(&mut self, entries: &[(&str, &Style)], x: f64, y: f64, style: &Style) {
+        let style = self.layer_style(style).with_defaults(
+            &Style::new("#000000", 1.0)
+                .with_fill("#ffffff")
+                .with_font_size(LABEL_FONT_SIZE),
+        );
+        let size = style.font_size.unwrap_or(LABEL_FONT_SIZE);
+        let (padding, row, swatch) = (size * 0.5, size * 1.5, size * 1.5);
+        // Without font metrics, allow an average glyph width per character.
+        let text_width = entries
+            .iter()
+            .map(|(text, _)| text.chars().count() as f64 * size * 0.6)
+            .fold(0.0, f64::max);
+        let (width, height) = (
+            padding * 3.0 + swatch + text_width,
+            padding * 2.0 + row * entries.len() as f64,
+        );
+        self.push_annotation(SVGElement::Polygon {
+            points: vec![
+                (x, y),
+                (x + width, y),
+                (x + width, y + height),
+                (x, y + height),
+            ],
+            style: style.clone(),
+        });
+
+        let text_style = Style {
+            stroke: None,
+            fill: Some(style.stroke_color().to_string()),
+            title: None,
+            text_anchor: Some(TextAnchor::Start),
+            ..style.clone()
+        };
+        for (index, (text, entry)) in entries.iter().enumerate() {
+            let middle = y + padding + row * (index as f64 + 0.5);
+            let left = x + padding;
+            let entry = Style {
+                id: None,
+                title: None,
+                ..(*entry).clone()
+            };
+            let swatch_element = if entry.fill.is_some() {
+                let half = size / 2.0;
+                let (x1, x2) = (left + swatch / 2.0 - half, left + swatch / 2.0 + half);
+                SVGElement::Polygon {
+                    points: vec![
+                        (x1, middle - half),
+                        (x2, middle - half),
+                        (x2, middle + half),
+                        (x1, middle + half),
+                    ],
+                    style: entry,
+                }
+            } else {
+                SVGElement::Line {
+                    x1: left,
+                    y1: middle,
+                    x2: left + swatch,
+                    y2: middle,
+                    style: entry,
+                }
+            };
+            self.push_annotation(swatch_element);
+            self.push_annotation(SVGElement::Text {
+                x: left + swatch + padding,
+                // Roughly centres lowercase and capital letters on the row.
+                y: middle + size * 0.35,
+                text: text.to_string(),
+                style: piece_style(&text_style, index + 1),
+            });
+        }
+    }
+
+    /// Draws an axis triad: arrows of `length` from `origin` along X, Y and
+    /// Z, red, green and blue unless the style sets a stroke, labelled just
+    /// past their tips. With an id in the style, the axes get `-x`, `-y`
+    /// and `-z` appended to it and their labels `-x-label` and so on.
+    pub fn add_axes(
+        &mut self,
+        origin: &Point3D,
+        length: f64,
+        style: &Style,
+    ) -> Result<(), GeometryError> {
+        validate_length("Axis length", length)?;
+        let style = self.layer_style(style);
+        let axes = [
+            ("X", Vector3D::unit_x(), "#ff0000"),
+            ("Y", Vector3D::unit_y(), "#00aa00"),
+            ("Z", Vector3D::unit_z(), "#0000ff"),
+        ];
+        for (name, axis, color) in axes {
+            let mut line =
+                style.with_defaults(&Style::new(color, 1.5).with_marker_end(Marker::Arrow));
+            line.id = style
+                .id
+                .as_ref()
+                .map(|id| format!("{}-{}", id, name.to_ascii_lowercase()));
+            let tip = *origin + axis * length;
+            self.add_edge(&LineSegment::new(*origin, tip)?, &line);
+
+            let label = Style {
+                id: line.id.as_ref().map(|id| format!("{}-label", id)),
+                marker_end: None,
+                ..line.with_defaults(&Style::default().with_text_anchor(TextAnchor::Middle))
+            };
+            let at = *origin + axis * (length * AXIS_LABEL_DISTANCE);
+            self.add_text(&at, name, label);
+        }
+        Ok(())
+    }
+
+    /// Draws a square grid `size` across on `plane`, centred on the point
+    /// of the plane closest to `center` and split into `divisions` cells
+    /// each way. Lines are light grey unless the style sets a stroke.
+    pub fn add_grid(
+        &mut self,
+        plane: &Plane,
+        center: &Point3D,
+        size: f64,
+        divisions: usize,
+        style: &Style,
+    ) -> Result<(), GeometryError> {
+        validate_length("Grid size", size)?;
+        if divisions == 0 {
+            return Err(GeometryError::InvalidParameter(
+                "Grid needs at least one division".to_string(),
+            ));
+        }
+        let (u, v) = perpendicular_axes(&plane.normal).ok_or_else(|| {
+            GeometryError::DegenerateCase("Plane normal must not be zero".to_string())
+        })?;
+        let center = plane.closest_point(center);
+        let style = self
+            .layer_style(style)
+            .with_defaults(&Style::new("#cccccc", 1.0));
+        let half = size / 2.0;
+        let mut piece = 0;
+        for i in 0..=divisions {
+            let offset = size * i as f64 / divisions as f64 - half;
+            for (along, across) in [(u, v), (v, u)] {
+                let middle = center + across * offset;
+                let line = LineSegment::new(middle + along * -half, middle + along * half)?;
+                self.add_edge(&line, &piece_style(&style, piece));
+                piece += 1;
+            }
+        }
+        Ok(())
+    }
+
+    /// Draws tick marks `size` pixels long across the line from `start` to
+    /// `end`, every `spacing` along it from `start`, each labelled with its
+    /// distance from `start`. Labels go below the line, or left of it if
+    /// it is vertical on screen.
+    pub fn add_ticks(
+        &mut self,
+        start: &Point3D,
+        end: &Point3D,
+        spacing: f64,
+        size: f64,
+        style: &Style,
+    ) -> Result<(), GeometryError> {
+        validate_length("Tick spacing", spacing)?;
+        let direction = (*end - *start).normalize()?;
+        let style = self
+            .layer_style(style)
+            .with_defaults(&Style::new("#000000", 1.0));
+        let mark = Style {
+            title: None,
+            marker_start: None,
+            marker_end: None,
+            ..style.clone()
+        };
+        let label = style.with_defaults(
+            &Style::default()
+                .with_text_anchor(TextAnchor::Middle)
+                .with_font_size(LABEL_FONT_SIZE),
+        );
+        let font = label.font_size.unwrap_or(LABEL_FONT_SIZE);
+        let count = (start.distance_to(end) / spacing + EPSILON).floor() as usize;
+        for i in 0..=count {
+            let distance = spacing * i as f64;
+            let point = *start + direction * distance;
+            let Some((x, y)) = self.project_visible_point(&point) else {
+                continue;
+            };
+            // Direction of the line on screen at the tick.
+            let ahead = point + direction * (spacing * 0.01);
+            let (ax, ay) = project_point(&ahead, &self.camera, self.width, self.height);
+            let length = (ax - x).hypot(ay - y);
+            let (mut nx, mut ny) = if length > EPSILON {
+                ((y - ay) / length, (ax - x) / length)
+            } else {
+                (0.0, 1.0)
+            };
+            if ny < 0.0 || (ny == 0.0 && nx > 0.0) {
+                (nx, ny) = (-nx, -ny);
+            }
+            let half = size / 2.0;
+            self.push_annotation(SVGElement::Line {
+                x1: x - nx * half,
+                y1: y - ny * half,
+                x2: x + nx * half,
+                y2: y + ny * half,
+                style: piece_style(&mark, 2 * i),
+            });
+            // Baselines sit lower than the middle of the text.
+            self.push_annotation(SVGElement::Text {
+                x: x + nx * (size + font * 0.5),
+                y: y + ny * (size + font * 0.5) + font * 0.35,
+                text: format_tick(distance),
+                style: piece_style(&label, 2 * i + 1),
+            });
+        }
+        Ok(())
+    }
+
+    /// Draws the edges of [`SVGRenderer::scene_bounds`], dashed in grey
+    /// unless the style says otherwise, and returns the box. Unlike boxes
+    /// added with `add_aabb`, it hides nothing in hidden-line mode. `None`
+    /// if nothing has been added yet.
+    pub fn add_scene_bounds(&mut self, style: &Style) -> Option<AABB> {
+        let bounds = self.bounds?;
+        let style = self
+            .layer_style(style)
+            .with_defaults(&Style::new("#888888", 1.0).with_dash_array(&[6.0, 4.0]));
+        self.add_box_edges(&bounds, &style);
+        Some(bounds)
+    }
+
+    /// Draws the open path through `points` as an annotation in pieces
+    /// numbered from `piece`, keeping markers at its ends only. Returns the
+    /// number of the next piece.
+    fn add_annotation_path(
+        &mut self,
+        points: &[Point3D],
+        style: &Style,
+        mut piece: usize,
+    ) -> usize {
+        let paths = self.project_path(points, false);
+        let last = paths.len().saturating_sub(1);
+        for (index, path) in paths.into_iter().enumerate() {
+            let mut style = piece_style(style, piece);
+            piece += 1;
+            if index != 0 {
+                style.marker_start = None;
+            }
+            if index != last {
+                style.marker_end = None;
+            }
+            self.push_annotation(path_element(path, false, style));
+        }
+        piece
+    }
+
+    /// Adds an element that is never hidden and sorts in front of all
+    /// others.
+    fn push_annotation(&mut self, element: SVGElement) {
+        self.entries.push(Entry {
+            element,
+            depth: f64::NEG_INFINITY,
+            source: None,
+            layer: self.current_layer,
+        });
+    }
+
+    /// Screen-space path through `points`, back to the first one if
+    /// `closed`. Linear cameras clip the path in homogeneous coordinates
+    /// first, which may split an open path or remove it entirely.
+    /// Non-linear cameras bend straight edges, so each edge is subdivided
+    /// and the path split wherever it jumps across the image, as at the
+    /// seam of an equirectangular panorama.
+    fn project_path(&self, points: &[Point3D], closed: bool) -> Vec<Vec<(f64, f64)>> {
+        if self.camera.is_linear() {
+            return self
+                .clip_path(points, closed)
+                .into_iter()
+                .map(|path| path.into_iter().map(|p| self.clip_to_screen(p)).collect())
+                .collect();
+        }
+
+        let project = |p: &Point3D| project_point(p, &self.camera, self.width, self.height);
+
+        let mut corners = points.to_vec();
+        if closed {
+            corners.push(points[0]);
+        }
+        let mut samples = vec![project(&corners[0])];
+        for edge in corners.windows(2) {
+            let step = (edge[1] - edge[0]) / CURVE_SEGMENTS as f64;
+            samples.extend((1..=CURVE_SEGMENTS).map(|i| project(&(edge[0] + step * i as f64))));
+        }
+
+        let (max_dx, max_dy) = (self.width as f64 / 2.0, self.height as f64 / 2.0);
+        let mut paths = vec![vec![samples[0]]];
+        for pair in samples.windows(2) {
+            let ((x1, y1), (x2, y2)) = (pair[0], pair[1]);
+            if (x2 - x1).abs() > max_dx || (y2 - y1).abs() > max_dy {
+                paths.push(Vec::new());
+            }
+            paths.last_mut().unwrap().push(pair[1]);
+        }
+
+        if closed {
+            if paths.len() == 1 {
+                paths[0].pop();
+            } else {
+                // The loop started mid-piece; rejoin its two ends.
+                let first = paths.remove(0);
+                paths.last_mut().unwrap().extend(first.into_iter().skip(1));
+            }
+        }
+        paths
+    }
+
+    fn clip_path(&self, points: &[Point3D], closed: bool) -> Vec<Vec<ClipPoint>> {
+        let planes = self.clip_planes();
+        let clip: Vec<ClipPoint> = points.iter().map(|p| self.to_clip_space(p)).collect();
+        if closed {
+            let polygon = clip_polygon(&clip, &planes);
+            return if polygon.is_empty() {
+                Vec::new()
+            } else {
+                vec![polygon]
+            };
+        }
+
+        let mut paths: Vec<Vec<ClipPoint>> = Vec::new();
+        for edge in clip.windows(2) {
+            if let Some((start, end)) = clip_segment(edge[0], edge[1], &planes) {
+                match paths.last_mut() {
+                    Some(path) if path.last() == Some(&start) => path.push(end),
+                    _ => paths.push(vec![start, end]),
+                }
+            }
+        }
+        paths
+    }
+
+    fn push(&mut self, element: SVGElement, center: &Point3D, source: Option<Source>) {
+        self.entries.push(Entry {
+            element,
+            depth: self.camera.view_depth(center),
+            source,
+            layer: self.current_layer,
+        });
+    }
+
+    /// Viewing direction of a parallel projection, `None` for cameras whose
+    /// rays meet at the eye.
+    fn parallel_view_direction(&self) -> Option<Vector3D> {
+        if !self.camera.is_parallel_projection() {
+            return None;
+        }
+        let (x, y) = (self.width as f64 / 2.0, self.height as f64 / 2.0);
+        Some(
+            self.camera
+                .screen_to_ray(x, y, self.width, self.height)
+                .direction,
+        )
+    }
+
+    /// Elements of the layers in `included` (and those outside any layer)
+    /// with their layer, in output order: sorted as selected with
+    /// [`SVGRenderer::set_depth_sort`], then with hidden lines handled as
+    /// selected with [`SVGRenderer::set_hidden_lines`]. Only elements that
+    /// are output can hide others.
+    fn output_elements(&self, included: &[bool]) -> Vec<(Cow<'_, SVGElement>, LayerIndex)> {
+        let shown = |layer: LayerIndex| layer.is_none_or(|index| included[index]);
+        let ordered = self.ordered_elements(&shown);
+        if self.hidden_lines == HiddenLines::Shown {
+            return ordered
+                .into_iter()
+                .map(|(element, _, layer)| (element, layer))
+                .collect();
+        }
+
+        // Outlines are redrawn below, split into visible and hidden parts;
+        // triangles keep only their fill.
+        let mut elements: Vec<(Cow<'_, SVGElement>, LayerIndex)> = ordered
+            .into_iter()
+            .filter_map(|(element, source, layer)| match source {
+                Some(Source::Edge(_)) => None,
+                Some(Source::Face(_)) => fill_only(&element).map(|e| (Cow::Owned(e), layer)),
+                None => Some((element, layer)),
+            })
+            .collect();
+
+        // A filled triangle keeps its id on the fill, so its outline
+        // pieces are numbered after it.
+        let faces = self.faces.iter().filter(|face| shown(face.layer));
+        let face_outlines = faces.clone().map(|face| {
+            let Triangle { a, b, c } = face.triangle;
+            let style = Style {
+                marker_start: None,
+                marker_end: None,
+                ..face.style.clone()
+            };
+            let edges = [(a, b), (b, c), (c, a)].map(|(start, end)| Edge {
+                start,
+                end,
+                style: style.clone(),
+                layer: face.layer,
+            });
+            (edges.to_vec(), usize::from(face.style.fill.is_some()))
+        });
+        let edges = self
+            .edges
+            .iter()
+            .filter(|edge| shown(edge.layer))
+            .map(|edge| (vec![edge.clone()], 0));
+        let occluders: Bvh<Occluder> = faces
+            .map(|face| Occluder::Triangle(face.triangle))
+            .chain(
+                self.solids
+                    .iter()
+                    .filter(|(_, layer)| shown(*layer))
+                    .map(|(solid, _)| *solid),
+            )
+            .collect();
+        let mut visible = Vec::new();
+        for (outline, mut piece) in edges.chain(face_outlines) {
+            for edge in outline {
+                let runs = self.visibility_runs(&occluders, &edge.start, &edge.end);
+                let last_run = runs.len() - 1;
+                for (run, (start, end, hidden)) in runs.into_iter().enumerate() {
+                    let mut style = edge.style.clone();
+                    if hidden {
+                        if self.hidden_lines == HiddenLines::Removed {
+                            continue;
+                        }
+                        let (dash, gap) = HIDDEN_LINE_DASH;
+                        let scale = style.stroke_width.unwrap_or(1.0).max(1.0);
+                        style.dash_array = Some(vec![dash * scale, gap * scale]);
+                    }
+                    let paths = self.project_path(&[start, end], false);
+                    let last_path = paths.len().saturating_sub(1);
+                    for (index, path) in paths.into_iter().enumerate() {
+                        // Markers only at the ends of the whole edge.
+                        let mut style = piece_style(&style, piece);
+                        piece += 1;
+                        if run != 0 || index != 0 {
+                            style.marker_start = None;
+                        }
+                        if run != last_run || index != last_path {
+                            style.marker_end = None;
+                        }
+                        let element = (Cow::Owned(path_element(path, false, style)), edge.layer);
+                        if hidden {
+                            elements.push(element);
+                        } else {
+                            visible.push(element);
+                        }
+                    }
+                }
+            }
+        }
+        elements.extend(visible);
+        elements
+    }
+
+    /// Splits the segment from `start` to `end` into runs that are all
+    /// visible or all hidden, as `(start, end, hidden)`. Samples are taken
+    /// between the ends, which often touch the occluder itself.
+    fn visibility_runs(
+        &self,
+        occluders: &Bvh<Occluder>,
+        start: &Point3D,
+        end: &Point3D,
+    ) -> Vec<(Point3D, Point3D, bool)> {
+        let point_at = |t: f64| *start + (*end - *start) * t;
+        let sample = |i: usize| (i as f64 + 0.5) / VISIBILITY_SAMPLES as f64;
+        let mut runs = Vec::new();
+        let mut run_start = 0.0;
+        let mut hidden = self.is_hidden(occluders, &point_at(sample(0)));
+        for i in 1..VISIBILITY_SAMPLES {
+            if self.is_hidden(occluders, &point_at(sample(i))) == hidden {
+                continue;
+            }
+            // Narrow down where the visibility changes.
+            let (mut low, mut high) = (sample(i - 1), sample(i));
+            for _ in 0..VISIBILITY_REFINEMENTS {
+                let middle = (low + high) / 2.0;
+                if self.is_hidden(occluders, &point_at(middle)) == hidden {
+                    low = middle;
+                } else {
+                    high = middle;
+                }
+            }
+            runs.push((point_at(run_start), point_at(high), hidden));
+            run_start = high;
+            hidden = !hidden;
+        }
+        runs.push((point_at(run_start), *end, hidden));
+        runs
+    }
+
+    /// Whether an occluder lies between `point` and the camera.
+    fn is_hidden(&self, occluders: &Bvh<Occluder>, point: &Point3D) -> bool {
+        let (direction, reach) = match self.parallel_view_direction() {
+            Some(view) => {
+                // Distance back along the viewing direction to the camera
+                // plane.
+                let depth = self.camera.view_depth(point);
+                let per_unit = self.camera.view_depth(&(*point + view)) - depth;
+                (-view, depth / per_unit)
+            }
+            None => {
+                let eye = self.camera.position();
+                (eye - *point, point.distance_to(&eye))
+            }
+        };
+        // Skip the surface the point itself lies on.
+        let tolerance = EPSILON * (1.0 + reach.abs());
+        let Ok(ray) = Ray::with_range(*point, direction, tolerance, reach - tolerance) else {
+            return false;
+        };
+        occluders.any_hit(&ray).is_some()
+    }
+
+    /// Elements of the layers `shown` accepts in the order selected with
+    /// [`SVGRenderer::set_depth_sort`], with what each was drawn from.
+    fn ordered_elements(&self, shown: &dyn Fn(LayerIndex) -> bool) -> Vec<Ordered<'_>> {
+        let entries: Vec<&Entry> = self.entries.iter().filter(|e| shown(e.layer)).collect();
+        let mut by_depth = entries.clone();
+        // Stable, so elements at equal depth keep their insertion order.
+        by_depth.sort_by(|a, b| b.depth.total_cmp(&a.depth));
+        match self.depth_sort {
+            DepthSort::None => entries.into_iter().map(Entry::borrowed).collect(),
+            DepthSort::Painter => by_depth.into_iter().map(Entry::borrowed).collect(),
+            DepthSort::Bsp => {
+                let faces = self.faces.iter().enumerate();
+                let tree = BspTree::new(faces.filter(|(_, face)| shown(face.layer)).map(
+                    |(index, face)| {
+                        let Triangle { a, b, c } = face.triangle;
+                        (vec![a, b, c], index)
+                    },
+                ));
+                let fragments = match self.parallel_view_direction() {
+                    Some(direction) => tree.back_to_front_along(&direction),
+                    None => tree.back_to_front(&self.camera.position()),
+                };
+
+                let mut others = by_depth
+                    .into_iter()
+                    .filter(|e| !matches!(e.source, Some(Source::Face(_))))
+                    .peekable();
+                let mut elements = Vec::with_capacity(entries.len());
+                let mut pieces = vec![0; self.faces.len()];
+                for (vertices, &index) in fragments {
+                    let sum = vertices
+                        .iter()
+                        .fold(Vector3D::zero(), |acc, v| acc + (*v - Point3D::origin()));
+                    let center = Point3D::origin() + sum / vertices.len() as f64;
+                    let depth = self.camera.view_depth(&center);
+                    while let Some(entry) = others.next_if(|e| e.depth > depth) {
+                        elements.push(entry.borrowed());
+                    }
+
+                    let face = &self.faces[index];
+                    let paths = self.project_path(vertices, true);
+                    let closed = paths.len() == 1;
+                    for path in paths {
+                        let style = piece_style(&face.style, pieces[index]);
+                        pieces[index] += 1;
+                        let element = path_element(path, closed, style);
+                        elements.push((Cow::Owned(element), Some(Source::Face(index)), face.layer));
+                    }
+                }
+                elements.extend(others.map(Entry::borrowed));
+                elements
+            }
+        }
+    }
+
+    pub fn to_svg_string(&self) -> String {
+        format!("{}", self)
+    }
+
+    /// The output with only the layers called `names`, and the elements
+    /// outside any layer. Hidden lines are computed from these alone.
+    pub fn to_svg_string_with_layers(&self, names: &[&str]) -> Result<String, GeometryError> {
+        let mut included = vec![false; self.layers.len()];
+        for name in names {
+            included[self.layer_index(name)?] = true;
+        }
+        let mut svg = String::new();
+        self.write_svg(&mut svg, &included)
+            .expect("writing to a String cannot fail");
+        Ok(svg)
+    }
+
+    pub fn to_file(&self, path: &str) -> std::io::Result<()> {
+        let mut file = std::fs::File::create(path)?;
+        file.write_all(self.to_svg_string().as_bytes())?;
+        Ok(())
+    }
+}
+
+/// Screen-space outline of a sphere.
+enum Outline {
+    /// Rotated `angle` radians clockwise.
+    Ellipse {
+        cx: f64,
+        cy: f64,
+        rx: f64,
+        ry: f64,
+        angle: f64,
+    },
+    Paths(Vec<Vec<(f64, f64)>>),
+}
+
+/// Two unit vectors perpendicular to `axis` and each other, `None` for a
+/// zero vector.
+fn perpendicular_axes(axis: &Vector3D) -> Option<(Vector3D, Vector3D)> {
+    let axis = axis.normalize().ok()?;
+    let helper = if axis.x.abs() < 0.9 {
+        Vector3D::unit_x()
+    } else {
+        Vector3D::unit_y()
+    };
+    let first = axis.cross(&helper).normalize().ok()?;
+    Some((first, axis.cross(&first)))
+}
+
+fn validate_length(name: &str, length: f64) -> Result<f64, GeometryError> {
+    if length > 0.0 && length.is_finite() {
+        Ok(length)
+    } else {
+        Err(GeometryError::InvalidParameter(format!(
+            "{} must be positive and finite",
+            name
+        )))
+    }
+}
+
+/// Tick value without trailing zeros or rounding noise, as `"1.5"`.
+fn format_tick(value: f64) -> String {
+    format!("{}", (value * 1e6).round() / 1e6)
+}
+
+/// Style for piece `index` of an element drawn in several pieces, as when
+/// clipping splits it: the first keeps the id, the others have `-2`, `-3`
+/// and so on appended to it.
+fn piece_style(style: &Style, index: usize) -> Style {
+    let mut style = style.clone();
+    if index > 0 {
+        style.id = style.id.map(|id| format!("{}-{}", id, index + 1));
+    }
+    style
+}
+
+/// Element drawing a screen-space path: a polygon if `closed`, otherwise a
+/// line or polyline.
+fn path_element(points: Vec<(f64, f64)>, closed: bool, style: Style) -> SVGElement {
+    match (closed, points.as_slice()) {
+        (true, _) => SVGElement::Polygon { points, style },
+        (false, &[(x1, y1), (x2, y2)]) => SVGElement::Line {
+            x1,
+            y1,
+            x2,
+            y2,
+            style,
+        },
+        (false, _) => SVGElement::Polyline { points, style },
+    }
+}
+
+/// A filled polygon without its outline, or `None` if there is no fill.
+fn fill_only(element: &SVGElement) -> Option<SVGElement> {
+    match element {
+        SVGElement::Polygon { points, style } if style.fill.is_some() => {
+            Some(SVGElement::Polygon {
+                points: points.clone(),
+                style: Style {
+                    stroke: Some("none".to_string()),
+                    stroke_width: None,
+                    dash_array: None,
+                    marker_start: None,
+                    marker_end: None,
+                    ..style.clone()
+                },
+            })
+        }
+        _ => None,
+    }
+}
+
+impl SVGRenderer {
+    fn write_svg<W: fmt::Write>(&self, f: &mut W, included: &[bool]) -> fmt::Result {
+        // Inkscape only treats groups as layers with its own attributes.
+        let inkscape = if self.layers.is_empty() {
+            ""
+        } else {
+            " xmlns:inkscape=\"http://www.inkscape.org/namespaces/inkscape\""
+        };
+        writeln!(
+            f,
+            "<svg width=\"{}\" height=\"{}\" xmlns=\"http://www.w3.org/2000/svg\"{}>",
+            self.width, self.height, inkscape
+        )?;
+
+        if let Some(css) = &self.stylesheet {
+            writeln!(f, "  <style><![CDATA[\n{}\n]]></style>", css)?;
+        }
+
+        let elements = self.output_elements(included);
+        let markers: BTreeSet<String> = elements
+            .iter()
+            .filter_map(|(element, _)| element.style())
+            .flat_map(|style| {
+                [&style.marker_start, &style.marker_end]
+                    .into_iter()
+                    .flatten()
+                    .filter_map(|marker| marker.definition(style.stroke_color()))
+            })
+            .collect();
+        if !markers.is_empty() {
+            writeln!(f, "  <defs>")?;
+            for marker in markers {
+                writeln!(f, "    {}", marker)?;
+            }
+            writeln!(f, "  </defs>")?;
+        }
+
+        if let Some(bg) = &self.background {
+            writeln!(
+                f,
+                "  <rect width=\"100%\" height=\"100%\" fill=\"{}\" />",
+                bg
+            )?;
+        }
+
+        for (element, _) in elements.iter().filter(|(_, layer)| layer.is_none()) {
+            writeln!(f, "  {}", element.to_svg_string())?;
+        }
+
+        for (index, layer) in self.layers.iter().enumerate() {
+            if !included[index] {
+                continue;
+            }
+            write!(
+                f,
+                "  <g id=\"{}\" inkscape:groupmode=\"layer\" inkscape:label=\"{}\"",
+                escape_xml(&layer.id()),
+                escape_xml(&layer.name)
+            )?;
+            if let Some(transform) = &layer.transform {
+                write!(f, " transform=\"{}\"", escape_xml(transform))?;
+            }
+            if !layer.visible {
+                write!(f, " display=\"none\"")?;
+            }
+            writeln!(f, ">")?;
+            for (element, _) in elements.iter().filter(|(_, l)| *l == Some(index)) {
+                writeln!(f, "    {}", element.to_svg_string())?;
+            }
+            writeln!(f, "  </g>")?;
+        }
+
+        write!(f, "</svg>")
+    }
+}
+
+impl fmt::Display for SVGRenderer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.write_svg(f, &vec![true; self.layers.len()])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::svg::style::LineCap;
+    use std::f64::consts::{FRAC_PI_2, PI};
+
+    #[test]
+    fn test_svg_renderer_creation() {
+        let camera = Camera::perspective(
+            Point3D::new(0.0, 0.0, 5.0),
+            Point3D::new(0.0, 0.0, 0.0),
+            Vector3D::new(0.0, 1.0, 0.0),
+            60.0,
+            16.0 / 9.0,
+            0.1,
+            100.0,
+        )
+        .unwrap();
+
+        let renderer = SVGRenderer::new(800, 600, camera);
+        assert_eq!(renderer.width, 800);
+        assert_eq!(renderer.height, 600);
+    }
+
+    #[test]
+    fn test_add_point() {
+        let camera = Camera::perspective(
+            Point3D::new(0.0, 0.0, 5.0),
+            Point3D::new(0.0, 0.0, 0.0),
+            Vector3D::new(0.0, 1.0, 0.0),
+            60.0,
+            1.0,
+            0.1,
+            100.0,
+        )
+        .unwrap();
+
+        let mut renderer = SVGRenderer::new(800, 600, camera);
+        renderer.add_point(&Point3D::new(0.0, 0.0, 0.0), "#ff0000", 5.0);
+        assert_eq!(renderer.element_count(), 1);
+    }
+
+    #[test]
+    fn test_add_line_segment() {
+        let camera = Camera::perspective(
+            Point3D::new(0.0, 0.0, 5.0),
+            Point3D::new(0.0, 0.0, 0.0),
+            Vector3D::new(0.0, 1.0, 0.0),
+            60.0,
+            1.0,
+            0.1,
+            100.0,
+        )
+        .unwrap();
+
+        let mut renderer = SVGRenderer::new(800, 600, camera);
+        let segment =
+            LineSegment::new(Point3D::new(-1.0, 0.0, 0.0), Point3D::new(1.0, 0.0, 0.0)).unwrap();
+        renderer.add_line_segment(&segment, "#00ff00", 2.0);
+        assert_eq!(renderer.element_count(), 1);
+    }
+
+    #[test]
+    fn test_add_triangle() {
+        let camera = Camera::perspective(
+            Point3D::new(0.0, 0.0, 5.0),
+            Point3D::new(0.0, 0.0, 0.0),
+            Vector3D::new(0.0, 1.0, 0.0),
+            60.0,
+            1.0,
+            0.1,
+            100.0,
+        )
+        .unwrap();
+
+        let mut renderer = SVGRenderer::new(800, 600, camera);
+        let triangle = Triangle::new(
+            Point3D::new(0.0, 1.0, 0.0),
+            Point3D::new(-1.0, -1.0, 0.0),
+            Point3D::new(1.0, -1.0, 0.0),
+        )
+        .unwrap();
+        renderer.add_triangle(&triangle, "#0000ff", Some("#ccccff"), 1.0);
+        assert_eq!(renderer.element_count(), 1);
+    }
+
+    #[test]
+    fn test_add_sphere() {
+        let camera = Camera::perspective(
+            Point3D::new(0.0, 0.0, 5.0),
+            Point3D::new(0.0, 0.0, 0.0),
+            Vector3D::new(0.0, 1.0, 0.0),
+            60.0,
+            1.0,
+            0.1,
+            100.0,
+        )
+        .unwrap();
+
+        let mut renderer = SVGRenderer::new(800, 600, camera);
+        let sphere = Sphere::new(Point3D::new(0.0, 0.0, 0.0), 1.0).unwrap();
+        renderer.add_sphere(&sphere, "#ff00ff", 2.0);
+        assert_eq!(renderer.element_count(), 1);
+        assert!(renderer.to_svg_string().contains("<ellipse"));
+    }
+
+    #[test]
+    fn test_sphere_outline() {
+        let camera = Camera::perspective(
+            Point3D::new(5.0, 0.0, 0.0),
+            Point3D::new(0.0, 0.0, 0.0),
+            Vector3D::new(0.0, 1.0, 0.0),
+            60.0,
+            1.0,
+            0.1,
+            100.0,
+        )
+        .unwrap();
+        let mut renderer = SVGRenderer::new(800, 800, camera.clone());
+        renderer.set_frustum_culling(false);
+        renderer.add_sphere(
+            &Sphere::new(Point3D::origin(), 1.0).unwrap(),
+            "#000000",
+            1.0,
+        );
+        let expected = 400.0 * 0.2f64.asin().tan() / 30f64.to_radians().tan();
+        match renderer.entries[0].element {
+            SVGElement::Ellipse { cx, cy, rx, ry, .. } => {
+                assert!((cx - 400.0).abs() < 1e-9 && (cy - 400.0).abs() < 1e-9);
+                assert!((rx - expected).abs() < 1e-9 && (ry - expected).abs() < 1e-9);
+            }
+            ref other => panic!("Expected ellipse, got {:?}", other),
+        }
+
+        // Off-axis outlines are tilted along the direction to the centre.
+        renderer.add_sphere(
+            &Sphere::new(Point3D::new(0.0, 2.0, 2.0), 1.0).unwrap(),
+            "#000000",
+            1.0,
+        );
+        match renderer.entries[1].element {
+            SVGElement::Ellipse {
+                rx, ry, rotation, ..
+            } => {
+                assert!(rx > ry);
+                assert!((rotation.abs() - 45.0).abs() < 1e-6);
+            }
+            ref other => panic!("Expected ellipse, got {:?}", other),
+        }
+
+        // Cut by the near plane: the visible part of the grazing circle.
+        renderer.add_sphere(
+            &Sphere::new(Point3D::new(4.0, 1.5, 0.0), 1.2).unwrap(),
+            "#000000",
+            1.0,
+        );
+        assert!(matches!(
+            renderer.entries[2].element,
+            SVGElement::Polygon { .. }
+        ));
+
+        let fisheye = Camera::fisheye(
+            Point3D::new(5.0, 0.0, 0.0),
+            Point3D::origin(),
+            Vector3D::unit_y(),
+            180.0,
+            1.0,
+        )
+        .unwrap();
+        let mut renderer = SVGRenderer::new(800, 800, fisheye);
+        renderer.add_sphere(
+            &Sphere::new(Point3D::origin(), 1.0).unwrap(),
+            "#000000",
+            1.0,
+        );
+        assert!(matches!(
+            renderer.entries[0].element,
+            SVGElement::Polygon { .. }
+        ));
+    }
+
+    #[test]
+    fn test_shaded_sphere() {
+        let camera = Camera::perspective(
+            Point3D::new(0.0, 0.0, 5.0),
+            Point3D::new(0.0, 0.0, 0.0),
+            Vector3D::new(0.0, 1.0, 0.0),
+            60.0,
+            1.0,
+            0.1,
+            100.0,
+        )
+        .unwrap();
+        let mut renderer = SVGRenderer::new(800, 800, camera);
+        renderer.add_light(Light::ambient("#ffffff", 0.2).unwrap());
+        renderer
+            .add_light(Light::directional(Vector3D::new(-1.0, 0.0, 0.0), "#ffffff", 0.8).unwrap());
+        let sphere = Sphere::new(Point3D::origin(), 1.0).unwrap();
+
+        renderer.add_filled_sphere(&sphere, "none", Some("#ff0000"), 1.0);
+        assert!(renderer.to_svg_string().contains("fill=\"#ff0000\""));
+
+        renderer.set_shading(Shading::Flat);
+        renderer.add_filled_sphere(&sphere, "none", Some("#ff0000"), 1.0);
+        assert_eq!(renderer.element_count(), 3);
+        match &renderer.entries[1].element {
+            SVGElement::RadialGradient {
+                id, cx, fx, stops, ..
+            } => {
+                assert_eq!(id, "sphere-shade-1");
+                // Lit from the right: the highlight is right of centre.
+                assert!(fx > cx);
+                assert_eq!(stops.len(), 2);
+                assert_eq!(stops[1].1, "#330000");
+            }
+            other => panic!("Expected gradient, got {:?}", other),
+        }
+        let svg = renderer.to_svg_string();
+        assert!(svg.contains("fill=\"url(#sphere-shade-1)\""));
+
+        // Fills other than hex colours are left as they are.
+        renderer.add_filled_sphere(&sphere, "none", Some("red"), 1.0);
+        assert_eq!(renderer.element_count(), 4);
+    }
+
+    #[test]
+    fn test_add_aabb() {
+        let camera = Camera::perspective(
+            Point3D::new(0.0, 0.0, 5.0),
+            Point3D::new(0.0, 0.0, 0.0),
+            Vector3D::new(0.0, 1.0, 0.0),
+            60.0,
+            1.0,
+            0.1,
+            100.0,
+        )
+        .unwrap();
+
+        let mut renderer = SVGRenderer::new(800, 600, camera);
+        let aabb = AABB::new(Point3D::new(-1.0, -1.0, -1.0), Point3D::new(1.0, 1.0, 1.0)).unwrap();
+        renderer.add_aabb(&aabb, "#00ffff", 1.0);
+        assert_eq!(renderer.element_count(), 12);
+    }
+
+    #[test]
+    fn test_frustum_culling() {
+        let camera = Camera::perspective(
+            Point3D::new(0.0, 0.0, 5.0),
+            Point3D::new(0.0, 0.0, 0.0),
+            Vector3D::new(0.0, 1.0, 0.0),
+            60.0,
+            1.0,
+            0.1,
+            100.0,
+        )
+        .unwrap();
+
+        let mut renderer = SVGRenderer::new(800, 600, camera);
+        let behind = Point3D::new(0.0, 0.0, 10.0);
+        renderer.add_point(&behind, "#ff0000", 5.0);
+        renderer.add_sphere(
+            &Sphere::new(Point3D::new(50.0, 0.0, 0.0), 1.0).unwrap(),
+            "#ff0000",
+            1.0,
+        );
+        renderer.add_aabb(
+            &AABB::new(Point3D::new(-1.0, -1.0, 8.0), Point3D::new(1.0, 1.0, 9.0)).unwrap(),
+            "#ff0000",
+            1.0,
+        );
+        assert_eq!(renderer.element_count(), 0);
+
+        // Partly visible segments are kept.
+        let segment = LineSegment::new(behind, Point3D::origin()).unwrap();
+        renderer.add_line_segment(&segment, "#00ff00", 1.0);
+        assert_eq!(renderer.element_count(), 1);
+
+        // Off to the side but in front of the camera.
+        renderer.set_frustum_culling(false);
+        renderer.add_point(&Point3D::new(50.0, 0.0, 0.0), "#ff0000", 5.0);
+        assert_eq!(renderer.element_count(), 2);
+    }
+
+    #[test]
+    fn test_near_plane_clipping() {
+        let camera = Camera::perspective(
+            Point3D::new(0.0, 0.0, 5.0),
+            Point3D::new(0.0, 0.0, 0.0),
+            Vector3D::new(0.0, 1.0, 0.0),
+            90.0,
+            1.0,
+            0.1,
+            100.0,
+        )
+        .unwrap();
+        let mut renderer = SVGRenderer::new(800, 800, camera);
+        renderer.set_frustum_culling(false);
+
+        // Projecting the end behind the camera would mirror it to the left.
+        let crossing =
+            LineSegment::new(Point3D::new(1.0, 0.0, 10.0), Point3D::new(1.0, 0.0, -5.0)).unwrap();
+        renderer.add_line_segment(&crossing, "#000000", 1.0);
+        match renderer.entries[0].element {
+            SVGElement::Line { x1, x2, .. } => assert!(x1 > 400.0 && x2 > 400.0),
+            ref other => panic!("Expected line, got {:?}", other),
+        }
+
+        let triangle = Triangle::new(
+            Point3D::new(-1.0, -1.0, 0.0),
+            Point3D::new(1.0, -1.0, 0.0),
+            Point3D::new(0.0, 1.0, 10.0),
+        )
+        .unwrap();
+        renderer.add_triangle(&triangle, "#000000", Some("#ff0000"), 1.0);
+        match &renderer.entries[1].element {
+            SVGElement::Polygon { points, .. } => assert_eq!(points.len(), 4),
+            other => panic!("Expected polygon, got {:?}", other),
+        }
 
-        let radius_point = Point3D::new(
-            sphere.center.x + sphere.radius,
-            sphere.center.y,
-            sphere.center.z,
+        // Entirely behind the camera: nothing is drawn even without culling.
+        let behind = Point3D::new(0.0, 0.0, 8.0);
+        renderer.add_point(&behind, "#000000", 2.0);
+        renderer.add_line_segment(
+            &LineSegment::new(behind, Point3D::new(1.0, 1.0, 9.0)).unwrap(),
+            "#000000",
+            1.0,
         );
-        let (rx, _) = project_point(&radius_point, &self.camera, self.width, self.height);
-        let projected_radius = (rx - cx).abs();
-
-        self.elements.push(SVGElement::Circle {
-            cx,
-            cy,
-            r: projected_radius,
-            stroke: color.to_string(),
-            fill: None,
-            stroke_width: width,
-        });
+        renderer.add_sphere(&Sphere::new(behind, 1.0).unwrap(), "#000000", 1.0);
+        assert_eq!(renderer.element_count(), 2);
     }
 
-    pub fn add_aabb(&mut self, aabb: &AABB, color: &str, width: f64) {
-        let min = &aabb.min;
-        let max = &aabb.max;
-
-        let corners = [
-            Point3D::new(min.x, min.y, min.z),
-            Point3D::new(max.x, min.y, min.z),
-            Point3D::new(max.x, max.y, min.z),
-            Point3D::new(min.x, max.y, min.z),
-            Point3D::new(min.x, min.y, max.z),
-            Point3D::new(max.x, min.y, max.z),
-            Point3D::new(max.x, max.y, max.z),
-            Point3D::new(min.x, max.y, max.z),
-        ];
-
-        let edges = [
-            (0, 1),
-            (1, 2),
-            (2, 3),
-            (3, 0),
-            (4, 5),
-            (5, 6),
-            (6, 7),
-            (7, 4),
-            (0, 4),
-            (1, 5),
-            (2, 6),
-            (3, 7),
-        ];
+    #[test]
+    fn test_frustum_clipping() {
+        let camera = Camera::orthographic(
+            Point3D::new(0.0, 0.0, 5.0),
+            Point3D::new(0.0, 0.0, 0.0),
+            Vector3D::new(0.0, 1.0, 0.0),
+            10.0,
+            10.0,
+        )
+        .unwrap();
+        let mut renderer = SVGRenderer::new(800, 800, camera);
+        let wide =
+            LineSegment::new(Point3D::new(-50.0, 0.0, 0.0), Point3D::new(50.0, 0.0, 0.0)).unwrap();
 
-        for (i, j) in edges.iter() {
-            let segment = LineSegment::new(corners[*i], corners[*j]).unwrap();
-            self.add_line_segment(&segment, color, width);
+        renderer.add_line_segment(&wide, "#000000", 1.0);
+        match renderer.entries[0].element {
+            SVGElement::Line { x1, x2, .. } => assert!(x1 < -1000.0 && x2 > 1000.0),
+            ref other => panic!("Expected line, got {:?}", other),
         }
-    }
 
-    pub fn to_svg_string(&self) -> String {
-        format!("{}", self)
-    }
+        renderer.set_frustum_clipping(true);
+        renderer.add_line_segment(&wide, "#000000", 1.0);
+        match renderer.entries[1].element {
+            SVGElement::Line { x1, x2, .. } => {
+                assert!((x1 - 0.0).abs() < 1e-9 && (x2 - 800.0).abs() < 1e-9)
+            }
+            ref other => panic!("Expected line, got {:?}", other),
+        }
 
-    pub fn to_file(&self, path: &str) -> std::io::Result<()> {
-        let mut file = std::fs::File::create(path)?;
-        file.write_all(self.to_svg_string().as_bytes())?;
-        Ok(())
+        renderer.add_point(&Point3D::new(20.0, 0.0, 0.0), "#000000", 2.0);
+        assert_eq!(renderer.element_count(), 2);
     }
-}
 
-impl fmt::Display for SVGRenderer {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        writeln!(
-            f,
-            "<svg width=\"{}\" height=\"{}\" xmlns=\"http://www.w3.org/2000/svg\">",
-            self.width, self.height
-        )?;
+    #[test]
+    fn test_non_linear_camera_curves() {
+        let camera = Camera::equirectangular(
+            Point3D::origin(),
+            Point3D::new(0.0, 0.0, -1.0),
+            Vector3D::unit_y(),
+        )
+        .unwrap();
+        let mut renderer = SVGRenderer::new(800, 400, camera);
 
-        if let Some(bg) = &self.background {
-            writeln!(
-                f,
-                "  <rect width=\"100%\" height=\"100%\" fill=\"{}\" />",
-                bg
-            )?;
+        let front =
+            LineSegment::new(Point3D::new(-1.0, 0.5, -1.0), Point3D::new(1.0, 0.5, -1.0)).unwrap();
+        renderer.add_line_segment(&front, "#000000", 1.0);
+        match &renderer.entries[0].element {
+            SVGElement::Polyline { points, .. } => assert_eq!(points.len(), CURVE_SEGMENTS + 1),
+            other => panic!("Expected polyline, got {:?}", other),
         }
 
-        for element in &self.elements {
-            writeln!(f, "  {}", element.to_svg_string())?;
+        // Directly behind the camera the panorama wraps around.
+        let behind =
+            LineSegment::new(Point3D::new(-1.0, 0.0, 1.0), Point3D::new(1.0, 0.0, 1.0)).unwrap();
+        renderer.add_line_segment(&behind, "#000000", 1.0);
+        assert_eq!(renderer.element_count(), 3);
+
+        let triangle = Triangle::new(
+            Point3D::new(-1.0, -1.0, -2.0),
+            Point3D::new(1.0, -1.0, -2.0),
+            Point3D::new(0.0, 1.0, -2.0),
+        )
+        .unwrap();
+        renderer.add_triangle(&triangle, "#000000", Some("#ff0000"), 1.0);
+        match &renderer.entries[3].element {
+            SVGElement::Polygon { points, style } => {
+                assert_eq!(points.len(), 3 * CURVE_SEGMENTS);
+                assert!(style.fill.is_some());
+            }
+            other => panic!("Expected polygon, got {:?}", other),
         }
 
-        write!(f, "</svg>")
+        let wrapping = Triangle::new(
+            Point3D::new(-1.0, -1.0, 2.0),
+            Point3D::new(1.0, -1.0, 2.0),
+            Point3D::new(0.0, 1.0, 2.0),
+        )
+        .unwrap();
+        renderer.add_triangle(&wrapping, "#000000", Some("#ff0000"), 1.0);
+        assert!(renderer.entries[4..]
+            .iter()
+            .all(|e| matches!(e.element, SVGElement::Polyline { .. })));
+        assert!(renderer.to_svg_string().contains("<polyline"));
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use crate::primitives::Vector3D;
+    fn fills(svg: &str) -> Vec<&str> {
+        svg.lines()
+            .filter(|line| line.contains("<polygon"))
+            .filter_map(|line| line.split("fill=\"").nth(1))
+            .map(|rest| &rest[..7])
+            .collect()
+    }
 
     #[test]
-    fn test_svg_renderer_creation() {
+    fn test_painter_depth_sort() {
         let camera = Camera::perspective(
             Point3D::new(0.0, 0.0, 5.0),
             Point3D::new(0.0, 0.0, 0.0),
             Vector3D::new(0.0, 1.0, 0.0),
             60.0,
-            16.0 / 9.0,
+            1.0,
             0.1,
             100.0,
-        );
+        )
+        .unwrap();
+        let mut renderer = SVGRenderer::new(800, 800, camera);
+        let triangle = |z: f64| {
+            Triangle::new(
+                Point3D::new(-1.0, -1.0, z),
+                Point3D::new(1.0, -1.0, z),
+                Point3D::new(0.0, 1.0, z),
+            )
+            .unwrap()
+        };
+        renderer.add_triangle(&triangle(1.0), "#000000", Some("#ff0000"), 1.0);
+        renderer.add_triangle(&triangle(-3.0), "#000000", Some("#0000ff"), 1.0);
+        renderer.add_point(&Point3D::new(0.0, 0.0, -1.0), "#00ff00", 2.0);
 
-        let renderer = SVGRenderer::new(800, 600, camera);
-        assert_eq!(renderer.width, 800);
-        assert_eq!(renderer.height, 600);
+        let depths: Vec<f64> = renderer.elements().map(|(_, depth)| depth).collect();
+        assert_eq!(depths, vec![4.0, 8.0, 6.0]);
+
+        assert_eq!(fills(&renderer.to_svg_string()), vec!["#ff0000", "#0000ff"]);
+        renderer.set_depth_sort(DepthSort::Painter);
+        let svg = renderer.to_svg_string();
+        assert_eq!(fills(&svg), vec!["#0000ff", "#ff0000"]);
+        let point = svg.find("<circle").unwrap();
+        assert!(svg.find("#0000ff").unwrap() < point && point < svg.find("#ff0000").unwrap());
     }
 
     #[test]
-    fn test_add_point() {
+    fn test_bsp_depth_sort() {
         let camera = Camera::perspective(
-            Point3D::new(0.0, 0.0, 5.0),
+            Point3D::new(0.0, 2.0, 5.0),
             Point3D::new(0.0, 0.0, 0.0),
             Vector3D::new(0.0, 1.0, 0.0),
             60.0,
             1.0,
             0.1,
             100.0,
-        );
+        )
+        .unwrap();
+        let mut renderer = SVGRenderer::new(800, 800, camera);
+        // A horizontal triangle pierced by a vertical one: neither is in
+        // front of the other everywhere.
+        let flat = Triangle::new(
+            Point3D::new(-2.0, 0.0, -2.0),
+            Point3D::new(2.0, 0.0, -2.0),
+            Point3D::new(0.0, 0.0, 2.0),
+        )
+        .unwrap();
+        let upright = Triangle::new(
+            Point3D::new(-1.0, -1.0, 0.0),
+            Point3D::new(1.0, -1.0, 0.0),
+            Point3D::new(0.0, 1.0, 0.0),
+        )
+        .unwrap();
+        renderer.add_triangle(&upright, "#000000", Some("#0000ff"), 1.0);
+        renderer.add_triangle(&flat, "#000000", Some("#ff0000"), 1.0);
+        renderer.set_depth_sort(DepthSort::Bsp);
 
-        let mut renderer = SVGRenderer::new(800, 600, camera);
-        renderer.add_point(&Point3D::new(0.0, 0.0, 0.0), "#ff0000", 5.0);
-        assert_eq!(renderer.elements.len(), 1);
+        // The flat triangle is cut by the upright one's plane; its far half
+        // is drawn first and its near half last.
+        let svg = renderer.to_svg_string();
+        assert_eq!(fills(&svg), vec!["#ff0000", "#0000ff", "#ff0000"]);
+        assert_eq!(renderer.element_count(), 2);
+
+        assert_eq!("BSP".parse::<DepthSort>().unwrap(), DepthSort::Bsp);
+        assert!("z-buffer".parse::<DepthSort>().is_err());
     }
 
     #[test]
-    fn test_add_line_segment() {
+    fn test_hidden_lines_of_box() {
         let camera = Camera::perspective(
-            Point3D::new(0.0, 0.0, 5.0),
+            Point3D::new(4.0, 3.0, 5.0),
             Point3D::new(0.0, 0.0, 0.0),
             Vector3D::new(0.0, 1.0, 0.0),
             60.0,
             1.0,
             0.1,
             100.0,
-        );
+        )
+        .unwrap();
+        let mut renderer = SVGRenderer::new(800, 800, camera);
+        let aabb = AABB::new(Point3D::new(-1.0, -1.0, -1.0), Point3D::new(1.0, 1.0, 1.0)).unwrap();
+        renderer.add_aabb(&aabb, "#000000", 1.0);
 
-        let mut renderer = SVGRenderer::new(800, 600, camera);
+        // The three edges meeting at the far corner are hidden.
+        renderer.set_hidden_lines(HiddenLines::Removed);
+        assert_eq!(renderer.to_svg_string().matches("<line").count(), 9);
+
+        renderer.set_hidden_lines(HiddenLines::Dashed);
+        let svg = renderer.to_svg_string();
+        assert_eq!(svg.matches("<line").count(), 12);
+        assert_eq!(svg.matches("stroke-dasharray=\"4.00 3.00\"").count(), 3);
+
+        renderer.set_hidden_lines(HiddenLines::Shown);
+        assert!(!renderer.to_svg_string().contains("stroke-dasharray"));
+        assert_eq!(renderer.element_count(), 12);
+
+        let camera = Camera::dimetric(Point3D::origin(), Vector3D::unit_y(), 6.0, 6.0).unwrap();
+        let mut renderer = SVGRenderer::new(800, 800, camera);
+        renderer.add_aabb(&aabb, "#000000", 1.0);
+        renderer.set_hidden_lines(HiddenLines::Removed);
+        assert_eq!(renderer.to_svg_string().matches("<line").count(), 9);
+    }
+
+    #[test]
+    fn test_hidden_lines_behind_triangle() {
+        let camera = Camera::perspective(
+            Point3D::new(0.0, 0.0, 5.0),
+            Point3D::new(0.0, 0.0, 0.0),
+            Vector3D::new(0.0, 1.0, 0.0),
+            90.0,
+            1.0,
+            0.1,
+            100.0,
+        )
+        .unwrap();
+        let mut renderer = SVGRenderer::new(800, 800, camera);
+        let triangle = Triangle::new(
+            Point3D::new(-1.0, -1.0, 0.0),
+            Point3D::new(1.0, -1.0, 0.0),
+            Point3D::new(0.0, 2.0, 0.0),
+        )
+        .unwrap();
+        renderer.add_triangle(&triangle, "#000000", Some("#ffffff"), 1.0);
         let segment =
-            LineSegment::new(Point3D::new(-1.0, 0.0, 0.0), Point3D::new(1.0, 0.0, 0.0)).unwrap();
-        renderer.add_line_segment(&segment, "#00ff00", 2.0);
-        assert_eq!(renderer.elements.len(), 1);
+            LineSegment::new(Point3D::new(-3.0, 0.0, -1.0), Point3D::new(3.0, 0.0, -1.0)).unwrap();
+        renderer.add_line_segment(&segment, "#ff0000", 1.0);
+        renderer.set_hidden_lines(HiddenLines::Dashed);
+
+        let elements: Vec<_> = renderer
+            .output_elements(&[])
+            .into_iter()
+            .map(|(element, _)| element)
+            .collect();
+        // Fill first, then the hidden middle of the segment, then the
+        // visible ends and the triangle's three edges.
+        assert_eq!(elements.len(), 7);
+        match elements[0].as_ref() {
+            SVGElement::Polygon { style, .. } => {
+                assert_eq!(style.stroke.as_deref(), Some("none"));
+                assert_eq!(style.fill.as_deref(), Some("#ffffff"));
+            }
+            other => panic!("Expected polygon, got {:?}", other),
+        }
+        // The triangle covers |x| <= 2/3 at y = 0, which hides |x| <= 0.8
+        // on the segment.
+        let edge = 400.0 * 0.8 / 6.0;
+        match elements[1].as_ref() {
+            SVGElement::Line { x1, x2, style, .. } => {
+                assert!((x1 - (400.0 - edge)).abs() < 1e-3);
+                assert!((x2 - (400.0 + edge)).abs() < 1e-3);
+                assert!(style.dash_array.is_some());
+            }
+            other => panic!("Expected line, got {:?}", other),
+        }
+        assert!(elements[2..].iter().all(
+            |e| matches!(e.as_ref(), SVGElement::Line { style, .. } if style.dash_array.is_none())
+        ));
+
+        assert!("Removed".parse::<HiddenLines>().is_ok());
+        assert!("hidden".parse::<HiddenLines>().is_err());
     }
 
     #[test]
-    fn test_add_triangle() {
+    fn test_shading_and_back_face_culling() {
         let camera = Camera::perspective(
             Point3D::new(0.0, 0.0, 5.0),
             Point3D::new(0.0, 0.0, 0.0),
@@ -315,21 +2871,97 @@ mod tests {
             1.0,
             0.1,
             100.0,
-        );
+        )
+        .unwrap();
+        let mut renderer = SVGRenderer::new(800, 800, camera);
+        renderer.add_light(Light::ambient("#ffffff", 0.2).unwrap());
+        renderer
+            .add_light(Light::directional(Vector3D::new(0.0, 0.0, -1.0), "#ffffff", 0.6).unwrap());
+        let front = Triangle::new(
+            Point3D::new(-1.0, -1.0, 0.0),
+            Point3D::new(1.0, -1.0, 0.0),
+            Point3D::new(0.0, 1.0, 0.0),
+        )
+        .unwrap();
+        let back = Triangle::new(front.a, front.c, front.b).unwrap();
 
-        let mut renderer = SVGRenderer::new(800, 600, camera);
+        renderer.add_triangle(&front, "#000000", Some("#ff8000"), 1.0);
+        renderer.set_shading(Shading::Flat);
+        renderer.add_triangle(&front, "#000000", Some("#ff8000"), 1.0);
+        // Both sides are lit alike without back-face culling.
+        renderer.add_triangle(&back, "#000000", Some("#ff8000"), 1.0);
+        renderer.add_triangle(&front, "#000000", Some("orange"), 1.0);
+        let svg = renderer.to_svg_string();
+        assert_eq!(fills(&svg)[..3], ["#ff8000", "#cc6600", "#cc6600"]);
+        assert!(svg.contains("fill=\"orange\""));
+
+        renderer.set_back_face_culling(true);
+        renderer.add_triangle(&back, "#000000", Some("#ff8000"), 1.0);
+        assert_eq!(renderer.element_count(), 4);
+        renderer.add_triangle(&front, "#000000", None, 1.0);
+        assert_eq!(renderer.element_count(), 5);
+
+        renderer.clear_lights();
+        assert!(renderer.lights().is_empty());
+    }
+
+    #[test]
+    fn test_gouraud_shading() {
+        let camera = Camera::perspective(
+            Point3D::new(0.0, 0.0, 5.0),
+            Point3D::new(0.0, 0.0, 0.0),
+            Vector3D::new(0.0, 1.0, 0.0),
+            60.0,
+            1.0,
+            0.1,
+            100.0,
+        )
+        .unwrap();
+        let mut renderer = SVGRenderer::new(800, 800, camera);
+        renderer.add_light(Light::ambient("#ffffff", 0.2).unwrap());
+        renderer
+            .add_light(Light::directional(Vector3D::new(0.0, 0.0, -1.0), "#ffffff", 0.6).unwrap());
+        renderer.set_shading("Gouraud".parse().unwrap());
         let triangle = Triangle::new(
-            Point3D::new(0.0, 1.0, 0.0),
             Point3D::new(-1.0, -1.0, 0.0),
             Point3D::new(1.0, -1.0, 0.0),
+            Point3D::new(0.0, 1.0, 0.0),
         )
         .unwrap();
-        renderer.add_triangle(&triangle, "#0000ff", Some("#ccccff"), 1.0);
-        assert_eq!(renderer.elements.len(), 1);
+
+        // Without vertex normals the brightness is even, so the fill is flat.
+        renderer.add_triangle(&triangle, "#000000", Some("#ff8000"), 1.0);
+        assert_eq!(fills(&renderer.to_svg_string()), ["#cc6600"]);
+
+        let up = Vector3D::new(0.0, 0.0, 1.0);
+        let tilted = Vector3D::new(1.0, 0.0, 0.0);
+        renderer.add_triangle_with_normals(&triangle, [up, tilted, up], &Style::default());
+        renderer.add_triangle_with_normals(
+            &triangle,
+            [up, tilted, up],
+            &Style {
+                fill: Some("#ff8000".to_string()),
+                ..Style::default()
+            },
+        );
+        let svg = renderer.to_svg_string();
+        assert_eq!(svg.matches("<linearGradient").count(), 1);
+        // The unlit `b` sits at one end, the others at the far stop.
+        assert!(svg.contains("<stop offset=\"0.000\" stop-color=\"#331a00\" />"));
+        assert!(svg.contains("<stop offset=\"1.000\" stop-color=\"#cc6600\" />"));
+        let id = svg
+            .split("<linearGradient id=\"")
+            .nth(1)
+            .and_then(|rest| rest.split('"').next())
+            .unwrap();
+        assert!(svg.contains(&format!("fill=\"url(#{})\"", id)));
+
+        assert_eq!("flat".parse::<Shading>().unwrap(), Shading::Flat);
+        assert!("phong".parse::<Shading>().is_err());
     }
 
     #[test]
-    fn test_add_sphere() {
+    fn test_styles() {
         let camera = Camera::perspective(
             Point3D::new(0.0, 0.0, 5.0),
             Point3D::new(0.0, 0.0, 0.0),
@@ -338,16 +2970,58 @@ mod tests {
             1.0,
             0.1,
             100.0,
-        );
+        )
+        .unwrap();
+        let mut renderer = SVGRenderer::new(800, 800, camera);
+        renderer.set_stylesheet(".edge:hover { stroke: orange; }");
+        let segment =
+            LineSegment::new(Point3D::new(-3.0, 0.0, -1.0), Point3D::new(3.0, 0.0, -1.0)).unwrap();
+        let style = Style::new("#ff0000", 2.0)
+            .with_class("edge")
+            .with_id("axis")
+            .with_title("x < 3")
+            .with_marker_end(Marker::Arrow)
+            .with_line_cap(LineCap::Round);
+        renderer.add_line_segment_with_style(&segment, &style);
+        let aabb = AABB::new(Point3D::new(-1.0, -1.0, -1.0), Point3D::new(1.0, 1.0, 1.0)).unwrap();
+        renderer.add_aabb_with_style(&aabb, &Style::default().with_id("box"));
 
-        let mut renderer = SVGRenderer::new(800, 600, camera);
-        let sphere = Sphere::new(Point3D::new(0.0, 0.0, 0.0), 1.0).unwrap();
-        renderer.add_sphere(&sphere, "#ff00ff", 2.0);
-        assert_eq!(renderer.elements.len(), 1);
+        let svg = renderer.to_svg_string();
+        assert!(svg.contains("<style><![CDATA[\n.edge:hover { stroke: orange; }\n]]></style>"));
+        assert_eq!(svg.matches("<marker ").count(), 1);
+        assert!(svg.contains("<marker id=\"arrow-ff0000\""));
+        assert!(svg.contains(
+            "id=\"axis\" class=\"edge\" stroke=\"#ff0000\" fill=\"none\" stroke-width=\"2.00\" \
+             stroke-linecap=\"round\" marker-end=\"url(#arrow-ff0000)\"><title>x &lt; 3</title></line>"
+        ));
+        // Every piece of a box gets its own id.
+        assert!(svg.contains("id=\"box\""));
+        assert!(svg.contains("id=\"box-12\""));
+
+        // Split by an occluder, the arrow stays on the far end only.
+        let triangle = Triangle::new(
+            Point3D::new(-2.0, -1.0, 0.0),
+            Point3D::new(2.0, -1.0, 0.0),
+            Point3D::new(0.0, 2.0, 0.0),
+        )
+        .unwrap();
+        renderer.add_triangle_with_style(&triangle, &Style::default());
+        renderer.set_hidden_lines(HiddenLines::Removed);
+        let elements = renderer.output_elements(&[]);
+        let pieces: Vec<&Style> = elements
+            .iter()
+            .filter_map(|(e, _)| e.style())
+            .filter(|style| style.class.as_deref() == Some("edge"))
+            .collect();
+        assert_eq!(pieces.len(), 2);
+        assert_eq!(pieces[0].id.as_deref(), Some("axis"));
+        assert_eq!(pieces[0].marker_end, None);
+        assert_eq!(pieces[1].id.as_deref(), Some("axis-2"));
+        assert_eq!(pieces[1].marker_end, Some(Marker::Arrow));
     }
 
     #[test]
-    fn test_add_aabb() {
+    fn test_layers() {
         let camera = Camera::perspective(
             Point3D::new(0.0, 0.0, 5.0),
             Point3D::new(0.0, 0.0, 0.0),
@@ -356,12 +3030,353 @@ mod tests {
             1.0,
             0.1,
             100.0,
+        )
+        .unwrap();
+        let mut renderer = SVGRenderer::new(800, 800, camera);
+        let segment =
+            LineSegment::new(Point3D::new(-1.0, 0.0, 0.0), Point3D::new(1.0, 0.0, 0.0)).unwrap();
+        renderer.add_line_segment(&segment, "#000000", 1.0);
+        assert!(!renderer.to_svg_string().contains("inkscape"));
+
+        let edges = Layer::new("Hidden Edges")
+            .with_style(Style::default().with_class("hidden"))
+            .with_transform("translate(10 0)")
+            .with_visible(false);
+        renderer.add_layer(edges).unwrap();
+        assert!(renderer.add_layer(Layer::new("Hidden-Edges")).is_err());
+        renderer.add_line_segment_with_style(&segment, &Style::default().with_id("x axis"));
+        renderer.add_layer(Layer::new("Points")).unwrap();
+        renderer.add_point(&Point3D::origin(), "#ff0000", 3.0);
+        renderer.set_current_layer(None).unwrap();
+        renderer.add_point(&Point3D::origin(), "#0000ff", 3.0);
+        assert!(renderer.set_current_layer(Some("Lines")).is_err());
+
+        let svg = renderer.to_svg_string();
+        assert!(svg.contains("xmlns:inkscape=\"http://www.inkscape.org/namespaces/inkscape\""));
+        assert!(svg.contains(
+            "  <g id=\"Hidden-Edges\" inkscape:groupmode=\"layer\" inkscape:label=\"Hidden Edges\" \
+             transform=\"translate(10 0)\" display=\"none\">\n    <line "
+        ));
+        assert!(svg.contains("id=\"Hidden-Edges-x-axis\" class=\"hidden\""));
+        // Elements outside any layer come first, layers in the order added.
+        let blue = svg.find("#0000ff").unwrap();
+        let edges = svg.find("<g id=\"Hidden-Edges\"").unwrap();
+        let points = svg.find("<g id=\"Points\"").unwrap();
+        assert!(blue < edges && edges < points);
+        assert!(svg[points..].contains("#ff0000"));
+
+        renderer.set_layer_visible("Hidden Edges", true).unwrap();
+        assert!(!renderer.to_svg_string().contains("display=\"none\""));
+
+        let subset = renderer.to_svg_string_with_layers(&["Points"]).unwrap();
+        assert!(!subset.contains("Hidden-Edges"));
+        assert!(subset.contains("#ff0000"));
+        assert!(subset.contains("#0000ff"));
+        assert!(renderer.to_svg_string_with_layers(&["Lines"]).is_err());
+    }
+
+    #[test]
+    fn test_annotations() {
+        let camera = Camera::orthographic(
+            Point3D::new(0.0, 0.0, 10.0),
+            Point3D::new(0.0, 0.0, 0.0),
+            Vector3D::new(0.0, 1.0, 0.0),
+            10.0,
+            10.0,
+        )
+        .unwrap();
+        let mut renderer = SVGRenderer::new(500, 500, camera);
+        renderer.set_depth_sort(DepthSort::Painter);
+        renderer.add_label(
+            &Point3D::new(1.0, 1.0, 0.0),
+            "A & B",
+            &Style::default().with_fill("#ff0000").with_title("corner"),
         );
+        let svg = renderer.to_svg_string();
+        assert!(svg.contains(
+            "<text x=\"300.00\" y=\"200.00\" fill=\"#ff0000\"><title>corner</title>A &amp; B</text>"
+        ));
 
-        let mut renderer = SVGRenderer::new(800, 600, camera);
-        let aabb = AABB::new(Point3D::new(-1.0, -1.0, -1.0), Point3D::new(1.0, 1.0, 1.0)).unwrap();
-        renderer.add_aabb(&aabb, "#00ffff", 1.0);
-        assert_eq!(renderer.elements.len(), 12);
+        // Annotations stay in front of geometry added after them.
+        let triangle = Triangle::new(
+            Point3D::new(-4.0, -4.0, 1.0),
+            Point3D::new(4.0, -4.0, 1.0),
+            Point3D::new(0.0, 4.0, 1.0),
+        )
+        .unwrap();
+        renderer.add_triangle(&triangle, "#000000", Some("#cccccc"), 1.0);
+        let distance = renderer.add_linear_dimension(
+            &Point3D::new(-2.0, 0.0, 0.0),
+            &Point3D::new(2.0, 0.0, 0.0),
+            &Vector3D::new(0.0, -1.0, 0.0),
+            &Style::new("#0000ff", 1.0).with_id("width"),
+        );
+        assert_eq!(distance, 4.0);
+        renderer.set_hidden_lines(HiddenLines::Removed);
+        let svg = renderer.to_svg_string();
+        assert!(svg.find("#cccccc").unwrap() < svg.find("A &amp; B").unwrap());
+        assert!(svg.contains(">4.00</text>"));
+        assert!(svg.contains("text-anchor=\"middle\""));
+        // Extension lines, then the dimension line with both arrowheads.
+        assert!(svg.contains("id=\"width-2\""));
+        assert!(svg.contains("id=\"width-3\""));
+        assert!(svg.contains(
+            "id=\"width-4\" stroke=\"#0000ff\" fill=\"none\" stroke-width=\"1.00\" \
+             marker-start=\"url(#arrow-0000ff)\" marker-end=\"url(#arrow-0000ff)\""
+        ));
+
+        let angle = renderer
+            .add_angular_dimension(
+                &Point3D::origin(),
+                &Point3D::new(1.0, 0.0, 0.0),
+                &Point3D::new(0.0, 3.0, 0.0),
+                2.0,
+                &Style::default(),
+            )
+            .unwrap();
+        assert!((angle - FRAC_PI_2).abs() < 1e-12);
+        assert!(renderer.to_svg_string().contains(">90.0\u{b0}</text>"));
+        let straight = renderer
+            .add_angular_dimension(
+                &Point3D::origin(),
+                &Point3D::new(1.0, 0.0, 0.0),
+                &Point3D::new(-1.0, 0.0, 0.0),
+                1.0,
+                &Style::default(),
+            )
+            .unwrap();
+        assert!((straight - PI).abs() < 1e-12);
+        assert!(renderer
+            .add_angular_dimension(
+                &Point3D::origin(),
+                &Point3D::origin(),
+                &Point3D::new(1.0, 0.0, 0.0),
+                1.0,
+                &Style::default()
+            )
+            .is_err());
+
+        renderer.add_leader_label(
+            &Point3D::new(1.0, 1.0, 0.0),
+            (-20.0, -10.0),
+            "note",
+            &Style::new("#00ff00", 1.0).with_marker_start(Marker::Dot),
+        );
+        let svg = renderer.to_svg_string();
+        assert!(svg.contains("<line x1=\"300.00\" y1=\"200.00\" x2=\"280.00\" y2=\"190.00\""));
+        assert!(svg.contains("text-anchor=\"end\">note</text>"));
+
+        let red = Style::new("#ff0000", 2.0);
+        let blue = Style::default().with_fill("#0000ff");
+        renderer.add_legend(
+            &[("Edges", &red), ("Faces", &blue)],
+            10.0,
+            10.0,
+            &Style::default(),
+        );
+        let svg = renderer.to_svg_string();
+        // Two 18 px rows with 6 px of padding.
+        assert!(svg.contains("10.00,58.00\" stroke=\"#000000\" fill=\"#ffffff\""));
+        assert!(svg.contains(">Edges</text>"));
+        assert!(svg.contains(">Faces</text>"));
+        assert!(svg.contains(
+            "<line x1=\"16.00\" y1=\"25.00\" x2=\"34.00\" y2=\"25.00\" stroke=\"#ff0000\""
+        ));
+    }
+
+    #[test]
+    fn test_scene_helpers() {
+        let camera = Camera::orthographic(
+            Point3D::new(0.0, 0.0, 10.0),
+            Point3D::new(0.0, 0.0, 0.0),
+            Vector3D::new(0.0, 1.0, 0.0),
+            10.0,
+            10.0,
+        )
+        .unwrap();
+        let mut renderer = SVGRenderer::new(500, 500, camera);
+        assert!(renderer.add_scene_bounds(&Style::default()).is_none());
+
+        renderer
+            .add_axes(&Point3D::origin(), 2.0, &Style::default().with_id("axes"))
+            .unwrap();
+        assert!(renderer
+            .add_axes(&Point3D::origin(), 0.0, &Style::default())
+            .is_err());
+        let svg = renderer.to_svg_string();
+        assert!(svg.contains(
+            "<line x1=\"250.00\" y1=\"250.00\" x2=\"350.00\" y2=\"250.00\" id=\"axes-x\" \
+             stroke=\"#ff0000\" fill=\"none\" stroke-width=\"1.50\" marker-end=\"url(#arrow-ff0000)\""
+        ));
+        assert!(svg.contains("id=\"axes-y\" stroke=\"#00aa00\""));
+        // Z points at the camera, so its label sits on the origin.
+        assert!(
+            svg.contains("<text x=\"360.00\" y=\"250.00\" id=\"axes-x-label\" fill=\"#ff0000\"")
+        );
+        assert!(svg.contains(">Z</text>"));
+
+        let ground = Plane::from_point_normal(&Point3D::origin(), &Vector3D::unit_z()).unwrap();
+        renderer
+            .add_grid(
+                &ground,
+                &Point3D::new(0.0, 0.0, 3.0),
+                4.0,
+                4,
+                &Style::default(),
+            )
+            .unwrap();
+        assert!(renderer
+            .add_grid(&ground, &Point3D::origin(), 4.0, 0, &Style::default())
+            .is_err());
+        let grid: Vec<&SVGElement> = renderer
+            .elements()
+            .map(|(element, _)| element)
+            .filter(|e| e.style().and_then(|s| s.stroke.as_deref()) == Some("#cccccc"))
+            .collect();
+        assert_eq!(grid.len(), 10);
+        for element in grid {
+            match element {
+                SVGElement::Line { x1, y1, x2, y2, .. } => {
+                    for v in [x1, y1, x2, y2] {
+                        assert!((150.0..=350.0).contains(v));
+                    }
+                }
+                other => panic!("Expected line, got {:?}", other),
+            }
+        }
+
+        renderer
+            .add_ticks(
+                &Point3D::origin(),
+                &Point3D::new(1.0, 0.0, 0.0),
+                0.5,
+                6.0,
+                &Style::default(),
+            )
+            .unwrap();
+        let svg = renderer.to_svg_string();
+        assert!(svg.contains("<line x1=\"300.00\" y1=\"247.00\" x2=\"300.00\" y2=\"253.00\""));
+        for label in [">0</text>", ">0.5</text>", ">1</text>"] {
+            assert!(svg.contains(label));
+        }
+
+        // Helpers leave the scene bounds alone.
+        assert!(renderer.scene_bounds().is_none());
+        renderer.add_point(&Point3D::new(1.0, 2.0, 3.0), "#000000", 1.0);
+        let sphere = Sphere::new(Point3D::new(-1.0, 0.0, 0.0), 1.0).unwrap();
+        renderer.add_sphere(&sphere, "#000000", 1.0);
+        let bounds = renderer.add_scene_bounds(&Style::default()).unwrap();
+        assert_eq!(bounds.min, Point3D::new(-2.0, -1.0, -1.0));
+        assert_eq!(bounds.max, Point3D::new(1.0, 2.0, 3.0));
+        assert!(renderer.to_svg_string().contains(
+            "stroke=\"#888888\" fill=\"none\" stroke-width=\"1.00\" stroke-dasharray=\"6.00 4.00\""
+        ));
+        assert_eq!(renderer.scene_bounds(), Some(bounds));
+    }
+
+    #[test]
+    fn test_rays_planes_and_vectors() {
+        let camera = Camera::orthographic(
+            Point3D::new(0.0, 0.0, 10.0),
+            Point3D::new(0.0, 0.0, 0.0),
+            Vector3D::new(0.0, 1.0, 0.0),
+            10.0,
+            10.0,
+        )
+        .unwrap();
+        let mut renderer = SVGRenderer::new(500, 500, camera);
+
+        // Clipped where it leaves the view, whatever side it starts on.
+        let ray = Ray::new(Point3D::origin(), Vector3D::unit_x()).unwrap();
+        renderer.add_ray(&ray, "#ff0000", 1.0);
+        let ray = Ray::new(Point3D::new(-20.0, 1.0, 0.0), Vector3D::unit_x()).unwrap();
+        renderer.add_ray_with_style(&ray, &Style::new("#00ff00", 1.0).with_id("entering"));
+        let ray = Ray::new(Point3D::new(20.0, 0.0, 0.0), Vector3D::unit_x()).unwrap();
+        renderer.add_ray(&ray, "#0000ff", 1.0);
+        let ray = Ray::with_range(Point3D::origin(), Vector3D::unit_y(), 1.0, 2.0).unwrap();
+        renderer.add_ray(&ray, "#000000", 1.0);
+        let svg = renderer.to_svg_string();
+        assert!(svg.contains(
+            "<line x1=\"250.00\" y1=\"250.00\" x2=\"500.00\" y2=\"250.00\" stroke=\"#ff0000\" \
+             fill=\"none\" stroke-width=\"1.00\" marker-end=\"url(#arrow-ff0000)\""
+        ));
+        assert!(svg.contains(
+            "<line x1=\"0.00\" y1=\"200.00\" x2=\"500.00\" y2=\"200.00\" id=\"entering\""
+        ));
+        assert!(!svg.contains("#0000ff"));
+        assert!(svg.contains("<line x1=\"250.00\" y1=\"200.00\" x2=\"250.00\" y2=\"150.00\""));
+        // Only where the rays start counts towards the bounds.
+        let bounds = renderer.scene_bounds().unwrap();
+        assert_eq!(bounds.min, Point3D::new(-20.0, 0.0, 0.0));
+        assert_eq!(bounds.max, Point3D::new(20.0, 1.0, 0.0));
+
+        let mut renderer = SVGRenderer::new(500, 500, renderer.camera.clone());
+        renderer
+            .add_vector(
+                &Point3D::origin(),
+                &Vector3D::new(1.0, 2.0, 0.0),
+                "#ff0000",
+                2.0,
+            )
+            .unwrap();
+        assert!(renderer
+            .add_vector(&Point3D::origin(), &Vector3D::zero(), "#ff0000", 2.0)
+            .is_err());
+        assert!(renderer.to_svg_string().contains(
+            "<line x1=\"250.00\" y1=\"250.00\" x2=\"300.00\" y2=\"150.00\" stroke=\"#ff0000\" \
+             fill=\"none\" stroke-width=\"2.00\" marker-end=\"url(#arrow-ff0000)\""
+        ));
+
+        let mut renderer = SVGRenderer::new(500, 500, renderer.camera.clone());
+        let ground = Plane::from_point_normal(&Point3D::origin(), &Vector3D::unit_z()).unwrap();
+        let style = Style::new("#000000", 1.0)
+            .with_fill("#ccccff")
+            .with_id("ground");
+        renderer
+            .add_plane_with_style(&ground, &Point3D::new(0.0, 0.0, 3.0), 2.0, &style)
+            .unwrap();
+        assert!(renderer
+            .add_plane_with_style(&ground, &Point3D::origin(), -1.0, &style)
+            .is_err());
+        let ids: Vec<&str> = renderer
+            .elements()
+            .filter_map(|(element, _)| element.style()?.id.as_deref())
+            .collect();
+        assert_eq!(
+            ids,
+            ["ground", "ground-2", "ground-3", "ground-4", "ground-5", "ground-6"]
+        );
+        let svg = renderer.to_svg_string();
+        assert!(svg.contains("id=\"ground\" stroke=\"none\" fill=\"#ccccff\""));
+        assert!(svg.contains(
+            "<line x1=\"300.00\" y1=\"300.00\" x2=\"300.00\" y2=\"200.00\" id=\"ground-3\" \
+             stroke=\"#000000\" fill=\"none\""
+        ));
+        let bounds = renderer.scene_bounds().unwrap();
+        assert_eq!(bounds.min, Point3D::new(-1.0, -1.0, 0.0));
+        assert_eq!(bounds.max, Point3D::new(1.0, 1.0, 0.0));
+
+        // Without a fill only the outline is drawn.
+        renderer
+            .add_plane(&ground, &Point3D::origin(), 4.0, "#00ff00", None, 1.0)
+            .unwrap();
+        assert_eq!(renderer.element_count(), 10);
+        assert_eq!(renderer.faces.len(), 2);
+
+        // Cameras without a frustum still end unbounded rays.
+        let fisheye = Camera::fisheye(
+            Point3D::new(5.0, 0.0, 0.0),
+            Point3D::origin(),
+            Vector3D::unit_y(),
+            180.0,
+            1.0,
+        )
+        .unwrap();
+        let mut renderer = SVGRenderer::new(800, 800, fisheye);
+        let ray = Ray::new(Point3D::origin(), Vector3D::unit_y()).unwrap();
+        assert_eq!(renderer.ray_extent(&ray), Some((0.0, 100.0)));
+        renderer.add_ray(&ray, "#000000", 1.0);
+        assert!(renderer.element_count() > 0);
     }
 
     #[test]
@@ -374,7 +3389,8 @@ mod tests {
             1.0,
             0.1,
             100.0,
-        );
+        )
+        .unwrap();
 
         let mut renderer = SVGRenderer::new(800, 600, camera);
         renderer.set_background("#ffffff");
@@ -391,7 +3407,8 @@ mod tests {
             1.0,
             0.1,
             100.0,
-        );
+        )
+        .unwrap();
 
         let mut renderer = SVGRenderer::new(800, 600, camera);
         renderer.set_background("#ffffff");
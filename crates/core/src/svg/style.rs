@@ -0,0 +1,498 @@
+use std::fmt::Write;
+use std::str::FromStr;
+
+use crate::error::GeometryError;
+
+/// Presentation of an element drawn by [`SVGRenderer`](super::SVGRenderer).
+///
+/// Unset fields are left out of the output, so SVG defaults or a
+/// stylesheet given with `SVGRenderer::set_stylesheet` apply, except the
+/// fill, which defaults to `none`. Attributes written here lose to CSS
+/// rules, so a class is enough to restyle an element from a stylesheet.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Style {
+    pub stroke: Option<String>,
+    pub stroke_width: Option<f64>,
+    pub fill: Option<String>,
+    /// From 0 (transparent) to 1 (opaque).
+    pub opacity: Option<f64>,
+    /// Alternating dash and gap lengths, in pixels.
+    pub dash_array: Option<Vec<f64>>,
+    pub line_cap: Option<LineCap>,
+    pub line_join: Option<LineJoin>,
+    pub marker_start: Option<Marker>,
+    pub marker_end: Option<Marker>,
+    /// CSS class names, separated by spaces.
+    pub class: Option<String>,
+    pub id: Option<String>,
+    /// Tooltip text, written as a `<title>` child of the element.
+    pub title: Option<String>,
+    /// Text size in pixels, for labels.
+    pub font_size: Option<f64>,
+    pub font_family: Option<String>,
+    /// Which end of a label lies on its anchor point.
+    pub text_anchor: Option<TextAnchor>,
+}
+
+impl Style {
+    /// Unfilled style stroked with `stroke` at `stroke_width` pixels.
+    pub fn new(stroke: &str, stroke_width: f64) -> Self {
+        Self {
+            stroke: Some(stroke.to_string()),
+            stroke_width: Some(stroke_width),
+            ..Self::default()
+        }
+    }
+
+    pub fn with_stroke(mut self, stroke: &str) -> Self {
+        self.stroke = Some(stroke.to_string());
+        self
+    }
+
+    pub fn with_stroke_width(mut self, width: f64) -> Self {
+        self.stroke_width = Some(width);
+        self
+    }
+
+    pub fn with_fill(mut self, fill: &str) -> Self {
+        self.fill = Some(fill.to_string());
+        self
+    }
+
+    pub fn with_opacity(mut self, opacity: f64) -> Self {
+        self.opacity = Some(opacity);
+        self
+    }
+
+    pub fn with_dash_array(mut self, dash_array: &[f64]) -> Self {
+        self.dash_array = Some(dash_array.to_vec());
+        self
+    }
+
+    pub fn with_line_cap(mut self, line_cap: LineCap) -> Self {
+        self.line_cap = Some(line_cap);
+        self
+    }
+
+    pub fn with_line_join(mut self, line_join: LineJoin) -> Self {
+        self.line_join = Some(line_join);
+        self
+    }
+
+    pub fn with_marker_start(mut self, marker: Marker) -> Self {
+        self.marker_start = Some(marker);
+        self
+    }
+
+    pub fn with_marker_end(mut self, marker: Marker) -> Self {
+        self.marker_end = Some(marker);
+        self
+    }
+
+    pub fn with_class(mut self, class: &str) -> Self {
+        self.class = Some(class.to_string());
+        self
+    }
+
+    pub fn with_id(mut self, id: &str) -> Self {
+        self.id = Some(id.to_string());
+        self
+    }
+
+    pub fn with_title(mut self, title: &str) -> Self {
+        self.title = Some(title.to_string());
+        self
+    }
+
+    pub fn with_font_size(mut self, size: f64) -> Self {
+        self.font_size = Some(size);
+        self
+    }
+
+    pub fn with_font_family(mut self, family: &str) -> Self {
+        self.font_family = Some(family.to_string());
+        self
+    }
+
+    pub fn with_text_anchor(mut self, anchor: TextAnchor) -> Self {
+        self.text_anchor = Some(anchor);
+        self
+    }
+
+    /// This style with its unset fields taken from `defaults`.
+    pub fn with_defaults(&self, defaults: &Style) -> Style {
+        fn pick<T: Clone>(own: &Option<T>, default: &Option<T>) -> Option<T> {
+            own.as_ref().or(default.as_ref()).cloned()
+        }
+        Style {
+            stroke: pick(&self.stroke, &defaults.stroke),
+            stroke_width: pick(&self.stroke_width, &defaults.stroke_width),
+            fill: pick(&self.fill, &defaults.fill),
+            opacity: pick(&self.opacity, &defaults.opacity),
+            dash_array: pick(&self.dash_array, &defaults.dash_array),
+            line_cap: pick(&self.line_cap, &defaults.line_cap),
+            line_join: pick(&self.line_join, &defaults.line_join),
+            marker_start: pick(&self.marker_start, &defaults.marker_start),
+            marker_end: pick(&self.marker_end, &defaults.marker_end),
+            class: pick(&self.class, &defaults.class),
+            id: pick(&self.id, &defaults.id),
+            title: pick(&self.title, &defaults.title),
+            font_size: pick(&self.font_size, &defaults.font_size),
+            font_family: pick(&self.font_family, &defaults.font_family),
+            text_anchor: pick(&self.text_anchor, &defaults.text_anchor),
+        }
+    }
+
+    /// Presentation attributes, each preceded by a space.
+    pub(crate) fn attributes(&self) -> String {
+        let mut out = String::new();
+        let mut text = |name: &str, value: Option<&str>| {
+            if let Some(value) = value {
+                let _ = write!(out, " {}=\"{}\"", name, escape_xml(value));
+            }
+        };
+        text("id", self.id.as_deref());
+        text("class", self.class.as_deref());
+        text("stroke", self.stroke.as_deref());
+        text("fill", Some(self.fill.as_deref().unwrap_or("none")));
+        if let Some(width) = self.stroke_width {
+            let _ = write!(out, " stroke-width=\"{:.2}\"", width);
+        }
+        if let Some(opacity) = self.opacity {
+            let _ = write!(out, " opacity=\"{:.3}\"", opacity);
+        }
+        if let Some(dash_array) = &self.dash_array {
+            let lengths: Vec<String> = dash_array.iter().map(|d| format!("{:.2}", d)).collect();
+            let _ = write!(out, " stroke-dasharray=\"{}\"", lengths.join(" "));
+        }
+        if let Some(cap) = self.line_cap {
+            let _ = write!(out, " stroke-linecap=\"{}\"", cap.as_str());
+        }
+        if let Some(join) = self.line_join {
+            let _ = write!(out, " stroke-linejoin=\"{}\"", join.as_str());
+        }
+        if let Some(size) = self.font_size {
+            let _ = write!(out, " font-size=\"{:.2}\"", size);
+        }
+        if let Some(family) = &self.font_family {
+            let _ = write!(out, " font-family=\"{}\"", escape_xml(family));
+        }
+        if let Some(anchor) = self.text_anchor {
+            let _ = write!(out, " text-anchor=\"{}\"", anchor.as_str());
+        }
+        for (name, marker) in [
+            ("marker-start", &self.marker_start),
+            ("marker-end", &self.marker_end),
+        ] {
+            if let Some(marker) = marker {
+                let id = marker.id(self.stroke_color());
+                let _ = write!(out, " {}=\"url(#{})\"", name, escape_xml(&id));
+            }
+        }
+        out
+    }
+
+    /// End of an element's tag opened with `<name`: self-closing, or
+    /// holding the title.
+    pub(crate) fn close_tag(&self, name: &str) -> String {
+        match &self.title {
+            Some(title) => format!("><title>{}</title></{}>", escape_xml(title), name),
+            None => " />".to_string(),
+        }
+    }
+
+    /// Colour built-in markers are painted in.
+    pub(crate) fn stroke_color(&self) -> &str {
+        match self.stroke.as_deref() {
+            Some("none") | None => "#000000",
+            Some(stroke) => stroke,
+        }
+    }
+}
+
+/// Shape drawn at the ends of open lines.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Marker {
+    /// Arrowhead with its tip on the end point, pointing away from the line.
+    Arrow,
+    /// Dot centred on the end point.
+    Dot,
+    /// A `<marker>` with this id defined outside the renderer's output, as
+    /// by the page embedding it.
+    Custom(String),
+}
+
+impl Marker {
+    /// Id of the marker definition used with a line stroked in `color`.
+    /// Built-in markers get one definition per colour.
+    pub(crate) fn id(&self, color: &str) -> String {
+        let color: String = color
+            .trim_start_matches('#')
+            .chars()
+            .map(|c| if c.is_ascii_alphanumeric() { c } else { '-' })
+            .collect();
+        match self {
+            Marker::Arrow => format!("arrow-{}", color),
+            Marker::Dot => format!("dot-{}", color),
+            Marker::Custom(id) => id.clone(),
+        }
+    }
+
+    /// `<marker>` definition for a line stroked in `color`, `None` for
+    /// custom markers.
+    pub(crate) fn definition(&self, color: &str) -> Option<String> {
+        let (reference, size, shape) = match self {
+            Marker::Arrow => (10, 6, "<path d=\"M 0 0 L 10 5 L 0 10 z\""),
+            Marker::Dot => (5, 4, "<circle cx=\"5\" cy=\"5\" r=\"5\""),
+            Marker::Custom(_) => return None,
+        };
+        Some(format!(
+            "<marker id=\"{}\" viewBox=\"0 0 10 10\" refX=\"{}\" refY=\"5\" markerWidth=\"{}\" markerHeight=\"{}\" orient=\"auto-start-reverse\">{} fill=\"{}\" /></marker>",
+            escape_xml(&self.id(color)),
+            reference,
+            size,
+            size,
+            shape,
+            escape_xml(color)
+        ))
+    }
+}
+
+impl FromStr for Marker {
+    type Err = GeometryError;
+
+    /// Parses `"arrow"` or `"dot"`, ignoring case, or `"url(#id)"` for a
+    /// custom marker.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if let Some(id) = s
+            .strip_prefix("url(#")
+            .and_then(|rest| rest.strip_suffix(')'))
+        {
+            return Ok(Marker::Custom(id.to_string()));
+        }
+        match s.to_ascii_lowercase().as_str() {
+            "arrow" => Ok(Marker::Arrow),
+            "dot" => Ok(Marker::Dot),
+            _ => Err(GeometryError::InvalidParameter(format!(
+                "Unknown marker '{}', expected 'arrow', 'dot' or 'url(#id)'",
+                s
+            ))),
+        }
+    }
+}
+
+/// Shape of the ends of stroked open lines.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum LineCap {
+    Butt,
+    Round,
+    Square,
+}
+
+impl LineCap {
+    fn as_str(&self) -> &'static str {
+        match self {
+            LineCap::Butt => "butt",
+            LineCap::Round => "round",
+            LineCap::Square => "square",
+        }
+    }
+}
+
+impl FromStr for LineCap {
+    type Err = GeometryError;
+
+    /// Parses `"butt"`, `"round"` or `"square"`, ignoring case.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "butt" => Ok(LineCap::Butt),
+            "round" => Ok(LineCap::Round),
+            "square" => Ok(LineCap::Square),
+            _ => Err(GeometryError::InvalidParameter(format!(
+                "Unknown line cap '{}', expected 'butt', 'round' or 'square'",
+                s
+            ))),
+        }
+    }
+}
+
+/// Shape of the corners of stroked lines.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum LineJoin {
+    Miter,
+    Round,
+    Bevel,
+}
+
+impl LineJoin {
+    fn as_str(&self) -> &'static str {
+        match self {
+            LineJoin::Miter => "miter",
+            LineJoin::Round => "round",
+            LineJoin::Bevel => "bevel",
+        }
+    }
+}
+
+impl FromStr for LineJoin {
+    type Err = GeometryError;
+
+    /// Parses `"miter"`, `"round"` or `"bevel"`, ignoring case.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "miter" => Ok(LineJoin::Miter),
+            "round" => Ok(LineJoin::Round),
+            "bevel" => Ok(LineJoin::Bevel),
+            _ => Err(GeometryError::InvalidParameter(format!(
+                "Unknown line join '{}', expected 'miter', 'round' or 'bevel'",
+                s
+            ))),
+        }
+    }
+}
+
+/// Which end of a line of text lies on its anchor point.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TextAnchor {
+    Start,
+    Middle,
+    End,
+}
+
+impl TextAnchor {
+    fn as_str(&self) -> &'static str {
+        match self {
+            TextAnchor::Start => "start",
+            TextAnchor::Middle => "middle",
+            TextAnchor::End => "end",
+        }
+    }
+}
+
+impl FromStr for TextAnchor {
+    type Err = GeometryError;
+
+    /// Parses `"start"`, `"middle"` or `"end"`, ignoring case.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "start" => Ok(TextAnchor::Start),
+            "middle" => Ok(TextAnchor::Middle),
+            "end" => Ok(TextAnchor::End),
+            _ => Err(GeometryError::InvalidParameter(format!(
+                "Unknown text anchor '{}', expected 'start', 'middle' or 'end'",
+                s
+            ))),
+        }
+    }
+}
+
+/// `text` with the characters XML gives a meaning escaped, for attribute
+/// values and element content.
+pub(crate) fn escape_xml(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// `key` as an XML id: characters other than ASCII letters, digits, `-`,
+/// `_` and `.` become `-`, and an `_` is put in front unless it starts
+/// with a letter or `_`.
+pub(crate) fn xml_id(key: &str) -> String {
+    let id: String = key
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.') {
+                c
+            } else {
+                '-'
+            }
+        })
+        .collect();
+    match id.chars().next() {
+        Some(c) if c.is_ascii_alphabetic() || c == '_' => id,
+        _ => format!("_{}", id),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_attributes() {
+        assert_eq!(
+            Style::new("#ff0000", 2.0).attributes(),
+            " stroke=\"#ff0000\" fill=\"none\" stroke-width=\"2.00\""
+        );
+        let style = Style::default()
+            .with_id("a\"b")
+            .with_class("edge hidden")
+            .with_opacity(0.5)
+            .with_dash_array(&[4.0, 2.0])
+            .with_line_cap(LineCap::Round)
+            .with_line_join(LineJoin::Bevel)
+            .with_marker_end(Marker::Arrow);
+        assert_eq!(
+            style.attributes(),
+            " id=\"a&quot;b\" class=\"edge hidden\" fill=\"none\" opacity=\"0.500\" \
+             stroke-dasharray=\"4.00 2.00\" stroke-linecap=\"round\" stroke-linejoin=\"bevel\" \
+             marker-end=\"url(#arrow-000000)\""
+        );
+        assert_eq!(style.close_tag("line"), " />");
+        assert_eq!(
+            Style::default()
+                .with_font_size(12.0)
+                .with_font_family("Helvetica, sans-serif")
+                .with_text_anchor(TextAnchor::End)
+                .attributes(),
+            " fill=\"none\" font-size=\"12.00\" font-family=\"Helvetica, sans-serif\" text-anchor=\"end\""
+        );
+        assert_eq!(
+            style.with_title("<tip>").close_tag("line"),
+            "><title>&lt;tip&gt;</title></line>"
+        );
+    }
+
+    #[test]
+    fn test_with_defaults() {
+        let defaults = Style::new("#000000", 1.0).with_class("layer");
+        let style = Style::default().with_stroke("#ff0000").with_fill("#00ff00");
+        let merged = style.with_defaults(&defaults);
+        assert_eq!(merged.stroke.as_deref(), Some("#ff0000"));
+        assert_eq!(merged.fill.as_deref(), Some("#00ff00"));
+        assert_eq!(merged.stroke_width, Some(1.0));
+        assert_eq!(merged.class.as_deref(), Some("layer"));
+    }
+
+    #[test]
+    fn test_markers_and_parsing() {
+        assert_eq!(Marker::Arrow.id("#ff0000"), "arrow-ff0000");
+        assert_eq!(Marker::Dot.id("rgb(1, 2, 3)"), "dot-rgb-1--2--3-");
+        assert!(Marker::Arrow
+            .definition("#ff0000")
+            .unwrap()
+            .contains("fill=\"#ff0000\""));
+        assert_eq!(Marker::Custom("tick".to_string()).definition("#000"), None);
+
+        assert_eq!("Arrow".parse::<Marker>().unwrap(), Marker::Arrow);
+        assert_eq!(
+            "url(#tick)".parse::<Marker>().unwrap(),
+            Marker::Custom("tick".to_string())
+        );
+        assert!("star".parse::<Marker>().is_err());
+        assert_eq!("ROUND".parse::<LineCap>().unwrap(), LineCap::Round);
+        assert_eq!("miter".parse::<LineJoin>().unwrap(), LineJoin::Miter);
+        assert!("pointy".parse::<LineJoin>().is_err());
+        assert_eq!("Middle".parse::<TextAnchor>().unwrap(), TextAnchor::Middle);
+        assert!("center".parse::<TextAnchor>().is_err());
+    }
+}
@@ -1,5 +1,6 @@
 use common_core_geometry::operations::{
-    ray_aabb_intersection, ray_sphere_intersection, ray_triangle_intersection, Transformable,
+    contact_sphere_aabb, epa_penetration, gjk_distance, ray_aabb_intersection,
+    ray_sphere_intersection, ray_triangle_intersection, Transformable,
 };
 use common_core_geometry::{Camera, Point3D, Ray, SVGRenderer, Sphere, Triangle, Vector3D, AABB};
 
@@ -44,20 +45,23 @@ fn test_ray_casting_through_scene() {
 
     let sphere_hit = ray_sphere_intersection(&ray, &sphere);
     assert!(sphere_hit.is_some());
-    let (t1, t2) = sphere_hit.unwrap();
-    assert!(t1 > 0.0 && t2 > 0.0);
-    assert!(t1 < 10.0);
+    let sphere_hit = sphere_hit.unwrap();
+    assert!(sphere_hit.t > 0.0 && sphere_hit.t < 10.0);
+    assert_eq!(sphere_hit.point, Point3D::new(0.0, 0.0, 2.0));
+    assert_eq!(sphere_hit.normal, Vector3D::new(0.0, 0.0, 1.0));
 
     let aabb_hit = ray_aabb_intersection(&ray, &aabb);
     assert!(aabb_hit.is_some());
-    let (t_min, t_max) = aabb_hit.unwrap();
-    assert!(t_min > 0.0 && t_max > 0.0);
+    let aabb_hit = aabb_hit.unwrap();
+    assert_eq!(aabb_hit.t, 9.0);
+    assert_eq!(aabb_hit.normal, Vector3D::new(0.0, 0.0, 1.0));
 
     let triangle_hit = ray_triangle_intersection(&ray, &triangle);
     assert!(triangle_hit.is_some());
-    let t = triangle_hit.unwrap();
-    assert!(t > 0.0);
-    assert_eq!(t, 5.0);
+    let triangle_hit = triangle_hit.unwrap();
+    assert_eq!(triangle_hit.t, 5.0);
+    assert!(triangle_hit.front_face);
+    assert!(triangle_hit.barycentric.is_some());
 }
 
 #[test]
@@ -104,7 +108,8 @@ fn test_svg_output_generation() {
         800.0 / 600.0,
         0.1,
         100.0,
-    );
+    )
+    .unwrap();
 
     let mut renderer = SVGRenderer::new(800, 600, camera);
     renderer.set_background("white");
@@ -136,7 +141,8 @@ fn test_camera_projections_perspective() {
         1.0,
         0.1,
         100.0,
-    );
+    )
+    .unwrap();
 
     let mut renderer = SVGRenderer::new(800, 800, camera);
 
@@ -159,7 +165,8 @@ fn test_camera_projections_orthographic() {
         Vector3D::new(0.0, 1.0, 0.0),
         10.0,
         10.0,
-    );
+    )
+    .unwrap();
 
     let mut renderer = SVGRenderer::new(800, 800, camera);
 
@@ -171,6 +178,31 @@ fn test_camera_projections_orthographic() {
     assert!(svg.contains("<svg"));
 }
 
+#[test]
+fn test_camera_projection_modes() {
+    let target = Point3D::new(0.0, 0.0, 0.0);
+    let eye = Point3D::new(0.0, 0.0, 10.0);
+    let up = Vector3D::new(0.0, 1.0, 0.0);
+    let cameras = [
+        Camera::isometric(target, up, 10.0, 10.0).unwrap(),
+        Camera::dimetric(target, up, 10.0, 10.0).unwrap(),
+        Camera::cabinet(eye, target, up, 10.0, 10.0).unwrap(),
+        Camera::off_axis(eye, target, up, [-0.05, 0.15, -0.1, 0.1], 0.1, 100.0).unwrap(),
+        Camera::fisheye(eye, target, up, 180.0, 1.0).unwrap(),
+        Camera::equirectangular(eye, target, up).unwrap(),
+    ];
+
+    let aabb = AABB::new(Point3D::new(-1.0, -1.0, -1.0), Point3D::new(1.0, 1.0, 1.0)).unwrap();
+    for camera in cameras {
+        let mut renderer = SVGRenderer::new(400, 400, camera);
+        renderer.add_aabb(&aabb, "black", 1.0);
+        assert_eq!(renderer.element_count(), 12);
+
+        let svg = renderer.to_svg_string();
+        assert!(!svg.contains("NaN"));
+    }
+}
+
 #[test]
 fn test_complex_scene_workflow() {
     let camera = Camera::perspective(
@@ -181,7 +213,8 @@ fn test_complex_scene_workflow() {
         16.0 / 9.0,
         0.1,
         100.0,
-    );
+    )
+    .unwrap();
 
     let mut renderer = SVGRenderer::new(1920, 1080, camera);
     renderer.set_background("#f0f0f0");
@@ -234,7 +267,8 @@ fn test_scene_with_transformations_and_rendering() {
         1.0,
         0.1,
         50.0,
-    );
+    )
+    .unwrap();
 
     let mut renderer = SVGRenderer::new(800, 800, camera);
 
@@ -261,3 +295,20 @@ fn test_scene_with_transformations_and_rendering() {
     assert_eq!(sphere2.center, Point3D::new(-3.0, 0.0, 0.0));
     assert_eq!(sphere3.radius, 2.0);
 }
+
+#[test]
+fn test_gjk_epa_agree_with_pairwise_queries() {
+    let aabb = AABB::new(Point3D::new(-1.0, -1.0, -1.0), Point3D::new(1.0, 1.0, 1.0)).unwrap();
+
+    let far = Sphere::new(Point3D::new(4.0, 0.5, 0.0), 1.0).unwrap();
+    let closest = gjk_distance(&far, &aabb).unwrap();
+    assert!((closest.distance - (aabb.distance_to_point(&far.center) - 1.0)).abs() < 1e-6);
+    assert!(contact_sphere_aabb(&far, &aabb).is_none());
+
+    let near = Sphere::new(Point3D::new(1.6, 0.2, 0.0), 1.0).unwrap();
+    assert!(gjk_distance(&near, &aabb).is_none());
+    let penetration = epa_penetration(&near, &aabb).unwrap();
+    let manifold = contact_sphere_aabb(&near, &aabb).unwrap();
+    assert!((penetration.depth - manifold.max_depth()).abs() < 1e-3);
+    assert!(penetration.normal.dot(&manifold.normal) > 0.99);
+}
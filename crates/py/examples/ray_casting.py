@@ -35,11 +35,10 @@ def main():
     )
     
     test_ray = Ray(Point3D(0, 0, 5), Vector3D(0, 0, -1).normalize())
-    result = test_ray.intersect_triangle(triangle)
+    hit = test_ray.intersect_triangle(triangle)
     
-    if result:
-        t, point = result
-        print(f"\nRay hit triangle at t={t:.2f}, point={point}")
+    if hit:
+        print(f"\nRay hit triangle at t={hit.t:.2f}, point={hit.point}, normal={hit.normal}")
     else:
         print("\nRay missed triangle")
 
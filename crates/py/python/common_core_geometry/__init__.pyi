@@ -1,4 +1,4 @@
-from typing import Iterator, Optional, Tuple
+from typing import Iterator, List, Optional, Tuple
 
 class Point3D:
     x: float
@@ -70,17 +70,38 @@ class Sphere:
 class Ray:
     origin: Point3D
     direction: Vector3D
+    t_min: float
+    t_max: float
     
-    def __init__(self, origin: Point3D, direction: Vector3D) -> None: ...
+    def __init__(
+        self,
+        origin: Point3D,
+        direction: Vector3D,
+        t_min: float = 0.0,
+        t_max: float = float("inf"),
+    ) -> None: ...
     
     def point_at(self, t: float) -> Point3D: ...
-    def intersect_sphere(self, sphere: Sphere) -> Optional[Tuple[float, Point3D]]: ...
-    def intersect_plane(self, plane: Plane) -> Optional[Point3D]: ...
-    def intersect_triangle(self, triangle: Triangle) -> Optional[Tuple[float, Point3D]]: ...
+    def intersect_sphere(self, sphere: Sphere) -> Optional[RayHit]: ...
+    def intersect_sphere_all(self, sphere: Sphere) -> List[RayHit]: ...
+    def intersect_plane(self, plane: Plane) -> Optional[RayHit]: ...
+    def intersect_triangle(self, triangle: Triangle) -> Optional[RayHit]: ...
+    def intersect_aabb(self, aabb: AABB) -> Optional[RayHit]: ...
+    def intersect_aabb_all(self, aabb: AABB) -> List[RayHit]: ...
     
     def __repr__(self) -> str: ...
     def __str__(self) -> str: ...
 
+class RayHit:
+    t: float
+    point: Point3D
+    normal: Vector3D
+    front_face: bool
+    uv: Optional[Tuple[float, float]]
+    barycentric: Optional[Tuple[float, float, float]]
+    
+    def __repr__(self) -> str: ...
+
 class Triangle:
     a: Point3D
     b: Point3D
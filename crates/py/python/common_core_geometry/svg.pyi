@@ -1,5 +1,5 @@
-from typing import Optional
-from . import Point3D, Vector3D, Sphere, Triangle, LineSegment, AABB
+from typing import List, Optional, Tuple
+from . import Point3D, Vector3D, Sphere, Triangle, LineSegment, AABB, Plane, Ray
 
 class Camera:
     @staticmethod
@@ -24,18 +24,127 @@ class Camera:
         far: float = 100.0
     ) -> Camera: ...
 
+    @staticmethod
+    def isometric(target: Point3D, up: Vector3D, width: float, height: float) -> Camera: ...
+    @staticmethod
+    def dimetric(target: Point3D, up: Vector3D, width: float, height: float) -> Camera: ...
+    @staticmethod
+    def trimetric(
+        target: Point3D,
+        up: Vector3D,
+        yaw: float,
+        elevation: float,
+        width: float,
+        height: float
+    ) -> Camera: ...
+    @staticmethod
+    def oblique(
+        position: Point3D,
+        target: Point3D,
+        up: Vector3D,
+        width: float,
+        height: float,
+        angle: float = 45.0,
+        depth_scale: float = 0.5
+    ) -> Camera: ...
+    @staticmethod
+    def cavalier(position: Point3D, target: Point3D, up: Vector3D, width: float, height: float) -> Camera: ...
+    @staticmethod
+    def cabinet(position: Point3D, target: Point3D, up: Vector3D, width: float, height: float) -> Camera: ...
+    @staticmethod
+    def off_axis(
+        position: Point3D,
+        target: Point3D,
+        up: Vector3D,
+        window: Tuple[float, float, float, float],
+        near: float = 0.1,
+        far: float = 100.0
+    ) -> Camera: ...
+    @staticmethod
+    def fisheye(position: Point3D, target: Point3D, up: Vector3D, fov: float = 180.0, aspect: float = 1.0) -> Camera: ...
+    @staticmethod
+    def equirectangular(position: Point3D, target: Point3D, up: Vector3D) -> Camera: ...
+
+    @property
+    def is_linear(self) -> bool: ...
+
+    def screen_to_ray(self, x: float, y: float, width: int, height: int) -> Ray: ...
+    def unproject(self, x: float, y: float, depth: float) -> Point3D: ...
+
+    @property
+    def position(self) -> Point3D: ...
+    @property
+    def target(self) -> Point3D: ...
+    @property
+    def up(self) -> Vector3D: ...
+
+    def look_at(self, target: Point3D) -> None: ...
+    def orbit(self, yaw: float, pitch: float) -> None: ...
+    def pan(self, dx: float, dy: float) -> None: ...
+    def dolly(self, distance: float) -> None: ...
+    def zoom(self, factor: float) -> None: ...
+    def fit_to_sphere(self, sphere: Sphere) -> None: ...
+    def fit_to_aabb(self, aabb: AABB) -> None: ...
+
+class Style:
+    def __init__(
+        self,
+        *,
+        stroke: Optional[str] = None,
+        fill: Optional[str] = None,
+        stroke_width: Optional[float] = None,
+        opacity: Optional[float] = None,
+        dash_array: Optional[List[float]] = None,
+        line_cap: Optional[str] = None,
+        line_join: Optional[str] = None,
+        marker_start: Optional[str] = None,
+        marker_end: Optional[str] = None,
+        css_class: Optional[str] = None,
+        id: Optional[str] = None,
+        title: Optional[str] = None,
+        font_size: Optional[float] = None,
+        font_family: Optional[str] = None,
+        text_anchor: Optional[str] = None,
+    ) -> None: ...
+    
+    def __repr__(self) -> str: ...
+
 class SVGRenderer:
     def __init__(self, width: int, height: int, camera: Camera) -> None: ...
     
     def set_background_color(self, color: str) -> None: ...
-    def add_point(self, point: Point3D, color: Optional[str] = None, radius: Optional[float] = None) -> None: ...
-    def add_line_segment(self, segment: LineSegment, color: Optional[str] = None, width: Optional[float] = None) -> None: ...
-    def add_triangle(self, triangle: Triangle, stroke: Optional[str] = None, fill: Optional[str] = None, stroke_width: Optional[float] = None) -> None: ...
-    def add_sphere(self, sphere: Sphere, color: Optional[str] = None, segments: Optional[int] = None) -> None: ...
-    def add_aabb(self, aabb: AABB, color: Optional[str] = None, width: Optional[float] = None) -> None: ...
+    def set_depth_sort(self, mode: str) -> None: ...
+    def set_hidden_lines(self, mode: str) -> None: ...
+    def add_ambient_light(self, color: str = "#ffffff", intensity: float = 1.0) -> None: ...
+    def add_directional_light(self, direction: Vector3D, color: str = "#ffffff", intensity: float = 1.0) -> None: ...
+    def add_point_light(self, position: Point3D, color: str = "#ffffff", intensity: float = 1.0) -> None: ...
+    def clear_lights(self) -> None: ...
+    def set_shading(self, mode: str) -> None: ...
+    def set_back_face_culling(self, enabled: bool) -> None: ...
+    def set_stylesheet(self, css: str) -> None: ...
+    def add_label(self, point: Point3D, text: str, color: str = "#000000", font_size: float = 12.0, offset: Optional[Tuple[float, float]] = None, style: Optional[Style] = None) -> None: ...
+    def add_linear_dimension(self, start: Point3D, end: Point3D, offset: Vector3D, color: str = "#000000", style: Optional[Style] = None) -> float: ...
+    def add_angular_dimension(self, vertex: Point3D, start: Point3D, end: Point3D, radius: float, color: str = "#000000", style: Optional[Style] = None) -> float: ...
+    def add_legend(self, entries: List[Tuple[str, Style]], x: float = 10.0, y: float = 10.0, style: Optional[Style] = None) -> None: ...
+    def add_axes(self, origin: Point3D, length: float = 1.0, style: Optional[Style] = None) -> None: ...
+    def add_grid(self, plane: Plane, center: Point3D, size: float, divisions: int = 10, color: str = "#cccccc", style: Optional[Style] = None) -> None: ...
+    def add_ticks(self, start: Point3D, end: Point3D, spacing: float, size: float = 6.0, color: str = "#000000", style: Optional[Style] = None) -> None: ...
+    def scene_bounds(self) -> Optional[AABB]: ...
+    def add_scene_bounds(self, color: str = "#888888", style: Optional[Style] = None) -> Optional[AABB]: ...
+    def add_layer(self, name: str, visible: bool = True, style: Optional[Style] = None, transform: Optional[str] = None) -> None: ...
+    def set_current_layer(self, name: Optional[str]) -> None: ...
+    def set_layer_visible(self, name: str, visible: bool) -> None: ...
+    def add_point(self, point: Point3D, color: Optional[str] = None, radius: Optional[float] = None, style: Optional[Style] = None) -> None: ...
+    def add_line_segment(self, segment: LineSegment, color: Optional[str] = None, width: Optional[float] = None, style: Optional[Style] = None) -> None: ...
+    def add_triangle(self, triangle: Triangle, stroke: Optional[str] = None, fill: Optional[str] = None, stroke_width: Optional[float] = None, style: Optional[Style] = None, normals: Optional[Tuple[Vector3D, Vector3D, Vector3D]] = None) -> None: ...
+    def add_sphere(self, sphere: Sphere, color: Optional[str] = None, width: Optional[float] = None, fill: Optional[str] = None, style: Optional[Style] = None) -> None: ...
+    def add_aabb(self, aabb: AABB, color: Optional[str] = None, width: Optional[float] = None, style: Optional[Style] = None) -> None: ...
+    def add_ray(self, ray: Ray, color: str = "#000000", width: float = 1.0, style: Optional[Style] = None) -> None: ...
+    def add_vector(self, origin: Point3D, vector: Vector3D, color: str = "#000000", width: float = 1.0, style: Optional[Style] = None) -> None: ...
+    def add_plane(self, plane: Plane, center: Point3D, size: float, stroke: str = "#000000", fill: Optional[str] = None, style: Optional[Style] = None) -> None: ...
     
-    def render(self) -> str: ...
-    def save(self, path: str) -> None: ...
+    def render(self, layers: Optional[List[str]] = None) -> str: ...
+    def save(self, path: str, layers: Optional[List[str]] = None) -> None: ...
     
     def __repr__(self) -> str: ...
     def __enter__(self) -> SVGRenderer: ...
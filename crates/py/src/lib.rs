@@ -11,6 +11,7 @@ fn common_core_geometry(m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_class::<PyVector3D>()?;
     m.add_class::<PySphere>()?;
     m.add_class::<PyRay>()?;
+    m.add_class::<PyRayHit>()?;
     m.add_class::<PyTriangle>()?;
     m.add_class::<PyPlane>()?;
     m.add_class::<PyAABB>()?;
@@ -592,26 +592,35 @@ pub struct PyRay {
 #[pymethods]
 impl PyRay {
     #[new]
-    #[pyo3(text_signature = "(origin, direction, /)")]
+    #[pyo3(signature = (origin, direction, t_min=0.0, t_max=f64::INFINITY))]
+    #[pyo3(text_signature = "(origin, direction, t_min=0.0, t_max=inf)")]
     /// Create a new ray.
     ///
     /// Args:
     ///     origin (Point3D): The starting point of the ray
     ///     direction (Vector3D): The direction vector (will be normalized)
+    ///     t_min (float): Smallest accepted hit parameter (default 0.0)
+    ///     t_max (float): Largest accepted hit parameter (default infinity)
     ///
     /// Returns:
     ///     Ray: A new ray
     ///
     /// Raises:
-    ///     ValueError: If direction is a zero vector
+    ///     ValueError: If direction is a zero vector or t_min > t_max
     ///
     /// Example:
     ///     >>> from common_core_geometry import Point3D, Vector3D, Ray
     ///     >>> origin = Point3D(0, 0, 0)
     ///     >>> direction = Vector3D(1, 0, 0)
     ///     >>> ray = Ray(origin, direction)
-    pub fn new(origin: &PyPoint3D, direction: &PyVector3D) -> PyResult<Self> {
-        match core::Ray::new(origin.inner, direction.inner) {
+    ///     >>> segment_ray = Ray(origin, direction, t_max=10.0)
+    pub fn new(
+        origin: &PyPoint3D,
+        direction: &PyVector3D,
+        t_min: f64,
+        t_max: f64,
+    ) -> PyResult<Self> {
+        match core::Ray::with_range(origin.inner, direction.inner, t_min, t_max) {
             Ok(r) => Ok(PyRay { inner: r }),
             Err(e) => Err(pyo3::exceptions::PyValueError::new_err(format!("{}", e))),
         }
@@ -633,6 +642,18 @@ impl PyRay {
         }
     }
 
+    #[getter]
+    /// Get the smallest hit parameter accepted by intersection queries.
+    pub fn t_min(&self) -> f64 {
+        self.inner.t_min
+    }
+
+    #[getter]
+    /// Get the largest hit parameter accepted by intersection queries.
+    pub fn t_max(&self) -> f64 {
+        self.inner.t_max
+    }
+
     #[pyo3(text_signature = "($self, t, /)")]
     /// Get a point along the ray at parameter t.
     ///
@@ -654,27 +675,24 @@ impl PyRay {
     }
 
     #[pyo3(text_signature = "($self, sphere, /)")]
-    /// Find the intersection with a sphere.
+    /// Find the nearest intersection with a sphere.
     ///
     /// Args:
     ///     sphere (Sphere): The sphere to test
     ///
     /// Returns:
-    ///     Optional[Tuple[float, Point3D]]: The nearest intersection as (t, point), or None
+    ///     Optional[RayHit]: The nearest hit record, or None
     ///
     /// Example:
     ///     >>> ray = Ray(Point3D(0, 0, 0), Vector3D(1, 0, 0))
     ///     >>> sphere = Sphere(Point3D(5, 0, 0), 1.0)
-    ///     >>> result = ray.intersect_sphere(sphere)
-    ///     >>> if result:
-    ///     ...     t, point = result
-    pub fn intersect_sphere(&self, sphere: &PySphere) -> Option<(f64, PyPoint3D)> {
+    ///     >>> hit = ray.intersect_sphere(sphere)
+    ///     >>> hit.t
+    ///     4.0
+    pub fn intersect_sphere(&self, sphere: &PySphere) -> Option<PyRayHit> {
         use common_core_geometry::operations::ray_sphere_intersection;
 
-        ray_sphere_intersection(&self.inner, &sphere.inner).map(|(t1, _t2)| {
-            let point = self.inner.point_at(t1);
-            (t1, PyPoint3D { inner: point })
-        })
+        ray_sphere_intersection(&self.inner, &sphere.inner).map(|hit| PyRayHit { inner: hit })
     }
 
     #[pyo3(text_signature = "($self, plane, /)")]
@@ -684,16 +702,18 @@ impl PyRay {
     ///     plane (Plane): The plane to test
     ///
     /// Returns:
-    ///     Optional[Point3D]: The intersection point, or None if parallel
+    ///     Optional[RayHit]: The hit record, or None if parallel or behind the ray
     ///
     /// Example:
     ///     >>> ray = Ray(Point3D(0, 0, 5), Vector3D(0, 0, -1))
     ///     >>> plane = Plane.from_point_normal(Point3D(0, 0, 0), Vector3D(0, 0, 1))
-    ///     >>> point = ray.intersect_plane(plane)
-    pub fn intersect_plane(&self, plane: &PyPlane) -> Option<PyPoint3D> {
+    ///     >>> hit = ray.intersect_plane(plane)
+    ///     >>> hit.point.z
+    ///     0.0
+    pub fn intersect_plane(&self, plane: &PyPlane) -> Option<PyRayHit> {
         use common_core_geometry::operations::ray_plane_intersection;
 
-        ray_plane_intersection(&self.inner, &plane.inner).map(|point| PyPoint3D { inner: point })
+        ray_plane_intersection(&self.inner, &plane.inner).map(|hit| PyRayHit { inner: hit })
     }
 
     #[pyo3(text_signature = "($self, triangle, /)")]
@@ -703,19 +723,85 @@ impl PyRay {
     ///     triangle (Triangle): The triangle to test
     ///
     /// Returns:
-    ///     Optional[Tuple[float, Point3D]]: The intersection as (t, point), or None
+    ///     Optional[RayHit]: The hit record with barycentric coordinates, or None
     ///
     /// Example:
     ///     >>> ray = Ray(Point3D(0, 0, 5), Vector3D(0, 0, -1))
     ///     >>> tri = Triangle(Point3D(-1, -1, 0), Point3D(1, -1, 0), Point3D(0, 1, 0))
-    ///     >>> result = ray.intersect_triangle(tri)
-    pub fn intersect_triangle(&self, triangle: &PyTriangle) -> Option<(f64, PyPoint3D)> {
+    ///     >>> hit = ray.intersect_triangle(tri)
+    ///     >>> hit.barycentric
+    ///     (0.25, 0.25, 0.5)
+    pub fn intersect_triangle(&self, triangle: &PyTriangle) -> Option<PyRayHit> {
         use common_core_geometry::operations::ray_triangle_intersection;
 
-        ray_triangle_intersection(&self.inner, &triangle.inner).map(|t| {
-            let point = self.inner.point_at(t);
-            (t, PyPoint3D { inner: point })
-        })
+        ray_triangle_intersection(&self.inner, &triangle.inner).map(|hit| PyRayHit { inner: hit })
+    }
+
+    #[pyo3(text_signature = "($self, sphere, /)")]
+    /// Find every intersection with a sphere inside the ray's interval.
+    ///
+    /// Args:
+    ///     sphere (Sphere): The sphere to test
+    ///
+    /// Returns:
+    ///     List[RayHit]: Entry and exit hits ordered by t (one for tangent rays)
+    ///
+    /// Example:
+    ///     >>> ray = Ray(Point3D(-5, 0, 0), Vector3D(1, 0, 0))
+    ///     >>> hits = ray.intersect_sphere_all(Sphere(Point3D(0, 0, 0), 1.0))
+    ///     >>> [hit.t for hit in hits]
+    ///     [4.0, 6.0]
+    pub fn intersect_sphere_all(&self, sphere: &PySphere) -> Vec<PyRayHit> {
+        use common_core_geometry::operations::ray_sphere_intersections;
+
+        ray_sphere_intersections(&self.inner, &sphere.inner)
+            .into_iter()
+            .map(|hit| PyRayHit { inner: hit })
+            .collect()
+    }
+
+    #[pyo3(text_signature = "($self, aabb, /)")]
+    /// Find the nearest intersection with an axis-aligned bounding box.
+    ///
+    /// Args:
+    ///     aabb (AABB): The box to test
+    ///
+    /// Returns:
+    ///     Optional[RayHit]: The hit record with face UVs, or None
+    ///
+    /// Example:
+    ///     >>> ray = Ray(Point3D(-5, 0, 0), Vector3D(1, 0, 0))
+    ///     >>> box = AABB(Point3D(-1, -1, -1), Point3D(1, 1, 1))
+    ///     >>> hit = ray.intersect_aabb(box)
+    ///     >>> hit.t
+    ///     4.0
+    pub fn intersect_aabb(&self, aabb: &PyAABB) -> Option<PyRayHit> {
+        use common_core_geometry::operations::ray_aabb_intersection;
+
+        ray_aabb_intersection(&self.inner, &aabb.inner).map(|hit| PyRayHit { inner: hit })
+    }
+
+    #[pyo3(text_signature = "($self, aabb, /)")]
+    /// Find the entry and exit hits with an axis-aligned bounding box.
+    ///
+    /// Args:
+    ///     aabb (AABB): The box to test
+    ///
+    /// Returns:
+    ///     List[RayHit]: Hits inside the ray's interval ordered by t
+    ///
+    /// Example:
+    ///     >>> ray = Ray(Point3D(-5, 0, 0), Vector3D(1, 0, 0))
+    ///     >>> hits = ray.intersect_aabb_all(AABB(Point3D(-1, -1, -1), Point3D(1, 1, 1)))
+    ///     >>> [hit.front_face for hit in hits]
+    ///     [True, False]
+    pub fn intersect_aabb_all(&self, aabb: &PyAABB) -> Vec<PyRayHit> {
+        use common_core_geometry::operations::ray_aabb_intersections;
+
+        ray_aabb_intersections(&self.inner, &aabb.inner)
+            .into_iter()
+            .map(|hit| PyRayHit { inner: hit })
+            .collect()
     }
 
     fn __repr__(&self) -> String {
@@ -731,6 +817,73 @@ impl PyRay {
     }
 }
 
+#[pyclass(name = "RayHit")]
+#[derive(Clone)]
+pub struct PyRayHit {
+    pub inner: common_core_geometry::RayHit,
+}
+
+#[pymethods]
+impl PyRayHit {
+    #[getter]
+    /// Get the ray parameter at the hit.
+    pub fn t(&self) -> f64 {
+        self.inner.t
+    }
+
+    #[getter]
+    /// Get the hit point.
+    pub fn point(&self) -> PyPoint3D {
+        PyPoint3D {
+            inner: self.inner.point,
+        }
+    }
+
+    #[getter]
+    /// Get the unit surface normal, oriented against the ray.
+    pub fn normal(&self) -> PyVector3D {
+        PyVector3D {
+            inner: self.inner.normal,
+        }
+    }
+
+    #[getter]
+    /// Whether the ray hit the outside of the surface.
+    pub fn front_face(&self) -> bool {
+        self.inner.front_face
+    }
+
+    #[getter]
+    /// Get the surface (u, v) coordinates, if the surface defines them.
+    pub fn uv(&self) -> Option<(f64, f64)> {
+        self.inner.uv
+    }
+
+    #[getter]
+    /// Get the barycentric coordinates of a triangle hit.
+    pub fn barycentric(&self) -> Option<(f64, f64, f64)> {
+        self.inner.barycentric
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "RayHit(t={}, point=Point3D({}, {}, {}), normal=Vector3D({}, {}, {}), front_face={})",
+            self.inner.t,
+            self.inner.point.x,
+            self.inner.point.y,
+            self.inner.point.z,
+            self.inner.normal.x,
+            self.inner.normal.y,
+            self.inner.normal.z,
+            if self.inner.front_face {
+                "True"
+            } else {
+                "False"
+            }
+        )
+    }
+}
+
 #[pyclass(name = "Triangle")]
 #[derive(Clone)]
 pub struct PyTriangle {
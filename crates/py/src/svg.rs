@@ -1,7 +1,9 @@
 use common_core_geometry as core;
 use pyo3::prelude::*;
 
-use crate::primitives::{PyAABB, PyLineSegment, PyPoint3D, PySphere, PyTriangle, PyVector3D};
+use crate::primitives::{
+    PyAABB, PyLineSegment, PyPlane, PyPoint3D, PyRay, PySphere, PyTriangle, PyVector3D,
+};
 
 #[pyclass(name = "Camera")]
 #[derive(Clone)]
@@ -28,6 +30,11 @@ impl PyCamera {
     /// Returns:
     ///     Camera: A perspective camera
     ///
+    /// Raises:
+    ///     ValueError: If position equals target, up is zero or parallel to
+    ///         the viewing direction, fov is not between 0 and 180, aspect is
+    ///         not positive, or near is not in (0, far)
+    ///
     /// Example:
     ///     >>> from common_core_geometry import Point3D, Vector3D
     ///     >>> from common_core_geometry.svg import Camera
@@ -46,17 +53,18 @@ impl PyCamera {
         aspect: f64,
         near: f64,
         far: f64,
-    ) -> Self {
-        PyCamera {
-            inner: core::svg::Camera::perspective(
-                position.inner,
-                target.inner,
-                up.inner,
-                fov,
-                aspect,
-                near,
-                far,
-            ),
+    ) -> PyResult<Self> {
+        match core::svg::Camera::perspective(
+            position.inner,
+            target.inner,
+            up.inner,
+            fov,
+            aspect,
+            near,
+            far,
+        ) {
+            Ok(inner) => Ok(PyCamera { inner }),
+            Err(e) => Err(pyo3::exceptions::PyValueError::new_err(format!("{}", e))),
         }
     }
 
@@ -74,6 +82,10 @@ impl PyCamera {
     /// Returns:
     ///     Camera: An orthographic camera
     ///
+    /// Raises:
+    ///     ValueError: If position equals target, up is zero or parallel to
+    ///         the viewing direction, or width or height is not positive
+    ///
     /// Example:
     ///     >>> camera = Camera.orthographic(
     ///     ...     Point3D(10, 10, 10),
@@ -88,197 +100,1677 @@ impl PyCamera {
         up: &PyVector3D,
         width: f64,
         height: f64,
-    ) -> Self {
-        PyCamera {
-            inner: core::svg::Camera::orthographic(
-                position.inner,
-                target.inner,
-                up.inner,
-                width,
-                height,
-            ),
+    ) -> PyResult<Self> {
+        match core::svg::Camera::orthographic(position.inner, target.inner, up.inner, width, height)
+        {
+            Ok(inner) => Ok(PyCamera { inner }),
+            Err(e) => Err(pyo3::exceptions::PyValueError::new_err(format!("{}", e))),
         }
     }
 
-    fn __repr__(&self) -> String {
-        match &self.inner {
-            core::svg::Camera::Perspective { fov, aspect, .. } => {
-                format!("Camera.perspective(fov={}, aspect={})", fov, aspect)
-            }
-            core::svg::Camera::Orthographic { width, height, .. } => {
-                format!("Camera.orthographic(width={}, height={})", width, height)
-            }
-        }
+    #[staticmethod]
+    #[pyo3(text_signature = "(target, up, width, height)")]
+    /// Create an isometric camera.
+    ///
+    /// An orthographic view that foreshortens all three world axes equally,
+    /// looking at the target from 45 degrees around the up vector and about
+    /// 35.26 degrees above the ground.
+    ///
+    /// Args:
+    ///     target (Point3D): Point the camera is looking at
+    ///     up (Vector3D): World up direction
+    ///     width (float): View width
+    ///     height (float): View height
+    ///
+    /// Returns:
+    ///     Camera: An orthographic camera in isometric position
+    ///
+    /// Raises:
+    ///     ValueError: If up is zero or width or height is not positive
+    ///
+    /// Example:
+    ///     >>> camera = Camera.isometric(Point3D(0, 0, 0), Vector3D(0, 0, 1), 10.0, 10.0)
+    pub fn isometric(
+        target: &PyPoint3D,
+        up: &PyVector3D,
+        width: f64,
+        height: f64,
+    ) -> PyResult<Self> {
+        to_py_camera(core::svg::Camera::isometric(
+            target.inner,
+            up.inner,
+            width,
+            height,
+        ))
     }
-}
-
-#[pyclass(name = "SVGRenderer")]
-pub struct PySVGRenderer {
-    inner: core::svg::SVGRenderer,
-}
 
-#[pymethods]
-impl PySVGRenderer {
-    #[new]
-    #[pyo3(text_signature = "(width, height, camera, /)")]
-    /// Create a new SVG renderer.
+    #[staticmethod]
+    #[pyo3(text_signature = "(target, up, width, height)")]
+    /// Create a dimetric camera.
+    ///
+    /// An orthographic view in which the up axis and one ground axis share a
+    /// scale and the other ground axis is drawn at half of it (DIN ISO
+    /// 5456-3). The half-scale axis is x when z is up and z when y is up.
     ///
     /// Args:
-    ///     width (int): Canvas width in pixels
-    ///     height (int): Canvas height in pixels
-    ///     camera (Camera): Camera for viewing the 3D scene
+    ///     target (Point3D): Point the camera is looking at
+    ///     up (Vector3D): World up direction
+    ///     width (float): View width
+    ///     height (float): View height
     ///
     /// Returns:
-    ///     SVGRenderer: A new SVG renderer
+    ///     Camera: An orthographic camera in dimetric position
+    ///
+    /// Raises:
+    ///     ValueError: If up is zero or width or height is not positive
     ///
     /// Example:
-    ///     >>> from common_core_geometry import Point3D, Vector3D
-    ///     >>> from common_core_geometry.svg import Camera, SVGRenderer
-    ///     >>> camera = Camera.perspective(
-    ///     ...     Point3D(4, 4, 4),
-    ///     ...     Point3D(0, 0, 0),
-    ///     ...     Vector3D(0, 1, 0),
-    ///     ...     60.0,
-    ///     ...     16/9
-    ///     ... )
-    ///     >>> renderer = SVGRenderer(800, 600, camera)
-    pub fn new(width: usize, height: usize, camera: &PyCamera) -> Self {
-        PySVGRenderer {
-            inner: core::svg::SVGRenderer::new(width, height, camera.inner.clone()),
-        }
+    ///     >>> camera = Camera.dimetric(Point3D(0, 0, 0), Vector3D(0, 0, 1), 10.0, 10.0)
+    pub fn dimetric(
+        target: &PyPoint3D,
+        up: &PyVector3D,
+        width: f64,
+        height: f64,
+    ) -> PyResult<Self> {
+        to_py_camera(core::svg::Camera::dimetric(
+            target.inner,
+            up.inner,
+            width,
+            height,
+        ))
     }
 
-    #[pyo3(text_signature = "($self, color, /)")]
-    /// Set the background color of the SVG.
+    #[staticmethod]
+    #[pyo3(text_signature = "(target, up, yaw, elevation, width, height)")]
+    /// Create a trimetric camera.
+    ///
+    /// An orthographic view looking at the target from `yaw` degrees around
+    /// the up vector and `elevation` degrees above the ground. Yaw starts at
+    /// x when z is up (z when y is up, y when x is up).
     ///
     /// Args:
-    ///     color (str): Color in CSS format (e.g., "#ffffff", "white")
+    ///     target (Point3D): Point the camera is looking at
+    ///     up (Vector3D): World up direction
+    ///     yaw (float): Angle around the up vector in degrees
+    ///     elevation (float): Angle above the ground in degrees, strictly
+    ///         between -90 and 90
+    ///     width (float): View width
+    ///     height (float): View height
+    ///
+    /// Returns:
+    ///     Camera: An orthographic camera
+    ///
+    /// Raises:
+    ///     ValueError: If up is zero, the elevation looks straight along up,
+    ///         or width or height is not positive
     ///
     /// Example:
-    ///     >>> renderer.set_background("#f0f0f0")
-    pub fn set_background(&mut self, color: &str) {
-        self.inner.set_background(color);
+    ///     >>> camera = Camera.trimetric(Point3D(0, 0, 0), Vector3D(0, 0, 1), 30.0, 20.0, 10.0, 10.0)
+    pub fn trimetric(
+        target: &PyPoint3D,
+        up: &PyVector3D,
+        yaw: f64,
+        elevation: f64,
+        width: f64,
+        height: f64,
+    ) -> PyResult<Self> {
+        to_py_camera(core::svg::Camera::trimetric(
+            target.inner,
+            up.inner,
+            yaw,
+            elevation,
+            width,
+            height,
+        ))
     }
 
-    #[pyo3(signature = (point, color="#000000", size=3.0))]
-    #[pyo3(text_signature = "($self, point, color='#000000', size=3.0)")]
-    /// Add a point to the scene.
+    #[staticmethod]
+    #[pyo3(signature = (position, target, up, width, height, angle=45.0, depth_scale=0.5))]
+    #[pyo3(text_signature = "(position, target, up, width, height, angle=45.0, depth_scale=0.5)")]
+    /// Create an oblique camera.
+    ///
+    /// A parallel projection that shows the plane through the target facing
+    /// the camera in its true shape and draws depth receding at `angle`
+    /// degrees, scaled by `depth_scale`. The defaults give a cabinet
+    /// projection.
     ///
     /// Args:
-    ///     point (Point3D): The point to render
-    ///     color (str): Point color (default: "#000000")
-    ///     size (float): Point radius in pixels (default: 3.0)
+    ///     position (Point3D): Camera position in 3D space
+    ///     target (Point3D): Point the camera is looking at
+    ///     up (Vector3D): Up direction vector
+    ///     width (float): View width
+    ///     height (float): View height
+    ///     angle (float): Direction of the receding axis on screen in
+    ///         degrees from the x axis (default: 45.0)
+    ///     depth_scale (float): Scale of the receding axis (default: 0.5)
+    ///
+    /// Returns:
+    ///     Camera: An oblique camera
+    ///
+    /// Raises:
+    ///     ValueError: If the view is degenerate, width or height is not
+    ///         positive, or depth_scale is negative
     ///
     /// Example:
-    ///     >>> renderer.add_point(Point3D(0, 0, 0), color="#ff0000", size=5.0)
-    pub fn add_point(&mut self, point: &PyPoint3D, color: &str, size: f64) {
-        self.inner.add_point(&point.inner, color, size);
+    ///     >>> camera = Camera.oblique(
+    ///     ...     Point3D(0, 0, 10),
+    ///     ...     Point3D(0, 0, 0),
+    ///     ...     Vector3D(0, 1, 0),
+    ///     ...     10.0,
+    ///     ...     10.0,
+    ///     ...     depth_scale=1.0
+    ///     ... )
+    pub fn oblique(
+        position: &PyPoint3D,
+        target: &PyPoint3D,
+        up: &PyVector3D,
+        width: f64,
+        height: f64,
+        angle: f64,
+        depth_scale: f64,
+    ) -> PyResult<Self> {
+        to_py_camera(core::svg::Camera::oblique(
+            position.inner,
+            target.inner,
+            up.inner,
+            width,
+            height,
+            angle,
+            depth_scale,
+        ))
     }
 
-    #[pyo3(signature = (segment, color="#000000", width=1.0))]
-    #[pyo3(text_signature = "($self, segment, color='#000000', width=1.0)")]
-    /// Add a line segment to the scene.
+    #[staticmethod]
+    #[pyo3(text_signature = "(position, target, up, width, height)")]
+    /// Create a cavalier oblique camera (depth at full scale, 45 degrees).
     ///
     /// Args:
-    ///     segment (LineSegment): The line segment to render
-    ///     color (str): Line color (default: "#000000")
-    ///     width (float): Line width in pixels (default: 1.0)
+    ///     position (Point3D): Camera position in 3D space
+    ///     target (Point3D): Point the camera is looking at
+    ///     up (Vector3D): Up direction vector
+    ///     width (float): View width
+    ///     height (float): View height
     ///
-    /// Example:
-    ///     >>> from common_core_geometry import LineSegment
-    ///     >>> segment = LineSegment(Point3D(0, 0, 0), Point3D(1, 1, 1))
-    ///     >>> renderer.add_line_segment(segment, color="#00ff00", width=2.0)
-    pub fn add_line_segment(&mut self, segment: &PyLineSegment, color: &str, width: f64) {
-        self.inner.add_line_segment(&segment.inner, color, width);
+    /// Returns:
+    ///     Camera: An oblique camera
+    ///
+    /// Raises:
+    ///     ValueError: If the view is degenerate or width or height is not
+    ///         positive
+    pub fn cavalier(
+        position: &PyPoint3D,
+        target: &PyPoint3D,
+        up: &PyVector3D,
+        width: f64,
+        height: f64,
+    ) -> PyResult<Self> {
+        to_py_camera(core::svg::Camera::cavalier(
+            position.inner,
+            target.inner,
+            up.inner,
+            width,
+            height,
+        ))
     }
 
-    #[pyo3(signature = (triangle, stroke="#000000", fill=None, width=1.0))]
-    #[pyo3(text_signature = "($self, triangle, stroke='#000000', fill=None, width=1.0)")]
-    /// Add a triangle to the scene.
+    #[staticmethod]
+    #[pyo3(text_signature = "(position, target, up, width, height)")]
+    /// Create a cabinet oblique camera (depth at half scale, 45 degrees).
     ///
     /// Args:
-    ///     triangle (Triangle): The triangle to render
-    ///     stroke (str): Stroke color (default: "#000000")
-    ///     fill (str | None): Fill color or None for no fill (default: None)
-    ///     width (float): Stroke width in pixels (default: 1.0)
+    ///     position (Point3D): Camera position in 3D space
+    ///     target (Point3D): Point the camera is looking at
+    ///     up (Vector3D): Up direction vector
+    ///     width (float): View width
+    ///     height (float): View height
     ///
-    /// Example:
-    ///     >>> from common_core_geometry import Triangle
-    ///     >>> tri = Triangle(Point3D(0, 0, 0), Point3D(1, 0, 0), Point3D(0, 1, 0))
-    ///     >>> renderer.add_triangle(tri, stroke="#0000ff", fill="#ccccff", width=1.5)
-    pub fn add_triangle(
-        &mut self,
-        triangle: &PyTriangle,
-        stroke: &str,
-        fill: Option<&str>,
+    /// Returns:
+    ///     Camera: An oblique camera
+    ///
+    /// Raises:
+    ///     ValueError: If the view is degenerate or width or height is not
+    ///         positive
+    pub fn cabinet(
+        position: &PyPoint3D,
+        target: &PyPoint3D,
+        up: &PyVector3D,
         width: f64,
-    ) {
-        self.inner
-            .add_triangle(&triangle.inner, stroke, fill, width);
+        height: f64,
+    ) -> PyResult<Self> {
+        to_py_camera(core::svg::Camera::cabinet(
+            position.inner,
+            target.inner,
+            up.inner,
+            width,
+            height,
+        ))
     }
 
-    #[pyo3(signature = (sphere, color="#000000", width=1.0))]
-    #[pyo3(text_signature = "($self, sphere, color='#000000', width=1.0)")]
-    /// Add a sphere to the scene (rendered as wireframe).
+    #[staticmethod]
+    #[pyo3(signature = (position, target, up, window, near=0.1, far=100.0))]
+    #[pyo3(text_signature = "(position, target, up, window, near=0.1, far=100.0)")]
+    /// Create an off-axis (asymmetric frustum) perspective camera.
     ///
     /// Args:
-    ///     sphere (Sphere): The sphere to render
-    ///     color (str): Wireframe color (default: "#000000")
-    ///     width (float): Line width in pixels (default: 1.0)
+    ///     position (Point3D): Camera position in 3D space
+    ///     target (Point3D): Point the camera is looking at
+    ///     up (Vector3D): Up direction vector
+    ///     window (tuple[float, float, float, float]): Left, right, bottom
+    ///         and top edges of the view on the near plane
+    ///     near (float): Near clipping plane distance (default: 0.1)
+    ///     far (float): Far clipping plane distance (default: 100.0)
+    ///
+    /// Returns:
+    ///     Camera: An off-axis perspective camera
+    ///
+    /// Raises:
+    ///     ValueError: If the view is degenerate, left >= right,
+    ///         bottom >= top, or near is not in (0, far)
     ///
     /// Example:
-    ///     >>> from common_core_geometry import Sphere
-    ///     >>> sphere = Sphere(Point3D(0, 0, 0), 1.0)
-    ///     >>> renderer.add_sphere(sphere, color="#ff00ff", width=1.5)
-    pub fn add_sphere(&mut self, sphere: &PySphere, color: &str, width: f64) {
-        self.inner.add_sphere(&sphere.inner, color, width);
+    ///     >>> camera = Camera.off_axis(
+    ///     ...     Point3D(0, 0, 10),
+    ///     ...     Point3D(0, 0, 0),
+    ///     ...     Vector3D(0, 1, 0),
+    ///     ...     (-0.05, 0.15, -0.1, 0.1)
+    ///     ... )
+    pub fn off_axis(
+        position: &PyPoint3D,
+        target: &PyPoint3D,
+        up: &PyVector3D,
+        window: (f64, f64, f64, f64),
+        near: f64,
+        far: f64,
+    ) -> PyResult<Self> {
+        let (left, right, bottom, top) = window;
+        to_py_camera(core::svg::Camera::off_axis(
+            position.inner,
+            target.inner,
+            up.inner,
+            [left, right, bottom, top],
+            near,
+            far,
+        ))
     }
 
-    #[pyo3(signature = (aabb, color="#000000", width=1.0))]
-    #[pyo3(text_signature = "($self, aabb, color='#000000', width=1.0)")]
-    /// Add an axis-aligned bounding box to the scene.
+    #[staticmethod]
+    #[pyo3(signature = (position, target, up, fov=180.0, aspect=1.0))]
+    #[pyo3(text_signature = "(position, target, up, fov=180.0, aspect=1.0)")]
+    /// Create an equidistant fisheye camera.
+    ///
+    /// The angle away from the viewing direction maps linearly to the
+    /// distance from the image centre, so straight lines are drawn curved.
     ///
     /// Args:
-    ///     aabb (AABB): The bounding box to render
-    ///     color (str): Line color (default: "#000000")
-    ///     width (float): Line width in pixels (default: 1.0)
+    ///     position (Point3D): Camera position in 3D space
+    ///     target (Point3D): Point the camera is looking at
+    ///     up (Vector3D): Up direction vector
+    ///     fov (float): Field of view across the image height in degrees,
+    ///         up to 360 (default: 180.0)
+    ///     aspect (float): Aspect ratio (width/height) (default: 1.0)
+    ///
+    /// Returns:
+    ///     Camera: A fisheye camera
+    ///
+    /// Raises:
+    ///     ValueError: If the view is degenerate, fov is not in (0, 360] or
+    ///         aspect is not positive
     ///
     /// Example:
-    ///     >>> from common_core_geometry import AABB
-    ///     >>> box = AABB(Point3D(0, 0, 0), Point3D(1, 1, 1))
-    ///     >>> renderer.add_aabb(box, color="#00ffff", width=2.0)
-    pub fn add_aabb(&mut self, aabb: &PyAABB, color: &str, width: f64) {
-        self.inner.add_aabb(&aabb.inner, color, width);
+    ///     >>> camera = Camera.fisheye(Point3D(0, 0, 5), Point3D(0, 0, 0), Vector3D(0, 1, 0))
+    pub fn fisheye(
+        position: &PyPoint3D,
+        target: &PyPoint3D,
+        up: &PyVector3D,
+        fov: f64,
+        aspect: f64,
+    ) -> PyResult<Self> {
+        to_py_camera(core::svg::Camera::fisheye(
+            position.inner,
+            target.inner,
+            up.inner,
+            fov,
+            aspect,
+        ))
     }
 
-    #[pyo3(text_signature = "($self)")]
-    /// Render the scene to an SVG string.
+    #[staticmethod]
+    #[pyo3(text_signature = "(position, target, up)")]
+    /// Create an equirectangular (360 by 180 degree panorama) camera.
+    ///
+    /// Longitude runs across the image and latitude up it, with the viewing
+    /// direction at the centre. Lines crossing the seam behind the camera
+    /// are split in two.
+    ///
+    /// Args:
+    ///     position (Point3D): Camera position in 3D space
+    ///     target (Point3D): Point shown at the image centre
+    ///     up (Vector3D): Up direction vector
     ///
     /// Returns:
-    ///     str: The SVG markup
+    ///     Camera: An equirectangular camera
+    ///
+    /// Raises:
+    ///     ValueError: If position equals target or up is zero or parallel to
+    ///         the viewing direction
     ///
     /// Example:
-    ///     >>> svg = renderer.render()
-    ///     >>> print(svg[:50])
-    ///     <svg xmlns="http://www.w3.org/2000/svg" width="80
-    pub fn render(&self) -> String {
-        self.inner.to_svg_string()
+    ///     >>> camera = Camera.equirectangular(Point3D(0, 0, 0), Point3D(0, 0, -1), Vector3D(0, 1, 0))
+    pub fn equirectangular(
+        position: &PyPoint3D,
+        target: &PyPoint3D,
+        up: &PyVector3D,
+    ) -> PyResult<Self> {
+        to_py_camera(core::svg::Camera::equirectangular(
+            position.inner,
+            target.inner,
+            up.inner,
+        ))
     }
 
-    #[pyo3(text_signature = "($self, path, /)")]
-    /// Save the rendered SVG to a file.
+    #[getter]
+    /// Whether straight lines stay straight (False for fisheye and
+    /// equirectangular cameras).
+    pub fn is_linear(&self) -> bool {
+        self.inner.is_linear()
+    }
+
+    #[getter]
+    /// Get the camera position.
+    pub fn position(&self) -> PyPoint3D {
+        PyPoint3D {
+            inner: self.inner.position(),
+        }
+    }
+
+    #[getter]
+    /// Get the point the camera is looking at.
+    pub fn target(&self) -> PyPoint3D {
+        PyPoint3D {
+            inner: self.inner.target(),
+        }
+    }
+
+    #[getter]
+    /// Get the camera up vector.
+    pub fn up(&self) -> PyVector3D {
+        PyVector3D {
+            inner: self.inner.up(),
+        }
+    }
+
+    #[pyo3(text_signature = "($self, target, /)")]
+    /// Point the camera at a new target, keeping its position.
     ///
     /// Args:
-    ///     path (str): File path to save to
+    ///     target (Point3D): New point to look at
     ///
     /// Raises:
-    ///     IOError: If the file cannot be written
+    ///     ValueError: If target equals the camera position or the new
+    ///         viewing direction is parallel to the up vector
     ///
     /// Example:
-    ///     >>> renderer.save("scene.svg")
-    pub fn save(&self, path: &str) -> PyResult<()> {
+    ///     >>> camera.look_at(Point3D(1, 0, 0))
+    pub fn look_at(&mut self, target: &PyPoint3D) -> PyResult<()> {
         self.inner
-            .to_file(path)
+            .look_at(target.inner)
+            .map_err(|e| pyo3::exceptions::PyValueError::new_err(format!("{}", e)))
+    }
+
+    #[pyo3(text_signature = "($self, yaw, pitch, /)")]
+    /// Rotate the camera around its target.
+    ///
+    /// The camera first turns `yaw` degrees about its up vector, then rises
+    /// `pitch` degrees towards it. The elevation stops just short of the
+    /// poles so the view never lines up with the up vector.
+    ///
+    /// Args:
+    ///     yaw (float): Rotation about the up vector in degrees
+    ///     pitch (float): Upward rotation in degrees (negative moves down)
+    ///
+    /// Example:
+    ///     >>> camera.orbit(30.0, 10.0)
+    pub fn orbit(&mut self, yaw: f64, pitch: f64) {
+        self.inner.orbit(yaw, pitch);
+    }
+
+    #[pyo3(text_signature = "($self, dx, dy, /)")]
+    /// Slide the camera and its target within the view plane.
+    ///
+    /// Args:
+    ///     dx (float): Distance to move right, in world units
+    ///     dy (float): Distance to move up, in world units
+    ///
+    /// Example:
+    ///     >>> camera.pan(1.0, 0.0)
+    pub fn pan(&mut self, dx: f64, dy: f64) {
+        self.inner.pan(dx, dy);
+    }
+
+    #[pyo3(text_signature = "($self, distance, /)")]
+    /// Move the camera towards its target.
+    ///
+    /// Args:
+    ///     distance (float): Distance to move; negative values move away
+    ///
+    /// Raises:
+    ///     ValueError: If the camera would reach or pass its target
+    ///
+    /// Example:
+    ///     >>> camera.dolly(2.0)
+    pub fn dolly(&mut self, distance: f64) -> PyResult<()> {
+        self.inner
+            .dolly(distance)
+            .map_err(|e| pyo3::exceptions::PyValueError::new_err(format!("{}", e)))
+    }
+
+    #[pyo3(text_signature = "($self, factor, /)")]
+    /// Magnify the view without moving the camera.
+    ///
+    /// Perspective cameras narrow their field of view; orthographic cameras
+    /// shrink their view width and height.
+    ///
+    /// Args:
+    ///     factor (float): Magnification; values below 1 zoom out
+    ///
+    /// Raises:
+    ///     ValueError: If factor is not positive or would leave the field of
+    ///         view or view size out of range
+    ///
+    /// Example:
+    ///     >>> camera.zoom(2.0)
+    pub fn zoom(&mut self, factor: f64) -> PyResult<()> {
+        self.inner
+            .zoom(factor)
+            .map_err(|e| pyo3::exceptions::PyValueError::new_err(format!("{}", e)))
+    }
+
+    #[pyo3(text_signature = "($self, sphere, /)")]
+    /// Frame a sphere, keeping the current viewing direction.
+    ///
+    /// The camera aims at the sphere's centre and moves back until the whole
+    /// sphere is visible. A perspective camera's near and far planes are
+    /// moved if needed.
+    ///
+    /// Args:
+    ///     sphere (Sphere): Sphere to frame
+    ///
+    /// Raises:
+    ///     ValueError: If the sphere's radius is not positive
+    ///
+    /// Example:
+    ///     >>> camera.fit_to_sphere(Sphere(Point3D(0, 0, 0), 2.0))
+    pub fn fit_to_sphere(&mut self, sphere: &PySphere) -> PyResult<()> {
+        self.inner
+            .fit_to_sphere(&sphere.inner)
+            .map_err(|e| pyo3::exceptions::PyValueError::new_err(format!("{}", e)))
+    }
+
+    #[pyo3(text_signature = "($self, aabb, /)")]
+    /// Frame an axis-aligned box, keeping the current viewing direction.
+    ///
+    /// Args:
+    ///     aabb (AABB): Box to frame
+    ///
+    /// Raises:
+    ///     ValueError: If the box is a single point
+    ///
+    /// Example:
+    ///     >>> camera.fit_to_aabb(AABB(Point3D(-1, -1, -1), Point3D(1, 1, 1)))
+    pub fn fit_to_aabb(&mut self, aabb: &PyAABB) -> PyResult<()> {
+        self.inner
+            .fit_to_aabb(&aabb.inner)
+            .map_err(|e| pyo3::exceptions::PyValueError::new_err(format!("{}", e)))
+    }
+
+    #[pyo3(text_signature = "($self, x, y, width, height)")]
+    /// Create a picking ray through a pixel of the rendered image.
+    ///
+    /// Args:
+    ///     x (float): Horizontal pixel coordinate, 0 at the left edge
+    ///     y (float): Vertical pixel coordinate, 0 at the top edge
+    ///     width (int): Image width in pixels, as passed to SVGRenderer
+    ///     height (int): Image height in pixels, as passed to SVGRenderer
+    ///
+    /// Returns:
+    ///     Ray: Ray from the camera through the pixel. Perspective rays start
+    ///     at the camera position; orthographic rays start on the camera
+    ///     plane and run along the viewing direction.
+    ///
+    /// Example:
+    ///     >>> ray = camera.screen_to_ray(400, 300, 800, 600)
+    ///     >>> hit = ray.intersect_sphere(sphere)
+    pub fn screen_to_ray(&self, x: f64, y: f64, width: usize, height: usize) -> PyRay {
+        PyRay {
+            inner: self.inner.screen_to_ray(x, y, width, height),
+        }
+    }
+
+    #[pyo3(text_signature = "($self, x, y, depth)")]
+    /// Map normalized device coordinates back to a world-space point.
+    ///
+    /// Args:
+    ///     x (float): Horizontal coordinate in [-1, 1], -1 at the left edge
+    ///     y (float): Vertical coordinate in [-1, 1], -1 at the bottom edge
+    ///     depth (float): Distance in front of the camera along its viewing
+    ///         direction
+    ///
+    /// Returns:
+    ///     Point3D: The world-space point
+    ///
+    /// Example:
+    ///     >>> center = camera.unproject(0.0, 0.0, 5.0)
+    pub fn unproject(&self, x: f64, y: f64, depth: f64) -> PyPoint3D {
+        PyPoint3D {
+            inner: self.inner.unproject(x, y, depth),
+        }
+    }
+
+    fn __repr__(&self) -> String {
+        match &self.inner {
+            core::svg::Camera::Perspective { fov, aspect, .. } => {
+                format!("Camera.perspective(fov={}, aspect={})", fov, aspect)
+            }
+            core::svg::Camera::Orthographic { width, height, .. } => {
+                format!("Camera.orthographic(width={}, height={})", width, height)
+            }
+            core::svg::Camera::Oblique {
+                angle, depth_scale, ..
+            } => {
+                format!(
+                    "Camera.oblique(angle={}, depth_scale={})",
+                    angle, depth_scale
+                )
+            }
+            core::svg::Camera::OffAxis {
+                left,
+                right,
+                bottom,
+                top,
+                ..
+            } => {
+                format!(
+                    "Camera.off_axis(window=({}, {}, {}, {}))",
+                    left, right, bottom, top
+                )
+            }
+            core::svg::Camera::Fisheye { fov, aspect, .. } => {
+                format!("Camera.fisheye(fov={}, aspect={})", fov, aspect)
+            }
+            core::svg::Camera::Equirectangular { .. } => "Camera.equirectangular()".to_string(),
+        }
+    }
+}
+
+fn to_py_camera(result: Result<core::svg::Camera, core::GeometryError>) -> PyResult<PyCamera> {
+    match result {
+        Ok(inner) => Ok(PyCamera { inner }),
+        Err(e) => Err(pyo3::exceptions::PyValueError::new_err(format!("{}", e))),
+    }
+}
+
+fn to_light(result: Result<core::svg::Light, core::GeometryError>) -> PyResult<core::svg::Light> {
+    result.map_err(|e| pyo3::exceptions::PyValueError::new_err(format!("{}", e)))
+}
+
+#[pyclass(name = "Style")]
+#[derive(Clone)]
+pub struct PyStyle {
+    pub inner: core::svg::Style,
+}
+
+#[pymethods]
+impl PyStyle {
+    #[new]
+    #[pyo3(signature = (**kwargs))]
+    #[pyo3(
+        text_signature = "(*, stroke=None, fill=None, stroke_width=None, opacity=None, dash_array=None, line_cap=None, line_join=None, marker_start=None, marker_end=None, css_class=None, id=None, title=None, font_size=None, font_family=None, text_anchor=None)"
+    )]
+    /// Create a style for the elements drawn by SVGRenderer.
+    ///
+    /// Every field is optional. Unset fields are left to the SVG defaults
+    /// or to a stylesheet set with SVGRenderer.set_stylesheet, except the
+    /// fill, which defaults to none. CSS rules override the attributes a
+    /// style writes, so a class is enough to restyle elements.
+    ///
+    /// Args:
+    ///     stroke (str): Stroke color
+    ///     fill (str): Fill color
+    ///     stroke_width (float): Stroke width in pixels
+    ///     opacity (float): Opacity from 0 (transparent) to 1 (opaque)
+    ///     dash_array (list[float]): Alternating dash and gap lengths in pixels
+    ///     line_cap (str): "butt", "round" or "square"
+    ///     line_join (str): "miter", "round" or "bevel"
+    ///     marker_start (str): "arrow", "dot", or "url(#id)" for a marker
+    ///         defined by the page embedding the SVG
+    ///     marker_end (str): As marker_start, for the end of the line
+    ///     css_class (str): CSS class names, separated by spaces
+    ///     id (str): Element id. Elements drawn in several pieces number
+    ///         the later ones "id-2", "id-3" and so on
+    ///     title (str): Tooltip text
+    ///     font_size (float): Text size in pixels, for labels
+    ///     font_family (str): Font family, for labels
+    ///     text_anchor (str): "start", "middle" or "end": which end of a
+    ///         label lies on its anchor point
+    ///
+    /// Returns:
+    ///     Style: A new style
+    ///
+    /// Raises:
+    ///     ValueError: If line_cap, line_join, a marker or text_anchor is not
+    ///         recognized
+    ///     TypeError: If an unknown keyword argument is given
+    ///
+    /// Example:
+    ///     >>> from common_core_geometry.svg import Style
+    ///     >>> style = Style(stroke="#ff0000", stroke_width=2.0,
+    ///     ...               marker_end="arrow", css_class="axis")
+    pub fn new(kwargs: Option<&Bound<'_, pyo3::types::PyDict>>) -> PyResult<Self> {
+        let mut style = core::svg::Style::default();
+        let parse = |value: &Bound<'_, PyAny>| -> PyResult<Option<String>> { value.extract() };
+        for (key, value) in kwargs.into_iter().flatten() {
+            let key: String = key.extract()?;
+            match key.as_str() {
+                "stroke" => style.stroke = parse(&value)?,
+                "fill" => style.fill = parse(&value)?,
+                "stroke_width" => style.stroke_width = value.extract()?,
+                "opacity" => style.opacity = value.extract()?,
+                "dash_array" => style.dash_array = value.extract()?,
+                "line_cap" => style.line_cap = parse_keyword(&value)?,
+                "line_join" => style.line_join = parse_keyword(&value)?,
+                "marker_start" => style.marker_start = parse_keyword(&value)?,
+                "marker_end" => style.marker_end = parse_keyword(&value)?,
+                "css_class" => style.class = parse(&value)?,
+                "id" => style.id = parse(&value)?,
+                "title" => style.title = parse(&value)?,
+                "font_size" => style.font_size = value.extract()?,
+                "font_family" => style.font_family = parse(&value)?,
+                "text_anchor" => style.text_anchor = parse_keyword(&value)?,
+                _ => {
+                    return Err(pyo3::exceptions::PyTypeError::new_err(format!(
+                        "Style() got an unexpected keyword argument '{}'",
+                        key
+                    )))
+                }
+            }
+        }
+        Ok(PyStyle { inner: style })
+    }
+
+    fn __repr__(&self) -> String {
+        format!("Style({:?})", self.inner)
+    }
+}
+
+/// Parse an optional keyword such as "round" or "arrow".
+fn parse_keyword<T>(value: &Bound<'_, PyAny>) -> PyResult<Option<T>>
+where
+    T: std::str::FromStr<Err = core::GeometryError>,
+{
+    let value: Option<String> = value.extract()?;
+    value
+        .map(|v| v.parse())
+        .transpose()
+        .map_err(|e| pyo3::exceptions::PyValueError::new_err(format!("{}", e)))
+}
+
+/// `style`, if given, with its unset fields taken from `defaults`.
+fn merged_style(style: Option<&PyStyle>, defaults: core::svg::Style) -> core::svg::Style {
+    match style {
+        Some(style) => style.inner.with_defaults(&defaults),
+        None => defaults,
+    }
+}
+
+#[pyclass(name = "SVGRenderer")]
+pub struct PySVGRenderer {
+    inner: core::svg::SVGRenderer,
+}
+
+#[pymethods]
+impl PySVGRenderer {
+    #[new]
+    #[pyo3(text_signature = "(width, height, camera, /)")]
+    /// Create a new SVG renderer.
+    ///
+    /// Args:
+    ///     width (int): Canvas width in pixels
+    ///     height (int): Canvas height in pixels
+    ///     camera (Camera): Camera for viewing the 3D scene
+    ///
+    /// Returns:
+    ///     SVGRenderer: A new SVG renderer
+    ///
+    /// Example:
+    ///     >>> from common_core_geometry import Point3D, Vector3D
+    ///     >>> from common_core_geometry.svg import Camera, SVGRenderer
+    ///     >>> camera = Camera.perspective(
+    ///     ...     Point3D(4, 4, 4),
+    ///     ...     Point3D(0, 0, 0),
+    ///     ...     Vector3D(0, 1, 0),
+    ///     ...     60.0,
+    ///     ...     16/9
+    ///     ... )
+    ///     >>> renderer = SVGRenderer(800, 600, camera)
+    pub fn new(width: usize, height: usize, camera: &PyCamera) -> Self {
+        PySVGRenderer {
+            inner: core::svg::SVGRenderer::new(width, height, camera.inner.clone()),
+        }
+    }
+
+    #[pyo3(text_signature = "($self, color, /)")]
+    /// Set the background color of the SVG.
+    ///
+    /// Args:
+    ///     color (str): Color in CSS format (e.g., "#ffffff", "white")
+    ///
+    /// Example:
+    ///     >>> renderer.set_background("#f0f0f0")
+    pub fn set_background(&mut self, color: &str) {
+        self.inner.set_background(color);
+    }
+
+    #[pyo3(text_signature = "($self, mode, /)")]
+    /// Set the order in which elements are drawn.
+    ///
+    /// Every element remembers how far it is from the camera, so the mode
+    /// can be changed at any time before rendering.
+    ///
+    /// Args:
+    ///     mode (str): One of:
+    ///         - "none": insertion order (default)
+    ///         - "painter": farthest element first, by the depth of its centre
+    ///         - "bsp": like "painter", but triangles are ordered with a BSP
+    ///           tree and split where needed, so intersecting or cyclically
+    ///           overlapping triangles are drawn correctly
+    ///
+    /// Raises:
+    ///     ValueError: If mode is not one of the above
+    ///
+    /// Example:
+    ///     >>> renderer.set_depth_sort("painter")
+    pub fn set_depth_sort(&mut self, mode: &str) -> PyResult<()> {
+        let mode = mode
+            .parse()
+            .map_err(|e| pyo3::exceptions::PyValueError::new_err(format!("{}", e)))?;
+        self.inner.set_depth_sort(mode);
+        Ok(())
+    }
+
+    #[pyo3(text_signature = "($self, mode, /)")]
+    /// Set how the hidden parts of lines and triangle outlines are drawn.
+    ///
+    /// Visibility is computed against every triangle, box and sphere in the
+    /// scene, which are all treated as opaque. Points and sphere outlines
+    /// are never hidden.
+    ///
+    /// Args:
+    ///     mode (str): One of:
+    ///         - "shown": draw everything as added (default)
+    ///         - "dashed": draw hidden parts as dashed lines
+    ///         - "removed": leave hidden parts out
+    ///
+    /// Raises:
+    ///     ValueError: If mode is not one of the above
+    ///
+    /// Example:
+    ///     >>> renderer.add_aabb(AABB(Point3D(-1, -1, -1), Point3D(1, 1, 1)))
+    ///     >>> renderer.set_hidden_lines("dashed")
+    pub fn set_hidden_lines(&mut self, mode: &str) -> PyResult<()> {
+        let mode = mode
+            .parse()
+            .map_err(|e| pyo3::exceptions::PyValueError::new_err(format!("{}", e)))?;
+        self.inner.set_hidden_lines(mode);
+        Ok(())
+    }
+
+    #[pyo3(signature = (color="#ffffff", intensity=1.0))]
+    #[pyo3(text_signature = "($self, color='#ffffff', intensity=1.0)")]
+    /// Add light that reaches every surface equally.
+    ///
+    /// Args:
+    ///     color (str): Light color as "#rgb" or "#rrggbb" (default: "#ffffff")
+    ///     intensity (float): Brightness multiplier (default: 1.0)
+    ///
+    /// Raises:
+    ///     ValueError: If color is not a hex color or intensity is negative
+    ///
+    /// Example:
+    ///     >>> renderer.add_ambient_light(intensity=0.2)
+    pub fn add_ambient_light(&mut self, color: &str, intensity: f64) -> PyResult<()> {
+        let light = to_light(core::svg::Light::ambient(color, intensity))?;
+        self.inner.add_light(light);
+        Ok(())
+    }
+
+    #[pyo3(signature = (direction, color="#ffffff", intensity=1.0))]
+    #[pyo3(text_signature = "($self, direction, color='#ffffff', intensity=1.0)")]
+    /// Add parallel light, as from the sun.
+    ///
+    /// Args:
+    ///     direction (Vector3D): Direction the light travels in
+    ///     color (str): Light color as "#rgb" or "#rrggbb" (default: "#ffffff")
+    ///     intensity (float): Brightness multiplier (default: 1.0)
+    ///
+    /// Raises:
+    ///     ValueError: If direction is zero, color is not a hex color or
+    ///         intensity is negative
+    ///
+    /// Example:
+    ///     >>> renderer.add_directional_light(Vector3D(-1, -2, -1), intensity=0.8)
+    pub fn add_directional_light(
+        &mut self,
+        direction: &PyVector3D,
+        color: &str,
+        intensity: f64,
+    ) -> PyResult<()> {
+        let light = to_light(core::svg::Light::directional(
+            direction.inner,
+            color,
+            intensity,
+        ))?;
+        self.inner.add_light(light);
+        Ok(())
+    }
+
+    #[pyo3(signature = (position, color="#ffffff", intensity=1.0))]
+    #[pyo3(text_signature = "($self, position, color='#ffffff', intensity=1.0)")]
+    /// Add light shining in all directions from a point.
+    ///
+    /// The light does not fall off with distance.
+    ///
+    /// Args:
+    ///     position (Point3D): Position of the light
+    ///     color (str): Light color as "#rgb" or "#rrggbb" (default: "#ffffff")
+    ///     intensity (float): Brightness multiplier (default: 1.0)
+    ///
+    /// Raises:
+    ///     ValueError: If color is not a hex color or intensity is negative
+    ///
+    /// Example:
+    ///     >>> renderer.add_point_light(Point3D(5, 5, 5), color="#ffeecc")
+    pub fn add_point_light(
+        &mut self,
+        position: &PyPoint3D,
+        color: &str,
+        intensity: f64,
+    ) -> PyResult<()> {
+        let light = to_light(core::svg::Light::point(position.inner, color, intensity))?;
+        self.inner.add_light(light);
+        Ok(())
+    }
+
+    #[pyo3(text_signature = "($self)")]
+    /// Remove all lights.
+    pub fn clear_lights(&mut self) {
+        self.inner.clear_lights();
+    }
+
+    #[pyo3(text_signature = "($self, mode, /)")]
+    /// Set how triangles and spheres added from now on are shaded.
+    ///
+    /// Fills are lit by the renderer's lights according to the direction
+    /// the surface faces. Fills that are not "#rgb" or "#rrggbb" strings are
+    /// left unchanged, and without any lights shaded shapes are black.
+    /// Spheres get a radial gradient in either lit mode.
+    ///
+    /// Args:
+    ///     mode (str): One of:
+    ///         - "none": fills are drawn as given (default)
+    ///         - "flat": one color per triangle, lit along its normal
+    ///         - "gouraud": each vertex is lit along its own normal (see
+    ///           add_triangle) and the colors are blended across the
+    ///           triangle with a linear gradient
+    ///
+    /// Raises:
+    ///     ValueError: If mode is not one of the above
+    ///
+    /// Example:
+    ///     >>> renderer.add_ambient_light(intensity=0.3)
+    ///     >>> renderer.add_directional_light(Vector3D(0, -1, -1), intensity=0.7)
+    ///     >>> renderer.set_shading("flat")
+    pub fn set_shading(&mut self, mode: &str) -> PyResult<()> {
+        let mode = mode
+            .parse()
+            .map_err(|e| pyo3::exceptions::PyValueError::new_err(format!("{}", e)))?;
+        self.inner.set_shading(mode);
+        Ok(())
+    }
+
+    #[pyo3(text_signature = "($self, enabled, /)")]
+    /// Enable or disable dropping triangles that face away from the camera.
+    ///
+    /// A triangle's front is the side its normal points to, following the
+    /// right-hand rule over vertices a, b, c. Applies to triangles added from
+    /// now on.
+    ///
+    /// Args:
+    ///     enabled (bool): Whether to cull back faces (default: off)
+    pub fn set_back_face_culling(&mut self, enabled: bool) {
+        self.inner.set_back_face_culling(enabled);
+    }
+
+    #[pyo3(signature = (point, color="#000000", size=3.0, style=None))]
+    #[pyo3(text_signature = "($self, point, color='#000000', size=3.0, style=None)")]
+    /// Add a point to the scene.
+    ///
+    /// Args:
+    ///     point (Point3D): The point to render
+    ///     color (str): Point color (default: "#000000")
+    ///     size (float): Point radius in pixels (default: 3.0)
+    ///     style (Style | None): Further styling; the fields it sets take
+    ///         precedence over color (default: None)
+    ///
+    /// Example:
+    ///     >>> renderer.add_point(Point3D(0, 0, 0), color="#ff0000", size=5.0)
+    pub fn add_point(
+        &mut self,
+        point: &PyPoint3D,
+        color: &str,
+        size: f64,
+        style: Option<&PyStyle>,
+    ) {
+        let defaults = core::svg::Style::new(color, 1.0).with_fill(color);
+        self.inner
+            .add_point_with_style(&point.inner, size, &merged_style(style, defaults));
+    }
+
+    #[pyo3(signature = (segment, color="#000000", width=1.0, style=None))]
+    #[pyo3(text_signature = "($self, segment, color='#000000', width=1.0, style=None)")]
+    /// Add a line segment to the scene.
+    ///
+    /// Args:
+    ///     segment (LineSegment): The line segment to render
+    ///     color (str): Line color (default: "#000000")
+    ///     width (float): Line width in pixels (default: 1.0)
+    ///     style (Style | None): Further styling; the fields it sets take
+    ///         precedence over color and width (default: None)
+    ///
+    /// Example:
+    ///     >>> from common_core_geometry import LineSegment
+    ///     >>> segment = LineSegment(Point3D(0, 0, 0), Point3D(1, 1, 1))
+    ///     >>> renderer.add_line_segment(segment, color="#00ff00", width=2.0)
+    ///     >>> renderer.add_line_segment(segment, style=Style(marker_end="arrow"))
+    pub fn add_line_segment(
+        &mut self,
+        segment: &PyLineSegment,
+        color: &str,
+        width: f64,
+        style: Option<&PyStyle>,
+    ) {
+        let defaults = core::svg::Style::new(color, width);
+        self.inner
+            .add_line_segment_with_style(&segment.inner, &merged_style(style, defaults));
+    }
+
+    #[pyo3(signature = (triangle, stroke="#000000", fill=None, width=1.0, style=None, normals=None))]
+    #[pyo3(
+        text_signature = "($self, triangle, stroke='#000000', fill=None, width=1.0, style=None, normals=None)"
+    )]
+    /// Add a triangle to the scene.
+    ///
+    /// Args:
+    ///     triangle (Triangle): The triangle to render
+    ///     stroke (str): Stroke color (default: "#000000")
+    ///     fill (str | None): Fill color or None for no fill (default: None)
+    ///     width (float): Stroke width in pixels (default: 1.0)
+    ///     style (Style | None): Further styling; the fields it sets take
+    ///         precedence over stroke, fill and width (default: None)
+    ///     normals (tuple[Vector3D, Vector3D, Vector3D] | None): Surface
+    ///         normals at a, b and c for "gouraud" shading, or None to use
+    ///         the triangle's own normal (default: None)
+    ///
+    /// Example:
+    ///     >>> from common_core_geometry import Triangle
+    ///     >>> tri = Triangle(Point3D(0, 0, 0), Point3D(1, 0, 0), Point3D(0, 1, 0))
+    ///     >>> renderer.add_triangle(tri, stroke="#0000ff", fill="#ccccff", width=1.5)
+    pub fn add_triangle(
+        &mut self,
+        triangle: &PyTriangle,
+        stroke: &str,
+        fill: Option<&str>,
+        width: f64,
+        style: Option<&PyStyle>,
+        normals: Option<(PyVector3D, PyVector3D, PyVector3D)>,
+    ) {
+        let mut defaults = core::svg::Style::new(stroke, width);
+        defaults.fill = fill.map(str::to_string);
+        let style = merged_style(style, defaults);
+        match normals {
+            Some((a, b, c)) => self.inner.add_triangle_with_normals(
+                &triangle.inner,
+                [a.inner, b.inner, c.inner],
+                &style,
+            ),
+            None => self.inner.add_triangle_with_style(&triangle.inner, &style),
+        }
+    }
+
+    #[pyo3(signature = (sphere, color="#000000", width=1.0, fill=None, style=None))]
+    #[pyo3(text_signature = "($self, sphere, color='#000000', width=1.0, fill=None, style=None)")]
+    /// Add a sphere to the scene.
+    ///
+    /// The sphere is drawn as its exact outline seen from the camera, which
+    /// is an ellipse for all but the fisheye and equirectangular cameras.
+    /// With shading enabled, a hex fill color is lit with a radial gradient
+    /// from the sphere's brightest visible point to its rim.
+    ///
+    /// Args:
+    ///     sphere (Sphere): The sphere to render
+    ///     color (str): Outline color (default: "#000000")
+    ///     width (float): Line width in pixels (default: 1.0)
+    ///     fill (str | None): Fill color or None for no fill (default: None)
+    ///     style (Style | None): Further styling; the fields it sets take
+    ///         precedence over color, width and fill (default: None)
+    ///
+    /// Example:
+    ///     >>> from common_core_geometry import Sphere
+    ///     >>> sphere = Sphere(Point3D(0, 0, 0), 1.0)
+    ///     >>> renderer.add_sphere(sphere, color="#ff00ff", width=1.5)
+    ///     >>> renderer.add_sphere(sphere, fill="#ffcc00")
+    pub fn add_sphere(
+        &mut self,
+        sphere: &PySphere,
+        color: &str,
+        width: f64,
+        fill: Option<&str>,
+        style: Option<&PyStyle>,
+    ) {
+        let mut defaults = core::svg::Style::new(color, width);
+        defaults.fill = fill.map(str::to_string);
+        self.inner
+            .add_sphere_with_style(&sphere.inner, &merged_style(style, defaults));
+    }
+
+    #[pyo3(signature = (aabb, color="#000000", width=1.0, style=None))]
+    #[pyo3(text_signature = "($self, aabb, color='#000000', width=1.0, style=None)")]
+    /// Add an axis-aligned bounding box to the scene.
+    ///
+    /// The box is drawn as its 12 edges. With an id in the style, the first
+    /// edge takes the id and the others are numbered "id-2" to "id-12".
+    ///
+    /// Args:
+    ///     aabb (AABB): The bounding box to render
+    ///     color (str): Line color (default: "#000000")
+    ///     width (float): Line width in pixels (default: 1.0)
+    ///     style (Style | None): Further styling; the fields it sets take
+    ///         precedence over color and width (default: None)
+    ///
+    /// Example:
+    ///     >>> from common_core_geometry import AABB
+    ///     >>> box = AABB(Point3D(0, 0, 0), Point3D(1, 1, 1))
+    ///     >>> renderer.add_aabb(box, color="#00ffff", width=2.0)
+    pub fn add_aabb(&mut self, aabb: &PyAABB, color: &str, width: f64, style: Option<&PyStyle>) {
+        let defaults = core::svg::Style::new(color, width);
+        self.inner
+            .add_aabb_with_style(&aabb.inner, &merged_style(style, defaults));
+    }
+
+    #[pyo3(signature = (ray, color="#000000", width=1.0, style=None))]
+    #[pyo3(text_signature = "($self, ray, color='#000000', width=1.0, style=None)")]
+    /// Add a ray to the scene, drawn as an arrow.
+    ///
+    /// The part of the ray between its t_min and t_max that lies in the
+    /// view is drawn, with an arrowhead where it leaves the view. Only the
+    /// start of the ray counts towards scene_bounds().
+    ///
+    /// Args:
+    ///     ray (Ray): The ray to render
+    ///     color (str): Line color (default: "#000000")
+    ///     width (float): Line width in pixels (default: 1.0)
+    ///     style (Style | None): Further styling; the fields it sets take
+    ///         precedence over color and width, and its markers replace the
+    ///         arrowhead (default: None)
+    ///
+    /// Example:
+    ///     >>> from common_core_geometry import Ray
+    ///     >>> ray = Ray(Point3D(0, 0, 0), Vector3D(1, 0, 0))
+    ///     >>> renderer.add_ray(ray, color="#ff0000")
+    pub fn add_ray(&mut self, ray: &PyRay, color: &str, width: f64, style: Option<&PyStyle>) {
+        let defaults = core::svg::Style::new(color, width);
+        self.inner
+            .add_ray_with_style(&ray.inner, &merged_style(style, defaults));
+    }
+
+    #[pyo3(signature = (origin, vector, color="#000000", width=1.0, style=None))]
+    #[pyo3(text_signature = "($self, origin, vector, color='#000000', width=1.0, style=None)")]
+    /// Add a vector to the scene, drawn as an arrow from a point.
+    ///
+    /// Args:
+    ///     origin (Point3D): Where the arrow starts
+    ///     vector (Vector3D): The vector to render
+    ///     color (str): Line color (default: "#000000")
+    ///     width (float): Line width in pixels (default: 1.0)
+    ///     style (Style | None): Further styling; the fields it sets take
+    ///         precedence over color and width, and its markers replace the
+    ///         arrowhead (default: None)
+    ///
+    /// Raises:
+    ///     ValueError: If the vector is zero
+    ///
+    /// Example:
+    ///     >>> renderer.add_vector(Point3D(0, 0, 0), Vector3D(1, 2, 0), color="#0000ff")
+    pub fn add_vector(
+        &mut self,
+        origin: &PyPoint3D,
+        vector: &PyVector3D,
+        color: &str,
+        width: f64,
+        style: Option<&PyStyle>,
+    ) -> PyResult<()> {
+        let defaults = core::svg::Style::new(color, width);
+        self.inner
+            .add_vector_with_style(&origin.inner, &vector.inner, &merged_style(style, defaults))
+            .map_err(|e| pyo3::exceptions::PyValueError::new_err(format!("{}", e)))
+    }
+
+    #[pyo3(signature = (plane, center, size, stroke="#000000", fill=None, style=None))]
+    #[pyo3(
+        text_signature = "($self, plane, center, size, stroke='#000000', fill=None, style=None)"
+    )]
+    /// Add a square patch of a plane to the scene.
+    ///
+    /// The patch is centred on the point of the plane closest to center.
+    /// With a fill it is drawn as two triangles that hide and are depth
+    /// sorted like any others, and its outline is drawn on top.
+    ///
+    /// Args:
+    ///     plane (Plane): The plane to render
+    ///     center (Point3D): Point the patch is centred near
+    ///     size (float): Width of the patch in scene units
+    ///     stroke (str): Outline color (default: "#000000")
+    ///     fill (str | None): Fill color or None for no fill (default: None)
+    ///     style (Style | None): Further styling; the fields it sets take
+    ///         precedence over stroke and fill (default: None)
+    ///
+    /// Raises:
+    ///     ValueError: If size is not positive
+    ///
+    /// Example:
+    ///     >>> ground = Plane.from_point_normal(Point3D(0, 0, 0), Vector3D(0, 1, 0))
+    ///     >>> renderer.add_plane(ground, Point3D(0, 0, 0), 4.0, fill="#ccccff")
+    pub fn add_plane(
+        &mut self,
+        plane: &PyPlane,
+        center: &PyPoint3D,
+        size: f64,
+        stroke: &str,
+        fill: Option<&str>,
+        style: Option<&PyStyle>,
+    ) -> PyResult<()> {
+        let mut defaults = core::svg::Style::new(stroke, 1.0);
+        defaults.fill = fill.map(str::to_string);
+        self.inner
+            .add_plane_with_style(
+                &plane.inner,
+                &center.inner,
+                size,
+                &merged_style(style, defaults),
+            )
+            .map_err(|e| pyo3::exceptions::PyValueError::new_err(format!("{}", e)))
+    }
+
+    #[pyo3(text_signature = "($self, css)")]
+    /// Embed a CSS stylesheet in the SVG output.
+    ///
+    /// The stylesheet is written in a <style> element at the top of the
+    /// document, so elements can be styled by the classes and ids given
+    /// in their Style. CSS rules take precedence over style attributes.
+    ///
+    /// Args:
+    ///     css (str): The CSS rules
+    ///
+    /// Example:
+    ///     >>> renderer.set_stylesheet(".axis { stroke: #888888; }")
+    ///     >>> renderer.add_line_segment(segment, style=Style(css_class="axis"))
+    pub fn set_stylesheet(&mut self, css: &str) {
+        self.inner.set_stylesheet(css);
+    }
+
+    #[pyo3(signature = (point, text, color="#000000", font_size=12.0, offset=None, style=None))]
+    #[pyo3(
+        text_signature = "($self, point, text, color='#000000', font_size=12.0, offset=None, style=None)"
+    )]
+    /// Add a text label at a point of the scene.
+    ///
+    /// Like every annotation, labels are never hidden and are drawn in
+    /// front of the other elements of their layer when depth sorting.
+    ///
+    /// Args:
+    ///     point (Point3D): The point the label is anchored to
+    ///     text (str): The label text
+    ///     color (str): Text color (default: "#000000")
+    ///     font_size (float): Text size in pixels (default: 12.0)
+    ///     offset (tuple[float, float] | None): If given, the text is moved
+    ///         this many pixels from the point and joined to it by a leader
+    ///         line (default: None)
+    ///     style (Style | None): Further styling; the fields it sets take
+    ///         precedence over color and font_size. Its markers are drawn
+    ///         on the leader line (default: None)
+    ///
+    /// Example:
+    ///     >>> renderer.add_label(Point3D(1, 0, 0), "A")
+    ///     >>> renderer.add_label(Point3D(0, 1, 0), "apex", offset=(20, -20),
+    ///     ...                    style=Style(marker_start="dot"))
+    pub fn add_label(
+        &mut self,
+        point: &PyPoint3D,
+        text: &str,
+        color: &str,
+        font_size: f64,
+        offset: Option<(f64, f64)>,
+        style: Option<&PyStyle>,
+    ) {
+        let defaults = core::svg::Style::new(color, 1.0).with_font_size(font_size);
+        let style = merged_style(style, defaults);
+        match offset {
+            Some(offset) => self
+                .inner
+                .add_leader_label(&point.inner, offset, text, &style),
+            None => self.inner.add_label(&point.inner, text, &style),
+        }
+    }
+
+    #[pyo3(signature = (start, end, offset, color="#000000", style=None))]
+    #[pyo3(text_signature = "($self, start, end, offset, color='#000000', style=None)")]
+    /// Add a dimension showing the distance between two points.
+    ///
+    /// Extension lines run from the points to the points moved by offset,
+    /// where a dimension line with arrowheads joins them. The distance is
+    /// written above its middle with two decimals.
+    ///
+    /// Args:
+    ///     start (Point3D): First point
+    ///     end (Point3D): Second point
+    ///     offset (Vector3D): How far the dimension line is drawn from the
+    ///         points
+    ///     color (str): Line and text color (default: "#000000")
+    ///     style (Style | None): Further styling; the fields it sets take
+    ///         precedence over color, and its markers replace the
+    ///         arrowheads (default: None)
+    ///
+    /// Returns:
+    ///     float: The distance between the points
+    ///
+    /// Example:
+    ///     >>> renderer.add_linear_dimension(
+    ///     ...     Point3D(0, 0, 0), Point3D(2, 0, 0), Vector3D(0, -0.5, 0))
+    ///     2.0
+    pub fn add_linear_dimension(
+        &mut self,
+        start: &PyPoint3D,
+        end: &PyPoint3D,
+        offset: &PyVector3D,
+        color: &str,
+        style: Option<&PyStyle>,
+    ) -> f64 {
+        let style = merged_style(style, core::svg::Style::new(color, 1.0));
+        self.inner
+            .add_linear_dimension(&start.inner, &end.inner, &offset.inner, &style)
+    }
+
+    #[pyo3(signature = (vertex, start, end, radius, color="#000000", style=None))]
+    #[pyo3(text_signature = "($self, vertex, start, end, radius, color='#000000', style=None)")]
+    /// Add a dimension showing the angle between two directions.
+    ///
+    /// An arc with arrowheads is drawn around the vertex from the direction
+    /// of start to the direction of end, with the angle in degrees written
+    /// above its middle.
+    ///
+    /// Args:
+    ///     vertex (Point3D): Corner of the angle
+    ///     start (Point3D): A point along the first side
+    ///     end (Point3D): A point along the second side
+    ///     radius (float): Radius of the arc, in scene units
+    ///     color (str): Line and text color (default: "#000000")
+    ///     style (Style | None): Further styling; the fields it sets take
+    ///         precedence over color, and its markers replace the
+    ///         arrowheads (default: None)
+    ///
+    /// Returns:
+    ///     float: The angle in radians
+    ///
+    /// Raises:
+    ///     ValueError: If start or end is at the vertex or radius is not
+    ///         positive
+    ///
+    /// Example:
+    ///     >>> renderer.add_angular_dimension(
+    ///     ...     Point3D(0, 0, 0), Point3D(1, 0, 0), Point3D(0, 1, 0), 0.5)
+    ///     1.5707963267948966
+    pub fn add_angular_dimension(
+        &mut self,
+        vertex: &PyPoint3D,
+        start: &PyPoint3D,
+        end: &PyPoint3D,
+        radius: f64,
+        color: &str,
+        style: Option<&PyStyle>,
+    ) -> PyResult<f64> {
+        let style = merged_style(style, core::svg::Style::new(color, 1.0));
+        self.inner
+            .add_angular_dimension(&vertex.inner, &start.inner, &end.inner, radius, &style)
+            .map_err(|e| pyo3::exceptions::PyValueError::new_err(format!("{}", e)))
+    }
+
+    #[pyo3(signature = (entries, x=10.0, y=10.0, style=None))]
+    #[pyo3(text_signature = "($self, entries, x=10.0, y=10.0, style=None)")]
+    /// Add a legend box explaining the styles used in the scene.
+    ///
+    /// Each entry is listed as its text after a swatch drawn in its style:
+    /// a square for filled styles, a short line otherwise.
+    ///
+    /// Args:
+    ///     entries (list[tuple[str, Style]]): Text and style of each row
+    ///     x (float): Left edge of the box in pixels (default: 10.0)
+    ///     y (float): Top edge of the box in pixels (default: 10.0)
+    ///     style (Style | None): Style of the box, white with a black
+    ///         outline by default; its font_size sets the size of the rows
+    ///         (default: None)
+    ///
+    /// Example:
+    ///     >>> renderer.add_legend([
+    ///     ...     ("Edges", Style(stroke="#ff0000")),
+    ///     ...     ("Faces", Style(fill="#ccccff")),
+    ///     ... ], x=20, y=20)
+    pub fn add_legend(
+        &mut self,
+        entries: Vec<(String, PyStyle)>,
+        x: f64,
+        y: f64,
+        style: Option<&PyStyle>,
+    ) {
+        let entries: Vec<(&str, &core::svg::Style)> = entries
+            .iter()
+            .map(|(text, style)| (text.as_str(), &style.inner))
+            .collect();
+        let style = style.map(|style| style.inner.clone()).unwrap_or_default();
+        self.inner.add_legend(&entries, x, y, &style);
+    }
+
+    #[pyo3(signature = (origin, length=1.0, style=None))]
+    #[pyo3(text_signature = "($self, origin, length=1.0, style=None)")]
+    /// Add an axis triad showing the X, Y and Z directions.
+    ///
+    /// Each axis is drawn as an arrow from the origin, red for X, green for
+    /// Y and blue for Z, and labelled just past its tip. The axes are
+    /// helpers: they are left out of scene_bounds().
+    ///
+    /// Args:
+    ///     origin (Point3D): Where the axes start
+    ///     length (float): Length of each axis in scene units (default: 1.0)
+    ///     style (Style | None): Further styling; a stroke replaces the
+    ///         three axis colors, and an id is suffixed with "-x", "-y"
+    ///         and "-z" for the axes (default: None)
+    ///
+    /// Raises:
+    ///     ValueError: If length is not positive
+    ///
+    /// Example:
+    ///     >>> renderer.add_axes(Point3D(0, 0, 0), length=5.0)
+    pub fn add_axes(
+        &mut self,
+        origin: &PyPoint3D,
+        length: f64,
+        style: Option<&PyStyle>,
+    ) -> PyResult<()> {
+        let style = style.map(|style| style.inner.clone()).unwrap_or_default();
+        self.inner
+            .add_axes(&origin.inner, length, &style)
+            .map_err(|e| pyo3::exceptions::PyValueError::new_err(format!("{}", e)))
+    }
+
+    #[pyo3(signature = (plane, center, size, divisions=10, color="#cccccc", style=None))]
+    #[pyo3(
+        text_signature = "($self, plane, center, size, divisions=10, color='#cccccc', style=None)"
+    )]
+    /// Add a square grid lying on a plane.
+    ///
+    /// The grid is centred on the point of the plane closest to center and
+    /// split into the same number of cells each way. It is a helper: it is
+    /// left out of scene_bounds().
+    ///
+    /// Args:
+    ///     plane (Plane): Plane the grid lies on
+    ///     center (Point3D): Point the grid is centred near
+    ///     size (float): Width of the grid in scene units
+    ///     divisions (int): Number of cells along each side (default: 10)
+    ///     color (str): Line color (default: "#cccccc")
+    ///     style (Style | None): Further styling; the fields it sets take
+    ///         precedence over color (default: None)
+    ///
+    /// Raises:
+    ///     ValueError: If size is not positive or divisions is zero
+    ///
+    /// Example:
+    ///     >>> ground = Plane.from_point_normal(Point3D(0, 0, 0), Vector3D(0, 1, 0))
+    ///     >>> renderer.add_grid(ground, Point3D(0, 0, 0), 10.0, divisions=10)
+    pub fn add_grid(
+        &mut self,
+        plane: &PyPlane,
+        center: &PyPoint3D,
+        size: f64,
+        divisions: usize,
+        color: &str,
+        style: Option<&PyStyle>,
+    ) -> PyResult<()> {
+        let style = merged_style(style, core::svg::Style::new(color, 1.0));
+        self.inner
+            .add_grid(&plane.inner, &center.inner, size, divisions, &style)
+            .map_err(|e| pyo3::exceptions::PyValueError::new_err(format!("{}", e)))
+    }
+
+    #[pyo3(signature = (start, end, spacing, size=6.0, color="#000000", style=None))]
+    #[pyo3(text_signature = "($self, start, end, spacing, size=6.0, color='#000000', style=None)")]
+    /// Add labelled tick marks along a line.
+    ///
+    /// A mark is drawn across the line every spacing from start, labelled
+    /// with its distance from start. Labels go below the line, or left of
+    /// it if it is vertical on screen.
+    ///
+    /// Args:
+    ///     start (Point3D): Where the ticks start, labelled 0
+    ///     end (Point3D): Where the ticks stop
+    ///     spacing (float): Distance between ticks in scene units
+    ///     size (float): Length of each mark in pixels (default: 6.0)
+    ///     color (str): Mark and label color (default: "#000000")
+    ///     style (Style | None): Further styling; the fields it sets take
+    ///         precedence over color (default: None)
+    ///
+    /// Raises:
+    ///     ValueError: If spacing or size is not positive or start equals
+    ///         end
+    ///
+    /// Example:
+    ///     >>> renderer.add_ticks(Point3D(0, 0, 0), Point3D(5, 0, 0), 1.0)
+    pub fn add_ticks(
+        &mut self,
+        start: &PyPoint3D,
+        end: &PyPoint3D,
+        spacing: f64,
+        size: f64,
+        color: &str,
+        style: Option<&PyStyle>,
+    ) -> PyResult<()> {
+        let style = merged_style(style, core::svg::Style::new(color, 1.0));
+        self.inner
+            .add_ticks(&start.inner, &end.inner, spacing, size, &style)
+            .map_err(|e| pyo3::exceptions::PyValueError::new_err(format!("{}", e)))
+    }
+
+    #[pyo3(text_signature = "($self)")]
+    /// Get the box around everything added to the scene so far.
+    ///
+    /// Points, segments, triangles, spheres and boxes count whether they
+    /// are in view or not; annotations and helpers such as axes and grids
+    /// do not.
+    ///
+    /// Returns:
+    ///     AABB | None: The scene bounds, or None if the scene is empty
+    ///
+    /// Example:
+    ///     >>> renderer.add_point(Point3D(1, 2, 3))
+    ///     >>> renderer.scene_bounds()
+    ///     AABB(min=Point3D(1, 2, 3), max=Point3D(1, 2, 3))
+    pub fn scene_bounds(&self) -> Option<PyAABB> {
+        self.inner.scene_bounds().map(|inner| PyAABB { inner })
+    }
+
+    #[pyo3(signature = (color="#888888", style=None))]
+    #[pyo3(text_signature = "($self, color='#888888', style=None)")]
+    /// Draw the edges of the scene bounds as a dashed box.
+    ///
+    /// Unlike boxes added with add_aabb, the bounds hide nothing when
+    /// hidden lines are removed.
+    ///
+    /// Args:
+    ///     color (str): Line color (default: "#888888")
+    ///     style (Style | None): Further styling; the fields it sets take
+    ///         precedence over color and the dashes (default: None)
+    ///
+    /// Returns:
+    ///     AABB | None: The box drawn, or None if the scene is empty
+    ///
+    /// Example:
+    ///     >>> bounds = renderer.add_scene_bounds()
+    pub fn add_scene_bounds(&mut self, color: &str, style: Option<&PyStyle>) -> Option<PyAABB> {
+        let style = merged_style(style, core::svg::Style::new(color, 1.0));
+        self.inner
+            .add_scene_bounds(&style)
+            .map(|inner| PyAABB { inner })
+    }
+
+    #[pyo3(signature = (name, visible=true, style=None, transform=None))]
+    #[pyo3(text_signature = "($self, name, visible=True, style=None, transform=None)")]
+    /// Add a layer and draw the elements added from now on into it.
+    ///
+    /// Each layer is written as a <g> element that Inkscape and Illustrator
+    /// open as a layer, with an id made from its name. Layers are stacked
+    /// in the order they are added, above the elements drawn outside any
+    /// layer. Ids given to the layer's elements are prefixed with the
+    /// layer's id, so the same key can be used in several layers.
+    ///
+    /// Args:
+    ///     name (str): Layer name, shown by editors
+    ///     visible (bool): Whether the layer is displayed; hidden layers are
+    ///         still written, with display="none" (default: True)
+    ///     style (Style | None): Defaults for the fields the styles of the
+    ///         layer's elements leave unset (default: None)
+    ///     transform (str | None): SVG transform of the layer in screen
+    ///         space, such as "translate(10 0)" (default: None)
+    ///
+    /// Raises:
+    ///     ValueError: If another layer has the same id
+    ///
+    /// Example:
+    ///     >>> renderer.add_layer("Hidden Edges", style=Style(css_class="hidden"))
+    ///     >>> renderer.add_line_segment(segment, style=Style(id="edge"))
+    ///     >>> renderer.set_current_layer(None)
+    pub fn add_layer(
+        &mut self,
+        name: &str,
+        visible: bool,
+        style: Option<&PyStyle>,
+        transform: Option<&str>,
+    ) -> PyResult<()> {
+        let mut layer = core::svg::Layer::new(name).with_visible(visible);
+        if let Some(style) = style {
+            layer = layer.with_style(style.inner.clone());
+        }
+        if let Some(transform) = transform {
+            layer = layer.with_transform(transform);
+        }
+        self.inner
+            .add_layer(layer)
+            .map_err(|e| pyo3::exceptions::PyValueError::new_err(format!("{}", e)))
+    }
+
+    #[pyo3(text_signature = "($self, name, /)")]
+    /// Choose the layer the elements added from now on are drawn into.
+    ///
+    /// Args:
+    ///     name (str | None): Layer name, or None to draw outside any layer
+    ///
+    /// Raises:
+    ///     ValueError: If there is no layer with that name
+    ///
+    /// Example:
+    ///     >>> renderer.set_current_layer("Hidden Edges")
+    pub fn set_current_layer(&mut self, name: Option<&str>) -> PyResult<()> {
+        self.inner
+            .set_current_layer(name)
+            .map_err(|e| pyo3::exceptions::PyValueError::new_err(format!("{}", e)))
+    }
+
+    #[pyo3(text_signature = "($self, name, visible, /)")]
+    /// Show or hide a layer.
+    ///
+    /// Args:
+    ///     name (str): Layer name
+    ///     visible (bool): Whether the layer is displayed
+    ///
+    /// Raises:
+    ///     ValueError: If there is no layer with that name
+    ///
+    /// Example:
+    ///     >>> renderer.set_layer_visible("Hidden Edges", False)
+    pub fn set_layer_visible(&mut self, name: &str, visible: bool) -> PyResult<()> {
+        self.inner
+            .set_layer_visible(name, visible)
+            .map_err(|e| pyo3::exceptions::PyValueError::new_err(format!("{}", e)))
+    }
+
+    #[pyo3(signature = (layers=None))]
+    #[pyo3(text_signature = "($self, layers=None)")]
+    /// Render the scene to an SVG string.
+    ///
+    /// Args:
+    ///     layers (list[str] | None): Names of the layers to include, along
+    ///         with the elements outside any layer, or None for all layers.
+    ///         Hidden lines are computed from the included elements alone
+    ///         (default: None)
+    ///
+    /// Returns:
+    ///     str: The SVG markup
+    ///
+    /// Raises:
+    ///     ValueError: If a layer name is unknown
+    ///
+    /// Example:
+    ///     >>> svg = renderer.render()
+    ///     >>> print(svg[:50])
+    ///     <svg xmlns="http://www.w3.org/2000/svg" width="80
+    ///     >>> edges_only = renderer.render(layers=["Hidden Edges"])
+    pub fn render(&self, layers: Option<Vec<String>>) -> PyResult<String> {
+        match layers {
+            Some(layers) => {
+                let names: Vec<&str> = layers.iter().map(String::as_str).collect();
+                self.inner
+                    .to_svg_string_with_layers(&names)
+                    .map_err(|e| pyo3::exceptions::PyValueError::new_err(format!("{}", e)))
+            }
+            None => Ok(self.inner.to_svg_string()),
+        }
+    }
+
+    #[pyo3(signature = (path, layers=None))]
+    #[pyo3(text_signature = "($self, path, layers=None)")]
+    /// Save the rendered SVG to a file.
+    ///
+    /// Args:
+    ///     path (str): File path to save to
+    ///     layers (list[str] | None): Names of the layers to include, as for
+    ///         render (default: None)
+    ///
+    /// Raises:
+    ///     IOError: If the file cannot be written
+    ///     ValueError: If a layer name is unknown
+    ///
+    /// Example:
+    ///     >>> renderer.save("scene.svg")
+    pub fn save(&self, path: &str, layers: Option<Vec<String>>) -> PyResult<()> {
+        let svg = self.render(layers)?;
+        std::fs::write(path, svg)
             .map_err(|e| pyo3::exceptions::PyIOError::new_err(format!("Failed to save SVG: {}", e)))
     }
 
@@ -296,12 +1788,13 @@ impl PySVGRenderer {
     }
 
     fn __repr__(&self) -> String {
-        format!("SVGRenderer()")
+        "SVGRenderer()".to_string()
     }
 }
 
 pub fn register_svg_module(parent: &Bound<'_, PyModule>) -> PyResult<()> {
     parent.add_class::<PyCamera>()?;
+    parent.add_class::<PyStyle>()?;
     parent.add_class::<PySVGRenderer>()?;
     Ok(())
 }
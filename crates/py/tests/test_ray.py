@@ -1,5 +1,5 @@
 import pytest
-from common_core_geometry import Point3D, Vector3D, Ray, Sphere, Plane, Triangle
+from common_core_geometry import Point3D, Vector3D, Ray, Sphere, Plane, Triangle, AABB
 
 
 class TestRayConstruction:
@@ -30,6 +30,49 @@ class TestRayConstruction:
             Ray(origin, direction)
 
 
+class TestRayInterval:
+    def test_default_interval(self):
+        ray = Ray(Point3D(0, 0, 0), Vector3D(1, 0, 0))
+        
+        assert ray.t_min == 0.0
+        assert ray.t_max == float("inf")
+
+    def test_invalid_interval_raises_error(self):
+        with pytest.raises(ValueError):
+            Ray(Point3D(0, 0, 0), Vector3D(1, 0, 0), t_min=2.0, t_max=1.0)
+
+    def test_t_max_limits_hits(self):
+        sphere = Sphere(Point3D(0, 0, 0), 1.0)
+        ray = Ray(Point3D(0, 0, 5), Vector3D(0, 0, -1), t_max=3.0)
+        
+        assert ray.intersect_sphere(sphere) is None
+
+    def test_t_min_skips_entry(self):
+        sphere = Sphere(Point3D(0, 0, 0), 1.0)
+        ray = Ray(Point3D(0, 0, 5), Vector3D(0, 0, -1), t_min=5.0)
+        
+        hit = ray.intersect_sphere(sphere)
+        assert hit is not None
+        assert abs(hit.t - 6.0) < 1e-10
+        assert not hit.front_face
+
+    def test_all_sphere_hits(self):
+        sphere = Sphere(Point3D(0, 0, 0), 1.0)
+        ray = Ray(Point3D(0, 0, 5), Vector3D(0, 0, -1))
+        
+        hits = ray.intersect_sphere_all(sphere)
+        assert [round(hit.t, 6) for hit in hits] == [4.0, 6.0]
+
+    def test_all_aabb_hits_from_inside(self):
+        aabb = AABB(Point3D(-1, -1, -1), Point3D(1, 1, 1))
+        ray = Ray(Point3D(0, 0, 0), Vector3D(1, 0, 0))
+        
+        hits = ray.intersect_aabb_all(aabb)
+        assert len(hits) == 1
+        assert abs(hits[0].t - 1.0) < 1e-10
+        assert not hits[0].front_face
+
+
 class TestRayPointAt:
     def test_point_at_zero(self):
         ray = Ray(Point3D(1, 2, 3), Vector3D(1, 0, 0))
@@ -59,23 +102,25 @@ class TestRaySphereIntersection:
         sphere = Sphere(Point3D(0, 0, 0), 1.0)
         ray = Ray(Point3D(0, 0, 5), Vector3D(0, 0, -1))
         
-        result = ray.intersect_sphere(sphere)
-        assert result is not None
+        hit = ray.intersect_sphere(sphere)
+        assert hit is not None
         
-        t, point = result
-        assert t > 0
-        assert abs(t - 4.0) < 1e-10
-        assert abs(point.z - 1.0) < 1e-10
+        assert hit.t > 0
+        assert abs(hit.t - 4.0) < 1e-10
+        assert abs(hit.point.z - 1.0) < 1e-10
+        assert abs(hit.normal.z - 1.0) < 1e-10
+        assert hit.front_face
+        assert hit.uv is not None
+        assert hit.barycentric is None
 
     def test_ray_hits_sphere_center(self):
         sphere = Sphere(Point3D(0, 0, 5), 1.0)
         ray = Ray(Point3D(0, 0, 0), Vector3D(0, 0, 1))
         
-        result = ray.intersect_sphere(sphere)
-        assert result is not None
+        hit = ray.intersect_sphere(sphere)
+        assert hit is not None
         
-        t, point = result
-        assert abs(t - 4.0) < 1e-10
+        assert abs(hit.t - 4.0) < 1e-10
 
     def test_ray_misses_sphere(self):
         sphere = Sphere(Point3D(0, 10, 0), 1.0)
@@ -88,8 +133,11 @@ class TestRaySphereIntersection:
         sphere = Sphere(Point3D(0, 0, 0), 5.0)
         ray = Ray(Point3D(0, 0, 0), Vector3D(1, 0, 0))
         
-        result = ray.intersect_sphere(sphere)
-        assert result is not None
+        hit = ray.intersect_sphere(sphere)
+        assert hit is not None
+        assert abs(hit.t - 5.0) < 1e-10
+        assert not hit.front_face
+        assert abs(hit.normal.x + 1.0) < 1e-10
 
     def test_ray_tangent_to_sphere(self):
         sphere = Sphere(Point3D(0, 1, 0), 1.0)
@@ -104,21 +152,23 @@ class TestRayPlaneIntersection:
         plane = Plane.from_point_normal(Point3D(0, 0, 5), Vector3D(0, 0, 1))
         ray = Ray(Point3D(0, 0, 0), Vector3D(0, 0, 1))
         
-        result = ray.intersect_plane(plane)
-        assert result is not None
+        hit = ray.intersect_plane(plane)
+        assert hit is not None
         
-        point = result
-        assert abs(point.z - 5.0) < 1e-10
+        assert abs(hit.t - 5.0) < 1e-10
+        assert abs(hit.point.z - 5.0) < 1e-10
+        assert not hit.front_face
 
     def test_ray_hits_plane_at_angle(self):
         plane = Plane.from_point_normal(Point3D(0, 0, 0), Vector3D(0, 1, 0))
         ray = Ray(Point3D(0, 5, 0), Vector3D(0, -1, 0))
         
-        result = ray.intersect_plane(plane)
-        assert result is not None
+        hit = ray.intersect_plane(plane)
+        assert hit is not None
         
-        point = result
-        assert abs(point.y - 0.0) < 1e-10
+        assert abs(hit.point.y - 0.0) < 1e-10
+        assert hit.front_face
+        assert abs(hit.normal.y - 1.0) < 1e-10
 
     def test_ray_parallel_to_plane(self):
         plane = Plane.from_point_normal(Point3D(0, 0, 5), Vector3D(0, 0, 1))
@@ -144,12 +194,16 @@ class TestRayTriangleIntersection:
         )
         ray = Ray(Point3D(0.25, 0.25, 5), Vector3D(0, 0, -1))
         
-        result = ray.intersect_triangle(triangle)
-        assert result is not None
+        hit = ray.intersect_triangle(triangle)
+        assert hit is not None
         
-        t, point = result
-        assert t > 0
-        assert abs(point.z - 0.0) < 1e-10
+        assert hit.t > 0
+        assert abs(hit.point.z - 0.0) < 1e-10
+        assert hit.front_face
+        w, u, v = hit.barycentric
+        assert abs(w - 0.5) < 1e-10
+        assert abs(u - 0.25) < 1e-10
+        assert abs(v - 0.25) < 1e-10
 
     def test_ray_hits_triangle_edge(self):
         triangle = Triangle(
@@ -207,6 +261,28 @@ class TestRayTriangleIntersection:
         assert result is None
 
 
+class TestRayAABBIntersection:
+    def test_ray_hits_aabb_face(self):
+        aabb = AABB(Point3D(-1, -1, -1), Point3D(1, 1, 1))
+        ray = Ray(Point3D(-5, 0, 0), Vector3D(1, 0, 0))
+        
+        hit = ray.intersect_aabb(aabb)
+        assert hit is not None
+        
+        assert abs(hit.t - 4.0) < 1e-10
+        assert abs(hit.normal.x + 1.0) < 1e-10
+        assert hit.front_face
+        u, v = hit.uv
+        assert abs(u - 0.5) < 1e-10
+        assert abs(v - 0.5) < 1e-10
+
+    def test_ray_misses_aabb(self):
+        aabb = AABB(Point3D(-1, -1, -1), Point3D(1, 1, 1))
+        ray = Ray(Point3D(-5, 5, 0), Vector3D(1, 0, 0))
+        
+        assert ray.intersect_aabb(aabb) is None
+
+
 class TestRayProtocols:
     def test_repr(self):
         ray = Ray(Point3D(1, 2, 3), Vector3D(1, 0, 0))
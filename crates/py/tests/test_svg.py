@@ -1,7 +1,7 @@
 import pytest
 import os
 import tempfile
-from common_core_geometry import Point3D, Vector3D, LineSegment, Triangle, Sphere, AABB, Camera, SVGRenderer
+from common_core_geometry import Point3D, Vector3D, LineSegment, Triangle, Sphere, AABB, Plane, Ray, Camera, SVGRenderer, Style
 
 
 class TestCamera:
@@ -31,6 +31,122 @@ class TestCamera:
         assert camera is not None
         assert "Camera" in repr(camera)
 
+    def test_screen_to_ray_center(self):
+        camera = Camera.perspective(
+            Point3D(0.0, 0.0, 5.0),
+            Point3D(0.0, 0.0, 0.0),
+            Vector3D(0.0, 1.0, 0.0),
+            60.0,
+            1.0
+        )
+        ray = camera.screen_to_ray(400.0, 300.0, 800, 600)
+        assert ray.origin.z == pytest.approx(5.0)
+        assert ray.direction.z == pytest.approx(-1.0)
+
+        hit = ray.intersect_sphere(Sphere(Point3D(0.0, 0.0, 0.0), 1.0))
+        assert hit is not None
+        assert hit.t == pytest.approx(4.0)
+
+    def test_unproject(self):
+        camera = Camera.orthographic(
+            Point3D(0.0, 0.0, 5.0),
+            Point3D(0.0, 0.0, 0.0),
+            Vector3D(0.0, 1.0, 0.0),
+            10.0,
+            10.0
+        )
+        point = camera.unproject(1.0, -1.0, 5.0)
+        assert point.x == pytest.approx(5.0)
+        assert point.y == pytest.approx(-5.0)
+        assert point.z == pytest.approx(0.0)
+
+    def test_invalid_parameters(self):
+        position = Point3D(0.0, 0.0, 5.0)
+        target = Point3D(0.0, 0.0, 0.0)
+        up = Vector3D(0.0, 1.0, 0.0)
+
+        with pytest.raises(ValueError):
+            Camera.perspective(position, target, up, 0.0, 1.0)
+        with pytest.raises(ValueError):
+            Camera.perspective(position, target, up, 60.0, 1.0, near=10.0, far=10.0)
+        with pytest.raises(ValueError):
+            Camera.perspective(position, position, up, 60.0, 1.0)
+        with pytest.raises(ValueError):
+            Camera.perspective(position, target, Vector3D(0.0, 0.0, 1.0), 60.0, 1.0)
+        with pytest.raises(ValueError):
+            Camera.orthographic(position, target, up, 0.0, 10.0)
+
+    def test_orbit_pan_dolly(self):
+        camera = Camera.perspective(
+            Point3D(0.0, 0.0, 5.0),
+            Point3D(0.0, 0.0, 0.0),
+            Vector3D(0.0, 1.0, 0.0),
+            60.0,
+            1.0
+        )
+        camera.orbit(90.0, 0.0)
+        assert camera.position.x == pytest.approx(5.0)
+        assert camera.position.z == pytest.approx(0.0)
+
+        camera.pan(0.0, 1.0)
+        assert camera.target.y == pytest.approx(1.0)
+
+        camera.dolly(2.0)
+        assert camera.position.x == pytest.approx(3.0)
+        with pytest.raises(ValueError):
+            camera.dolly(5.0)
+        with pytest.raises(ValueError):
+            camera.zoom(0.0)
+
+    def test_projection_modes(self):
+        target = Point3D(0.0, 0.0, 0.0)
+        eye = Point3D(0.0, 0.0, 10.0)
+        up = Vector3D(0.0, 1.0, 0.0)
+        cameras = [
+            Camera.isometric(target, up, 10.0, 10.0),
+            Camera.dimetric(target, up, 10.0, 10.0),
+            Camera.trimetric(target, up, 30.0, 20.0, 10.0, 10.0),
+            Camera.cavalier(eye, target, up, 10.0, 10.0),
+            Camera.cabinet(eye, target, up, 10.0, 10.0),
+            Camera.oblique(eye, target, up, 10.0, 10.0, angle=30.0, depth_scale=0.75),
+            Camera.off_axis(eye, target, up, (-0.05, 0.15, -0.1, 0.1)),
+            Camera.fisheye(eye, target, up),
+            Camera.equirectangular(eye, target, up),
+        ]
+        for camera in cameras:
+            renderer = SVGRenderer(400, 400, camera)
+            renderer.add_aabb(AABB(Point3D(-1.0, -1.0, -1.0), Point3D(1.0, 1.0, 1.0)))
+            assert "NaN" not in renderer.render()
+
+        assert cameras[0].is_linear
+        assert not cameras[7].is_linear
+        assert "fisheye" in repr(cameras[7])
+
+        with pytest.raises(ValueError):
+            Camera.off_axis(eye, target, up, (0.1, -0.1, -0.1, 0.1))
+        with pytest.raises(ValueError):
+            Camera.fisheye(eye, target, up, fov=400.0)
+        with pytest.raises(ValueError):
+            Camera.equirectangular(eye, eye, up)
+
+    def test_look_at_and_fit(self):
+        camera = Camera.perspective(
+            Point3D(0.0, 0.0, 5.0),
+            Point3D(0.0, 0.0, 0.0),
+            Vector3D(0.0, 1.0, 0.0),
+            60.0,
+            1.0
+        )
+        camera.look_at(Point3D(1.0, 0.0, 0.0))
+        assert camera.target.x == pytest.approx(1.0)
+
+        camera.fit_to_sphere(Sphere(Point3D(0.0, 0.0, 0.0), 2.0))
+        assert camera.target.x == pytest.approx(0.0)
+        assert camera.position.distance_to(Point3D(0.0, 0.0, 0.0)) == pytest.approx(4.0)
+
+        camera.fit_to_aabb(AABB(Point3D(-1.0, -1.0, -1.0), Point3D(1.0, 1.0, 1.0)))
+        assert camera.target.x == pytest.approx(0.0)
+
 
 class TestSVGRenderer:
     def test_basic_construction(self):
@@ -96,6 +212,95 @@ class TestSVGRenderer:
         )
         renderer.add_triangle(triangle, stroke="#0000ff", fill="#ccccff", width=1.5)
     
+    def test_depth_sort(self):
+        camera = Camera.perspective(
+            Point3D(0.0, 0.0, 5.0),
+            Point3D(0.0, 0.0, 0.0),
+            Vector3D(0.0, 1.0, 0.0),
+            60.0,
+            1.0
+        )
+        renderer = SVGRenderer(800, 600, camera)
+        
+        def triangle(z):
+            return Triangle(Point3D(-1.0, -1.0, z), Point3D(1.0, -1.0, z), Point3D(0.0, 1.0, z))
+        
+        renderer.add_triangle(triangle(1.0), fill="#ff0000")
+        renderer.add_triangle(triangle(-3.0), fill="#0000ff")
+        
+        svg = renderer.render()
+        assert svg.index("#ff0000") < svg.index("#0000ff")
+        
+        renderer.set_depth_sort("painter")
+        svg = renderer.render()
+        assert svg.index("#0000ff") < svg.index("#ff0000")
+        
+        renderer.set_depth_sort("bsp")
+        svg = renderer.render()
+        assert svg.index("#0000ff") < svg.index("#ff0000")
+        
+        with pytest.raises(ValueError):
+            renderer.set_depth_sort("z-buffer")
+    
+    def test_hidden_lines(self):
+        camera = Camera.perspective(
+            Point3D(4.0, 3.0, 5.0),
+            Point3D(0.0, 0.0, 0.0),
+            Vector3D(0.0, 1.0, 0.0),
+            60.0,
+            1.0
+        )
+        renderer = SVGRenderer(800, 800, camera)
+        renderer.add_aabb(AABB(Point3D(-1.0, -1.0, -1.0), Point3D(1.0, 1.0, 1.0)))
+        
+        renderer.set_hidden_lines("removed")
+        assert renderer.render().count("<line") == 9
+        
+        renderer.set_hidden_lines("dashed")
+        svg = renderer.render()
+        assert svg.count("<line") == 12
+        assert svg.count("stroke-dasharray") == 3
+        
+        with pytest.raises(ValueError):
+            renderer.set_hidden_lines("invisible")
+    
+    def test_shading_and_back_face_culling(self):
+        camera = Camera.perspective(
+            Point3D(0.0, 0.0, 5.0),
+            Point3D(0.0, 0.0, 0.0),
+            Vector3D(0.0, 1.0, 0.0),
+            60.0,
+            1.0
+        )
+        renderer = SVGRenderer(800, 800, camera)
+        renderer.add_ambient_light(intensity=0.2)
+        renderer.add_directional_light(Vector3D(0.0, 0.0, -1.0), intensity=0.6)
+        front = Triangle(Point3D(-1.0, -1.0, 0.0), Point3D(1.0, -1.0, 0.0), Point3D(0.0, 1.0, 0.0))
+        back = Triangle(Point3D(-1.0, -1.0, 0.0), Point3D(0.0, 1.0, 0.0), Point3D(1.0, -1.0, 0.0))
+        
+        renderer.set_shading("flat")
+        renderer.add_triangle(front, fill="#ff8000")
+        assert 'fill="#cc6600"' in renderer.render()
+        
+        renderer.set_shading("gouraud")
+        up = Vector3D(0.0, 0.0, 1.0)
+        renderer.add_triangle(front, fill="#ff8000", normals=(up, Vector3D(1.0, 0.0, 0.0), up))
+        svg = renderer.render()
+        assert "<linearGradient" in svg
+        assert "url(#triangle-shade-" in svg
+        with pytest.raises(ValueError):
+            renderer.set_shading("phong")
+        
+        renderer.set_back_face_culling(True)
+        renderer.add_triangle(back, fill="#00ff00")
+        assert "#00ff00" not in renderer.render()
+        
+        with pytest.raises(ValueError):
+            renderer.add_point_light(Point3D(0.0, 0.0, 5.0), color="white")
+        with pytest.raises(ValueError):
+            renderer.add_directional_light(Vector3D(0.0, 0.0, 0.0))
+        renderer.clear_lights()
+    
     def test_add_sphere(self):
         camera = Camera.perspective(
             Point3D(0.0, 0.0, 5.0),
@@ -107,6 +312,229 @@ class TestSVGRenderer:
         renderer = SVGRenderer(800, 600, camera)
         sphere = Sphere(Point3D(0.0, 0.0, 0.0), 1.0)
         renderer.add_sphere(sphere, color="#ff00ff", width=2.0)
+        assert "<ellipse" in renderer.render()
+    
+    def test_shaded_sphere(self):
+        camera = Camera.perspective(
+            Point3D(0.0, 0.0, 5.0),
+            Point3D(0.0, 0.0, 0.0),
+            Vector3D(0.0, 1.0, 0.0),
+            60.0,
+            1.0
+        )
+        renderer = SVGRenderer(800, 600, camera)
+        sphere = Sphere(Point3D(0.0, 0.0, 0.0), 1.0)
+        renderer.add_sphere(sphere, fill="#ff0000")
+        assert 'fill="#ff0000"' in renderer.render()
+        
+        renderer.add_directional_light(Vector3D(-1.0, 0.0, 0.0))
+        renderer.set_shading("flat")
+        renderer.add_sphere(sphere, fill="#ff0000")
+        svg = renderer.render()
+        assert "<radialGradient" in svg
+        assert "url(#sphere-shade-" in svg
+    
+    def test_styles(self):
+        camera = Camera.perspective(
+            Point3D(0.0, 0.0, 5.0),
+            Point3D(0.0, 0.0, 0.0),
+            Vector3D(0.0, 1.0, 0.0),
+            60.0,
+            1.0
+        )
+        renderer = SVGRenderer(800, 600, camera)
+        renderer.set_stylesheet(".axis { stroke-width: 3; }")
+        segment = LineSegment(Point3D(-1.0, 0.0, 0.0), Point3D(1.0, 0.0, 0.0))
+        style = Style(
+            stroke="#ff0000",
+            dash_array=[4.0, 2.0],
+            line_cap="round",
+            marker_end="arrow",
+            css_class="axis",
+            id="x-axis",
+            title="X axis",
+        )
+        renderer.add_line_segment(segment, color="#00ff00", style=style)
+        svg = renderer.render()
+        assert "<style>" in svg
+        assert 'id="x-axis"' in svg
+        assert 'class="axis"' in svg
+        assert 'stroke="#ff0000"' in svg
+        assert 'stroke-dasharray="4.00 2.00"' in svg
+        assert 'marker-end="url(#arrow-ff0000)"' in svg
+        assert "<title>X axis</title>" in svg
+        
+        box = AABB(Point3D(-1.0, -1.0, -1.0), Point3D(1.0, 1.0, 1.0))
+        renderer.add_aabb(box, style=Style(id="box", opacity=0.5))
+        svg = renderer.render()
+        assert 'id="box-12"' in svg
+        assert 'opacity="0.500"' in svg
+        
+        with pytest.raises(ValueError):
+            Style(line_cap="pointy")
+        with pytest.raises(ValueError):
+            Style(marker_end="star")
+        with pytest.raises(TypeError):
+            Style(colour="#ff0000")
+    
+    def test_annotations(self):
+        camera = Camera.orthographic(
+            Point3D(0.0, 0.0, 10.0),
+            Point3D(0.0, 0.0, 0.0),
+            Vector3D(0.0, 1.0, 0.0),
+            10.0,
+            10.0
+        )
+        renderer = SVGRenderer(500, 500, camera)
+        renderer.add_label(Point3D(1.0, 1.0, 0.0), "A & B", color="#ff0000")
+        renderer.add_label(Point3D(0.0, 0.0, 0.0), "origin", offset=(20.0, -10.0))
+        distance = renderer.add_linear_dimension(
+            Point3D(-2.0, 0.0, 0.0),
+            Point3D(2.0, 0.0, 0.0),
+            Vector3D(0.0, -1.0, 0.0),
+        )
+        assert distance == pytest.approx(4.0)
+        angle = renderer.add_angular_dimension(
+            Point3D(0.0, 0.0, 0.0),
+            Point3D(1.0, 0.0, 0.0),
+            Point3D(0.0, 1.0, 0.0),
+            0.5,
+        )
+        assert angle == pytest.approx(1.5707963267948966)
+        renderer.add_legend([
+            ("Edges", Style(stroke="#ff0000")),
+            ("Faces", Style(fill="#0000ff")),
+        ])
+        
+        svg = renderer.render()
+        assert "A &amp; B</text>" in svg
+        assert 'fill="#ff0000" font-size="12.00"' in svg
+        assert ">origin</text>" in svg
+        assert ">4.00</text>" in svg
+        assert "90.0\u00b0</text>" in svg
+        assert "marker-end=\"url(#arrow-000000)\"" in svg
+        assert ">Edges</text>" in svg
+        assert ">Faces</text>" in svg
+        
+        with pytest.raises(ValueError):
+            renderer.add_angular_dimension(
+                Point3D(0.0, 0.0, 0.0),
+                Point3D(0.0, 0.0, 0.0),
+                Point3D(0.0, 1.0, 0.0),
+                0.5,
+            )
+        with pytest.raises(ValueError):
+            Style(text_anchor="center")
+    
+    def test_scene_helpers(self):
+        camera = Camera.orthographic(
+            Point3D(0.0, 0.0, 10.0),
+            Point3D(0.0, 0.0, 0.0),
+            Vector3D(0.0, 1.0, 0.0),
+            10.0,
+            10.0
+        )
+        renderer = SVGRenderer(500, 500, camera)
+        assert renderer.scene_bounds() is None
+        assert renderer.add_scene_bounds() is None
+        
+        renderer.add_axes(Point3D(0.0, 0.0, 0.0), length=2.0, style=Style(id="axes"))
+        ground = Plane.from_point_normal(Point3D(0.0, 0.0, 0.0), Vector3D(0.0, 0.0, 1.0))
+        renderer.add_grid(ground, Point3D(0.0, 0.0, 0.0), 4.0, divisions=4)
+        renderer.add_ticks(Point3D(0.0, 0.0, 0.0), Point3D(1.0, 0.0, 0.0), 0.5)
+        assert renderer.scene_bounds() is None
+        
+        renderer.add_point(Point3D(-1.0, -1.0, 0.0))
+        renderer.add_point(Point3D(1.0, 2.0, 0.0))
+        bounds = renderer.add_scene_bounds(color="#3498db")
+        assert bounds.min.x == pytest.approx(-1.0)
+        assert bounds.max.y == pytest.approx(2.0)
+        
+        svg = renderer.render()
+        assert 'id="axes-x" stroke="#ff0000"' in svg
+        assert ">Y</text>" in svg
+        assert 'stroke="#cccccc"' in svg
+        assert ">0.5</text>" in svg
+        assert 'stroke="#3498db"' in svg
+        assert 'stroke-dasharray="6.00 4.00"' in svg
+        
+        with pytest.raises(ValueError):
+            renderer.add_axes(Point3D(0.0, 0.0, 0.0), length=0.0)
+        with pytest.raises(ValueError):
+            renderer.add_grid(ground, Point3D(0.0, 0.0, 0.0), 4.0, divisions=0)
+        with pytest.raises(ValueError):
+            renderer.add_ticks(Point3D(0.0, 0.0, 0.0), Point3D(1.0, 0.0, 0.0), -1.0)
+    
+    def test_rays_planes_and_vectors(self):
+        camera = Camera.orthographic(
+            Point3D(0.0, 0.0, 10.0),
+            Point3D(0.0, 0.0, 0.0),
+            Vector3D(0.0, 1.0, 0.0),
+            10.0,
+            10.0
+        )
+        renderer = SVGRenderer(500, 500, camera)
+        renderer.add_ray(Ray(Point3D(0.0, 0.0, 0.0), Vector3D(1.0, 0.0, 0.0)), color="#ff0000")
+        renderer.add_vector(Point3D(0.0, 0.0, 0.0), Vector3D(1.0, 2.0, 0.0), color="#0000ff", width=2.0)
+        ground = Plane.from_point_normal(Point3D(0.0, 0.0, 0.0), Vector3D(0.0, 0.0, 1.0))
+        renderer.add_plane(ground, Point3D(0.0, 0.0, 3.0), 2.0, fill="#ccccff", style=Style(id="ground"))
+        
+        svg = renderer.render()
+        assert 'x2="500.00" y2="250.00" stroke="#ff0000"' in svg
+        assert 'x2="300.00" y2="150.00" stroke="#0000ff"' in svg
+        assert 'marker-end="url(#arrow-0000ff)"' in svg
+        assert 'id="ground" stroke="none" fill="#ccccff"' in svg
+        assert 'id="ground-3" stroke="#000000"' in svg
+        
+        with pytest.raises(ValueError):
+            renderer.add_vector(Point3D(0.0, 0.0, 0.0), Vector3D(0.0, 0.0, 0.0))
+        with pytest.raises(ValueError):
+            renderer.add_plane(ground, Point3D(0.0, 0.0, 0.0), 0.0)
+    
+    def test_layers(self):
+        camera = Camera.perspective(
+            Point3D(0.0, 0.0, 5.0),
+            Point3D(0.0, 0.0, 0.0),
+            Vector3D(0.0, 1.0, 0.0),
+            60.0,
+            1.0
+        )
+        renderer = SVGRenderer(800, 600, camera)
+        segment = LineSegment(Point3D(-1.0, 0.0, 0.0), Point3D(1.0, 0.0, 0.0))
+        renderer.add_layer(
+            "Hidden Edges",
+            visible=False,
+            style=Style(css_class="hidden"),
+            transform="translate(10 0)",
+        )
+        renderer.add_line_segment(segment, style=Style(id="edge"))
+        renderer.add_layer("Points")
+        renderer.add_point(Point3D(0.0, 0.0, 0.0), color="#ff0000")
+        renderer.set_current_layer(None)
+        renderer.add_point(Point3D(0.0, 0.0, 0.0), color="#0000ff")
+        
+        svg = renderer.render()
+        assert 'inkscape:groupmode="layer"' in svg
+        assert 'inkscape:label="Hidden Edges"' in svg
+        assert 'transform="translate(10 0)"' in svg
+        assert 'display="none"' in svg
+        assert 'id="Hidden-Edges-edge"' in svg
+        assert 'class="hidden"' in svg
+        
+        renderer.set_layer_visible("Hidden Edges", True)
+        assert 'display="none"' not in renderer.render()
+        
+        subset = renderer.render(layers=["Points"])
+        assert "Hidden-Edges" not in subset
+        assert "#ff0000" in subset
+        assert "#0000ff" in subset
+        
+        with pytest.raises(ValueError):
+            renderer.add_layer("Points")
+        with pytest.raises(ValueError):
+            renderer.set_current_layer("Lines")
+        with pytest.raises(ValueError):
+            renderer.render(layers=["Lines"])
     
     def test_add_aabb(self):
         camera = Camera.perspective(
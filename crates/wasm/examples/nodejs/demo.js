@@ -32,17 +32,18 @@ const rayOrigin = new Point3D(-5, 0, 0);
 const rayDir = new Vector3D(1, 0, 0);
 const ray = new Ray(rayOrigin, rayDir);
 const sphere = new Sphere(Point3D.origin(), 1.0);
-const intersection = ray.intersectSphere(sphere);
-if (intersection) {
+const hit = ray.intersectSphere(sphere);
+if (hit) {
+  console.log(`   Ray hits sphere at t=${hit.t.toFixed(3)}`);
+  const hitPoint = hit.point;
   console.log(
-    `   Ray hits sphere at t1=${intersection.t1.toFixed(3)}, t2=${
-      intersection.t2.toFixed(3)
-    }`,
+    `   Hit point: (${hitPoint.x.toFixed(3)}, ${hitPoint.y.toFixed(3)}, ${
+      hitPoint.z.toFixed(3)
+    })`,
   );
-  const hitPoint = ray.pointAt(intersection.t1);
   console.log(
-    `   First hit point: (${hitPoint.x.toFixed(3)}, ${hitPoint.y.toFixed(3)}, ${
-      hitPoint.z.toFixed(3)
+    `   Normal: (${hit.normal.x.toFixed(3)}, ${hit.normal.y.toFixed(3)}, ${
+      hit.normal.z.toFixed(3)
     })\n`,
   );
 }
@@ -4,67 +4,92 @@ export function init(): void;
 export class AABB {
   free(): void;
   [Symbol.dispose](): void;
-  constructor(min: Point3D, max: Point3D);
+  intersects(other: AABB): boolean;
   static fromPoints(points: Point3D[]): AABB;
-  center(): Point3D;
-  size(): Vector3D;
-  volume(): number;
   surfaceArea(): number;
-  diagonal(): number;
   containsPoint(point: Point3D): boolean;
-  intersects(other: AABB): boolean;
-  union(other: AABB): AABB;
   expandByPoint(point: Point3D): AABB;
   expandByScalar(amount: number): AABB;
-  readonly min: Point3D;
+  constructor(min: Point3D, max: Point3D);
+  size(): Vector3D;
+  union(other: AABB): AABB;
+  center(): Point3D;
+  volume(): number;
+  diagonal(): number;
   readonly max: Point3D;
+  readonly min: Point3D;
 }
 export class Camera {
   private constructor();
   free(): void;
   [Symbol.dispose](): void;
+  fitToAabb(aabb: AABB): void;
   static perspective(position: Point3D, target: Point3D, up: Vector3D, fov: number, aspect: number, near: number, far: number): Camera;
-  static orthographic(position: Point3D, target: Point3D, up: Vector3D, width: number, height: number): Camera;
   viewMatrix(): Float64Array;
+  static orthographic(position: Point3D, target: Point3D, up: Vector3D, width: number, height: number): Camera;
+  fitToSphere(sphere: Sphere): void;
+  screenToRay(x: number, y: number, width: number, height: number): Ray;
+  static equirectangular(position: Point3D, target: Point3D, up: Vector3D): Camera;
   projectionMatrix(): Float64Array;
+  pan(dx: number, dy: number): void;
+  zoom(factor: number): void;
+  dolly(distance: number): void;
+  orbit(yaw: number, pitch: number): void;
+  static cabinet(position: Point3D, target: Point3D, up: Vector3D, width: number, height: number): Camera;
+  static fisheye(position: Point3D, target: Point3D, up: Vector3D, fov: number, aspect: number): Camera;
+  lookAt(target: Point3D): void;
+  static oblique(position: Point3D, target: Point3D, up: Vector3D, width: number, height: number, angle: number, depth_scale: number): Camera;
+  static cavalier(position: Point3D, target: Point3D, up: Vector3D, width: number, height: number): Camera;
+  static dimetric(target: Point3D, up: Vector3D, width: number, height: number): Camera;
+  /**
+   * `window` is `[left, right, bottom, top]` on the near plane.
+   */
+  static offAxis(position: Point3D, target: Point3D, up: Vector3D, window: Float64Array, near: number, far: number): Camera;
+  static isometric(target: Point3D, up: Vector3D, width: number, height: number): Camera;
+  static trimetric(target: Point3D, up: Vector3D, yaw: number, elevation: number, width: number, height: number): Camera;
+  unproject(x: number, y: number, depth: number): Point3D;
+  readonly up: Vector3D;
+  readonly target: Point3D;
+  readonly position: Point3D;
+  readonly isLinear: boolean;
 }
 export class LineSegment {
   free(): void;
   [Symbol.dispose](): void;
+  closestPoint(point: Point3D): Point3D;
+  distanceToPoint(point: Point3D): number;
   constructor(start: Point3D, end: Point3D);
   length(): number;
-  direction(): Vector3D;
   midpoint(): Point3D;
   pointAt(t: number): Point3D;
-  closestPoint(point: Point3D): Point3D;
-  distanceToPoint(point: Point3D): number;
-  readonly start: Point3D;
+  direction(): Vector3D;
   readonly end: Point3D;
+  readonly start: Point3D;
 }
 export class Plane {
   private constructor();
   free(): void;
   [Symbol.dispose](): void;
-  static fromPointNormal(point: Point3D, normal: Vector3D): Plane;
-  static fromThreePoints(p1: Point3D, p2: Point3D, p3: Point3D): Plane;
-  distanceToPoint(point: Point3D): number;
-  closestPoint(point: Point3D): Point3D;
-  containsPoint(point: Point3D): boolean;
   flipNormal(): Plane;
   isParallel(other: Plane): boolean;
-  readonly normal: Vector3D;
+  closestPoint(point: Point3D): Point3D;
+  containsPoint(point: Point3D): boolean;
+  distanceToPoint(point: Point3D): number;
+  static fromPointNormal(point: Point3D, normal: Vector3D): Plane;
+  static fromThreePoints(p1: Point3D, p2: Point3D, p3: Point3D): Plane;
   readonly d: number;
+  readonly normal: Vector3D;
 }
 export class Point3D {
   free(): void;
   [Symbol.dispose](): void;
-  constructor(x: number, y: number, z: number);
+  static fromArray(arr: Float64Array): Point3D;
   distanceTo(other: Point3D): number;
+  constructor(x: number, y: number, z: number);
+  static origin(): Point3D;
   midpoint(other: Point3D): Point3D;
-  translate(vector: Vector3D): Point3D;
   toArray(): Float64Array;
-  static fromArray(arr: Float64Array): Point3D;
-  static origin(): Point3D;
+  translate(vector: Vector3D): Point3D;
   readonly x: number;
   readonly y: number;
   readonly z: number;
@@ -72,45 +97,142 @@ export class Point3D {
 export class Ray {
   free(): void;
   [Symbol.dispose](): void;
-  constructor(origin: Point3D, direction: Vector3D);
-  pointAt(t: number): Point3D;
-  intersectSphere(sphere: Sphere): any;
+  static withRange(origin: Point3D, direction: Vector3D, t_min: number, t_max: number): Ray;
+  intersectAABB(aabb: AABB): any;
   intersectPlane(plane: Plane): any;
+  intersectSphere(sphere: Sphere): any;
+  intersectAABBAll(aabb: AABB): RayHit[];
   intersectTriangle(triangle: Triangle): any;
+  intersectSphereAll(sphere: Sphere): RayHit[];
+  constructor(origin: Point3D, direction: Vector3D);
+  pointAt(t: number): Point3D;
+  readonly tMax: number;
+  readonly tMin: number;
   readonly origin: Point3D;
   readonly direction: Vector3D;
 }
+export class RayHit {
+  private constructor();
+  free(): void;
+  [Symbol.dispose](): void;
+  readonly frontFace: boolean;
+  readonly barycentric: Float64Array | undefined;
+  readonly t: number;
+  readonly uv: Float64Array | undefined;
+  readonly point: Point3D;
+  readonly normal: Vector3D;
+}
 export class SVGRenderer {
   free(): void;
   [Symbol.dispose](): void;
-  constructor(width: number, height: number, camera: Camera);
+  addLegend(texts: string[], styles: Style[], x: number, y: number, style: Style): void;
+  addSphere(sphere: Sphere, color: string, width: number): void;
+  addVector(origin: Point3D, vector: Vector3D, color: string, width: number): void;
+  /**
+   * Lights the fill of triangles and spheres added from now on:
+   * `"none"`, `"flat"` or `"gouraud"`.
+   */
+  setShading(mode: string): void;
+  addTriangle(triangle: Triangle, stroke: string, fill: string | null | undefined, width: number): void;
+  clearLights(): void;
+  sceneBounds(): AABB | undefined;
+  toSvgString(): string;
   setBackground(color: string): void;
-  addPoint(point: Point3D, color: string, size: number): void;
+  /**
+   * Sets the drawing order: "none", "painter" or "bsp".
+   */
+  setDepthSort(mode: string): void;
+  setStylesheet(css: string): void;
+  addPointLight(position: Point3D, color: string, intensity: number): void;
+  addLeaderLabel(point: Point3D, dx: number, dy: number, text: string, style: Style): void;
   addLineSegment(segment: LineSegment, color: string, width: number): void;
-  addTriangle(triangle: Triangle, stroke: string, fill: string | null | undefined, width: number): void;
-  addSphere(sphere: Sphere, color: string, width: number): void;
+  addSceneBounds(style: Style): AABB | undefined;
+  /**
+   * Sets how hidden edges are drawn: "shown", "dashed" or "removed".
+   */
+  setHiddenLines(mode: string): void;
+  /**
+   * `color` is a "#rgb" or "#rrggbb" string.
+   */
+  addAmbientLight(color: string, intensity: number): void;
+  addFilledSphere(sphere: Sphere, stroke: string, fill: string | null | undefined, width: number): void;
+  setCurrentLayer(name?: string | null): void;
+  setLayerVisible(name: string, visible: boolean): void;
+  addRayWithStyle(ray: Ray, style: Style): void;
+  addAabbWithStyle(aabb: AABB, style: Style): void;
+  addLayerWithStyle(name: string, visible: boolean, transform: string | null | undefined, style: Style): void;
+  addLinearDimension(start: Point3D, end: Point3D, offset: Vector3D, style: Style): number;
+  addPlaneWithStyle(plane: Plane, center: Point3D, size: number, style: Style): void;
+  addPointWithStyle(point: Point3D, size: number, style: Style): void;
+  addAngularDimension(vertex: Point3D, from: Point3D, to: Point3D, radius: number, style: Style): number;
+  /**
+   * `direction` is the way the light travels.
+   */
+  addDirectionalLight(direction: Vector3D, color: string, intensity: number): void;
+  addSphereWithStyle(sphere: Sphere, style: Style): void;
+  addVectorWithStyle(origin: Point3D, vector: Vector3D, style: Style): void;
+  /**
+   * Drops triangles added from now on that face away from the camera.
+   */
+  setBackFaceCulling(enabled: boolean): void;
+  addTriangleWithStyle(triangle: Triangle, style: Style): void;
+  /**
+   * Like `addTriangleWithStyle`, with the surface normals at `a`, `b`
+   * and `c` for Gouraud shading.
+   */
+  addTriangleWithNormals(triangle: Triangle, normal_a: Vector3D, normal_b: Vector3D, normal_c: Vector3D, style: Style): void;
+  toSvgStringWithLayers(layers: string[]): string;
+  addLineSegmentWithStyle(segment: LineSegment, style: Style): void;
+  constructor(width: number, height: number, camera: Camera);
+  addRay(ray: Ray, color: string, width: number): void;
   addAabb(aabb: AABB, color: string, width: number): void;
-  toSvgString(): string;
+  addAxes(origin: Point3D, length: number, style: Style): void;
+  addGrid(plane: Plane, center: Point3D, size: number, divisions: number, style: Style): void;
+  addLabel(point: Point3D, text: string, style: Style): void;
+  addLayer(name: string, visible: boolean, transform?: string | null): void;
+  addPlane(plane: Plane, center: Point3D, size: number, stroke: string, fill: string | null | undefined, width: number): void;
+  addPoint(point: Point3D, color: string, size: number): void;
+  addTicks(start: Point3D, end: Point3D, spacing: number, size: number, style: Style): void;
 }
 export class Sphere {
   free(): void;
   [Symbol.dispose](): void;
+  surfaceArea(): number;
   constructor(center: Point3D, radius: number);
   volume(): number;
-  surfaceArea(): number;
   contains(point: Point3D): boolean;
   readonly center: Point3D;
   readonly radius: number;
 }
+export class Style {
+  free(): void;
+  [Symbol.dispose](): void;
+  setStroke(color?: string | null): void;
+  setOpacity(opacity?: number | null): void;
+  setLineCap(cap?: string | null): void;
+  setFontSize(size?: number | null): void;
+  setLineJoin(join?: string | null): void;
+  setDashArray(dashes?: Float64Array | null): void;
+  setMarkerEnd(marker?: string | null): void;
+  setFontFamily(family?: string | null): void;
+  setTextAnchor(anchor?: string | null): void;
+  setMarkerStart(marker?: string | null): void;
+  setStrokeWidth(width?: number | null): void;
+  constructor();
+  setId(id?: string | null): void;
+  setFill(color?: string | null): void;
+  setClass(_class?: string | null): void;
+  setTitle(title?: string | null): void;
+}
 export class Triangle {
   free(): void;
   [Symbol.dispose](): void;
+  containsPoint(point: Point3D): boolean;
+  barycentricCoords(point: Point3D): Float64Array;
   constructor(a: Point3D, b: Point3D, c: Point3D);
-  normal(): Vector3D;
   area(): number;
+  normal(): Vector3D;
   centroid(): Point3D;
-  barycentricCoords(point: Point3D): Float64Array;
-  containsPoint(point: Point3D): boolean;
   readonly a: Point3D;
   readonly b: Point3D;
   readonly c: Point3D;
@@ -118,25 +240,25 @@ export class Triangle {
 export class Vector3D {
   free(): void;
   [Symbol.dispose](): void;
-  constructor(x: number, y: number, z: number);
-  magnitude(): number;
-  normalize(): Vector3D;
-  dot(other: Vector3D): number;
-  cross(other: Vector3D): Vector3D;
-  add(other: Vector3D): Vector3D;
-  sub(other: Vector3D): Vector3D;
-  scale(scalar: number): Vector3D;
-  angle(other: Vector3D): number;
-  projectOnto(other: Vector3D): Vector3D;
+  static fromArray(arr: Float64Array): Vector3D;
+  static fromPoints(from: Point3D, to: Point3D): Vector3D;
   isParallel(other: Vector3D): boolean;
+  projectOnto(other: Vector3D): Vector3D;
   isPerpendicular(other: Vector3D): boolean;
-  toArray(): Float64Array;
-  static fromArray(arr: Float64Array): Vector3D;
+  add(other: Vector3D): Vector3D;
+  dot(other: Vector3D): number;
+  constructor(x: number, y: number, z: number);
+  sub(other: Vector3D): Vector3D;
   static zero(): Vector3D;
+  angle(other: Vector3D): number;
+  cross(other: Vector3D): Vector3D;
+  scale(scalar: number): Vector3D;
   static unitX(): Vector3D;
   static unitY(): Vector3D;
   static unitZ(): Vector3D;
-  static fromPoints(from: Point3D, to: Point3D): Vector3D;
+  toArray(): Float64Array;
+  magnitude(): number;
+  normalize(): Vector3D;
   readonly x: number;
   readonly y: number;
   readonly z: number;
@@ -146,122 +268,211 @@ export type InitInput = RequestInfo | URL | Response | BufferSource | WebAssembl
 
 export interface InitOutput {
   readonly memory: WebAssembly.Memory;
-  readonly __wbg_sphere_free: (a: number, b: number) => void;
-  readonly sphere_new: (a: number, b: number) => [number, number, number];
-  readonly sphere_center: (a: number) => number;
-  readonly sphere_radius: (a: number) => number;
-  readonly sphere_volume: (a: number) => number;
-  readonly sphere_surfaceArea: (a: number) => number;
-  readonly sphere_contains: (a: number, b: number) => number;
   readonly __wbg_svgrenderer_free: (a: number, b: number) => void;
-  readonly svgrenderer_new: (a: number, b: number, c: number) => number;
-  readonly svgrenderer_setBackground: (a: number, b: number, c: number) => void;
-  readonly svgrenderer_addPoint: (a: number, b: number, c: number, d: number, e: number) => void;
+  readonly svgrenderer_addAabb: (a: number, b: number, c: number, d: number, e: number) => void;
+  readonly svgrenderer_addAabbWithStyle: (a: number, b: number, c: number) => void;
+  readonly svgrenderer_addAmbientLight: (a: number, b: number, c: number, d: number) => [number, number];
+  readonly svgrenderer_addAngularDimension: (a: number, b: number, c: number, d: number, e: number, f: number) => [number, number, number];
+  readonly svgrenderer_addAxes: (a: number, b: number, c: number, d: number) => [number, number];
+  readonly svgrenderer_addDirectionalLight: (a: number, b: number, c: number, d: number, e: number) => [number, number];
+  readonly svgrenderer_addFilledSphere: (a: number, b: number, c: number, d: number, e: number, f: number, g: number) => void;
+  readonly svgrenderer_addGrid: (a: number, b: number, c: number, d: number, e: number, f: number) => [number, number];
+  readonly svgrenderer_addLabel: (a: number, b: number, c: number, d: number, e: number) => void;
+  readonly svgrenderer_addLayer: (a: number, b: number, c: number, d: number, e: number, f: number) => [number, number];
+  readonly svgrenderer_addLayerWithStyle: (a: number, b: number, c: number, d: number, e: number, f: number, g: number) => [number, number];
+  readonly svgrenderer_addLeaderLabel: (a: number, b: number, c: number, d: number, e: number, f: number, g: number) => void;
+  readonly svgrenderer_addLegend: (a: number, b: number, c: number, d: number, e: number, f: number, g: number, h: number) => [number, number];
   readonly svgrenderer_addLineSegment: (a: number, b: number, c: number, d: number, e: number) => void;
-  readonly svgrenderer_addTriangle: (a: number, b: number, c: number, d: number, e: number, f: number, g: number) => void;
+  readonly svgrenderer_addLineSegmentWithStyle: (a: number, b: number, c: number) => void;
+  readonly svgrenderer_addLinearDimension: (a: number, b: number, c: number, d: number, e: number) => number;
+  readonly svgrenderer_addPlane: (a: number, b: number, c: number, d: number, e: number, f: number, g: number, h: number, i: number) => [number, number];
+  readonly svgrenderer_addPlaneWithStyle: (a: number, b: number, c: number, d: number, e: number) => [number, number];
+  readonly svgrenderer_addPoint: (a: number, b: number, c: number, d: number, e: number) => void;
+  readonly svgrenderer_addPointLight: (a: number, b: number, c: number, d: number, e: number) => [number, number];
+  readonly svgrenderer_addPointWithStyle: (a: number, b: number, c: number, d: number) => void;
+  readonly svgrenderer_addRay: (a: number, b: number, c: number, d: number, e: number) => void;
+  readonly svgrenderer_addRayWithStyle: (a: number, b: number, c: number) => void;
+  readonly svgrenderer_addSceneBounds: (a: number, b: number) => number;
   readonly svgrenderer_addSphere: (a: number, b: number, c: number, d: number, e: number) => void;
-  readonly svgrenderer_addAabb: (a: number, b: number, c: number, d: number, e: number) => void;
+  readonly svgrenderer_addSphereWithStyle: (a: number, b: number, c: number) => void;
+  readonly svgrenderer_addTicks: (a: number, b: number, c: number, d: number, e: number, f: number) => [number, number];
+  readonly svgrenderer_addTriangle: (a: number, b: number, c: number, d: number, e: number, f: number, g: number) => void;
+  readonly svgrenderer_addTriangleWithNormals: (a: number, b: number, c: number, d: number, e: number, f: number) => void;
+  readonly svgrenderer_addTriangleWithStyle: (a: number, b: number, c: number) => void;
+  readonly svgrenderer_addVector: (a: number, b: number, c: number, d: number, e: number, f: number) => [number, number];
+  readonly svgrenderer_addVectorWithStyle: (a: number, b: number, c: number, d: number) => [number, number];
+  readonly svgrenderer_clearLights: (a: number) => void;
+  readonly svgrenderer_new: (a: number, b: number, c: number) => number;
+  readonly svgrenderer_sceneBounds: (a: number) => number;
+  readonly svgrenderer_setBackFaceCulling: (a: number, b: number) => void;
+  readonly svgrenderer_setBackground: (a: number, b: number, c: number) => void;
+  readonly svgrenderer_setCurrentLayer: (a: number, b: number, c: number) => [number, number];
+  readonly svgrenderer_setDepthSort: (a: number, b: number, c: number) => [number, number];
+  readonly svgrenderer_setHiddenLines: (a: number, b: number, c: number) => [number, number];
+  readonly svgrenderer_setLayerVisible: (a: number, b: number, c: number, d: number) => [number, number];
+  readonly svgrenderer_setShading: (a: number, b: number, c: number) => [number, number];
+  readonly svgrenderer_setStylesheet: (a: number, b: number, c: number) => void;
   readonly svgrenderer_toSvgString: (a: number) => [number, number];
-  readonly __wbg_camera_free: (a: number, b: number) => void;
-  readonly camera_perspective: (a: number, b: number, c: number, d: number, e: number, f: number, g: number) => number;
-  readonly camera_orthographic: (a: number, b: number, c: number, d: number, e: number) => number;
-  readonly camera_viewMatrix: (a: number) => [number, number];
-  readonly camera_projectionMatrix: (a: number) => [number, number];
-  readonly __wbg_plane_free: (a: number, b: number) => void;
-  readonly plane_fromPointNormal: (a: number, b: number) => [number, number, number];
-  readonly plane_fromThreePoints: (a: number, b: number, c: number) => [number, number, number];
-  readonly plane_normal: (a: number) => number;
-  readonly plane_d: (a: number) => number;
-  readonly plane_distanceToPoint: (a: number, b: number) => number;
-  readonly plane_closestPoint: (a: number, b: number) => number;
-  readonly plane_containsPoint: (a: number, b: number) => number;
-  readonly plane_flipNormal: (a: number) => number;
-  readonly plane_isParallel: (a: number, b: number) => number;
-  readonly __wbg_triangle_free: (a: number, b: number) => void;
-  readonly triangle_new: (a: number, b: number, c: number) => [number, number, number];
-  readonly triangle_b: (a: number) => number;
-  readonly triangle_c: (a: number) => number;
-  readonly triangle_normal: (a: number) => number;
-  readonly triangle_area: (a: number) => number;
-  readonly triangle_centroid: (a: number) => number;
-  readonly triangle_barycentricCoords: (a: number, b: number) => [number, number];
-  readonly triangle_containsPoint: (a: number, b: number) => number;
-  readonly triangle_a: (a: number) => number;
+  readonly svgrenderer_toSvgStringWithLayers: (a: number, b: number, c: number) => [number, number, number, number];
   readonly __wbg_linesegment_free: (a: number, b: number) => void;
-  readonly linesegment_new: (a: number, b: number) => [number, number, number];
-  readonly linesegment_start: (a: number) => number;
+  readonly __wbg_vector3d_free: (a: number, b: number) => void;
+  readonly linesegment_closestPoint: (a: number, b: number) => number;
+  readonly linesegment_direction: (a: number) => number;
+  readonly linesegment_distanceToPoint: (a: number, b: number) => number;
   readonly linesegment_end: (a: number) => number;
   readonly linesegment_length: (a: number) => number;
-  readonly linesegment_direction: (a: number) => number;
   readonly linesegment_midpoint: (a: number) => number;
+  readonly linesegment_new: (a: number, b: number) => [number, number, number];
   readonly linesegment_pointAt: (a: number, b: number) => number;
-  readonly linesegment_closestPoint: (a: number, b: number) => number;
-  readonly linesegment_distanceToPoint: (a: number, b: number) => number;
-  readonly __wbg_point3d_free: (a: number, b: number) => void;
-  readonly point3d_new: (a: number, b: number, c: number) => number;
-  readonly point3d_x: (a: number) => number;
-  readonly point3d_y: (a: number) => number;
-  readonly point3d_z: (a: number) => number;
-  readonly point3d_distanceTo: (a: number, b: number) => number;
-  readonly point3d_midpoint: (a: number, b: number) => number;
-  readonly point3d_translate: (a: number, b: number) => number;
-  readonly point3d_toArray: (a: number) => [number, number];
-  readonly point3d_fromArray: (a: number, b: number) => [number, number, number];
-  readonly point3d_origin: () => number;
-  readonly init: () => void;
-  readonly __wbg_ray_free: (a: number, b: number) => void;
-  readonly ray_new: (a: number, b: number) => [number, number, number];
-  readonly ray_origin: (a: number) => number;
-  readonly ray_direction: (a: number) => number;
-  readonly ray_pointAt: (a: number, b: number) => number;
-  readonly ray_intersectSphere: (a: number, b: number) => any;
-  readonly ray_intersectPlane: (a: number, b: number) => any;
-  readonly ray_intersectTriangle: (a: number, b: number) => any;
-  readonly __wbg_vector3d_free: (a: number, b: number) => void;
-  readonly vector3d_new: (a: number, b: number, c: number) => number;
-  readonly vector3d_x: (a: number) => number;
-  readonly vector3d_y: (a: number) => number;
-  readonly vector3d_z: (a: number) => number;
-  readonly vector3d_magnitude: (a: number) => number;
-  readonly vector3d_normalize: (a: number) => [number, number, number];
-  readonly vector3d_dot: (a: number, b: number) => number;
-  readonly vector3d_cross: (a: number, b: number) => number;
+  readonly linesegment_start: (a: number) => number;
   readonly vector3d_add: (a: number, b: number) => number;
-  readonly vector3d_sub: (a: number, b: number) => number;
-  readonly vector3d_scale: (a: number, b: number) => number;
   readonly vector3d_angle: (a: number, b: number) => number;
-  readonly vector3d_projectOnto: (a: number, b: number) => number;
+  readonly vector3d_cross: (a: number, b: number) => number;
+  readonly vector3d_dot: (a: number, b: number) => number;
+  readonly vector3d_fromArray: (a: number, b: number) => [number, number, number];
+  readonly vector3d_fromPoints: (a: number, b: number) => number;
   readonly vector3d_isParallel: (a: number, b: number) => number;
   readonly vector3d_isPerpendicular: (a: number, b: number) => number;
+  readonly vector3d_magnitude: (a: number) => number;
+  readonly vector3d_new: (a: number, b: number, c: number) => number;
+  readonly vector3d_normalize: (a: number) => [number, number, number];
+  readonly vector3d_projectOnto: (a: number, b: number) => number;
+  readonly vector3d_scale: (a: number, b: number) => number;
+  readonly vector3d_sub: (a: number, b: number) => number;
   readonly vector3d_toArray: (a: number) => [number, number];
-  readonly vector3d_fromArray: (a: number, b: number) => [number, number, number];
-  readonly vector3d_zero: () => number;
   readonly vector3d_unitX: () => number;
   readonly vector3d_unitY: () => number;
   readonly vector3d_unitZ: () => number;
-  readonly vector3d_fromPoints: (a: number, b: number) => number;
+  readonly vector3d_x: (a: number) => number;
+  readonly vector3d_y: (a: number) => number;
+  readonly vector3d_z: (a: number) => number;
+  readonly vector3d_zero: () => number;
+  readonly __wbg_triangle_free: (a: number, b: number) => void;
+  readonly triangle_a: (a: number) => number;
+  readonly triangle_area: (a: number) => number;
+  readonly triangle_b: (a: number) => number;
+  readonly triangle_barycentricCoords: (a: number, b: number) => [number, number];
+  readonly triangle_c: (a: number) => number;
+  readonly triangle_centroid: (a: number) => number;
+  readonly triangle_containsPoint: (a: number, b: number) => number;
+  readonly triangle_new: (a: number, b: number, c: number) => [number, number, number];
+  readonly triangle_normal: (a: number) => number;
+  readonly init: () => void;
   readonly __wbg_aabb_free: (a: number, b: number) => void;
-  readonly aabb_new: (a: number, b: number) => [number, number, number];
+  readonly __wbg_plane_free: (a: number, b: number) => void;
+  readonly aabb_center: (a: number) => number;
+  readonly aabb_containsPoint: (a: number, b: number) => number;
+  readonly aabb_diagonal: (a: number) => number;
+  readonly aabb_expandByPoint: (a: number, b: number) => number;
+  readonly aabb_expandByScalar: (a: number, b: number) => number;
   readonly aabb_fromPoints: (a: number, b: number) => [number, number, number];
-  readonly aabb_min: (a: number) => number;
+  readonly aabb_intersects: (a: number, b: number) => number;
   readonly aabb_max: (a: number) => number;
-  readonly aabb_center: (a: number) => number;
+  readonly aabb_min: (a: number) => number;
+  readonly aabb_new: (a: number, b: number) => [number, number, number];
   readonly aabb_size: (a: number) => number;
-  readonly aabb_volume: (a: number) => number;
   readonly aabb_surfaceArea: (a: number) => number;
-  readonly aabb_diagonal: (a: number) => number;
-  readonly aabb_containsPoint: (a: number, b: number) => number;
-  readonly aabb_intersects: (a: number, b: number) => number;
   readonly aabb_union: (a: number, b: number) => number;
-  readonly aabb_expandByPoint: (a: number, b: number) => number;
-  readonly aabb_expandByScalar: (a: number, b: number) => number;
+  readonly aabb_volume: (a: number) => number;
+  readonly plane_closestPoint: (a: number, b: number) => number;
+  readonly plane_containsPoint: (a: number, b: number) => number;
+  readonly plane_d: (a: number) => number;
+  readonly plane_distanceToPoint: (a: number, b: number) => number;
+  readonly plane_flipNormal: (a: number) => number;
+  readonly plane_fromPointNormal: (a: number, b: number) => [number, number, number];
+  readonly plane_fromThreePoints: (a: number, b: number, c: number) => [number, number, number];
+  readonly plane_isParallel: (a: number, b: number) => number;
+  readonly plane_normal: (a: number) => number;
+  readonly __wbg_camera_free: (a: number, b: number) => void;
+  readonly camera_cabinet: (a: number, b: number, c: number, d: number, e: number) => [number, number, number];
+  readonly camera_cavalier: (a: number, b: number, c: number, d: number, e: number) => [number, number, number];
+  readonly camera_dimetric: (a: number, b: number, c: number, d: number) => [number, number, number];
+  readonly camera_dolly: (a: number, b: number) => [number, number];
+  readonly camera_equirectangular: (a: number, b: number, c: number) => [number, number, number];
+  readonly camera_fisheye: (a: number, b: number, c: number, d: number, e: number) => [number, number, number];
+  readonly camera_fitToAabb: (a: number, b: number) => [number, number];
+  readonly camera_fitToSphere: (a: number, b: number) => [number, number];
+  readonly camera_isLinear: (a: number) => number;
+  readonly camera_isometric: (a: number, b: number, c: number, d: number) => [number, number, number];
+  readonly camera_lookAt: (a: number, b: number) => [number, number];
+  readonly camera_oblique: (a: number, b: number, c: number, d: number, e: number, f: number, g: number) => [number, number, number];
+  readonly camera_offAxis: (a: number, b: number, c: number, d: number, e: number, f: number, g: number) => [number, number, number];
+  readonly camera_orbit: (a: number, b: number, c: number) => void;
+  readonly camera_orthographic: (a: number, b: number, c: number, d: number, e: number) => [number, number, number];
+  readonly camera_pan: (a: number, b: number, c: number) => void;
+  readonly camera_perspective: (a: number, b: number, c: number, d: number, e: number, f: number, g: number) => [number, number, number];
+  readonly camera_position: (a: number) => number;
+  readonly camera_projectionMatrix: (a: number) => [number, number];
+  readonly camera_screenToRay: (a: number, b: number, c: number, d: number, e: number) => number;
+  readonly camera_target: (a: number) => number;
+  readonly camera_trimetric: (a: number, b: number, c: number, d: number, e: number, f: number) => [number, number, number];
+  readonly camera_unproject: (a: number, b: number, c: number, d: number) => number;
+  readonly camera_up: (a: number) => number;
+  readonly camera_viewMatrix: (a: number) => [number, number];
+  readonly camera_zoom: (a: number, b: number) => [number, number];
+  readonly __wbg_style_free: (a: number, b: number) => void;
+  readonly style_new: () => number;
+  readonly style_setClass: (a: number, b: number, c: number) => void;
+  readonly style_setDashArray: (a: number, b: number, c: number) => void;
+  readonly style_setFill: (a: number, b: number, c: number) => void;
+  readonly style_setFontFamily: (a: number, b: number, c: number) => void;
+  readonly style_setFontSize: (a: number, b: number, c: number) => void;
+  readonly style_setId: (a: number, b: number, c: number) => void;
+  readonly style_setLineCap: (a: number, b: number, c: number) => [number, number];
+  readonly style_setLineJoin: (a: number, b: number, c: number) => [number, number];
+  readonly style_setMarkerEnd: (a: number, b: number, c: number) => [number, number];
+  readonly style_setMarkerStart: (a: number, b: number, c: number) => [number, number];
+  readonly style_setOpacity: (a: number, b: number, c: number) => void;
+  readonly style_setStroke: (a: number, b: number, c: number) => void;
+  readonly style_setStrokeWidth: (a: number, b: number, c: number) => void;
+  readonly style_setTextAnchor: (a: number, b: number, c: number) => [number, number];
+  readonly style_setTitle: (a: number, b: number, c: number) => void;
+  readonly __wbg_ray_free: (a: number, b: number) => void;
+  readonly __wbg_rayhit_free: (a: number, b: number) => void;
+  readonly ray_direction: (a: number) => number;
+  readonly ray_intersectAABB: (a: number, b: number) => any;
+  readonly ray_intersectAABBAll: (a: number, b: number) => [number, number];
+  readonly ray_intersectPlane: (a: number, b: number) => any;
+  readonly ray_intersectSphere: (a: number, b: number) => any;
+  readonly ray_intersectSphereAll: (a: number, b: number) => [number, number];
+  readonly ray_intersectTriangle: (a: number, b: number) => any;
+  readonly ray_new: (a: number, b: number) => [number, number, number];
+  readonly ray_origin: (a: number) => number;
+  readonly ray_pointAt: (a: number, b: number) => number;
+  readonly ray_tMax: (a: number) => number;
+  readonly ray_tMin: (a: number) => number;
+  readonly ray_withRange: (a: number, b: number, c: number, d: number) => [number, number, number];
+  readonly rayhit_barycentric: (a: number) => [number, number];
+  readonly rayhit_frontFace: (a: number) => number;
+  readonly rayhit_normal: (a: number) => number;
+  readonly rayhit_point: (a: number) => number;
+  readonly rayhit_uv: (a: number) => [number, number];
+  readonly rayhit_t: (a: number) => number;
+  readonly __wbg_point3d_free: (a: number, b: number) => void;
+  readonly point3d_distanceTo: (a: number, b: number) => number;
+  readonly point3d_fromArray: (a: number, b: number) => [number, number, number];
+  readonly point3d_midpoint: (a: number, b: number) => number;
+  readonly point3d_new: (a: number, b: number, c: number) => number;
+  readonly point3d_origin: () => number;
+  readonly point3d_toArray: (a: number) => [number, number];
+  readonly point3d_translate: (a: number, b: number) => number;
+  readonly point3d_x: (a: number) => number;
+  readonly point3d_y: (a: number) => number;
+  readonly point3d_z: (a: number) => number;
+  readonly __wbg_sphere_free: (a: number, b: number) => void;
+  readonly sphere_center: (a: number) => number;
+  readonly sphere_contains: (a: number, b: number) => number;
+  readonly sphere_new: (a: number, b: number) => [number, number, number];
+  readonly sphere_radius: (a: number) => number;
+  readonly sphere_surfaceArea: (a: number) => number;
+  readonly sphere_volume: (a: number) => number;
   readonly __wbindgen_free: (a: number, b: number, c: number) => void;
-  readonly __wbindgen_exn_store: (a: number) => void;
-  readonly __externref_table_alloc: () => number;
-  readonly __wbindgen_export_3: WebAssembly.Table;
   readonly __wbindgen_malloc: (a: number, b: number) => number;
   readonly __wbindgen_realloc: (a: number, b: number, c: number, d: number) => number;
+  readonly __wbindgen_export_3: WebAssembly.Table;
+  readonly __externref_table_alloc: () => number;
   readonly __externref_table_dealloc: (a: number) => void;
+  readonly __externref_drop_slice: (a: number, b: number) => void;
   readonly __wbindgen_start: () => void;
 }
 
@@ -30,21 +30,6 @@ function getStringFromWasm0(ptr, len) {
     return decodeText(ptr, len);
 }
 
-function addToExternrefTable0(obj) {
-    const idx = wasm.__externref_table_alloc();
-    wasm.__wbindgen_export_3.set(idx, obj);
-    return idx;
-}
-
-function handleError(f, args) {
-    try {
-        return f.apply(this, args);
-    } catch (e) {
-        const idx = addToExternrefTable0(e);
-        wasm.__wbindgen_exn_store(idx);
-    }
-}
-
 let WASM_VECTOR_LEN = 0;
 
 const cachedTextEncoder = new TextEncoder();
@@ -108,69 +93,24 @@ function getDataViewMemory0() {
     return cachedDataViewMemory0;
 }
 
-function debugString(val) {
-    // primitive types
-    const type = typeof val;
-    if (type == 'number' || type == 'boolean' || val == null) {
-        return  `${val}`;
-    }
-    if (type == 'string') {
-        return `"${val}"`;
-    }
-    if (type == 'symbol') {
-        const description = val.description;
-        if (description == null) {
-            return 'Symbol';
-        } else {
-            return `Symbol(${description})`;
-        }
-    }
-    if (type == 'function') {
-        const name = val.name;
-        if (typeof name == 'string' && name.length > 0) {
-            return `Function(${name})`;
-        } else {
-            return 'Function';
-        }
-    }
-    // objects
-    if (Array.isArray(val)) {
-        const length = val.length;
-        let debug = '[';
-        if (length > 0) {
-            debug += debugString(val[0]);
-        }
-        for(let i = 1; i < length; i++) {
-            debug += ', ' + debugString(val[i]);
-        }
-        debug += ']';
-        return debug;
-    }
-    // Test for built-in
-    const builtInMatches = /\[object ([^\]]+)\]/.exec(toString.call(val));
-    let className;
-    if (builtInMatches && builtInMatches.length > 1) {
-        className = builtInMatches[1];
-    } else {
-        // Failed to match the standard '[object ClassName]'
-        return toString.call(val);
-    }
-    if (className == 'Object') {
-        // we're a user defined class or Object
-        // JSON.stringify avoids problems with cycles, and is generally much
-        // easier than looping through ownProperties of `val`.
-        try {
-            return 'Object(' + JSON.stringify(val) + ')';
-        } catch (_) {
-            return 'Object';
-        }
-    }
-    // errors
-    if (val instanceof Error) {
-        return `${val.name}: ${val.message}\n${val.stack}`;
+function isLikeNone(x) {
+    return x === undefined || x === null;
+}
+
+function addToExternrefTable0(obj) {
+    const idx = wasm.__externref_table_alloc();
+    wasm.__wbindgen_export_3.set(idx, obj);
+    return idx;
+}
+
+function passArrayJsValueToWasm0(array, malloc) {
+    const ptr = malloc(array.length * 4, 4) >>> 0;
+    for (let i = 0; i < array.length; i++) {
+        const add = addToExternrefTable0(array[i]);
+        getDataViewMemory0().setUint32(ptr + 4 * i, add, true);
     }
-    // TODO we could test for more things here, like `Set`s and `Map`s.
-    return className;
+    WASM_VECTOR_LEN = array.length;
+    return ptr;
 }
 
 function _assertClass(instance, klass) {
@@ -185,10 +125,6 @@ function takeFromExternrefTable0(idx) {
     return value;
 }
 
-function isLikeNone(x) {
-    return x === undefined || x === null;
-}
-
 let cachedFloat64ArrayMemory0 = null;
 
 function getFloat64ArrayMemory0() {
@@ -198,11 +134,6 @@ function getFloat64ArrayMemory0() {
     return cachedFloat64ArrayMemory0;
 }
 
-function getArrayF64FromWasm0(ptr, len) {
-    ptr = ptr >>> 0;
-    return getFloat64ArrayMemory0().subarray(ptr / 8, ptr / 8 + len);
-}
-
 function passArrayF64ToWasm0(arg, malloc) {
     const ptr = malloc(arg.length * 8, 8) >>> 0;
     getFloat64ArrayMemory0().set(arg, ptr / 8);
@@ -210,18 +141,24 @@ function passArrayF64ToWasm0(arg, malloc) {
     return ptr;
 }
 
+function getArrayF64FromWasm0(ptr, len) {
+    ptr = ptr >>> 0;
+    return getFloat64ArrayMemory0().subarray(ptr / 8, ptr / 8 + len);
+}
+
 export function init() {
     wasm.init();
 }
 
-function passArrayJsValueToWasm0(array, malloc) {
-    const ptr = malloc(array.length * 4, 4) >>> 0;
-    for (let i = 0; i < array.length; i++) {
-        const add = addToExternrefTable0(array[i]);
-        getDataViewMemory0().setUint32(ptr + 4 * i, add, true);
+function getArrayJsValueFromWasm0(ptr, len) {
+    ptr = ptr >>> 0;
+    const mem = getDataViewMemory0();
+    const result = [];
+    for (let i = ptr; i < ptr + 4 * len; i += 4) {
+        result.push(wasm.__wbindgen_export_3.get(mem.getUint32(i, true)));
     }
-    WASM_VECTOR_LEN = array.length;
-    return ptr;
+    wasm.__externref_drop_slice(ptr, len);
+    return result;
 }
 
 const AABBFinalization = (typeof FinalizationRegistry === 'undefined')
@@ -250,19 +187,13 @@ export class AABB {
         wasm.__wbg_aabb_free(ptr, 0);
     }
     /**
-     * @param {Point3D} min
-     * @param {Point3D} max
+     * @param {AABB} other
+     * @returns {boolean}
      */
-    constructor(min, max) {
-        _assertClass(min, Point3D);
-        _assertClass(max, Point3D);
-        const ret = wasm.aabb_new(min.__wbg_ptr, max.__wbg_ptr);
-        if (ret[2]) {
-            throw takeFromExternrefTable0(ret[1]);
-        }
-        this.__wbg_ptr = ret[0] >>> 0;
-        AABBFinalization.register(this, this.__wbg_ptr, this);
-        return this;
+    intersects(other) {
+        _assertClass(other, AABB);
+        const ret = wasm.aabb_intersects(this.__wbg_ptr, other.__wbg_ptr);
+        return ret !== 0;
     }
     /**
      * @param {Point3D[]} points
@@ -278,71 +209,73 @@ export class AABB {
         return AABB.__wrap(ret[0]);
     }
     /**
-     * @returns {Point3D}
-     */
-    get min() {
-        const ret = wasm.aabb_min(this.__wbg_ptr);
-        return Point3D.__wrap(ret);
-    }
-    /**
-     * @returns {Point3D}
+     * @returns {number}
      */
-    get max() {
-        const ret = wasm.aabb_max(this.__wbg_ptr);
-        return Point3D.__wrap(ret);
+    surfaceArea() {
+        const ret = wasm.aabb_surfaceArea(this.__wbg_ptr);
+        return ret;
     }
     /**
-     * @returns {Point3D}
+     * @param {Point3D} point
+     * @returns {boolean}
      */
-    center() {
-        const ret = wasm.aabb_center(this.__wbg_ptr);
-        return Point3D.__wrap(ret);
+    containsPoint(point) {
+        _assertClass(point, Point3D);
+        const ret = wasm.aabb_containsPoint(this.__wbg_ptr, point.__wbg_ptr);
+        return ret !== 0;
     }
     /**
-     * @returns {Vector3D}
+     * @param {Point3D} point
+     * @returns {AABB}
      */
-    size() {
-        const ret = wasm.aabb_size(this.__wbg_ptr);
-        return Vector3D.__wrap(ret);
+    expandByPoint(point) {
+        _assertClass(point, Point3D);
+        const ret = wasm.aabb_expandByPoint(this.__wbg_ptr, point.__wbg_ptr);
+        return AABB.__wrap(ret);
     }
     /**
-     * @returns {number}
+     * @param {number} amount
+     * @returns {AABB}
      */
-    volume() {
-        const ret = wasm.aabb_volume(this.__wbg_ptr);
-        return ret;
+    expandByScalar(amount) {
+        const ret = wasm.aabb_expandByScalar(this.__wbg_ptr, amount);
+        return AABB.__wrap(ret);
     }
     /**
-     * @returns {number}
+     * @returns {Point3D}
      */
-    surfaceArea() {
-        const ret = wasm.aabb_surfaceArea(this.__wbg_ptr);
-        return ret;
+    get max() {
+        const ret = wasm.aabb_max(this.__wbg_ptr);
+        return Point3D.__wrap(ret);
     }
     /**
-     * @returns {number}
+     * @returns {Point3D}
      */
-    diagonal() {
-        const ret = wasm.aabb_diagonal(this.__wbg_ptr);
-        return ret;
+    get min() {
+        const ret = wasm.aabb_min(this.__wbg_ptr);
+        return Point3D.__wrap(ret);
     }
     /**
-     * @param {Point3D} point
-     * @returns {boolean}
+     * @param {Point3D} min
+     * @param {Point3D} max
      */
-    containsPoint(point) {
-        _assertClass(point, Point3D);
-        const ret = wasm.aabb_containsPoint(this.__wbg_ptr, point.__wbg_ptr);
-        return ret !== 0;
+    constructor(min, max) {
+        _assertClass(min, Point3D);
+        _assertClass(max, Point3D);
+        const ret = wasm.aabb_new(min.__wbg_ptr, max.__wbg_ptr);
+        if (ret[2]) {
+            throw takeFromExternrefTable0(ret[1]);
+        }
+        this.__wbg_ptr = ret[0] >>> 0;
+        AABBFinalization.register(this, this.__wbg_ptr, this);
+        return this;
     }
     /**
-     * @param {AABB} other
-     * @returns {boolean}
+     * @returns {Vector3D}
      */
-    intersects(other) {
-        _assertClass(other, AABB);
-        const ret = wasm.aabb_intersects(this.__wbg_ptr, other.__wbg_ptr);
-        return ret !== 0;
+    size() {
+        const ret = wasm.aabb_size(this.__wbg_ptr);
+        return Vector3D.__wrap(ret);
     }
     /**
      * @param {AABB} other
@@ -354,21 +287,25 @@ export class AABB {
         return AABB.__wrap(ret);
     }
     /**
-     * @param {Point3D} point
-     * @returns {AABB}
+     * @returns {Point3D}
      */
-    expandByPoint(point) {
-        _assertClass(point, Point3D);
-        const ret = wasm.aabb_expandByPoint(this.__wbg_ptr, point.__wbg_ptr);
-        return AABB.__wrap(ret);
+    center() {
+        const ret = wasm.aabb_center(this.__wbg_ptr);
+        return Point3D.__wrap(ret);
     }
     /**
-     * @param {number} amount
-     * @returns {AABB}
+     * @returns {number}
      */
-    expandByScalar(amount) {
-        const ret = wasm.aabb_expandByScalar(this.__wbg_ptr, amount);
-        return AABB.__wrap(ret);
+    volume() {
+        const ret = wasm.aabb_volume(this.__wbg_ptr);
+        return ret;
+    }
+    /**
+     * @returns {number}
+     */
+    diagonal() {
+        const ret = wasm.aabb_diagonal(this.__wbg_ptr);
+        return ret;
     }
 }
 if (Symbol.dispose) AABB.prototype[Symbol.dispose] = AABB.prototype.free;
@@ -398,6 +335,16 @@ export class Camera {
         const ptr = this.__destroy_into_raw();
         wasm.__wbg_camera_free(ptr, 0);
     }
+    /**
+     * @param {AABB} aabb
+     */
+    fitToAabb(aabb) {
+        _assertClass(aabb, AABB);
+        const ret = wasm.camera_fitToAabb(this.__wbg_ptr, aabb.__wbg_ptr);
+        if (ret[1]) {
+            throw takeFromExternrefTable0(ret[0]);
+        }
+    }
     /**
      * @param {Point3D} position
      * @param {Point3D} target
@@ -413,7 +360,19 @@ export class Camera {
         _assertClass(target, Point3D);
         _assertClass(up, Vector3D);
         const ret = wasm.camera_perspective(position.__wbg_ptr, target.__wbg_ptr, up.__wbg_ptr, fov, aspect, near, far);
-        return Camera.__wrap(ret);
+        if (ret[2]) {
+            throw takeFromExternrefTable0(ret[1]);
+        }
+        return Camera.__wrap(ret[0]);
+    }
+    /**
+     * @returns {Float64Array}
+     */
+    viewMatrix() {
+        const ret = wasm.camera_viewMatrix(this.__wbg_ptr);
+        var v1 = getArrayF64FromWasm0(ret[0], ret[1]).slice();
+        wasm.__wbindgen_free(ret[0], ret[1] * 8, 8);
+        return v1;
     }
     /**
      * @param {Point3D} position
@@ -428,16 +387,47 @@ export class Camera {
         _assertClass(target, Point3D);
         _assertClass(up, Vector3D);
         const ret = wasm.camera_orthographic(position.__wbg_ptr, target.__wbg_ptr, up.__wbg_ptr, width, height);
-        return Camera.__wrap(ret);
+        if (ret[2]) {
+            throw takeFromExternrefTable0(ret[1]);
+        }
+        return Camera.__wrap(ret[0]);
     }
     /**
-     * @returns {Float64Array}
+     * @param {Sphere} sphere
      */
-    viewMatrix() {
-        const ret = wasm.camera_viewMatrix(this.__wbg_ptr);
-        var v1 = getArrayF64FromWasm0(ret[0], ret[1]).slice();
-        wasm.__wbindgen_free(ret[0], ret[1] * 8, 8);
-        return v1;
+    fitToSphere(sphere) {
+        _assertClass(sphere, Sphere);
+        const ret = wasm.camera_fitToSphere(this.__wbg_ptr, sphere.__wbg_ptr);
+        if (ret[1]) {
+            throw takeFromExternrefTable0(ret[0]);
+        }
+    }
+    /**
+     * @param {number} x
+     * @param {number} y
+     * @param {number} width
+     * @param {number} height
+     * @returns {Ray}
+     */
+    screenToRay(x, y, width, height) {
+        const ret = wasm.camera_screenToRay(this.__wbg_ptr, x, y, width, height);
+        return Ray.__wrap(ret);
+    }
+    /**
+     * @param {Point3D} position
+     * @param {Point3D} target
+     * @param {Vector3D} up
+     * @returns {Camera}
+     */
+    static equirectangular(position, target, up) {
+        _assertClass(position, Point3D);
+        _assertClass(target, Point3D);
+        _assertClass(up, Vector3D);
+        const ret = wasm.camera_equirectangular(position.__wbg_ptr, target.__wbg_ptr, up.__wbg_ptr);
+        if (ret[2]) {
+            throw takeFromExternrefTable0(ret[1]);
+        }
+        return Camera.__wrap(ret[0]);
     }
     /**
      * @returns {Float64Array}
@@ -448,16 +438,242 @@ export class Camera {
         wasm.__wbindgen_free(ret[0], ret[1] * 8, 8);
         return v1;
     }
-}
-if (Symbol.dispose) Camera.prototype[Symbol.dispose] = Camera.prototype.free;
-
-const LineSegmentFinalization = (typeof FinalizationRegistry === 'undefined')
-    ? { register: () => {}, unregister: () => {} }
-    : new FinalizationRegistry(ptr => wasm.__wbg_linesegment_free(ptr >>> 0, 1));
-
-export class LineSegment {
-
-    __destroy_into_raw() {
+    /**
+     * @returns {Vector3D}
+     */
+    get up() {
+        const ret = wasm.camera_up(this.__wbg_ptr);
+        return Vector3D.__wrap(ret);
+    }
+    /**
+     * @param {number} dx
+     * @param {number} dy
+     */
+    pan(dx, dy) {
+        wasm.camera_pan(this.__wbg_ptr, dx, dy);
+    }
+    /**
+     * @param {number} factor
+     */
+    zoom(factor) {
+        const ret = wasm.camera_zoom(this.__wbg_ptr, factor);
+        if (ret[1]) {
+            throw takeFromExternrefTable0(ret[0]);
+        }
+    }
+    /**
+     * @param {number} distance
+     */
+    dolly(distance) {
+        const ret = wasm.camera_dolly(this.__wbg_ptr, distance);
+        if (ret[1]) {
+            throw takeFromExternrefTable0(ret[0]);
+        }
+    }
+    /**
+     * @param {number} yaw
+     * @param {number} pitch
+     */
+    orbit(yaw, pitch) {
+        wasm.camera_orbit(this.__wbg_ptr, yaw, pitch);
+    }
+    /**
+     * @returns {Point3D}
+     */
+    get target() {
+        const ret = wasm.camera_target(this.__wbg_ptr);
+        return Point3D.__wrap(ret);
+    }
+    /**
+     * @param {Point3D} position
+     * @param {Point3D} target
+     * @param {Vector3D} up
+     * @param {number} width
+     * @param {number} height
+     * @returns {Camera}
+     */
+    static cabinet(position, target, up, width, height) {
+        _assertClass(position, Point3D);
+        _assertClass(target, Point3D);
+        _assertClass(up, Vector3D);
+        const ret = wasm.camera_cabinet(position.__wbg_ptr, target.__wbg_ptr, up.__wbg_ptr, width, height);
+        if (ret[2]) {
+            throw takeFromExternrefTable0(ret[1]);
+        }
+        return Camera.__wrap(ret[0]);
+    }
+    /**
+     * @param {Point3D} position
+     * @param {Point3D} target
+     * @param {Vector3D} up
+     * @param {number} fov
+     * @param {number} aspect
+     * @returns {Camera}
+     */
+    static fisheye(position, target, up, fov, aspect) {
+        _assertClass(position, Point3D);
+        _assertClass(target, Point3D);
+        _assertClass(up, Vector3D);
+        const ret = wasm.camera_fisheye(position.__wbg_ptr, target.__wbg_ptr, up.__wbg_ptr, fov, aspect);
+        if (ret[2]) {
+            throw takeFromExternrefTable0(ret[1]);
+        }
+        return Camera.__wrap(ret[0]);
+    }
+    /**
+     * @param {Point3D} target
+     */
+    lookAt(target) {
+        _assertClass(target, Point3D);
+        const ret = wasm.camera_lookAt(this.__wbg_ptr, target.__wbg_ptr);
+        if (ret[1]) {
+            throw takeFromExternrefTable0(ret[0]);
+        }
+    }
+    /**
+     * @param {Point3D} position
+     * @param {Point3D} target
+     * @param {Vector3D} up
+     * @param {number} width
+     * @param {number} height
+     * @param {number} angle
+     * @param {number} depth_scale
+     * @returns {Camera}
+     */
+    static oblique(position, target, up, width, height, angle, depth_scale) {
+        _assertClass(position, Point3D);
+        _assertClass(target, Point3D);
+        _assertClass(up, Vector3D);
+        const ret = wasm.camera_oblique(position.__wbg_ptr, target.__wbg_ptr, up.__wbg_ptr, width, height, angle, depth_scale);
+        if (ret[2]) {
+            throw takeFromExternrefTable0(ret[1]);
+        }
+        return Camera.__wrap(ret[0]);
+    }
+    /**
+     * @param {Point3D} position
+     * @param {Point3D} target
+     * @param {Vector3D} up
+     * @param {number} width
+     * @param {number} height
+     * @returns {Camera}
+     */
+    static cavalier(position, target, up, width, height) {
+        _assertClass(position, Point3D);
+        _assertClass(target, Point3D);
+        _assertClass(up, Vector3D);
+        const ret = wasm.camera_cavalier(position.__wbg_ptr, target.__wbg_ptr, up.__wbg_ptr, width, height);
+        if (ret[2]) {
+            throw takeFromExternrefTable0(ret[1]);
+        }
+        return Camera.__wrap(ret[0]);
+    }
+    /**
+     * @param {Point3D} target
+     * @param {Vector3D} up
+     * @param {number} width
+     * @param {number} height
+     * @returns {Camera}
+     */
+    static dimetric(target, up, width, height) {
+        _assertClass(target, Point3D);
+        _assertClass(up, Vector3D);
+        const ret = wasm.camera_dimetric(target.__wbg_ptr, up.__wbg_ptr, width, height);
+        if (ret[2]) {
+            throw takeFromExternrefTable0(ret[1]);
+        }
+        return Camera.__wrap(ret[0]);
+    }
+    /**
+     * `window` is `[left, right, bottom, top]` on the near plane.
+     * @param {Point3D} position
+     * @param {Point3D} target
+     * @param {Vector3D} up
+     * @param {Float64Array} window
+     * @param {number} near
+     * @param {number} far
+     * @returns {Camera}
+     */
+    static offAxis(position, target, up, window, near, far) {
+        _assertClass(position, Point3D);
+        _assertClass(target, Point3D);
+        _assertClass(up, Vector3D);
+        const ptr0 = passArrayF64ToWasm0(window, wasm.__wbindgen_malloc);
+        const len0 = WASM_VECTOR_LEN;
+        const ret = wasm.camera_offAxis(position.__wbg_ptr, target.__wbg_ptr, up.__wbg_ptr, ptr0, len0, near, far);
+        if (ret[2]) {
+            throw takeFromExternrefTable0(ret[1]);
+        }
+        return Camera.__wrap(ret[0]);
+    }
+    /**
+     * @returns {Point3D}
+     */
+    get position() {
+        const ret = wasm.camera_position(this.__wbg_ptr);
+        return Point3D.__wrap(ret);
+    }
+    /**
+     * @returns {boolean}
+     */
+    get isLinear() {
+        const ret = wasm.camera_isLinear(this.__wbg_ptr);
+        return ret !== 0;
+    }
+    /**
+     * @param {Point3D} target
+     * @param {Vector3D} up
+     * @param {number} width
+     * @param {number} height
+     * @returns {Camera}
+     */
+    static isometric(target, up, width, height) {
+        _assertClass(target, Point3D);
+        _assertClass(up, Vector3D);
+        const ret = wasm.camera_isometric(target.__wbg_ptr, up.__wbg_ptr, width, height);
+        if (ret[2]) {
+            throw takeFromExternrefTable0(ret[1]);
+        }
+        return Camera.__wrap(ret[0]);
+    }
+    /**
+     * @param {Point3D} target
+     * @param {Vector3D} up
+     * @param {number} yaw
+     * @param {number} elevation
+     * @param {number} width
+     * @param {number} height
+     * @returns {Camera}
+     */
+    static trimetric(target, up, yaw, elevation, width, height) {
+        _assertClass(target, Point3D);
+        _assertClass(up, Vector3D);
+        const ret = wasm.camera_trimetric(target.__wbg_ptr, up.__wbg_ptr, yaw, elevation, width, height);
+        if (ret[2]) {
+            throw takeFromExternrefTable0(ret[1]);
+        }
+        return Camera.__wrap(ret[0]);
+    }
+    /**
+     * @param {number} x
+     * @param {number} y
+     * @param {number} depth
+     * @returns {Point3D}
+     */
+    unproject(x, y, depth) {
+        const ret = wasm.camera_unproject(this.__wbg_ptr, x, y, depth);
+        return Point3D.__wrap(ret);
+    }
+}
+if (Symbol.dispose) Camera.prototype[Symbol.dispose] = Camera.prototype.free;
+
+const LineSegmentFinalization = (typeof FinalizationRegistry === 'undefined')
+    ? { register: () => {}, unregister: () => {} }
+    : new FinalizationRegistry(ptr => wasm.__wbg_linesegment_free(ptr >>> 0, 1));
+
+export class LineSegment {
+
+    __destroy_into_raw() {
         const ptr = this.__wbg_ptr;
         this.__wbg_ptr = 0;
         LineSegmentFinalization.unregister(this);
@@ -468,6 +684,31 @@ export class LineSegment {
         const ptr = this.__destroy_into_raw();
         wasm.__wbg_linesegment_free(ptr, 0);
     }
+    /**
+     * @param {Point3D} point
+     * @returns {Point3D}
+     */
+    closestPoint(point) {
+        _assertClass(point, Point3D);
+        const ret = wasm.linesegment_closestPoint(this.__wbg_ptr, point.__wbg_ptr);
+        return Point3D.__wrap(ret);
+    }
+    /**
+     * @param {Point3D} point
+     * @returns {number}
+     */
+    distanceToPoint(point) {
+        _assertClass(point, Point3D);
+        const ret = wasm.linesegment_distanceToPoint(this.__wbg_ptr, point.__wbg_ptr);
+        return ret;
+    }
+    /**
+     * @returns {Point3D}
+     */
+    get end() {
+        const ret = wasm.linesegment_end(this.__wbg_ptr);
+        return Point3D.__wrap(ret);
+    }
     /**
      * @param {Point3D} start
      * @param {Point3D} end
@@ -490,13 +731,6 @@ export class LineSegment {
         const ret = wasm.linesegment_start(this.__wbg_ptr);
         return Point3D.__wrap(ret);
     }
-    /**
-     * @returns {Point3D}
-     */
-    get end() {
-        const ret = wasm.linesegment_end(this.__wbg_ptr);
-        return Point3D.__wrap(ret);
-    }
     /**
      * @returns {number}
      */
@@ -504,13 +738,6 @@ export class LineSegment {
         const ret = wasm.linesegment_length(this.__wbg_ptr);
         return ret;
     }
-    /**
-     * @returns {Vector3D}
-     */
-    direction() {
-        const ret = wasm.linesegment_direction(this.__wbg_ptr);
-        return Vector3D.__wrap(ret);
-    }
     /**
      * @returns {Point3D}
      */
@@ -527,22 +754,11 @@ export class LineSegment {
         return Point3D.__wrap(ret);
     }
     /**
-     * @param {Point3D} point
-     * @returns {Point3D}
-     */
-    closestPoint(point) {
-        _assertClass(point, Point3D);
-        const ret = wasm.linesegment_closestPoint(this.__wbg_ptr, point.__wbg_ptr);
-        return Point3D.__wrap(ret);
-    }
-    /**
-     * @param {Point3D} point
-     * @returns {number}
+     * @returns {Vector3D}
      */
-    distanceToPoint(point) {
-        _assertClass(point, Point3D);
-        const ret = wasm.linesegment_distanceToPoint(this.__wbg_ptr, point.__wbg_ptr);
-        return ret;
+    direction() {
+        const ret = wasm.linesegment_direction(this.__wbg_ptr);
+        return Vector3D.__wrap(ret);
     }
 }
 if (Symbol.dispose) LineSegment.prototype[Symbol.dispose] = LineSegment.prototype.free;
@@ -573,48 +789,38 @@ export class Plane {
         wasm.__wbg_plane_free(ptr, 0);
     }
     /**
-     * @param {Point3D} point
-     * @param {Vector3D} normal
      * @returns {Plane}
      */
-    static fromPointNormal(point, normal) {
-        _assertClass(point, Point3D);
-        _assertClass(normal, Vector3D);
-        const ret = wasm.plane_fromPointNormal(point.__wbg_ptr, normal.__wbg_ptr);
-        if (ret[2]) {
-            throw takeFromExternrefTable0(ret[1]);
-        }
-        return Plane.__wrap(ret[0]);
+    flipNormal() {
+        const ret = wasm.plane_flipNormal(this.__wbg_ptr);
+        return Plane.__wrap(ret);
     }
     /**
-     * @param {Point3D} p1
-     * @param {Point3D} p2
-     * @param {Point3D} p3
-     * @returns {Plane}
+     * @param {Plane} other
+     * @returns {boolean}
      */
-    static fromThreePoints(p1, p2, p3) {
-        _assertClass(p1, Point3D);
-        _assertClass(p2, Point3D);
-        _assertClass(p3, Point3D);
-        const ret = wasm.plane_fromThreePoints(p1.__wbg_ptr, p2.__wbg_ptr, p3.__wbg_ptr);
-        if (ret[2]) {
-            throw takeFromExternrefTable0(ret[1]);
-        }
-        return Plane.__wrap(ret[0]);
+    isParallel(other) {
+        _assertClass(other, Plane);
+        const ret = wasm.plane_isParallel(this.__wbg_ptr, other.__wbg_ptr);
+        return ret !== 0;
     }
     /**
-     * @returns {Vector3D}
+     * @param {Point3D} point
+     * @returns {Point3D}
      */
-    get normal() {
-        const ret = wasm.plane_normal(this.__wbg_ptr);
-        return Vector3D.__wrap(ret);
+    closestPoint(point) {
+        _assertClass(point, Point3D);
+        const ret = wasm.plane_closestPoint(this.__wbg_ptr, point.__wbg_ptr);
+        return Point3D.__wrap(ret);
     }
     /**
-     * @returns {number}
+     * @param {Point3D} point
+     * @returns {boolean}
      */
-    get d() {
-        const ret = wasm.plane_d(this.__wbg_ptr);
-        return ret;
+    containsPoint(point) {
+        _assertClass(point, Point3D);
+        const ret = wasm.plane_containsPoint(this.__wbg_ptr, point.__wbg_ptr);
+        return ret !== 0;
     }
     /**
      * @param {Point3D} point
@@ -627,37 +833,47 @@ export class Plane {
     }
     /**
      * @param {Point3D} point
-     * @returns {Point3D}
+     * @param {Vector3D} normal
+     * @returns {Plane}
      */
-    closestPoint(point) {
+    static fromPointNormal(point, normal) {
         _assertClass(point, Point3D);
-        const ret = wasm.plane_closestPoint(this.__wbg_ptr, point.__wbg_ptr);
-        return Point3D.__wrap(ret);
+        _assertClass(normal, Vector3D);
+        const ret = wasm.plane_fromPointNormal(point.__wbg_ptr, normal.__wbg_ptr);
+        if (ret[2]) {
+            throw takeFromExternrefTable0(ret[1]);
+        }
+        return Plane.__wrap(ret[0]);
     }
     /**
-     * @param {Point3D} point
-     * @returns {boolean}
+     * @param {Point3D} p1
+     * @param {Point3D} p2
+     * @param {Point3D} p3
+     * @returns {Plane}
      */
-    containsPoint(point) {
-        _assertClass(point, Point3D);
-        const ret = wasm.plane_containsPoint(this.__wbg_ptr, point.__wbg_ptr);
-        return ret !== 0;
+    static fromThreePoints(p1, p2, p3) {
+        _assertClass(p1, Point3D);
+        _assertClass(p2, Point3D);
+        _assertClass(p3, Point3D);
+        const ret = wasm.plane_fromThreePoints(p1.__wbg_ptr, p2.__wbg_ptr, p3.__wbg_ptr);
+        if (ret[2]) {
+            throw takeFromExternrefTable0(ret[1]);
+        }
+        return Plane.__wrap(ret[0]);
     }
     /**
-     * @returns {Plane}
+     * @returns {number}
      */
-    flipNormal() {
-        const ret = wasm.plane_flipNormal(this.__wbg_ptr);
-        return Plane.__wrap(ret);
+    get d() {
+        const ret = wasm.plane_d(this.__wbg_ptr);
+        return ret;
     }
     /**
-     * @param {Plane} other
-     * @returns {boolean}
+     * @returns {Vector3D}
      */
-    isParallel(other) {
-        _assertClass(other, Plane);
-        const ret = wasm.plane_isParallel(this.__wbg_ptr, other.__wbg_ptr);
-        return ret !== 0;
+    get normal() {
+        const ret = wasm.plane_normal(this.__wbg_ptr);
+        return Vector3D.__wrap(ret);
     }
 }
 if (Symbol.dispose) Plane.prototype[Symbol.dispose] = Plane.prototype.free;
@@ -695,15 +911,26 @@ export class Point3D {
         wasm.__wbg_point3d_free(ptr, 0);
     }
     /**
-     * @param {number} x
-     * @param {number} y
-     * @param {number} z
+     * @param {Float64Array} arr
+     * @returns {Point3D}
      */
-    constructor(x, y, z) {
-        const ret = wasm.point3d_new(x, y, z);
-        this.__wbg_ptr = ret >>> 0;
-        Point3DFinalization.register(this, this.__wbg_ptr, this);
-        return this;
+    static fromArray(arr) {
+        const ptr0 = passArrayF64ToWasm0(arr, wasm.__wbindgen_malloc);
+        const len0 = WASM_VECTOR_LEN;
+        const ret = wasm.point3d_fromArray(ptr0, len0);
+        if (ret[2]) {
+            throw takeFromExternrefTable0(ret[1]);
+        }
+        return Point3D.__wrap(ret[0]);
+    }
+    /**
+     * @param {Point3D} other
+     * @returns {number}
+     */
+    distanceTo(other) {
+        _assertClass(other, Point3D);
+        const ret = wasm.point3d_distanceTo(this.__wbg_ptr, other.__wbg_ptr);
+        return ret;
     }
     /**
      * @returns {number}
@@ -727,30 +954,30 @@ export class Point3D {
         return ret;
     }
     /**
-     * @param {Point3D} other
-     * @returns {number}
+     * @param {number} x
+     * @param {number} y
+     * @param {number} z
      */
-    distanceTo(other) {
-        _assertClass(other, Point3D);
-        const ret = wasm.point3d_distanceTo(this.__wbg_ptr, other.__wbg_ptr);
-        return ret;
+    constructor(x, y, z) {
+        const ret = wasm.point3d_new(x, y, z);
+        this.__wbg_ptr = ret >>> 0;
+        Point3DFinalization.register(this, this.__wbg_ptr, this);
+        return this;
     }
     /**
-     * @param {Point3D} other
      * @returns {Point3D}
      */
-    midpoint(other) {
-        _assertClass(other, Point3D);
-        const ret = wasm.point3d_midpoint(this.__wbg_ptr, other.__wbg_ptr);
+    static origin() {
+        const ret = wasm.point3d_origin();
         return Point3D.__wrap(ret);
     }
     /**
-     * @param {Vector3D} vector
+     * @param {Point3D} other
      * @returns {Point3D}
      */
-    translate(vector) {
-        _assertClass(vector, Vector3D);
-        const ret = wasm.point3d_translate(this.__wbg_ptr, vector.__wbg_ptr);
+    midpoint(other) {
+        _assertClass(other, Point3D);
+        const ret = wasm.point3d_midpoint(this.__wbg_ptr, other.__wbg_ptr);
         return Point3D.__wrap(ret);
     }
     /**
@@ -763,23 +990,12 @@ export class Point3D {
         return v1;
     }
     /**
-     * @param {Float64Array} arr
-     * @returns {Point3D}
-     */
-    static fromArray(arr) {
-        const ptr0 = passArrayF64ToWasm0(arr, wasm.__wbindgen_malloc);
-        const len0 = WASM_VECTOR_LEN;
-        const ret = wasm.point3d_fromArray(ptr0, len0);
-        if (ret[2]) {
-            throw takeFromExternrefTable0(ret[1]);
-        }
-        return Point3D.__wrap(ret[0]);
-    }
-    /**
+     * @param {Vector3D} vector
      * @returns {Point3D}
      */
-    static origin() {
-        const ret = wasm.point3d_origin();
+    translate(vector) {
+        _assertClass(vector, Vector3D);
+        const ret = wasm.point3d_translate(this.__wbg_ptr, vector.__wbg_ptr);
         return Point3D.__wrap(ret);
     }
 }
@@ -791,6 +1007,14 @@ const RayFinalization = (typeof FinalizationRegistry === 'undefined')
 
 export class Ray {
 
+    static __wrap(ptr) {
+        ptr = ptr >>> 0;
+        const obj = Object.create(Ray.prototype);
+        obj.__wbg_ptr = ptr;
+        RayFinalization.register(obj, obj.__wbg_ptr, obj);
+        return obj;
+    }
+
     __destroy_into_raw() {
         const ptr = this.__wbg_ptr;
         this.__wbg_ptr = 0;
@@ -802,6 +1026,80 @@ export class Ray {
         const ptr = this.__destroy_into_raw();
         wasm.__wbg_ray_free(ptr, 0);
     }
+    /**
+     * @param {Point3D} origin
+     * @param {Vector3D} direction
+     * @param {number} t_min
+     * @param {number} t_max
+     * @returns {Ray}
+     */
+    static withRange(origin, direction, t_min, t_max) {
+        _assertClass(origin, Point3D);
+        _assertClass(direction, Vector3D);
+        const ret = wasm.ray_withRange(origin.__wbg_ptr, direction.__wbg_ptr, t_min, t_max);
+        if (ret[2]) {
+            throw takeFromExternrefTable0(ret[1]);
+        }
+        return Ray.__wrap(ret[0]);
+    }
+    /**
+     * @param {AABB} aabb
+     * @returns {any}
+     */
+    intersectAABB(aabb) {
+        _assertClass(aabb, AABB);
+        const ret = wasm.ray_intersectAABB(this.__wbg_ptr, aabb.__wbg_ptr);
+        return ret;
+    }
+    /**
+     * @param {Plane} plane
+     * @returns {any}
+     */
+    intersectPlane(plane) {
+        _assertClass(plane, Plane);
+        const ret = wasm.ray_intersectPlane(this.__wbg_ptr, plane.__wbg_ptr);
+        return ret;
+    }
+    /**
+     * @param {Sphere} sphere
+     * @returns {any}
+     */
+    intersectSphere(sphere) {
+        _assertClass(sphere, Sphere);
+        const ret = wasm.ray_intersectSphere(this.__wbg_ptr, sphere.__wbg_ptr);
+        return ret;
+    }
+    /**
+     * @param {AABB} aabb
+     * @returns {RayHit[]}
+     */
+    intersectAABBAll(aabb) {
+        _assertClass(aabb, AABB);
+        const ret = wasm.ray_intersectAABBAll(this.__wbg_ptr, aabb.__wbg_ptr);
+        var v1 = getArrayJsValueFromWasm0(ret[0], ret[1]).slice();
+        wasm.__wbindgen_free(ret[0], ret[1] * 4, 4);
+        return v1;
+    }
+    /**
+     * @param {Triangle} triangle
+     * @returns {any}
+     */
+    intersectTriangle(triangle) {
+        _assertClass(triangle, Triangle);
+        const ret = wasm.ray_intersectTriangle(this.__wbg_ptr, triangle.__wbg_ptr);
+        return ret;
+    }
+    /**
+     * @param {Sphere} sphere
+     * @returns {RayHit[]}
+     */
+    intersectSphereAll(sphere) {
+        _assertClass(sphere, Sphere);
+        const ret = wasm.ray_intersectSphereAll(this.__wbg_ptr, sphere.__wbg_ptr);
+        var v1 = getArrayJsValueFromWasm0(ret[0], ret[1]).slice();
+        wasm.__wbindgen_free(ret[0], ret[1] * 4, 4);
+        return v1;
+    }
     /**
      * @param {Point3D} origin
      * @param {Vector3D} direction
@@ -817,6 +1115,20 @@ export class Ray {
         RayFinalization.register(this, this.__wbg_ptr, this);
         return this;
     }
+    /**
+     * @returns {number}
+     */
+    get tMax() {
+        const ret = wasm.ray_tMax(this.__wbg_ptr);
+        return ret;
+    }
+    /**
+     * @returns {number}
+     */
+    get tMin() {
+        const ret = wasm.ray_tMin(this.__wbg_ptr);
+        return ret;
+    }
     /**
      * @returns {Point3D}
      */
@@ -825,66 +1137,552 @@ export class Ray {
         return Point3D.__wrap(ret);
     }
     /**
-     * @returns {Vector3D}
+     * @param {number} t
+     * @returns {Point3D}
+     */
+    pointAt(t) {
+        const ret = wasm.ray_pointAt(this.__wbg_ptr, t);
+        return Point3D.__wrap(ret);
+    }
+    /**
+     * @returns {Vector3D}
+     */
+    get direction() {
+        const ret = wasm.ray_direction(this.__wbg_ptr);
+        return Vector3D.__wrap(ret);
+    }
+}
+if (Symbol.dispose) Ray.prototype[Symbol.dispose] = Ray.prototype.free;
+
+const RayHitFinalization = (typeof FinalizationRegistry === 'undefined')
+    ? { register: () => {}, unregister: () => {} }
+    : new FinalizationRegistry(ptr => wasm.__wbg_rayhit_free(ptr >>> 0, 1));
+
+export class RayHit {
+
+    static __wrap(ptr) {
+        ptr = ptr >>> 0;
+        const obj = Object.create(RayHit.prototype);
+        obj.__wbg_ptr = ptr;
+        RayHitFinalization.register(obj, obj.__wbg_ptr, obj);
+        return obj;
+    }
+
+    __destroy_into_raw() {
+        const ptr = this.__wbg_ptr;
+        this.__wbg_ptr = 0;
+        RayHitFinalization.unregister(this);
+        return ptr;
+    }
+
+    free() {
+        const ptr = this.__destroy_into_raw();
+        wasm.__wbg_rayhit_free(ptr, 0);
+    }
+    /**
+     * @returns {boolean}
+     */
+    get frontFace() {
+        const ret = wasm.rayhit_frontFace(this.__wbg_ptr);
+        return ret !== 0;
+    }
+    /**
+     * @returns {Float64Array | undefined}
+     */
+    get barycentric() {
+        const ret = wasm.rayhit_barycentric(this.__wbg_ptr);
+        let v1;
+        if (ret[0] !== 0) {
+            v1 = getArrayF64FromWasm0(ret[0], ret[1]).slice();
+            wasm.__wbindgen_free(ret[0], ret[1] * 8, 8);
+        }
+        return v1;
+    }
+    /**
+     * @returns {number}
+     */
+    get t() {
+        const ret = wasm.ray_tMax(this.__wbg_ptr);
+        return ret;
+    }
+    /**
+     * @returns {Float64Array | undefined}
+     */
+    get uv() {
+        const ret = wasm.rayhit_uv(this.__wbg_ptr);
+        let v1;
+        if (ret[0] !== 0) {
+            v1 = getArrayF64FromWasm0(ret[0], ret[1]).slice();
+            wasm.__wbindgen_free(ret[0], ret[1] * 8, 8);
+        }
+        return v1;
+    }
+    /**
+     * @returns {Point3D}
+     */
+    get point() {
+        const ret = wasm.rayhit_point(this.__wbg_ptr);
+        return Point3D.__wrap(ret);
+    }
+    /**
+     * @returns {Vector3D}
+     */
+    get normal() {
+        const ret = wasm.rayhit_normal(this.__wbg_ptr);
+        return Vector3D.__wrap(ret);
+    }
+}
+if (Symbol.dispose) RayHit.prototype[Symbol.dispose] = RayHit.prototype.free;
+
+const SVGRendererFinalization = (typeof FinalizationRegistry === 'undefined')
+    ? { register: () => {}, unregister: () => {} }
+    : new FinalizationRegistry(ptr => wasm.__wbg_svgrenderer_free(ptr >>> 0, 1));
+
+export class SVGRenderer {
+
+    __destroy_into_raw() {
+        const ptr = this.__wbg_ptr;
+        this.__wbg_ptr = 0;
+        SVGRendererFinalization.unregister(this);
+        return ptr;
+    }
+
+    free() {
+        const ptr = this.__destroy_into_raw();
+        wasm.__wbg_svgrenderer_free(ptr, 0);
+    }
+    /**
+     * @param {string[]} texts
+     * @param {Style[]} styles
+     * @param {number} x
+     * @param {number} y
+     * @param {Style} style
+     */
+    addLegend(texts, styles, x, y, style) {
+        const ptr0 = passArrayJsValueToWasm0(texts, wasm.__wbindgen_malloc);
+        const len0 = WASM_VECTOR_LEN;
+        const ptr1 = passArrayJsValueToWasm0(styles, wasm.__wbindgen_malloc);
+        const len1 = WASM_VECTOR_LEN;
+        _assertClass(style, Style);
+        const ret = wasm.svgrenderer_addLegend(this.__wbg_ptr, ptr0, len0, ptr1, len1, x, y, style.__wbg_ptr);
+        if (ret[1]) {
+            throw takeFromExternrefTable0(ret[0]);
+        }
+    }
+    /**
+     * @param {Sphere} sphere
+     * @param {string} color
+     * @param {number} width
+     */
+    addSphere(sphere, color, width) {
+        _assertClass(sphere, Sphere);
+        const ptr0 = passStringToWasm0(color, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
+        const len0 = WASM_VECTOR_LEN;
+        wasm.svgrenderer_addSphere(this.__wbg_ptr, sphere.__wbg_ptr, ptr0, len0, width);
+    }
+    /**
+     * @param {Point3D} origin
+     * @param {Vector3D} vector
+     * @param {string} color
+     * @param {number} width
+     */
+    addVector(origin, vector, color, width) {
+        _assertClass(origin, Point3D);
+        _assertClass(vector, Vector3D);
+        const ptr0 = passStringToWasm0(color, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
+        const len0 = WASM_VECTOR_LEN;
+        const ret = wasm.svgrenderer_addVector(this.__wbg_ptr, origin.__wbg_ptr, vector.__wbg_ptr, ptr0, len0, width);
+        if (ret[1]) {
+            throw takeFromExternrefTable0(ret[0]);
+        }
+    }
+    /**
+     * Lights the fill of triangles and spheres added from now on:
+     * `"none"`, `"flat"` or `"gouraud"`.
+     * @param {string} mode
+     */
+    setShading(mode) {
+        const ptr0 = passStringToWasm0(mode, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
+        const len0 = WASM_VECTOR_LEN;
+        const ret = wasm.svgrenderer_setShading(this.__wbg_ptr, ptr0, len0);
+        if (ret[1]) {
+            throw takeFromExternrefTable0(ret[0]);
+        }
+    }
+    /**
+     * @param {Triangle} triangle
+     * @param {string} stroke
+     * @param {string | null | undefined} fill
+     * @param {number} width
+     */
+    addTriangle(triangle, stroke, fill, width) {
+        _assertClass(triangle, Triangle);
+        const ptr0 = passStringToWasm0(stroke, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
+        const len0 = WASM_VECTOR_LEN;
+        var ptr1 = isLikeNone(fill) ? 0 : passStringToWasm0(fill, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
+        var len1 = WASM_VECTOR_LEN;
+        wasm.svgrenderer_addTriangle(this.__wbg_ptr, triangle.__wbg_ptr, ptr0, len0, ptr1, len1, width);
+    }
+    clearLights() {
+        wasm.svgrenderer_clearLights(this.__wbg_ptr);
+    }
+    /**
+     * @returns {AABB | undefined}
+     */
+    sceneBounds() {
+        const ret = wasm.svgrenderer_sceneBounds(this.__wbg_ptr);
+        return ret === 0 ? undefined : AABB.__wrap(ret);
+    }
+    /**
+     * @returns {string}
+     */
+    toSvgString() {
+        let deferred1_0;
+        let deferred1_1;
+        try {
+            const ret = wasm.svgrenderer_toSvgString(this.__wbg_ptr);
+            deferred1_0 = ret[0];
+            deferred1_1 = ret[1];
+            return getStringFromWasm0(ret[0], ret[1]);
+        } finally {
+            wasm.__wbindgen_free(deferred1_0, deferred1_1, 1);
+        }
+    }
+    /**
+     * @param {string} color
+     */
+    setBackground(color) {
+        const ptr0 = passStringToWasm0(color, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
+        const len0 = WASM_VECTOR_LEN;
+        wasm.svgrenderer_setBackground(this.__wbg_ptr, ptr0, len0);
+    }
+    /**
+     * Sets the drawing order: "none", "painter" or "bsp".
+     * @param {string} mode
+     */
+    setDepthSort(mode) {
+        const ptr0 = passStringToWasm0(mode, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
+        const len0 = WASM_VECTOR_LEN;
+        const ret = wasm.svgrenderer_setDepthSort(this.__wbg_ptr, ptr0, len0);
+        if (ret[1]) {
+            throw takeFromExternrefTable0(ret[0]);
+        }
+    }
+    /**
+     * @param {string} css
+     */
+    setStylesheet(css) {
+        const ptr0 = passStringToWasm0(css, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
+        const len0 = WASM_VECTOR_LEN;
+        wasm.svgrenderer_setStylesheet(this.__wbg_ptr, ptr0, len0);
+    }
+    /**
+     * @param {Point3D} position
+     * @param {string} color
+     * @param {number} intensity
+     */
+    addPointLight(position, color, intensity) {
+        _assertClass(position, Point3D);
+        const ptr0 = passStringToWasm0(color, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
+        const len0 = WASM_VECTOR_LEN;
+        const ret = wasm.svgrenderer_addPointLight(this.__wbg_ptr, position.__wbg_ptr, ptr0, len0, intensity);
+        if (ret[1]) {
+            throw takeFromExternrefTable0(ret[0]);
+        }
+    }
+    /**
+     * @param {Point3D} point
+     * @param {number} dx
+     * @param {number} dy
+     * @param {string} text
+     * @param {Style} style
+     */
+    addLeaderLabel(point, dx, dy, text, style) {
+        _assertClass(point, Point3D);
+        const ptr0 = passStringToWasm0(text, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
+        const len0 = WASM_VECTOR_LEN;
+        _assertClass(style, Style);
+        wasm.svgrenderer_addLeaderLabel(this.__wbg_ptr, point.__wbg_ptr, dx, dy, ptr0, len0, style.__wbg_ptr);
+    }
+    /**
+     * @param {LineSegment} segment
+     * @param {string} color
+     * @param {number} width
+     */
+    addLineSegment(segment, color, width) {
+        _assertClass(segment, LineSegment);
+        const ptr0 = passStringToWasm0(color, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
+        const len0 = WASM_VECTOR_LEN;
+        wasm.svgrenderer_addLineSegment(this.__wbg_ptr, segment.__wbg_ptr, ptr0, len0, width);
+    }
+    /**
+     * @param {Style} style
+     * @returns {AABB | undefined}
+     */
+    addSceneBounds(style) {
+        _assertClass(style, Style);
+        const ret = wasm.svgrenderer_addSceneBounds(this.__wbg_ptr, style.__wbg_ptr);
+        return ret === 0 ? undefined : AABB.__wrap(ret);
+    }
+    /**
+     * Sets how hidden edges are drawn: "shown", "dashed" or "removed".
+     * @param {string} mode
+     */
+    setHiddenLines(mode) {
+        const ptr0 = passStringToWasm0(mode, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
+        const len0 = WASM_VECTOR_LEN;
+        const ret = wasm.svgrenderer_setHiddenLines(this.__wbg_ptr, ptr0, len0);
+        if (ret[1]) {
+            throw takeFromExternrefTable0(ret[0]);
+        }
+    }
+    /**
+     * `color` is a "#rgb" or "#rrggbb" string.
+     * @param {string} color
+     * @param {number} intensity
+     */
+    addAmbientLight(color, intensity) {
+        const ptr0 = passStringToWasm0(color, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
+        const len0 = WASM_VECTOR_LEN;
+        const ret = wasm.svgrenderer_addAmbientLight(this.__wbg_ptr, ptr0, len0, intensity);
+        if (ret[1]) {
+            throw takeFromExternrefTable0(ret[0]);
+        }
+    }
+    /**
+     * @param {Sphere} sphere
+     * @param {string} stroke
+     * @param {string | null | undefined} fill
+     * @param {number} width
+     */
+    addFilledSphere(sphere, stroke, fill, width) {
+        _assertClass(sphere, Sphere);
+        const ptr0 = passStringToWasm0(stroke, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
+        const len0 = WASM_VECTOR_LEN;
+        var ptr1 = isLikeNone(fill) ? 0 : passStringToWasm0(fill, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
+        var len1 = WASM_VECTOR_LEN;
+        wasm.svgrenderer_addFilledSphere(this.__wbg_ptr, sphere.__wbg_ptr, ptr0, len0, ptr1, len1, width);
+    }
+    /**
+     * @param {string | null} [name]
+     */
+    setCurrentLayer(name) {
+        var ptr0 = isLikeNone(name) ? 0 : passStringToWasm0(name, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
+        var len0 = WASM_VECTOR_LEN;
+        const ret = wasm.svgrenderer_setCurrentLayer(this.__wbg_ptr, ptr0, len0);
+        if (ret[1]) {
+            throw takeFromExternrefTable0(ret[0]);
+        }
+    }
+    /**
+     * @param {string} name
+     * @param {boolean} visible
+     */
+    setLayerVisible(name, visible) {
+        const ptr0 = passStringToWasm0(name, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
+        const len0 = WASM_VECTOR_LEN;
+        const ret = wasm.svgrenderer_setLayerVisible(this.__wbg_ptr, ptr0, len0, visible);
+        if (ret[1]) {
+            throw takeFromExternrefTable0(ret[0]);
+        }
+    }
+    /**
+     * @param {Ray} ray
+     * @param {Style} style
+     */
+    addRayWithStyle(ray, style) {
+        _assertClass(ray, Ray);
+        _assertClass(style, Style);
+        wasm.svgrenderer_addRayWithStyle(this.__wbg_ptr, ray.__wbg_ptr, style.__wbg_ptr);
+    }
+    /**
+     * @param {AABB} aabb
+     * @param {Style} style
+     */
+    addAabbWithStyle(aabb, style) {
+        _assertClass(aabb, AABB);
+        _assertClass(style, Style);
+        wasm.svgrenderer_addAabbWithStyle(this.__wbg_ptr, aabb.__wbg_ptr, style.__wbg_ptr);
+    }
+    /**
+     * @param {string} name
+     * @param {boolean} visible
+     * @param {string | null | undefined} transform
+     * @param {Style} style
+     */
+    addLayerWithStyle(name, visible, transform, style) {
+        const ptr0 = passStringToWasm0(name, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
+        const len0 = WASM_VECTOR_LEN;
+        var ptr1 = isLikeNone(transform) ? 0 : passStringToWasm0(transform, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
+        var len1 = WASM_VECTOR_LEN;
+        _assertClass(style, Style);
+        const ret = wasm.svgrenderer_addLayerWithStyle(this.__wbg_ptr, ptr0, len0, visible, ptr1, len1, style.__wbg_ptr);
+        if (ret[1]) {
+            throw takeFromExternrefTable0(ret[0]);
+        }
+    }
+    /**
+     * @param {Point3D} start
+     * @param {Point3D} end
+     * @param {Vector3D} offset
+     * @param {Style} style
+     * @returns {number}
+     */
+    addLinearDimension(start, end, offset, style) {
+        _assertClass(start, Point3D);
+        _assertClass(end, Point3D);
+        _assertClass(offset, Vector3D);
+        _assertClass(style, Style);
+        const ret = wasm.svgrenderer_addLinearDimension(this.__wbg_ptr, start.__wbg_ptr, end.__wbg_ptr, offset.__wbg_ptr, style.__wbg_ptr);
+        return ret;
+    }
+    /**
+     * @param {Plane} plane
+     * @param {Point3D} center
+     * @param {number} size
+     * @param {Style} style
+     */
+    addPlaneWithStyle(plane, center, size, style) {
+        _assertClass(plane, Plane);
+        _assertClass(center, Point3D);
+        _assertClass(style, Style);
+        const ret = wasm.svgrenderer_addPlaneWithStyle(this.__wbg_ptr, plane.__wbg_ptr, center.__wbg_ptr, size, style.__wbg_ptr);
+        if (ret[1]) {
+            throw takeFromExternrefTable0(ret[0]);
+        }
+    }
+    /**
+     * @param {Point3D} point
+     * @param {number} size
+     * @param {Style} style
+     */
+    addPointWithStyle(point, size, style) {
+        _assertClass(point, Point3D);
+        _assertClass(style, Style);
+        wasm.svgrenderer_addPointWithStyle(this.__wbg_ptr, point.__wbg_ptr, size, style.__wbg_ptr);
+    }
+    /**
+     * @param {Point3D} vertex
+     * @param {Point3D} from
+     * @param {Point3D} to
+     * @param {number} radius
+     * @param {Style} style
+     * @returns {number}
+     */
+    addAngularDimension(vertex, from, to, radius, style) {
+        _assertClass(vertex, Point3D);
+        _assertClass(from, Point3D);
+        _assertClass(to, Point3D);
+        _assertClass(style, Style);
+        const ret = wasm.svgrenderer_addAngularDimension(this.__wbg_ptr, vertex.__wbg_ptr, from.__wbg_ptr, to.__wbg_ptr, radius, style.__wbg_ptr);
+        if (ret[2]) {
+            throw takeFromExternrefTable0(ret[1]);
+        }
+        return ret[0];
+    }
+    /**
+     * `direction` is the way the light travels.
+     * @param {Vector3D} direction
+     * @param {string} color
+     * @param {number} intensity
+     */
+    addDirectionalLight(direction, color, intensity) {
+        _assertClass(direction, Vector3D);
+        const ptr0 = passStringToWasm0(color, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
+        const len0 = WASM_VECTOR_LEN;
+        const ret = wasm.svgrenderer_addDirectionalLight(this.__wbg_ptr, direction.__wbg_ptr, ptr0, len0, intensity);
+        if (ret[1]) {
+            throw takeFromExternrefTable0(ret[0]);
+        }
+    }
+    /**
+     * @param {Sphere} sphere
+     * @param {Style} style
      */
-    get direction() {
-        const ret = wasm.ray_direction(this.__wbg_ptr);
-        return Vector3D.__wrap(ret);
+    addSphereWithStyle(sphere, style) {
+        _assertClass(sphere, Sphere);
+        _assertClass(style, Style);
+        wasm.svgrenderer_addSphereWithStyle(this.__wbg_ptr, sphere.__wbg_ptr, style.__wbg_ptr);
     }
     /**
-     * @param {number} t
-     * @returns {Point3D}
+     * @param {Point3D} origin
+     * @param {Vector3D} vector
+     * @param {Style} style
      */
-    pointAt(t) {
-        const ret = wasm.ray_pointAt(this.__wbg_ptr, t);
-        return Point3D.__wrap(ret);
+    addVectorWithStyle(origin, vector, style) {
+        _assertClass(origin, Point3D);
+        _assertClass(vector, Vector3D);
+        _assertClass(style, Style);
+        const ret = wasm.svgrenderer_addVectorWithStyle(this.__wbg_ptr, origin.__wbg_ptr, vector.__wbg_ptr, style.__wbg_ptr);
+        if (ret[1]) {
+            throw takeFromExternrefTable0(ret[0]);
+        }
     }
     /**
-     * @param {Sphere} sphere
-     * @returns {any}
+     * Drops triangles added from now on that face away from the camera.
+     * @param {boolean} enabled
      */
-    intersectSphere(sphere) {
-        _assertClass(sphere, Sphere);
-        const ret = wasm.ray_intersectSphere(this.__wbg_ptr, sphere.__wbg_ptr);
-        return ret;
+    setBackFaceCulling(enabled) {
+        wasm.svgrenderer_setBackFaceCulling(this.__wbg_ptr, enabled);
     }
     /**
-     * @param {Plane} plane
-     * @returns {any}
+     * @param {Triangle} triangle
+     * @param {Style} style
      */
-    intersectPlane(plane) {
-        _assertClass(plane, Plane);
-        const ret = wasm.ray_intersectPlane(this.__wbg_ptr, plane.__wbg_ptr);
-        return ret;
+    addTriangleWithStyle(triangle, style) {
+        _assertClass(triangle, Triangle);
+        _assertClass(style, Style);
+        wasm.svgrenderer_addTriangleWithStyle(this.__wbg_ptr, triangle.__wbg_ptr, style.__wbg_ptr);
     }
     /**
+     * Like `addTriangleWithStyle`, with the surface normals at `a`, `b`
+     * and `c` for Gouraud shading.
      * @param {Triangle} triangle
-     * @returns {any}
+     * @param {Vector3D} normal_a
+     * @param {Vector3D} normal_b
+     * @param {Vector3D} normal_c
+     * @param {Style} style
      */
-    intersectTriangle(triangle) {
+    addTriangleWithNormals(triangle, normal_a, normal_b, normal_c, style) {
         _assertClass(triangle, Triangle);
-        const ret = wasm.ray_intersectTriangle(this.__wbg_ptr, triangle.__wbg_ptr);
-        return ret;
+        _assertClass(normal_a, Vector3D);
+        _assertClass(normal_b, Vector3D);
+        _assertClass(normal_c, Vector3D);
+        _assertClass(style, Style);
+        wasm.svgrenderer_addTriangleWithNormals(this.__wbg_ptr, triangle.__wbg_ptr, normal_a.__wbg_ptr, normal_b.__wbg_ptr, normal_c.__wbg_ptr, style.__wbg_ptr);
     }
-}
-if (Symbol.dispose) Ray.prototype[Symbol.dispose] = Ray.prototype.free;
-
-const SVGRendererFinalization = (typeof FinalizationRegistry === 'undefined')
-    ? { register: () => {}, unregister: () => {} }
-    : new FinalizationRegistry(ptr => wasm.__wbg_svgrenderer_free(ptr >>> 0, 1));
-
-export class SVGRenderer {
-
-    __destroy_into_raw() {
-        const ptr = this.__wbg_ptr;
-        this.__wbg_ptr = 0;
-        SVGRendererFinalization.unregister(this);
-        return ptr;
+    /**
+     * @param {string[]} layers
+     * @returns {string}
+     */
+    toSvgStringWithLayers(layers) {
+        let deferred3_0;
+        let deferred3_1;
+        try {
+            const ptr0 = passArrayJsValueToWasm0(layers, wasm.__wbindgen_malloc);
+            const len0 = WASM_VECTOR_LEN;
+            const ret = wasm.svgrenderer_toSvgStringWithLayers(this.__wbg_ptr, ptr0, len0);
+            var ptr2 = ret[0];
+            var len2 = ret[1];
+            if (ret[3]) {
+                ptr2 = 0; len2 = 0;
+                throw takeFromExternrefTable0(ret[2]);
+            }
+            deferred3_0 = ptr2;
+            deferred3_1 = len2;
+            return getStringFromWasm0(ptr2, len2);
+        } finally {
+            wasm.__wbindgen_free(deferred3_0, deferred3_1, 1);
+        }
     }
-
-    free() {
-        const ptr = this.__destroy_into_raw();
-        wasm.__wbg_svgrenderer_free(ptr, 0);
+    /**
+     * @param {LineSegment} segment
+     * @param {Style} style
+     */
+    addLineSegmentWithStyle(segment, style) {
+        _assertClass(segment, LineSegment);
+        _assertClass(style, Style);
+        wasm.svgrenderer_addLineSegmentWithStyle(this.__wbg_ptr, segment.__wbg_ptr, style.__wbg_ptr);
     }
     /**
      * @param {number} width
@@ -899,84 +1697,128 @@ export class SVGRenderer {
         return this;
     }
     /**
+     * @param {Ray} ray
      * @param {string} color
+     * @param {number} width
      */
-    setBackground(color) {
+    addRay(ray, color, width) {
+        _assertClass(ray, Ray);
         const ptr0 = passStringToWasm0(color, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
         const len0 = WASM_VECTOR_LEN;
-        wasm.svgrenderer_setBackground(this.__wbg_ptr, ptr0, len0);
+        wasm.svgrenderer_addRay(this.__wbg_ptr, ray.__wbg_ptr, ptr0, len0, width);
     }
     /**
-     * @param {Point3D} point
+     * @param {AABB} aabb
      * @param {string} color
+     * @param {number} width
+     */
+    addAabb(aabb, color, width) {
+        _assertClass(aabb, AABB);
+        const ptr0 = passStringToWasm0(color, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
+        const len0 = WASM_VECTOR_LEN;
+        wasm.svgrenderer_addAabb(this.__wbg_ptr, aabb.__wbg_ptr, ptr0, len0, width);
+    }
+    /**
+     * @param {Point3D} origin
+     * @param {number} length
+     * @param {Style} style
+     */
+    addAxes(origin, length, style) {
+        _assertClass(origin, Point3D);
+        _assertClass(style, Style);
+        const ret = wasm.svgrenderer_addAxes(this.__wbg_ptr, origin.__wbg_ptr, length, style.__wbg_ptr);
+        if (ret[1]) {
+            throw takeFromExternrefTable0(ret[0]);
+        }
+    }
+    /**
+     * @param {Plane} plane
+     * @param {Point3D} center
      * @param {number} size
+     * @param {number} divisions
+     * @param {Style} style
      */
-    addPoint(point, color, size) {
+    addGrid(plane, center, size, divisions, style) {
+        _assertClass(plane, Plane);
+        _assertClass(center, Point3D);
+        _assertClass(style, Style);
+        const ret = wasm.svgrenderer_addGrid(this.__wbg_ptr, plane.__wbg_ptr, center.__wbg_ptr, size, divisions, style.__wbg_ptr);
+        if (ret[1]) {
+            throw takeFromExternrefTable0(ret[0]);
+        }
+    }
+    /**
+     * @param {Point3D} point
+     * @param {string} text
+     * @param {Style} style
+     */
+    addLabel(point, text, style) {
         _assertClass(point, Point3D);
-        const ptr0 = passStringToWasm0(color, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
+        const ptr0 = passStringToWasm0(text, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
         const len0 = WASM_VECTOR_LEN;
-        wasm.svgrenderer_addPoint(this.__wbg_ptr, point.__wbg_ptr, ptr0, len0, size);
+        _assertClass(style, Style);
+        wasm.svgrenderer_addLabel(this.__wbg_ptr, point.__wbg_ptr, ptr0, len0, style.__wbg_ptr);
     }
     /**
-     * @param {LineSegment} segment
-     * @param {string} color
-     * @param {number} width
+     * @param {string} name
+     * @param {boolean} visible
+     * @param {string | null} [transform]
      */
-    addLineSegment(segment, color, width) {
-        _assertClass(segment, LineSegment);
-        const ptr0 = passStringToWasm0(color, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
+    addLayer(name, visible, transform) {
+        const ptr0 = passStringToWasm0(name, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
         const len0 = WASM_VECTOR_LEN;
-        wasm.svgrenderer_addLineSegment(this.__wbg_ptr, segment.__wbg_ptr, ptr0, len0, width);
+        var ptr1 = isLikeNone(transform) ? 0 : passStringToWasm0(transform, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
+        var len1 = WASM_VECTOR_LEN;
+        const ret = wasm.svgrenderer_addLayer(this.__wbg_ptr, ptr0, len0, visible, ptr1, len1);
+        if (ret[1]) {
+            throw takeFromExternrefTable0(ret[0]);
+        }
     }
     /**
-     * @param {Triangle} triangle
+     * @param {Plane} plane
+     * @param {Point3D} center
+     * @param {number} size
      * @param {string} stroke
      * @param {string | null | undefined} fill
      * @param {number} width
      */
-    addTriangle(triangle, stroke, fill, width) {
-        _assertClass(triangle, Triangle);
+    addPlane(plane, center, size, stroke, fill, width) {
+        _assertClass(plane, Plane);
+        _assertClass(center, Point3D);
         const ptr0 = passStringToWasm0(stroke, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
         const len0 = WASM_VECTOR_LEN;
         var ptr1 = isLikeNone(fill) ? 0 : passStringToWasm0(fill, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
         var len1 = WASM_VECTOR_LEN;
-        wasm.svgrenderer_addTriangle(this.__wbg_ptr, triangle.__wbg_ptr, ptr0, len0, ptr1, len1, width);
-    }
-    /**
-     * @param {Sphere} sphere
-     * @param {string} color
-     * @param {number} width
-     */
-    addSphere(sphere, color, width) {
-        _assertClass(sphere, Sphere);
-        const ptr0 = passStringToWasm0(color, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
-        const len0 = WASM_VECTOR_LEN;
-        wasm.svgrenderer_addSphere(this.__wbg_ptr, sphere.__wbg_ptr, ptr0, len0, width);
+        const ret = wasm.svgrenderer_addPlane(this.__wbg_ptr, plane.__wbg_ptr, center.__wbg_ptr, size, ptr0, len0, ptr1, len1, width);
+        if (ret[1]) {
+            throw takeFromExternrefTable0(ret[0]);
+        }
     }
     /**
-     * @param {AABB} aabb
+     * @param {Point3D} point
      * @param {string} color
-     * @param {number} width
+     * @param {number} size
      */
-    addAabb(aabb, color, width) {
-        _assertClass(aabb, AABB);
+    addPoint(point, color, size) {
+        _assertClass(point, Point3D);
         const ptr0 = passStringToWasm0(color, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
         const len0 = WASM_VECTOR_LEN;
-        wasm.svgrenderer_addAabb(this.__wbg_ptr, aabb.__wbg_ptr, ptr0, len0, width);
+        wasm.svgrenderer_addPoint(this.__wbg_ptr, point.__wbg_ptr, ptr0, len0, size);
     }
     /**
-     * @returns {string}
+     * @param {Point3D} start
+     * @param {Point3D} end
+     * @param {number} spacing
+     * @param {number} size
+     * @param {Style} style
      */
-    toSvgString() {
-        let deferred1_0;
-        let deferred1_1;
-        try {
-            const ret = wasm.svgrenderer_toSvgString(this.__wbg_ptr);
-            deferred1_0 = ret[0];
-            deferred1_1 = ret[1];
-            return getStringFromWasm0(ret[0], ret[1]);
-        } finally {
-            wasm.__wbindgen_free(deferred1_0, deferred1_1, 1);
+    addTicks(start, end, spacing, size, style) {
+        _assertClass(start, Point3D);
+        _assertClass(end, Point3D);
+        _assertClass(style, Style);
+        const ret = wasm.svgrenderer_addTicks(this.__wbg_ptr, start.__wbg_ptr, end.__wbg_ptr, spacing, size, style.__wbg_ptr);
+        if (ret[1]) {
+            throw takeFromExternrefTable0(ret[0]);
         }
     }
 }
@@ -999,6 +1841,13 @@ export class Sphere {
         const ptr = this.__destroy_into_raw();
         wasm.__wbg_sphere_free(ptr, 0);
     }
+    /**
+     * @returns {number}
+     */
+    surfaceArea() {
+        const ret = wasm.sphere_surfaceArea(this.__wbg_ptr);
+        return ret;
+    }
     /**
      * @param {Point3D} center
      * @param {number} radius
@@ -1034,13 +1883,6 @@ export class Sphere {
         const ret = wasm.sphere_volume(this.__wbg_ptr);
         return ret;
     }
-    /**
-     * @returns {number}
-     */
-    surfaceArea() {
-        const ret = wasm.sphere_surfaceArea(this.__wbg_ptr);
-        return ret;
-    }
     /**
      * @param {Point3D} point
      * @returns {boolean}
@@ -1053,6 +1895,168 @@ export class Sphere {
 }
 if (Symbol.dispose) Sphere.prototype[Symbol.dispose] = Sphere.prototype.free;
 
+const StyleFinalization = (typeof FinalizationRegistry === 'undefined')
+    ? { register: () => {}, unregister: () => {} }
+    : new FinalizationRegistry(ptr => wasm.__wbg_style_free(ptr >>> 0, 1));
+
+export class Style {
+
+    static __unwrap(jsValue) {
+        if (!(jsValue instanceof Style)) {
+            return 0;
+        }
+        return jsValue.__destroy_into_raw();
+    }
+
+    __destroy_into_raw() {
+        const ptr = this.__wbg_ptr;
+        this.__wbg_ptr = 0;
+        StyleFinalization.unregister(this);
+        return ptr;
+    }
+
+    free() {
+        const ptr = this.__destroy_into_raw();
+        wasm.__wbg_style_free(ptr, 0);
+    }
+    /**
+     * @param {string | null} [color]
+     */
+    setStroke(color) {
+        var ptr0 = isLikeNone(color) ? 0 : passStringToWasm0(color, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
+        var len0 = WASM_VECTOR_LEN;
+        wasm.style_setStroke(this.__wbg_ptr, ptr0, len0);
+    }
+    /**
+     * @param {number | null} [opacity]
+     */
+    setOpacity(opacity) {
+        wasm.style_setOpacity(this.__wbg_ptr, !isLikeNone(opacity), isLikeNone(opacity) ? 0 : opacity);
+    }
+    /**
+     * @param {string | null} [cap]
+     */
+    setLineCap(cap) {
+        var ptr0 = isLikeNone(cap) ? 0 : passStringToWasm0(cap, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
+        var len0 = WASM_VECTOR_LEN;
+        const ret = wasm.style_setLineCap(this.__wbg_ptr, ptr0, len0);
+        if (ret[1]) {
+            throw takeFromExternrefTable0(ret[0]);
+        }
+    }
+    /**
+     * @param {number | null} [size]
+     */
+    setFontSize(size) {
+        wasm.style_setFontSize(this.__wbg_ptr, !isLikeNone(size), isLikeNone(size) ? 0 : size);
+    }
+    /**
+     * @param {string | null} [join]
+     */
+    setLineJoin(join) {
+        var ptr0 = isLikeNone(join) ? 0 : passStringToWasm0(join, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
+        var len0 = WASM_VECTOR_LEN;
+        const ret = wasm.style_setLineJoin(this.__wbg_ptr, ptr0, len0);
+        if (ret[1]) {
+            throw takeFromExternrefTable0(ret[0]);
+        }
+    }
+    /**
+     * @param {Float64Array | null} [dashes]
+     */
+    setDashArray(dashes) {
+        var ptr0 = isLikeNone(dashes) ? 0 : passArrayF64ToWasm0(dashes, wasm.__wbindgen_malloc);
+        var len0 = WASM_VECTOR_LEN;
+        wasm.style_setDashArray(this.__wbg_ptr, ptr0, len0);
+    }
+    /**
+     * @param {string | null} [marker]
+     */
+    setMarkerEnd(marker) {
+        var ptr0 = isLikeNone(marker) ? 0 : passStringToWasm0(marker, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
+        var len0 = WASM_VECTOR_LEN;
+        const ret = wasm.style_setMarkerEnd(this.__wbg_ptr, ptr0, len0);
+        if (ret[1]) {
+            throw takeFromExternrefTable0(ret[0]);
+        }
+    }
+    /**
+     * @param {string | null} [family]
+     */
+    setFontFamily(family) {
+        var ptr0 = isLikeNone(family) ? 0 : passStringToWasm0(family, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
+        var len0 = WASM_VECTOR_LEN;
+        wasm.style_setFontFamily(this.__wbg_ptr, ptr0, len0);
+    }
+    /**
+     * @param {string | null} [anchor]
+     */
+    setTextAnchor(anchor) {
+        var ptr0 = isLikeNone(anchor) ? 0 : passStringToWasm0(anchor, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
+        var len0 = WASM_VECTOR_LEN;
+        const ret = wasm.style_setTextAnchor(this.__wbg_ptr, ptr0, len0);
+        if (ret[1]) {
+            throw takeFromExternrefTable0(ret[0]);
+        }
+    }
+    /**
+     * @param {string | null} [marker]
+     */
+    setMarkerStart(marker) {
+        var ptr0 = isLikeNone(marker) ? 0 : passStringToWasm0(marker, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
+        var len0 = WASM_VECTOR_LEN;
+        const ret = wasm.style_setMarkerStart(this.__wbg_ptr, ptr0, len0);
+        if (ret[1]) {
+            throw takeFromExternrefTable0(ret[0]);
+        }
+    }
+    /**
+     * @param {number | null} [width]
+     */
+    setStrokeWidth(width) {
+        wasm.style_setStrokeWidth(this.__wbg_ptr, !isLikeNone(width), isLikeNone(width) ? 0 : width);
+    }
+    constructor() {
+        const ret = wasm.style_new();
+        this.__wbg_ptr = ret >>> 0;
+        StyleFinalization.register(this, this.__wbg_ptr, this);
+        return this;
+    }
+    /**
+     * @param {string | null} [id]
+     */
+    setId(id) {
+        var ptr0 = isLikeNone(id) ? 0 : passStringToWasm0(id, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
+        var len0 = WASM_VECTOR_LEN;
+        wasm.style_setId(this.__wbg_ptr, ptr0, len0);
+    }
+    /**
+     * @param {string | null} [color]
+     */
+    setFill(color) {
+        var ptr0 = isLikeNone(color) ? 0 : passStringToWasm0(color, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
+        var len0 = WASM_VECTOR_LEN;
+        wasm.style_setFill(this.__wbg_ptr, ptr0, len0);
+    }
+    /**
+     * @param {string | null} [_class]
+     */
+    setClass(_class) {
+        var ptr0 = isLikeNone(_class) ? 0 : passStringToWasm0(_class, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
+        var len0 = WASM_VECTOR_LEN;
+        wasm.style_setClass(this.__wbg_ptr, ptr0, len0);
+    }
+    /**
+     * @param {string | null} [title]
+     */
+    setTitle(title) {
+        var ptr0 = isLikeNone(title) ? 0 : passStringToWasm0(title, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
+        var len0 = WASM_VECTOR_LEN;
+        wasm.style_setTitle(this.__wbg_ptr, ptr0, len0);
+    }
+}
+if (Symbol.dispose) Style.prototype[Symbol.dispose] = Style.prototype.free;
+
 const TriangleFinalization = (typeof FinalizationRegistry === 'undefined')
     ? { register: () => {}, unregister: () => {} }
     : new FinalizationRegistry(ptr => wasm.__wbg_triangle_free(ptr >>> 0, 1));
@@ -1071,27 +2075,30 @@ export class Triangle {
         wasm.__wbg_triangle_free(ptr, 0);
     }
     /**
-     * @param {Point3D} a
-     * @param {Point3D} b
-     * @param {Point3D} c
+     * @param {Point3D} point
+     * @returns {boolean}
+     */
+    containsPoint(point) {
+        _assertClass(point, Point3D);
+        const ret = wasm.triangle_containsPoint(this.__wbg_ptr, point.__wbg_ptr);
+        return ret !== 0;
+    }
+    /**
+     * @param {Point3D} point
+     * @returns {Float64Array}
      */
-    constructor(a, b, c) {
-        _assertClass(a, Point3D);
-        _assertClass(b, Point3D);
-        _assertClass(c, Point3D);
-        const ret = wasm.triangle_new(a.__wbg_ptr, b.__wbg_ptr, c.__wbg_ptr);
-        if (ret[2]) {
-            throw takeFromExternrefTable0(ret[1]);
-        }
-        this.__wbg_ptr = ret[0] >>> 0;
-        TriangleFinalization.register(this, this.__wbg_ptr, this);
-        return this;
+    barycentricCoords(point) {
+        _assertClass(point, Point3D);
+        const ret = wasm.triangle_barycentricCoords(this.__wbg_ptr, point.__wbg_ptr);
+        var v1 = getArrayF64FromWasm0(ret[0], ret[1]).slice();
+        wasm.__wbindgen_free(ret[0], ret[1] * 8, 8);
+        return v1;
     }
     /**
      * @returns {Point3D}
      */
     get a() {
-        const ret = wasm.plane_normal(this.__wbg_ptr);
+        const ret = wasm.triangle_a(this.__wbg_ptr);
         return Point3D.__wrap(ret);
     }
     /**
@@ -1109,11 +2116,21 @@ export class Triangle {
         return Point3D.__wrap(ret);
     }
     /**
-     * @returns {Vector3D}
+     * @param {Point3D} a
+     * @param {Point3D} b
+     * @param {Point3D} c
      */
-    normal() {
-        const ret = wasm.triangle_normal(this.__wbg_ptr);
-        return Vector3D.__wrap(ret);
+    constructor(a, b, c) {
+        _assertClass(a, Point3D);
+        _assertClass(b, Point3D);
+        _assertClass(c, Point3D);
+        const ret = wasm.triangle_new(a.__wbg_ptr, b.__wbg_ptr, c.__wbg_ptr);
+        if (ret[2]) {
+            throw takeFromExternrefTable0(ret[1]);
+        }
+        this.__wbg_ptr = ret[0] >>> 0;
+        TriangleFinalization.register(this, this.__wbg_ptr, this);
+        return this;
     }
     /**
      * @returns {number}
@@ -1122,6 +2139,13 @@ export class Triangle {
         const ret = wasm.triangle_area(this.__wbg_ptr);
         return ret;
     }
+    /**
+     * @returns {Vector3D}
+     */
+    normal() {
+        const ret = wasm.triangle_normal(this.__wbg_ptr);
+        return Vector3D.__wrap(ret);
+    }
     /**
      * @returns {Point3D}
      */
@@ -1129,26 +2153,6 @@ export class Triangle {
         const ret = wasm.triangle_centroid(this.__wbg_ptr);
         return Point3D.__wrap(ret);
     }
-    /**
-     * @param {Point3D} point
-     * @returns {Float64Array}
-     */
-    barycentricCoords(point) {
-        _assertClass(point, Point3D);
-        const ret = wasm.triangle_barycentricCoords(this.__wbg_ptr, point.__wbg_ptr);
-        var v1 = getArrayF64FromWasm0(ret[0], ret[1]).slice();
-        wasm.__wbindgen_free(ret[0], ret[1] * 8, 8);
-        return v1;
-    }
-    /**
-     * @param {Point3D} point
-     * @returns {boolean}
-     */
-    containsPoint(point) {
-        _assertClass(point, Point3D);
-        const ret = wasm.triangle_containsPoint(this.__wbg_ptr, point.__wbg_ptr);
-        return ret !== 0;
-    }
 }
 if (Symbol.dispose) Triangle.prototype[Symbol.dispose] = Triangle.prototype.free;
 
@@ -1178,15 +2182,55 @@ export class Vector3D {
         wasm.__wbg_vector3d_free(ptr, 0);
     }
     /**
-     * @param {number} x
-     * @param {number} y
-     * @param {number} z
+     * @param {Float64Array} arr
+     * @returns {Vector3D}
      */
-    constructor(x, y, z) {
-        const ret = wasm.vector3d_new(x, y, z);
-        this.__wbg_ptr = ret >>> 0;
-        Vector3DFinalization.register(this, this.__wbg_ptr, this);
-        return this;
+    static fromArray(arr) {
+        const ptr0 = passArrayF64ToWasm0(arr, wasm.__wbindgen_malloc);
+        const len0 = WASM_VECTOR_LEN;
+        const ret = wasm.vector3d_fromArray(ptr0, len0);
+        if (ret[2]) {
+            throw takeFromExternrefTable0(ret[1]);
+        }
+        return Vector3D.__wrap(ret[0]);
+    }
+    /**
+     * @param {Point3D} from
+     * @param {Point3D} to
+     * @returns {Vector3D}
+     */
+    static fromPoints(from, to) {
+        _assertClass(from, Point3D);
+        _assertClass(to, Point3D);
+        const ret = wasm.vector3d_fromPoints(from.__wbg_ptr, to.__wbg_ptr);
+        return Vector3D.__wrap(ret);
+    }
+    /**
+     * @param {Vector3D} other
+     * @returns {boolean}
+     */
+    isParallel(other) {
+        _assertClass(other, Vector3D);
+        const ret = wasm.vector3d_isParallel(this.__wbg_ptr, other.__wbg_ptr);
+        return ret !== 0;
+    }
+    /**
+     * @param {Vector3D} other
+     * @returns {Vector3D}
+     */
+    projectOnto(other) {
+        _assertClass(other, Vector3D);
+        const ret = wasm.vector3d_projectOnto(this.__wbg_ptr, other.__wbg_ptr);
+        return Vector3D.__wrap(ret);
+    }
+    /**
+     * @param {Vector3D} other
+     * @returns {boolean}
+     */
+    isPerpendicular(other) {
+        _assertClass(other, Vector3D);
+        const ret = wasm.vector3d_isPerpendicular(this.__wbg_ptr, other.__wbg_ptr);
+        return ret !== 0;
     }
     /**
      * @returns {number}
@@ -1210,21 +2254,13 @@ export class Vector3D {
         return ret;
     }
     /**
-     * @returns {number}
-     */
-    magnitude() {
-        const ret = wasm.vector3d_magnitude(this.__wbg_ptr);
-        return ret;
-    }
-    /**
+     * @param {Vector3D} other
      * @returns {Vector3D}
      */
-    normalize() {
-        const ret = wasm.vector3d_normalize(this.__wbg_ptr);
-        if (ret[2]) {
-            throw takeFromExternrefTable0(ret[1]);
-        }
-        return Vector3D.__wrap(ret[0]);
+    add(other) {
+        _assertClass(other, Vector3D);
+        const ret = wasm.vector3d_add(this.__wbg_ptr, other.__wbg_ptr);
+        return Vector3D.__wrap(ret);
     }
     /**
      * @param {Vector3D} other
@@ -1236,22 +2272,15 @@ export class Vector3D {
         return ret;
     }
     /**
-     * @param {Vector3D} other
-     * @returns {Vector3D}
-     */
-    cross(other) {
-        _assertClass(other, Vector3D);
-        const ret = wasm.vector3d_cross(this.__wbg_ptr, other.__wbg_ptr);
-        return Vector3D.__wrap(ret);
-    }
-    /**
-     * @param {Vector3D} other
-     * @returns {Vector3D}
+     * @param {number} x
+     * @param {number} y
+     * @param {number} z
      */
-    add(other) {
-        _assertClass(other, Vector3D);
-        const ret = wasm.vector3d_add(this.__wbg_ptr, other.__wbg_ptr);
-        return Vector3D.__wrap(ret);
+    constructor(x, y, z) {
+        const ret = wasm.vector3d_new(x, y, z);
+        this.__wbg_ptr = ret >>> 0;
+        Vector3DFinalization.register(this, this.__wbg_ptr, this);
+        return this;
     }
     /**
      * @param {Vector3D} other
@@ -1263,11 +2292,10 @@ export class Vector3D {
         return Vector3D.__wrap(ret);
     }
     /**
-     * @param {number} scalar
      * @returns {Vector3D}
      */
-    scale(scalar) {
-        const ret = wasm.vector3d_scale(this.__wbg_ptr, scalar);
+    static zero() {
+        const ret = wasm.vector3d_zero();
         return Vector3D.__wrap(ret);
     }
     /**
@@ -1283,56 +2311,17 @@ export class Vector3D {
      * @param {Vector3D} other
      * @returns {Vector3D}
      */
-    projectOnto(other) {
+    cross(other) {
         _assertClass(other, Vector3D);
-        const ret = wasm.vector3d_projectOnto(this.__wbg_ptr, other.__wbg_ptr);
+        const ret = wasm.vector3d_cross(this.__wbg_ptr, other.__wbg_ptr);
         return Vector3D.__wrap(ret);
     }
     /**
-     * @param {Vector3D} other
-     * @returns {boolean}
-     */
-    isParallel(other) {
-        _assertClass(other, Vector3D);
-        const ret = wasm.vector3d_isParallel(this.__wbg_ptr, other.__wbg_ptr);
-        return ret !== 0;
-    }
-    /**
-     * @param {Vector3D} other
-     * @returns {boolean}
-     */
-    isPerpendicular(other) {
-        _assertClass(other, Vector3D);
-        const ret = wasm.vector3d_isPerpendicular(this.__wbg_ptr, other.__wbg_ptr);
-        return ret !== 0;
-    }
-    /**
-     * @returns {Float64Array}
-     */
-    toArray() {
-        const ret = wasm.vector3d_toArray(this.__wbg_ptr);
-        var v1 = getArrayF64FromWasm0(ret[0], ret[1]).slice();
-        wasm.__wbindgen_free(ret[0], ret[1] * 8, 8);
-        return v1;
-    }
-    /**
-     * @param {Float64Array} arr
-     * @returns {Vector3D}
-     */
-    static fromArray(arr) {
-        const ptr0 = passArrayF64ToWasm0(arr, wasm.__wbindgen_malloc);
-        const len0 = WASM_VECTOR_LEN;
-        const ret = wasm.vector3d_fromArray(ptr0, len0);
-        if (ret[2]) {
-            throw takeFromExternrefTable0(ret[1]);
-        }
-        return Vector3D.__wrap(ret[0]);
-    }
-    /**
+     * @param {number} scalar
      * @returns {Vector3D}
      */
-    static zero() {
-        const ret = wasm.vector3d_zero();
+    scale(scalar) {
+        const ret = wasm.vector3d_scale(this.__wbg_ptr, scalar);
         return Vector3D.__wrap(ret);
     }
     /**
@@ -1357,15 +2346,30 @@ export class Vector3D {
         return Vector3D.__wrap(ret);
     }
     /**
-     * @param {Point3D} from
-     * @param {Point3D} to
+     * @returns {Float64Array}
+     */
+    toArray() {
+        const ret = wasm.vector3d_toArray(this.__wbg_ptr);
+        var v1 = getArrayF64FromWasm0(ret[0], ret[1]).slice();
+        wasm.__wbindgen_free(ret[0], ret[1] * 8, 8);
+        return v1;
+    }
+    /**
+     * @returns {number}
+     */
+    magnitude() {
+        const ret = wasm.vector3d_magnitude(this.__wbg_ptr);
+        return ret;
+    }
+    /**
      * @returns {Vector3D}
      */
-    static fromPoints(from, to) {
-        _assertClass(from, Point3D);
-        _assertClass(to, Point3D);
-        const ret = wasm.vector3d_fromPoints(from.__wbg_ptr, to.__wbg_ptr);
-        return Vector3D.__wrap(ret);
+    normalize() {
+        const ret = wasm.vector3d_normalize(this.__wbg_ptr);
+        if (ret[2]) {
+            throw takeFromExternrefTable0(ret[1]);
+        }
+        return Vector3D.__wrap(ret[0]);
     }
 }
 if (Symbol.dispose) Vector3D.prototype[Symbol.dispose] = Vector3D.prototype.free;
@@ -1419,26 +2423,18 @@ function __wbg_get_imports() {
             wasm.__wbindgen_free(deferred0_0, deferred0_1, 1);
         }
     };
-    imports.wbg.__wbg_new_19c25a3f2fa63a02 = function() {
-        const ret = new Object();
-        return ret;
-    };
     imports.wbg.__wbg_new_8a6f238a6ece86ea = function() {
         const ret = new Error();
         return ret;
     };
-    imports.wbg.__wbg_point3d_new = function(arg0) {
-        const ret = Point3D.__wrap(arg0);
-        return ret;
-    };
     imports.wbg.__wbg_point3d_unwrap = function(arg0) {
         const ret = Point3D.__unwrap(arg0);
         return ret;
     };
-    imports.wbg.__wbg_set_453345bcda80b89a = function() { return handleError(function (arg0, arg1, arg2) {
-        const ret = Reflect.set(arg0, arg1, arg2);
+    imports.wbg.__wbg_rayhit_new = function(arg0) {
+        const ret = RayHit.__wrap(arg0);
         return ret;
-    }, arguments) };
+    };
     imports.wbg.__wbg_stack_0ed75d68575b0f3c = function(arg0, arg1) {
         const ret = arg1.stack;
         const ptr1 = passStringToWasm0(ret, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
@@ -1446,10 +2442,15 @@ function __wbg_get_imports() {
         getDataViewMemory0().setInt32(arg0 + 4 * 1, len1, true);
         getDataViewMemory0().setInt32(arg0 + 4 * 0, ptr1, true);
     };
-    imports.wbg.__wbg_wbindgendebugstring_99ef257a3ddda34d = function(arg0, arg1) {
-        const ret = debugString(arg1);
-        const ptr1 = passStringToWasm0(ret, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
-        const len1 = WASM_VECTOR_LEN;
+    imports.wbg.__wbg_style_unwrap = function(arg0) {
+        const ret = Style.__unwrap(arg0);
+        return ret;
+    };
+    imports.wbg.__wbg_wbindgenstringget_0f16a6ddddef376f = function(arg0, arg1) {
+        const obj = arg1;
+        const ret = typeof(obj) === 'string' ? obj : undefined;
+        var ptr1 = isLikeNone(ret) ? 0 : passStringToWasm0(ret, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
+        var len1 = WASM_VECTOR_LEN;
         getDataViewMemory0().setInt32(arg0 + 4 * 1, len1, true);
         getDataViewMemory0().setInt32(arg0 + 4 * 0, ptr1, true);
     };
@@ -1461,11 +2462,6 @@ function __wbg_get_imports() {
         const ret = getStringFromWasm0(arg0, arg1);
         return ret;
     };
-    imports.wbg.__wbindgen_cast_d6cd19b81560fd6e = function(arg0) {
-        // Cast intrinsic for `F64 -> Externref`.
-        const ret = arg0;
-        return ret;
-    };
     imports.wbg.__wbindgen_init_externref_table = function() {
         const table = wasm.__wbindgen_export_3;
         const offset = table.grow(4);
@@ -1,120 +1,209 @@
 /* tslint:disable */
 /* eslint-disable */
 export const memory: WebAssembly.Memory;
-export const __wbg_sphere_free: (a: number, b: number) => void;
-export const sphere_new: (a: number, b: number) => [number, number, number];
-export const sphere_center: (a: number) => number;
-export const sphere_radius: (a: number) => number;
-export const sphere_volume: (a: number) => number;
-export const sphere_surfaceArea: (a: number) => number;
-export const sphere_contains: (a: number, b: number) => number;
 export const __wbg_svgrenderer_free: (a: number, b: number) => void;
-export const svgrenderer_new: (a: number, b: number, c: number) => number;
-export const svgrenderer_setBackground: (a: number, b: number, c: number) => void;
-export const svgrenderer_addPoint: (a: number, b: number, c: number, d: number, e: number) => void;
+export const svgrenderer_addAabb: (a: number, b: number, c: number, d: number, e: number) => void;
+export const svgrenderer_addAabbWithStyle: (a: number, b: number, c: number) => void;
+export const svgrenderer_addAmbientLight: (a: number, b: number, c: number, d: number) => [number, number];
+export const svgrenderer_addAngularDimension: (a: number, b: number, c: number, d: number, e: number, f: number) => [number, number, number];
+export const svgrenderer_addAxes: (a: number, b: number, c: number, d: number) => [number, number];
+export const svgrenderer_addDirectionalLight: (a: number, b: number, c: number, d: number, e: number) => [number, number];
+export const svgrenderer_addFilledSphere: (a: number, b: number, c: number, d: number, e: number, f: number, g: number) => void;
+export const svgrenderer_addGrid: (a: number, b: number, c: number, d: number, e: number, f: number) => [number, number];
+export const svgrenderer_addLabel: (a: number, b: number, c: number, d: number, e: number) => void;
+export const svgrenderer_addLayer: (a: number, b: number, c: number, d: number, e: number, f: number) => [number, number];
+export const svgrenderer_addLayerWithStyle: (a: number, b: number, c: number, d: number, e: number, f: number, g: number) => [number, number];
+export const svgrenderer_addLeaderLabel: (a: number, b: number, c: number, d: number, e: number, f: number, g: number) => void;
+export const svgrenderer_addLegend: (a: number, b: number, c: number, d: number, e: number, f: number, g: number, h: number) => [number, number];
 export const svgrenderer_addLineSegment: (a: number, b: number, c: number, d: number, e: number) => void;
-export const svgrenderer_addTriangle: (a: number, b: number, c: number, d: number, e: number, f: number, g: number) => void;
+export const svgrenderer_addLineSegmentWithStyle: (a: number, b: number, c: number) => void;
+export const svgrenderer_addLinearDimension: (a: number, b: number, c: number, d: number, e: number) => number;
+export const svgrenderer_addPlane: (a: number, b: number, c: number, d: number, e: number, f: number, g: number, h: number, i: number) => [number, number];
+export const svgrenderer_addPlaneWithStyle: (a: number, b: number, c: number, d: number, e: number) => [number, number];
+export const svgrenderer_addPoint: (a: number, b: number, c: number, d: number, e: number) => void;
+export const svgrenderer_addPointLight: (a: number, b: number, c: number, d: number, e: number) => [number, number];
+export const svgrenderer_addPointWithStyle: (a: number, b: number, c: number, d: number) => void;
+export const svgrenderer_addRay: (a: number, b: number, c: number, d: number, e: number) => void;
+export const svgrenderer_addRayWithStyle: (a: number, b: number, c: number) => void;
+export const svgrenderer_addSceneBounds: (a: number, b: number) => number;
 export const svgrenderer_addSphere: (a: number, b: number, c: number, d: number, e: number) => void;
-export const svgrenderer_addAabb: (a: number, b: number, c: number, d: number, e: number) => void;
+export const svgrenderer_addSphereWithStyle: (a: number, b: number, c: number) => void;
+export const svgrenderer_addTicks: (a: number, b: number, c: number, d: number, e: number, f: number) => [number, number];
+export const svgrenderer_addTriangle: (a: number, b: number, c: number, d: number, e: number, f: number, g: number) => void;
+export const svgrenderer_addTriangleWithNormals: (a: number, b: number, c: number, d: number, e: number, f: number) => void;
+export const svgrenderer_addTriangleWithStyle: (a: number, b: number, c: number) => void;
+export const svgrenderer_addVector: (a: number, b: number, c: number, d: number, e: number, f: number) => [number, number];
+export const svgrenderer_addVectorWithStyle: (a: number, b: number, c: number, d: number) => [number, number];
+export const svgrenderer_clearLights: (a: number) => void;
+export const svgrenderer_new: (a: number, b: number, c: number) => number;
+export const svgrenderer_sceneBounds: (a: number) => number;
+export const svgrenderer_setBackFaceCulling: (a: number, b: number) => void;
+export const svgrenderer_setBackground: (a: number, b: number, c: number) => void;
+export const svgrenderer_setCurrentLayer: (a: number, b: number, c: number) => [number, number];
+export const svgrenderer_setDepthSort: (a: number, b: number, c: number) => [number, number];
+export const svgrenderer_setHiddenLines: (a: number, b: number, c: number) => [number, number];
+export const svgrenderer_setLayerVisible: (a: number, b: number, c: number, d: number) => [number, number];
+export const svgrenderer_setShading: (a: number, b: number, c: number) => [number, number];
+export const svgrenderer_setStylesheet: (a: number, b: number, c: number) => void;
 export const svgrenderer_toSvgString: (a: number) => [number, number];
-export const __wbg_camera_free: (a: number, b: number) => void;
-export const camera_perspective: (a: number, b: number, c: number, d: number, e: number, f: number, g: number) => number;
-export const camera_orthographic: (a: number, b: number, c: number, d: number, e: number) => number;
-export const camera_viewMatrix: (a: number) => [number, number];
-export const camera_projectionMatrix: (a: number) => [number, number];
-export const __wbg_plane_free: (a: number, b: number) => void;
-export const plane_fromPointNormal: (a: number, b: number) => [number, number, number];
-export const plane_fromThreePoints: (a: number, b: number, c: number) => [number, number, number];
-export const plane_normal: (a: number) => number;
-export const plane_d: (a: number) => number;
-export const plane_distanceToPoint: (a: number, b: number) => number;
-export const plane_closestPoint: (a: number, b: number) => number;
-export const plane_containsPoint: (a: number, b: number) => number;
-export const plane_flipNormal: (a: number) => number;
-export const plane_isParallel: (a: number, b: number) => number;
-export const __wbg_triangle_free: (a: number, b: number) => void;
-export const triangle_new: (a: number, b: number, c: number) => [number, number, number];
-export const triangle_b: (a: number) => number;
-export const triangle_c: (a: number) => number;
-export const triangle_normal: (a: number) => number;
-export const triangle_area: (a: number) => number;
-export const triangle_centroid: (a: number) => number;
-export const triangle_barycentricCoords: (a: number, b: number) => [number, number];
-export const triangle_containsPoint: (a: number, b: number) => number;
-export const triangle_a: (a: number) => number;
+export const svgrenderer_toSvgStringWithLayers: (a: number, b: number, c: number) => [number, number, number, number];
 export const __wbg_linesegment_free: (a: number, b: number) => void;
-export const linesegment_new: (a: number, b: number) => [number, number, number];
-export const linesegment_start: (a: number) => number;
+export const __wbg_vector3d_free: (a: number, b: number) => void;
+export const linesegment_closestPoint: (a: number, b: number) => number;
+export const linesegment_direction: (a: number) => number;
+export const linesegment_distanceToPoint: (a: number, b: number) => number;
 export const linesegment_end: (a: number) => number;
 export const linesegment_length: (a: number) => number;
-export const linesegment_direction: (a: number) => number;
 export const linesegment_midpoint: (a: number) => number;
+export const linesegment_new: (a: number, b: number) => [number, number, number];
 export const linesegment_pointAt: (a: number, b: number) => number;
-export const linesegment_closestPoint: (a: number, b: number) => number;
-export const linesegment_distanceToPoint: (a: number, b: number) => number;
-export const __wbg_point3d_free: (a: number, b: number) => void;
-export const point3d_new: (a: number, b: number, c: number) => number;
-export const point3d_x: (a: number) => number;
-export const point3d_y: (a: number) => number;
-export const point3d_z: (a: number) => number;
-export const point3d_distanceTo: (a: number, b: number) => number;
-export const point3d_midpoint: (a: number, b: number) => number;
-export const point3d_translate: (a: number, b: number) => number;
-export const point3d_toArray: (a: number) => [number, number];
-export const point3d_fromArray: (a: number, b: number) => [number, number, number];
-export const point3d_origin: () => number;
-export const init: () => void;
-export const __wbg_ray_free: (a: number, b: number) => void;
-export const ray_new: (a: number, b: number) => [number, number, number];
-export const ray_origin: (a: number) => number;
-export const ray_direction: (a: number) => number;
-export const ray_pointAt: (a: number, b: number) => number;
-export const ray_intersectSphere: (a: number, b: number) => any;
-export const ray_intersectPlane: (a: number, b: number) => any;
-export const ray_intersectTriangle: (a: number, b: number) => any;
-export const __wbg_vector3d_free: (a: number, b: number) => void;
-export const vector3d_new: (a: number, b: number, c: number) => number;
-export const vector3d_x: (a: number) => number;
-export const vector3d_y: (a: number) => number;
-export const vector3d_z: (a: number) => number;
-export const vector3d_magnitude: (a: number) => number;
-export const vector3d_normalize: (a: number) => [number, number, number];
-export const vector3d_dot: (a: number, b: number) => number;
-export const vector3d_cross: (a: number, b: number) => number;
+export const linesegment_start: (a: number) => number;
 export const vector3d_add: (a: number, b: number) => number;
-export const vector3d_sub: (a: number, b: number) => number;
-export const vector3d_scale: (a: number, b: number) => number;
 export const vector3d_angle: (a: number, b: number) => number;
-export const vector3d_projectOnto: (a: number, b: number) => number;
+export const vector3d_cross: (a: number, b: number) => number;
+export const vector3d_dot: (a: number, b: number) => number;
+export const vector3d_fromArray: (a: number, b: number) => [number, number, number];
+export const vector3d_fromPoints: (a: number, b: number) => number;
 export const vector3d_isParallel: (a: number, b: number) => number;
 export const vector3d_isPerpendicular: (a: number, b: number) => number;
+export const vector3d_magnitude: (a: number) => number;
+export const vector3d_new: (a: number, b: number, c: number) => number;
+export const vector3d_normalize: (a: number) => [number, number, number];
+export const vector3d_projectOnto: (a: number, b: number) => number;
+export const vector3d_scale: (a: number, b: number) => number;
+export const vector3d_sub: (a: number, b: number) => number;
 export const vector3d_toArray: (a: number) => [number, number];
-export const vector3d_fromArray: (a: number, b: number) => [number, number, number];
-export const vector3d_zero: () => number;
 export const vector3d_unitX: () => number;
 export const vector3d_unitY: () => number;
 export const vector3d_unitZ: () => number;
-export const vector3d_fromPoints: (a: number, b: number) => number;
+export const vector3d_x: (a: number) => number;
+export const vector3d_y: (a: number) => number;
+export const vector3d_z: (a: number) => number;
+export const vector3d_zero: () => number;
+export const __wbg_triangle_free: (a: number, b: number) => void;
+export const triangle_a: (a: number) => number;
+export const triangle_area: (a: number) => number;
+export const triangle_b: (a: number) => number;
+export const triangle_barycentricCoords: (a: number, b: number) => [number, number];
+export const triangle_c: (a: number) => number;
+export const triangle_centroid: (a: number) => number;
+export const triangle_containsPoint: (a: number, b: number) => number;
+export const triangle_new: (a: number, b: number, c: number) => [number, number, number];
+export const triangle_normal: (a: number) => number;
+export const init: () => void;
 export const __wbg_aabb_free: (a: number, b: number) => void;
-export const aabb_new: (a: number, b: number) => [number, number, number];
+export const __wbg_plane_free: (a: number, b: number) => void;
+export const aabb_center: (a: number) => number;
+export const aabb_containsPoint: (a: number, b: number) => number;
+export const aabb_diagonal: (a: number) => number;
+export const aabb_expandByPoint: (a: number, b: number) => number;
+export const aabb_expandByScalar: (a: number, b: number) => number;
 export const aabb_fromPoints: (a: number, b: number) => [number, number, number];
-export const aabb_min: (a: number) => number;
+export const aabb_intersects: (a: number, b: number) => number;
 export const aabb_max: (a: number) => number;
-export const aabb_center: (a: number) => number;
+export const aabb_min: (a: number) => number;
+export const aabb_new: (a: number, b: number) => [number, number, number];
 export const aabb_size: (a: number) => number;
-export const aabb_volume: (a: number) => number;
 export const aabb_surfaceArea: (a: number) => number;
-export const aabb_diagonal: (a: number) => number;
-export const aabb_containsPoint: (a: number, b: number) => number;
-export const aabb_intersects: (a: number, b: number) => number;
 export const aabb_union: (a: number, b: number) => number;
-export const aabb_expandByPoint: (a: number, b: number) => number;
-export const aabb_expandByScalar: (a: number, b: number) => number;
+export const aabb_volume: (a: number) => number;
+export const plane_closestPoint: (a: number, b: number) => number;
+export const plane_containsPoint: (a: number, b: number) => number;
+export const plane_d: (a: number) => number;
+export const plane_distanceToPoint: (a: number, b: number) => number;
+export const plane_flipNormal: (a: number) => number;
+export const plane_fromPointNormal: (a: number, b: number) => [number, number, number];
+export const plane_fromThreePoints: (a: number, b: number, c: number) => [number, number, number];
+export const plane_isParallel: (a: number, b: number) => number;
+export const plane_normal: (a: number) => number;
+export const __wbg_camera_free: (a: number, b: number) => void;
+export const camera_cabinet: (a: number, b: number, c: number, d: number, e: number) => [number, number, number];
+export const camera_cavalier: (a: number, b: number, c: number, d: number, e: number) => [number, number, number];
+export const camera_dimetric: (a: number, b: number, c: number, d: number) => [number, number, number];
+export const camera_dolly: (a: number, b: number) => [number, number];
+export const camera_equirectangular: (a: number, b: number, c: number) => [number, number, number];
+export const camera_fisheye: (a: number, b: number, c: number, d: number, e: number) => [number, number, number];
+export const camera_fitToAabb: (a: number, b: number) => [number, number];
+export const camera_fitToSphere: (a: number, b: number) => [number, number];
+export const camera_isLinear: (a: number) => number;
+export const camera_isometric: (a: number, b: number, c: number, d: number) => [number, number, number];
+export const camera_lookAt: (a: number, b: number) => [number, number];
+export const camera_oblique: (a: number, b: number, c: number, d: number, e: number, f: number, g: number) => [number, number, number];
+export const camera_offAxis: (a: number, b: number, c: number, d: number, e: number, f: number, g: number) => [number, number, number];
+export const camera_orbit: (a: number, b: number, c: number) => void;
+export const camera_orthographic: (a: number, b: number, c: number, d: number, e: number) => [number, number, number];
+export const camera_pan: (a: number, b: number, c: number) => void;
+export const camera_perspective: (a: number, b: number, c: number, d: number, e: number, f: number, g: number) => [number, number, number];
+export const camera_position: (a: number) => number;
+export const camera_projectionMatrix: (a: number) => [number, number];
+export const camera_screenToRay: (a: number, b: number, c: number, d: number, e: number) => number;
+export const camera_target: (a: number) => number;
+export const camera_trimetric: (a: number, b: number, c: number, d: number, e: number, f: number) => [number, number, number];
+export const camera_unproject: (a: number, b: number, c: number, d: number) => number;
+export const camera_up: (a: number) => number;
+export const camera_viewMatrix: (a: number) => [number, number];
+export const camera_zoom: (a: number, b: number) => [number, number];
+export const __wbg_style_free: (a: number, b: number) => void;
+export const style_new: () => number;
+export const style_setClass: (a: number, b: number, c: number) => void;
+export const style_setDashArray: (a: number, b: number, c: number) => void;
+export const style_setFill: (a: number, b: number, c: number) => void;
+export const style_setFontFamily: (a: number, b: number, c: number) => void;
+export const style_setFontSize: (a: number, b: number, c: number) => void;
+export const style_setId: (a: number, b: number, c: number) => void;
+export const style_setLineCap: (a: number, b: number, c: number) => [number, number];
+export const style_setLineJoin: (a: number, b: number, c: number) => [number, number];
+export const style_setMarkerEnd: (a: number, b: number, c: number) => [number, number];
+export const style_setMarkerStart: (a: number, b: number, c: number) => [number, number];
+export const style_setOpacity: (a: number, b: number, c: number) => void;
+export const style_setStroke: (a: number, b: number, c: number) => void;
+export const style_setStrokeWidth: (a: number, b: number, c: number) => void;
+export const style_setTextAnchor: (a: number, b: number, c: number) => [number, number];
+export const style_setTitle: (a: number, b: number, c: number) => void;
+export const __wbg_ray_free: (a: number, b: number) => void;
+export const __wbg_rayhit_free: (a: number, b: number) => void;
+export const ray_direction: (a: number) => number;
+export const ray_intersectAABB: (a: number, b: number) => any;
+export const ray_intersectAABBAll: (a: number, b: number) => [number, number];
+export const ray_intersectPlane: (a: number, b: number) => any;
+export const ray_intersectSphere: (a: number, b: number) => any;
+export const ray_intersectSphereAll: (a: number, b: number) => [number, number];
+export const ray_intersectTriangle: (a: number, b: number) => any;
+export const ray_new: (a: number, b: number) => [number, number, number];
+export const ray_origin: (a: number) => number;
+export const ray_pointAt: (a: number, b: number) => number;
+export const ray_tMax: (a: number) => number;
+export const ray_tMin: (a: number) => number;
+export const ray_withRange: (a: number, b: number, c: number, d: number) => [number, number, number];
+export const rayhit_barycentric: (a: number) => [number, number];
+export const rayhit_frontFace: (a: number) => number;
+export const rayhit_normal: (a: number) => number;
+export const rayhit_point: (a: number) => number;
+export const rayhit_uv: (a: number) => [number, number];
+export const rayhit_t: (a: number) => number;
+export const __wbg_point3d_free: (a: number, b: number) => void;
+export const point3d_distanceTo: (a: number, b: number) => number;
+export const point3d_fromArray: (a: number, b: number) => [number, number, number];
+export const point3d_midpoint: (a: number, b: number) => number;
+export const point3d_new: (a: number, b: number, c: number) => number;
+export const point3d_origin: () => number;
+export const point3d_toArray: (a: number) => [number, number];
+export const point3d_translate: (a: number, b: number) => number;
+export const point3d_x: (a: number) => number;
+export const point3d_y: (a: number) => number;
+export const point3d_z: (a: number) => number;
+export const __wbg_sphere_free: (a: number, b: number) => void;
+export const sphere_center: (a: number) => number;
+export const sphere_contains: (a: number, b: number) => number;
+export const sphere_new: (a: number, b: number) => [number, number, number];
+export const sphere_radius: (a: number) => number;
+export const sphere_surfaceArea: (a: number) => number;
+export const sphere_volume: (a: number) => number;
 export const __wbindgen_free: (a: number, b: number, c: number) => void;
-export const __wbindgen_exn_store: (a: number) => void;
-export const __externref_table_alloc: () => number;
-export const __wbindgen_export_3: WebAssembly.Table;
 export const __wbindgen_malloc: (a: number, b: number) => number;
 export const __wbindgen_realloc: (a: number, b: number, c: number, d: number) => number;
+export const __wbindgen_export_3: WebAssembly.Table;
+export const __externref_table_alloc: () => number;
 export const __externref_table_dealloc: (a: number) => void;
+export const __externref_drop_slice: (a: number, b: number) => void;
 export const __wbindgen_start: () => void;
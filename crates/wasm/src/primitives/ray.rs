@@ -17,6 +17,18 @@ impl Ray {
             .map_err(to_js_error)
     }
 
+    #[wasm_bindgen(js_name = withRange)]
+    pub fn with_range(
+        origin: &super::Point3D,
+        direction: &super::Vector3D,
+        t_min: f64,
+        t_max: f64,
+    ) -> Result<Ray, JsValue> {
+        core::Ray::with_range(origin.inner, direction.inner, t_min, t_max)
+            .map(|r| Ray { inner: r })
+            .map_err(to_js_error)
+    }
+
     #[wasm_bindgen(getter)]
     pub fn origin(&self) -> super::Point3D {
         super::Point3D {
@@ -31,6 +43,16 @@ impl Ray {
         }
     }
 
+    #[wasm_bindgen(getter, js_name = tMin)]
+    pub fn t_min(&self) -> f64 {
+        self.inner.t_min
+    }
+
+    #[wasm_bindgen(getter, js_name = tMax)]
+    pub fn t_max(&self) -> f64 {
+        self.inner.t_max
+    }
+
     #[wasm_bindgen(js_name = pointAt)]
     pub fn point_at(&self, t: f64) -> super::Point3D {
         super::Point3D {
@@ -40,40 +62,98 @@ impl Ray {
 
     #[wasm_bindgen(js_name = intersectSphere)]
     pub fn intersect_sphere(&self, sphere: &super::Sphere) -> JsValue {
-        match core::operations::ray_sphere_intersection(&self.inner, &sphere.inner) {
-            Some((t1, t2)) => {
-                let obj = js_sys::Object::new();
-                js_sys::Reflect::set(&obj, &"t1".into(), &t1.into()).unwrap();
-                js_sys::Reflect::set(&obj, &"t2".into(), &t2.into()).unwrap();
-                obj.into()
-            }
-            None => JsValue::NULL,
-        }
+        hit_to_js(core::operations::ray_sphere_intersection(
+            &self.inner,
+            &sphere.inner,
+        ))
+    }
+
+    #[wasm_bindgen(js_name = intersectSphereAll)]
+    pub fn intersect_sphere_all(&self, sphere: &super::Sphere) -> Vec<RayHit> {
+        core::operations::ray_sphere_intersections(&self.inner, &sphere.inner)
+            .into_iter()
+            .map(|inner| RayHit { inner })
+            .collect()
     }
 
     #[wasm_bindgen(js_name = intersectPlane)]
     pub fn intersect_plane(&self, plane: &super::Plane) -> JsValue {
-        match core::operations::ray_plane_intersection(&self.inner, &plane.inner) {
-            Some(point) => {
-                let p = super::Point3D { inner: point };
-                JsValue::from(p)
-            }
-            None => JsValue::NULL,
-        }
+        hit_to_js(core::operations::ray_plane_intersection(
+            &self.inner,
+            &plane.inner,
+        ))
     }
 
     #[wasm_bindgen(js_name = intersectTriangle)]
     pub fn intersect_triangle(&self, triangle: &super::Triangle) -> JsValue {
-        match core::operations::ray_triangle_intersection(&self.inner, &triangle.inner) {
-            Some(t) => {
-                let obj = js_sys::Object::new();
-                js_sys::Reflect::set(&obj, &"t".into(), &t.into()).unwrap();
-                let point = self.inner.point_at(t);
-                let p = super::Point3D { inner: point };
-                js_sys::Reflect::set(&obj, &"point".into(), &JsValue::from(p)).unwrap();
-                obj.into()
-            }
-            None => JsValue::NULL,
+        hit_to_js(core::operations::ray_triangle_intersection(
+            &self.inner,
+            &triangle.inner,
+        ))
+    }
+
+    #[wasm_bindgen(js_name = intersectAABB)]
+    pub fn intersect_aabb(&self, aabb: &super::AABB) -> JsValue {
+        hit_to_js(core::operations::ray_aabb_intersection(
+            &self.inner,
+            &aabb.inner,
+        ))
+    }
+
+    #[wasm_bindgen(js_name = intersectAABBAll)]
+    pub fn intersect_aabb_all(&self, aabb: &super::AABB) -> Vec<RayHit> {
+        core::operations::ray_aabb_intersections(&self.inner, &aabb.inner)
+            .into_iter()
+            .map(|inner| RayHit { inner })
+            .collect()
+    }
+}
+
+fn hit_to_js(hit: Option<core::RayHit>) -> JsValue {
+    match hit {
+        Some(inner) => JsValue::from(RayHit { inner }),
+        None => JsValue::NULL,
+    }
+}
+
+#[wasm_bindgen]
+pub struct RayHit {
+    pub(crate) inner: core::RayHit,
+}
+
+#[wasm_bindgen]
+impl RayHit {
+    #[wasm_bindgen(getter)]
+    pub fn t(&self) -> f64 {
+        self.inner.t
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn point(&self) -> super::Point3D {
+        super::Point3D {
+            inner: self.inner.point,
+        }
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn normal(&self) -> super::Vector3D {
+        super::Vector3D {
+            inner: self.inner.normal,
         }
     }
+
+    #[wasm_bindgen(getter, js_name = frontFace)]
+    pub fn front_face(&self) -> bool {
+        self.inner.front_face
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn uv(&self) -> Option<Vec<f64>> {
+        self.inner.uv.map(|(u, v)| vec![u, v])
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn barycentric(&self) -> Option<Vec<f64>> {
+        self.inner.barycentric.map(|(u, v, w)| vec![u, v, w])
+    }
 }
@@ -1,7 +1,8 @@
 use common_core_geometry::svg as core_svg;
 use wasm_bindgen::prelude::*;
 
-use crate::primitives::{Point3D, Vector3D};
+use crate::primitives::{Point3D, Ray, Sphere, Vector3D, AABB};
+use crate::utils::to_js_error;
 
 #[wasm_bindgen]
 pub struct Camera {
@@ -19,18 +20,18 @@ impl Camera {
         aspect: f64,
         near: f64,
         far: f64,
-    ) -> Camera {
-        Camera {
-            inner: core_svg::camera::Camera::perspective(
-                position.inner,
-                target.inner,
-                up.inner,
-                fov,
-                aspect,
-                near,
-                far,
-            ),
-        }
+    ) -> Result<Camera, JsValue> {
+        core_svg::camera::Camera::perspective(
+            position.inner,
+            target.inner,
+            up.inner,
+            fov,
+            aspect,
+            near,
+            far,
+        )
+        .map(|inner| Camera { inner })
+        .map_err(to_js_error)
     }
 
     #[wasm_bindgen(js_name = orthographic)]
@@ -40,18 +41,203 @@ impl Camera {
         up: &Vector3D,
         width: f64,
         height: f64,
-    ) -> Camera {
-        Camera {
-            inner: core_svg::camera::Camera::orthographic(
-                position.inner,
-                target.inner,
-                up.inner,
-                width,
-                height,
-            ),
+    ) -> Result<Camera, JsValue> {
+        core_svg::camera::Camera::orthographic(
+            position.inner,
+            target.inner,
+            up.inner,
+            width,
+            height,
+        )
+        .map(|inner| Camera { inner })
+        .map_err(to_js_error)
+    }
+
+    pub fn isometric(
+        target: &Point3D,
+        up: &Vector3D,
+        width: f64,
+        height: f64,
+    ) -> Result<Camera, JsValue> {
+        core_svg::camera::Camera::isometric(target.inner, up.inner, width, height)
+            .map(|inner| Camera { inner })
+            .map_err(to_js_error)
+    }
+
+    pub fn dimetric(
+        target: &Point3D,
+        up: &Vector3D,
+        width: f64,
+        height: f64,
+    ) -> Result<Camera, JsValue> {
+        core_svg::camera::Camera::dimetric(target.inner, up.inner, width, height)
+            .map(|inner| Camera { inner })
+            .map_err(to_js_error)
+    }
+
+    pub fn trimetric(
+        target: &Point3D,
+        up: &Vector3D,
+        yaw: f64,
+        elevation: f64,
+        width: f64,
+        height: f64,
+    ) -> Result<Camera, JsValue> {
+        core_svg::camera::Camera::trimetric(target.inner, up.inner, yaw, elevation, width, height)
+            .map(|inner| Camera { inner })
+            .map_err(to_js_error)
+    }
+
+    pub fn oblique(
+        position: &Point3D,
+        target: &Point3D,
+        up: &Vector3D,
+        width: f64,
+        height: f64,
+        angle: f64,
+        depth_scale: f64,
+    ) -> Result<Camera, JsValue> {
+        core_svg::camera::Camera::oblique(
+            position.inner,
+            target.inner,
+            up.inner,
+            width,
+            height,
+            angle,
+            depth_scale,
+        )
+        .map(|inner| Camera { inner })
+        .map_err(to_js_error)
+    }
+
+    pub fn cavalier(
+        position: &Point3D,
+        target: &Point3D,
+        up: &Vector3D,
+        width: f64,
+        height: f64,
+    ) -> Result<Camera, JsValue> {
+        core_svg::camera::Camera::cavalier(position.inner, target.inner, up.inner, width, height)
+            .map(|inner| Camera { inner })
+            .map_err(to_js_error)
+    }
+
+    pub fn cabinet(
+        position: &Point3D,
+        target: &Point3D,
+        up: &Vector3D,
+        width: f64,
+        height: f64,
+    ) -> Result<Camera, JsValue> {
+        core_svg::camera::Camera::cabinet(position.inner, target.inner, up.inner, width, height)
+            .map(|inner| Camera { inner })
+            .map_err(to_js_error)
+    }
+
+    /// `window` is `[left, right, bottom, top]` on the near plane.
+    #[wasm_bindgen(js_name = offAxis)]
+    pub fn off_axis(
+        position: &Point3D,
+        target: &Point3D,
+        up: &Vector3D,
+        window: Vec<f64>,
+        near: f64,
+        far: f64,
+    ) -> Result<Camera, JsValue> {
+        let window: [f64; 4] = window
+            .try_into()
+            .map_err(|_| to_js_error("Window must have exactly 4 elements"))?;
+        core_svg::camera::Camera::off_axis(
+            position.inner,
+            target.inner,
+            up.inner,
+            window,
+            near,
+            far,
+        )
+        .map(|inner| Camera { inner })
+        .map_err(to_js_error)
+    }
+
+    pub fn fisheye(
+        position: &Point3D,
+        target: &Point3D,
+        up: &Vector3D,
+        fov: f64,
+        aspect: f64,
+    ) -> Result<Camera, JsValue> {
+        core_svg::camera::Camera::fisheye(position.inner, target.inner, up.inner, fov, aspect)
+            .map(|inner| Camera { inner })
+            .map_err(to_js_error)
+    }
+
+    pub fn equirectangular(
+        position: &Point3D,
+        target: &Point3D,
+        up: &Vector3D,
+    ) -> Result<Camera, JsValue> {
+        core_svg::camera::Camera::equirectangular(position.inner, target.inner, up.inner)
+            .map(|inner| Camera { inner })
+            .map_err(to_js_error)
+    }
+
+    #[wasm_bindgen(getter, js_name = isLinear)]
+    pub fn is_linear(&self) -> bool {
+        self.inner.is_linear()
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn position(&self) -> Point3D {
+        Point3D {
+            inner: self.inner.position(),
+        }
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn target(&self) -> Point3D {
+        Point3D {
+            inner: self.inner.target(),
+        }
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn up(&self) -> Vector3D {
+        Vector3D {
+            inner: self.inner.up(),
         }
     }
 
+    #[wasm_bindgen(js_name = lookAt)]
+    pub fn look_at(&mut self, target: &Point3D) -> Result<(), JsValue> {
+        self.inner.look_at(target.inner).map_err(to_js_error)
+    }
+
+    pub fn orbit(&mut self, yaw: f64, pitch: f64) {
+        self.inner.orbit(yaw, pitch);
+    }
+
+    pub fn pan(&mut self, dx: f64, dy: f64) {
+        self.inner.pan(dx, dy);
+    }
+
+    pub fn dolly(&mut self, distance: f64) -> Result<(), JsValue> {
+        self.inner.dolly(distance).map_err(to_js_error)
+    }
+
+    pub fn zoom(&mut self, factor: f64) -> Result<(), JsValue> {
+        self.inner.zoom(factor).map_err(to_js_error)
+    }
+
+    #[wasm_bindgen(js_name = fitToSphere)]
+    pub fn fit_to_sphere(&mut self, sphere: &Sphere) -> Result<(), JsValue> {
+        self.inner.fit_to_sphere(&sphere.inner).map_err(to_js_error)
+    }
+
+    #[wasm_bindgen(js_name = fitToAabb)]
+    pub fn fit_to_aabb(&mut self, aabb: &AABB) -> Result<(), JsValue> {
+        self.inner.fit_to_aabb(&aabb.inner).map_err(to_js_error)
+    }
+
     #[wasm_bindgen(js_name = viewMatrix)]
     pub fn view_matrix(&self) -> Vec<f64> {
         let matrix = self.inner.view_matrix();
@@ -63,4 +249,20 @@ impl Camera {
         let matrix = self.inner.projection_matrix();
         matrix.iter().flat_map(|row| row.iter().copied()).collect()
     }
+
+    #[wasm_bindgen(js_name = screenToRay)]
+    pub fn screen_to_ray(&self, x: f64, y: f64, width: u32, height: u32) -> Ray {
+        Ray {
+            inner: self
+                .inner
+                .screen_to_ray(x, y, width as usize, height as usize),
+        }
+    }
+
+    #[wasm_bindgen(js_name = unproject)]
+    pub fn unproject(&self, x: f64, y: f64, depth: f64) -> Point3D {
+        Point3D {
+            inner: self.inner.unproject(x, y, depth),
+        }
+    }
 }
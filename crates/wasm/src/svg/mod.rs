@@ -1,5 +1,7 @@
 pub mod camera;
 pub mod renderer;
+pub mod style;
 
 pub use camera::Camera;
 pub use renderer::SVGRenderer;
+pub use style::Style;
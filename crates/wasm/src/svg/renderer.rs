@@ -1,8 +1,11 @@
 use common_core_geometry::svg as core_svg;
+use common_core_geometry::svg::Light;
 use wasm_bindgen::prelude::*;
 
-use crate::primitives::{LineSegment, Point3D, Sphere, Triangle, AABB};
+use crate::primitives::{LineSegment, Plane, Point3D, Ray, Sphere, Triangle, Vector3D, AABB};
 use crate::svg::camera::Camera;
+use crate::svg::style::Style;
+use crate::utils::to_js_error;
 
 #[wasm_bindgen]
 pub struct SVGRenderer {
@@ -27,6 +30,75 @@ impl SVGRenderer {
         self.inner.set_background(color);
     }
 
+    /// Sets the drawing order: "none", "painter" or "bsp".
+    #[wasm_bindgen(js_name = setDepthSort)]
+    pub fn set_depth_sort(&mut self, mode: &str) -> Result<(), JsValue> {
+        let mode = mode.parse().map_err(to_js_error)?;
+        self.inner.set_depth_sort(mode);
+        Ok(())
+    }
+
+    /// Sets how hidden edges are drawn: "shown", "dashed" or "removed".
+    #[wasm_bindgen(js_name = setHiddenLines)]
+    pub fn set_hidden_lines(&mut self, mode: &str) -> Result<(), JsValue> {
+        let mode = mode.parse().map_err(to_js_error)?;
+        self.inner.set_hidden_lines(mode);
+        Ok(())
+    }
+
+    /// `color` is a "#rgb" or "#rrggbb" string.
+    #[wasm_bindgen(js_name = addAmbientLight)]
+    pub fn add_ambient_light(&mut self, color: &str, intensity: f64) -> Result<(), JsValue> {
+        let light = Light::ambient(color, intensity).map_err(to_js_error)?;
+        self.inner.add_light(light);
+        Ok(())
+    }
+
+    /// `direction` is the way the light travels.
+    #[wasm_bindgen(js_name = addDirectionalLight)]
+    pub fn add_directional_light(
+        &mut self,
+        direction: &Vector3D,
+        color: &str,
+        intensity: f64,
+    ) -> Result<(), JsValue> {
+        let light = Light::directional(direction.inner, color, intensity).map_err(to_js_error)?;
+        self.inner.add_light(light);
+        Ok(())
+    }
+
+    #[wasm_bindgen(js_name = addPointLight)]
+    pub fn add_point_light(
+        &mut self,
+        position: &Point3D,
+        color: &str,
+        intensity: f64,
+    ) -> Result<(), JsValue> {
+        let light = Light::point(position.inner, color, intensity).map_err(to_js_error)?;
+        self.inner.add_light(light);
+        Ok(())
+    }
+
+    #[wasm_bindgen(js_name = clearLights)]
+    pub fn clear_lights(&mut self) {
+        self.inner.clear_lights();
+    }
+
+    /// Lights the fill of triangles and spheres added from now on:
+    /// `"none"`, `"flat"` or `"gouraud"`.
+    #[wasm_bindgen(js_name = setShading)]
+    pub fn set_shading(&mut self, mode: &str) -> Result<(), JsValue> {
+        let mode = mode.parse().map_err(to_js_error)?;
+        self.inner.set_shading(mode);
+        Ok(())
+    }
+
+    /// Drops triangles added from now on that face away from the camera.
+    #[wasm_bindgen(js_name = setBackFaceCulling)]
+    pub fn set_back_face_culling(&mut self, enabled: bool) {
+        self.inner.set_back_face_culling(enabled);
+    }
+
     #[wasm_bindgen(js_name = addPoint)]
     pub fn add_point(&mut self, point: &Point3D, color: &str, size: f64) {
         self.inner.add_point(&point.inner, color, size);
@@ -54,13 +126,311 @@ impl SVGRenderer {
         self.inner.add_sphere(&sphere.inner, color, width);
     }
 
+    #[wasm_bindgen(js_name = addFilledSphere)]
+    pub fn add_filled_sphere(
+        &mut self,
+        sphere: &Sphere,
+        stroke: &str,
+        fill: Option<String>,
+        width: f64,
+    ) {
+        self.inner
+            .add_filled_sphere(&sphere.inner, stroke, fill.as_deref(), width);
+    }
+
     #[wasm_bindgen(js_name = addAabb)]
     pub fn add_aabb(&mut self, aabb: &AABB, color: &str, width: f64) {
         self.inner.add_aabb(&aabb.inner, color, width);
     }
 
+    #[wasm_bindgen(js_name = addRay)]
+    pub fn add_ray(&mut self, ray: &Ray, color: &str, width: f64) {
+        self.inner.add_ray(&ray.inner, color, width);
+    }
+
+    #[wasm_bindgen(js_name = addVector)]
+    pub fn add_vector(
+        &mut self,
+        origin: &Point3D,
+        vector: &Vector3D,
+        color: &str,
+        width: f64,
+    ) -> Result<(), JsValue> {
+        self.inner
+            .add_vector(&origin.inner, &vector.inner, color, width)
+            .map_err(to_js_error)
+    }
+
+    #[wasm_bindgen(js_name = addPlane)]
+    pub fn add_plane(
+        &mut self,
+        plane: &Plane,
+        center: &Point3D,
+        size: f64,
+        stroke: &str,
+        fill: Option<String>,
+        width: f64,
+    ) -> Result<(), JsValue> {
+        self.inner
+            .add_plane(
+                &plane.inner,
+                &center.inner,
+                size,
+                stroke,
+                fill.as_deref(),
+                width,
+            )
+            .map_err(to_js_error)
+    }
+
+    #[wasm_bindgen(js_name = addPointWithStyle)]
+    pub fn add_point_with_style(&mut self, point: &Point3D, size: f64, style: &Style) {
+        self.inner
+            .add_point_with_style(&point.inner, size, &style.inner);
+    }
+
+    #[wasm_bindgen(js_name = addLineSegmentWithStyle)]
+    pub fn add_line_segment_with_style(&mut self, segment: &LineSegment, style: &Style) {
+        self.inner
+            .add_line_segment_with_style(&segment.inner, &style.inner);
+    }
+
+    #[wasm_bindgen(js_name = addTriangleWithStyle)]
+    pub fn add_triangle_with_style(&mut self, triangle: &Triangle, style: &Style) {
+        self.inner
+            .add_triangle_with_style(&triangle.inner, &style.inner);
+    }
+
+    /// Like `addTriangleWithStyle`, with the surface normals at `a`, `b`
+    /// and `c` for Gouraud shading.
+    #[wasm_bindgen(js_name = addTriangleWithNormals)]
+    pub fn add_triangle_with_normals(
+        &mut self,
+        triangle: &Triangle,
+        normal_a: &Vector3D,
+        normal_b: &Vector3D,
+        normal_c: &Vector3D,
+        style: &Style,
+    ) {
+        self.inner.add_triangle_with_normals(
+            &triangle.inner,
+            [normal_a.inner, normal_b.inner, normal_c.inner],
+            &style.inner,
+        );
+    }
+
+    #[wasm_bindgen(js_name = addSphereWithStyle)]
+    pub fn add_sphere_with_style(&mut self, sphere: &Sphere, style: &Style) {
+        self.inner
+            .add_sphere_with_style(&sphere.inner, &style.inner);
+    }
+
+    #[wasm_bindgen(js_name = addAabbWithStyle)]
+    pub fn add_aabb_with_style(&mut self, aabb: &AABB, style: &Style) {
+        self.inner.add_aabb_with_style(&aabb.inner, &style.inner);
+    }
+
+    #[wasm_bindgen(js_name = addRayWithStyle)]
+    pub fn add_ray_with_style(&mut self, ray: &Ray, style: &Style) {
+        self.inner.add_ray_with_style(&ray.inner, &style.inner);
+    }
+
+    #[wasm_bindgen(js_name = addVectorWithStyle)]
+    pub fn add_vector_with_style(
+        &mut self,
+        origin: &Point3D,
+        vector: &Vector3D,
+        style: &Style,
+    ) -> Result<(), JsValue> {
+        self.inner
+            .add_vector_with_style(&origin.inner, &vector.inner, &style.inner)
+            .map_err(to_js_error)
+    }
+
+    #[wasm_bindgen(js_name = addPlaneWithStyle)]
+    pub fn add_plane_with_style(
+        &mut self,
+        plane: &Plane,
+        center: &Point3D,
+        size: f64,
+        style: &Style,
+    ) -> Result<(), JsValue> {
+        self.inner
+            .add_plane_with_style(&plane.inner, &center.inner, size, &style.inner)
+            .map_err(to_js_error)
+    }
+
+    #[wasm_bindgen(js_name = setStylesheet)]
+    pub fn set_stylesheet(&mut self, css: &str) {
+        self.inner.set_stylesheet(css);
+    }
+
+    #[wasm_bindgen(js_name = addLabel)]
+    pub fn add_label(&mut self, point: &Point3D, text: &str, style: &Style) {
+        self.inner.add_label(&point.inner, text, &style.inner);
+    }
+
+    #[wasm_bindgen(js_name = addLeaderLabel)]
+    pub fn add_leader_label(
+        &mut self,
+        point: &Point3D,
+        dx: f64,
+        dy: f64,
+        text: &str,
+        style: &Style,
+    ) {
+        self.inner
+            .add_leader_label(&point.inner, (dx, dy), text, &style.inner);
+    }
+
+    #[wasm_bindgen(js_name = addLinearDimension)]
+    pub fn add_linear_dimension(
+        &mut self,
+        start: &Point3D,
+        end: &Point3D,
+        offset: &Vector3D,
+        style: &Style,
+    ) -> f64 {
+        self.inner
+            .add_linear_dimension(&start.inner, &end.inner, &offset.inner, &style.inner)
+    }
+
+    #[wasm_bindgen(js_name = addAngularDimension)]
+    pub fn add_angular_dimension(
+        &mut self,
+        vertex: &Point3D,
+        from: &Point3D,
+        to: &Point3D,
+        radius: f64,
+        style: &Style,
+    ) -> Result<f64, JsValue> {
+        self.inner
+            .add_angular_dimension(&vertex.inner, &from.inner, &to.inner, radius, &style.inner)
+            .map_err(to_js_error)
+    }
+
+    #[wasm_bindgen(js_name = addLegend)]
+    pub fn add_legend(
+        &mut self,
+        texts: Vec<String>,
+        styles: Vec<Style>,
+        x: f64,
+        y: f64,
+        style: &Style,
+    ) -> Result<(), JsValue> {
+        if texts.len() != styles.len() {
+            return Err(to_js_error("Legend needs one style per text"));
+        }
+        let entries: Vec<(&str, &core_svg::Style)> = texts
+            .iter()
+            .zip(&styles)
+            .map(|(text, style)| (text.as_str(), &style.inner))
+            .collect();
+        self.inner.add_legend(&entries, x, y, &style.inner);
+        Ok(())
+    }
+
+    #[wasm_bindgen(js_name = addAxes)]
+    pub fn add_axes(
+        &mut self,
+        origin: &Point3D,
+        length: f64,
+        style: &Style,
+    ) -> Result<(), JsValue> {
+        self.inner
+            .add_axes(&origin.inner, length, &style.inner)
+            .map_err(to_js_error)
+    }
+
+    #[wasm_bindgen(js_name = addGrid)]
+    pub fn add_grid(
+        &mut self,
+        plane: &Plane,
+        center: &Point3D,
+        size: f64,
+        divisions: usize,
+        style: &Style,
+    ) -> Result<(), JsValue> {
+        self.inner
+            .add_grid(&plane.inner, &center.inner, size, divisions, &style.inner)
+            .map_err(to_js_error)
+    }
+
+    #[wasm_bindgen(js_name = addTicks)]
+    pub fn add_ticks(
+        &mut self,
+        start: &Point3D,
+        end: &Point3D,
+        spacing: f64,
+        size: f64,
+        style: &Style,
+    ) -> Result<(), JsValue> {
+        self.inner
+            .add_ticks(&start.inner, &end.inner, spacing, size, &style.inner)
+            .map_err(to_js_error)
+    }
+
+    #[wasm_bindgen(js_name = sceneBounds)]
+    pub fn scene_bounds(&self) -> Option<AABB> {
+        self.inner.scene_bounds().map(|inner| AABB { inner })
+    }
+
+    #[wasm_bindgen(js_name = addSceneBounds)]
+    pub fn add_scene_bounds(&mut self, style: &Style) -> Option<AABB> {
+        self.inner
+            .add_scene_bounds(&style.inner)
+            .map(|inner| AABB { inner })
+    }
+
+    #[wasm_bindgen(js_name = addLayer)]
+    pub fn add_layer(
+        &mut self,
+        name: &str,
+        visible: bool,
+        transform: Option<String>,
+    ) -> Result<(), JsValue> {
+        self.add_layer_with_style(name, visible, transform, &Style::default())
+    }
+
+    #[wasm_bindgen(js_name = addLayerWithStyle)]
+    pub fn add_layer_with_style(
+        &mut self,
+        name: &str,
+        visible: bool,
+        transform: Option<String>,
+        style: &Style,
+    ) -> Result<(), JsValue> {
+        let mut layer = core_svg::Layer::new(name)
+            .with_visible(visible)
+            .with_style(style.inner.clone());
+        layer.transform = transform;
+        self.inner.add_layer(layer).map_err(to_js_error)
+    }
+
+    #[wasm_bindgen(js_name = setCurrentLayer)]
+    pub fn set_current_layer(&mut self, name: Option<String>) -> Result<(), JsValue> {
+        self.inner
+            .set_current_layer(name.as_deref())
+            .map_err(to_js_error)
+    }
+
+    #[wasm_bindgen(js_name = setLayerVisible)]
+    pub fn set_layer_visible(&mut self, name: &str, visible: bool) -> Result<(), JsValue> {
+        self.inner
+            .set_layer_visible(name, visible)
+            .map_err(to_js_error)
+    }
+
     #[wasm_bindgen(js_name = toSvgString)]
     pub fn to_svg_string(&self) -> String {
         self.inner.to_svg_string()
     }
+
+    #[wasm_bindgen(js_name = toSvgStringWithLayers)]
+    pub fn to_svg_string_with_layers(&self, layers: Vec<String>) -> Result<String, JsValue> {
+        let names: Vec<&str> = layers.iter().map(String::as_str).collect();
+        self.inner
+            .to_svg_string_with_layers(&names)
+            .map_err(to_js_error)
+    }
 }
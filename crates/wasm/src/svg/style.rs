@@ -0,0 +1,98 @@
+use common_core_geometry::svg as core_svg;
+use wasm_bindgen::prelude::*;
+
+use crate::utils::to_js_error;
+
+#[wasm_bindgen]
+#[derive(Clone, Default)]
+pub struct Style {
+    pub(crate) inner: core_svg::Style,
+}
+
+#[wasm_bindgen]
+impl Style {
+    #[wasm_bindgen(constructor)]
+    pub fn new() -> Style {
+        Style::default()
+    }
+
+    #[wasm_bindgen(js_name = setStroke)]
+    pub fn set_stroke(&mut self, color: Option<String>) {
+        self.inner.stroke = color;
+    }
+
+    #[wasm_bindgen(js_name = setStrokeWidth)]
+    pub fn set_stroke_width(&mut self, width: Option<f64>) {
+        self.inner.stroke_width = width;
+    }
+
+    #[wasm_bindgen(js_name = setFill)]
+    pub fn set_fill(&mut self, color: Option<String>) {
+        self.inner.fill = color;
+    }
+
+    #[wasm_bindgen(js_name = setOpacity)]
+    pub fn set_opacity(&mut self, opacity: Option<f64>) {
+        self.inner.opacity = opacity;
+    }
+
+    #[wasm_bindgen(js_name = setDashArray)]
+    pub fn set_dash_array(&mut self, dashes: Option<Vec<f64>>) {
+        self.inner.dash_array = dashes;
+    }
+
+    #[wasm_bindgen(js_name = setLineCap)]
+    pub fn set_line_cap(&mut self, cap: Option<String>) -> Result<(), JsValue> {
+        self.inner.line_cap = cap.map(|c| c.parse()).transpose().map_err(to_js_error)?;
+        Ok(())
+    }
+
+    #[wasm_bindgen(js_name = setLineJoin)]
+    pub fn set_line_join(&mut self, join: Option<String>) -> Result<(), JsValue> {
+        self.inner.line_join = join.map(|j| j.parse()).transpose().map_err(to_js_error)?;
+        Ok(())
+    }
+
+    #[wasm_bindgen(js_name = setMarkerStart)]
+    pub fn set_marker_start(&mut self, marker: Option<String>) -> Result<(), JsValue> {
+        self.inner.marker_start = marker.map(|m| m.parse()).transpose().map_err(to_js_error)?;
+        Ok(())
+    }
+
+    #[wasm_bindgen(js_name = setMarkerEnd)]
+    pub fn set_marker_end(&mut self, marker: Option<String>) -> Result<(), JsValue> {
+        self.inner.marker_end = marker.map(|m| m.parse()).transpose().map_err(to_js_error)?;
+        Ok(())
+    }
+
+    #[wasm_bindgen(js_name = setClass)]
+    pub fn set_class(&mut self, class: Option<String>) {
+        self.inner.class = class;
+    }
+
+    #[wasm_bindgen(js_name = setId)]
+    pub fn set_id(&mut self, id: Option<String>) {
+        self.inner.id = id;
+    }
+
+    #[wasm_bindgen(js_name = setTitle)]
+    pub fn set_title(&mut self, title: Option<String>) {
+        self.inner.title = title;
+    }
+
+    #[wasm_bindgen(js_name = setFontSize)]
+    pub fn set_font_size(&mut self, size: Option<f64>) {
+        self.inner.font_size = size;
+    }
+
+    #[wasm_bindgen(js_name = setFontFamily)]
+    pub fn set_font_family(&mut self, family: Option<String>) {
+        self.inner.font_family = family;
+    }
+
+    #[wasm_bindgen(js_name = setTextAnchor)]
+    pub fn set_text_anchor(&mut self, anchor: Option<String>) -> Result<(), JsValue> {
+        self.inner.text_anchor = anchor.map(|a| a.parse()).transpose().map_err(to_js_error)?;
+        Ok(())
+    }
+}
@@ -105,10 +105,50 @@ describe('Ray', () => {
     const direction = new Vector3D(1, 0, 0);
     const ray = new Ray(origin, direction);
     const sphere = new Sphere(new Point3D(0, 0, 0), 1.0);
-    const result = ray.intersectSphere(sphere);
-    expect(result).not.toBe(null);
-    expect(result.t1).toBeGreaterThan(0);
-    expect(result.t2).toBeGreaterThan(0);
+    const hit = ray.intersectSphere(sphere);
+    expect(hit).not.toBe(null);
+    expect(hit.t).toBeCloseTo(4.0);
+    expect(hit.point.x).toBeCloseTo(-1.0);
+    expect(hit.normal.x).toBeCloseTo(-1.0);
+    expect(hit.frontFace).toBe(true);
+    expect(hit.uv).toBeDefined();
+  });
+
+  it('should intersect triangle with barycentrics', () => {
+    const ray = new Ray(new Point3D(0.25, 0.25, 5), new Vector3D(0, 0, -1));
+    const tri = new Triangle(
+      new Point3D(0, 0, 0),
+      new Point3D(1, 0, 0),
+      new Point3D(0, 1, 0),
+    );
+    const hit = ray.intersectTriangle(tri);
+    expect(hit).not.toBe(null);
+    expect(hit.t).toBeCloseTo(5.0);
+    expect(hit.barycentric[0]).toBeCloseTo(0.5);
+    expect(hit.barycentric[1]).toBeCloseTo(0.25);
+    expect(hit.barycentric[2]).toBeCloseTo(0.25);
+  });
+
+  it('should respect the ray interval', () => {
+    const sphere = new Sphere(new Point3D(0, 0, 0), 1.0);
+    const short = Ray.withRange(new Point3D(-5, 0, 0), new Vector3D(1, 0, 0), 0, 3);
+    expect(short.tMax).toBe(3);
+    expect(short.intersectSphere(sphere)).toBe(null);
+
+    const ray = new Ray(new Point3D(-5, 0, 0), new Vector3D(1, 0, 0));
+    const hits = ray.intersectSphereAll(sphere);
+    expect(hits.length).toBe(2);
+    expect(hits[0].t).toBeCloseTo(4.0);
+    expect(hits[1].frontFace).toBe(false);
+  });
+
+  it('should intersect AABB', () => {
+    const ray = new Ray(new Point3D(-5, 0, 0), new Vector3D(1, 0, 0));
+    const aabb = new AABB(new Point3D(-1, -1, -1), new Point3D(1, 1, 1));
+    const hit = ray.intersectAABB(aabb);
+    expect(hit).not.toBe(null);
+    expect(hit.t).toBeCloseTo(4.0);
+    expect(hit.normal.x).toBeCloseTo(-1.0);
   });
 });
 
@@ -1,6 +1,6 @@
 import { describe, it } from 'bun:test';
 import { expect } from 'bun:test';
-import { Point3D, Vector3D, LineSegment, Triangle, Sphere, AABB, Camera, SVGRenderer } from '../pkg-nodejs/common_core_geometry_wasm.js';
+import { Point3D, Vector3D, LineSegment, Triangle, Sphere, AABB, Plane, Ray, Camera, SVGRenderer, Style } from '../pkg-nodejs/common_core_geometry_wasm.js';
 
 describe('Camera', () => {
     it('perspective creation', () => {
@@ -30,6 +30,115 @@ describe('Camera', () => {
         expect(viewMatrix).toBeInstanceOf(Float64Array);
         expect(viewMatrix.length).toBe(16);
     });
+
+    it('screenToRay picks objects', () => {
+        const camera = Camera.perspective(
+            new Point3D(0, 0, 5),
+            new Point3D(0, 0, 0),
+            new Vector3D(0, 1, 0),
+            60, 4/3, 0.1, 100
+        );
+        const ray = camera.screenToRay(400, 300, 800, 600);
+        expect(ray.origin.z).toBeCloseTo(5);
+        expect(ray.direction.z).toBeCloseTo(-1);
+
+        const hit = ray.intersectSphere(new Sphere(new Point3D(0, 0, 0), 1));
+        expect(hit).not.toBeNull();
+        expect(hit.t).toBeCloseTo(4);
+
+        const miss = camera.screenToRay(0, 0, 800, 600).intersectSphere(new Sphere(new Point3D(0, 0, 0), 1));
+        expect(miss).toBeNull();
+    });
+
+    it('unproject', () => {
+        const camera = Camera.orthographic(
+            new Point3D(0, 0, 5),
+            new Point3D(0, 0, 0),
+            new Vector3D(0, 1, 0),
+            10, 10
+        );
+        const point = camera.unproject(1, -1, 5);
+        expect(point.x).toBeCloseTo(5);
+        expect(point.y).toBeCloseTo(-5);
+        expect(point.z).toBeCloseTo(0);
+    });
+
+    it('rejects invalid parameters', () => {
+        const position = new Point3D(0, 0, 5);
+        const target = new Point3D(0, 0, 0);
+        const up = new Vector3D(0, 1, 0);
+        expect(() => Camera.perspective(position, target, up, 0, 1, 0.1, 100)).toThrow();
+        expect(() => Camera.perspective(position, target, up, 60, 1, 10, 10)).toThrow();
+        expect(() => Camera.perspective(position, position, up, 60, 1, 0.1, 100)).toThrow();
+        expect(() => Camera.orthographic(position, target, new Vector3D(0, 0, 1), 10, 10)).toThrow();
+    });
+
+    it('orbit, pan, dolly and zoom', () => {
+        const camera = Camera.perspective(
+            new Point3D(0, 0, 5),
+            new Point3D(0, 0, 0),
+            new Vector3D(0, 1, 0),
+            60, 1, 0.1, 100
+        );
+        camera.orbit(90, 0);
+        expect(camera.position.x).toBeCloseTo(5);
+        expect(camera.position.z).toBeCloseTo(0);
+
+        camera.pan(0, 1);
+        expect(camera.target.y).toBeCloseTo(1);
+
+        camera.dolly(2);
+        expect(camera.position.x).toBeCloseTo(3);
+        expect(() => camera.dolly(5)).toThrow();
+        expect(() => camera.zoom(0)).toThrow();
+    });
+
+    it('projection modes', () => {
+        const target = new Point3D(0, 0, 0);
+        const eye = new Point3D(0, 0, 10);
+        const up = new Vector3D(0, 1, 0);
+        const cameras = [
+            Camera.isometric(target, up, 10, 10),
+            Camera.dimetric(target, up, 10, 10),
+            Camera.trimetric(target, up, 30, 20, 10, 10),
+            Camera.cavalier(eye, target, up, 10, 10),
+            Camera.cabinet(eye, target, up, 10, 10),
+            Camera.oblique(eye, target, up, 10, 10, 30, 0.75),
+            Camera.offAxis(eye, target, up, [-0.05, 0.15, -0.1, 0.1], 0.1, 100),
+            Camera.fisheye(eye, target, up, 180, 1),
+            Camera.equirectangular(eye, target, up),
+        ];
+        expect(cameras[0].isLinear).toBe(true);
+        expect(cameras[7].isLinear).toBe(false);
+        for (const camera of cameras) {
+            const renderer = new SVGRenderer(400, 400, camera);
+            renderer.addAabb(new AABB(new Point3D(-1, -1, -1), new Point3D(1, 1, 1)), "#000000", 1);
+            expect(renderer.toSvgString()).not.toContain('NaN');
+        }
+
+        expect(() => Camera.offAxis(eye, target, up, [0.1, -0.1, -0.1, 0.1], 0.1, 100)).toThrow();
+        expect(() => Camera.offAxis(eye, target, up, [0.1, 0.2], 0.1, 100)).toThrow();
+        expect(() => Camera.fisheye(eye, target, up, 400, 1)).toThrow();
+    });
+
+    it('lookAt and fit', () => {
+        const camera = Camera.perspective(
+            new Point3D(0, 0, 5),
+            new Point3D(0, 0, 0),
+            new Vector3D(0, 1, 0),
+            60, 1, 0.1, 100
+        );
+        camera.lookAt(new Point3D(1, 0, 0));
+        expect(camera.target.x).toBeCloseTo(1);
+
+        // The view direction is kept, so the camera backs off along it.
+        camera.fitToSphere(new Sphere(new Point3D(0, 0, 0), 2));
+        expect(camera.target.x).toBeCloseTo(0);
+        expect(camera.position.distanceTo(camera.target)).toBeCloseTo(4);
+
+        camera.fitToAabb(new AABB(new Point3D(-1, -1, -1), new Point3D(1, 1, 1)));
+        expect(camera.target.x).toBeCloseTo(0);
+    });
 });
 
 describe('SVGRenderer', () => {
@@ -152,10 +261,212 @@ describe('SVGRenderer', () => {
         renderer.addSphere(sphere, '#ff00ff', 2);
         
         const svg = renderer.toSvgString();
-        expect(svg).toContain('<circle');
+        expect(svg).toContain('<ellipse');
         expect(svg).toContain('#ff00ff');
     });
 
+    it('addFilledSphere', () => {
+        const camera = Camera.perspective(
+            new Point3D(0, 0, 5),
+            new Point3D(0, 0, 0),
+            new Vector3D(0, 1, 0),
+            60, 1, 0.1, 100
+        );
+        const renderer = new SVGRenderer(800, 600, camera);
+        const sphere = new Sphere(new Point3D(0, 0, 0), 1);
+        renderer.addFilledSphere(sphere, '#000000', '#ff0000', 1);
+        expect(renderer.toSvgString()).toContain('fill="#ff0000"');
+
+        renderer.addDirectionalLight(new Vector3D(-1, 0, 0), '#ffffff', 1);
+        renderer.setShading('flat');
+        renderer.addFilledSphere(sphere, '#000000', '#ff0000', 1);
+        const svg = renderer.toSvgString();
+        expect(svg).toContain('<radialGradient');
+        expect(svg).toContain('url(#sphere-shade-');
+    });
+
+    it('styles', () => {
+        const camera = Camera.perspective(
+            new Point3D(0, 0, 5),
+            new Point3D(0, 0, 0),
+            new Vector3D(0, 1, 0),
+            60, 1, 0.1, 100
+        );
+        const renderer = new SVGRenderer(800, 600, camera);
+        renderer.setStylesheet('.axis { stroke-width: 3; }');
+        const style = new Style();
+        style.setStroke('#ff0000');
+        style.setDashArray(new Float64Array([4, 2]));
+        style.setLineCap('round');
+        style.setMarkerEnd('arrow');
+        style.setClass('axis');
+        style.setId('x-axis');
+        style.setTitle('X axis');
+        const segment = new LineSegment(new Point3D(-1, 0, 0), new Point3D(1, 0, 0));
+        renderer.addLineSegmentWithStyle(segment, style);
+
+        const svg = renderer.toSvgString();
+        expect(svg).toContain('<style>');
+        expect(svg).toContain('id="x-axis"');
+        expect(svg).toContain('class="axis"');
+        expect(svg).toContain('stroke-dasharray="4.00 2.00"');
+        expect(svg).toContain('marker-end="url(#arrow-ff0000)"');
+        expect(svg).toContain('<title>X axis</title>');
+
+        expect(() => style.setLineCap('pointy')).toThrow();
+        expect(() => style.setMarkerStart('star')).toThrow();
+    });
+
+    it('annotations', () => {
+        const camera = Camera.orthographic(
+            new Point3D(0, 0, 10),
+            new Point3D(0, 0, 0),
+            new Vector3D(0, 1, 0),
+            10, 10
+        );
+        const renderer = new SVGRenderer(500, 500, camera);
+        const style = new Style();
+        style.setStroke('#ff0000');
+        style.setFontSize(14);
+        renderer.addLabel(new Point3D(1, 1, 0), 'A & B', style);
+        renderer.addLeaderLabel(new Point3D(0, 0, 0), 20, -10, 'origin', new Style());
+        const distance = renderer.addLinearDimension(
+            new Point3D(-2, 0, 0),
+            new Point3D(2, 0, 0),
+            new Vector3D(0, -1, 0),
+            new Style()
+        );
+        expect(distance).toBeCloseTo(4);
+        const angle = renderer.addAngularDimension(
+            new Point3D(0, 0, 0),
+            new Point3D(1, 0, 0),
+            new Point3D(0, 1, 0),
+            0.5,
+            new Style()
+        );
+        expect(angle).toBeCloseTo(Math.PI / 2);
+        const faces = new Style();
+        faces.setFill('#0000ff');
+        renderer.addLegend(['Faces'], [faces], 10, 10, new Style());
+
+        const svg = renderer.toSvgString();
+        expect(svg).toContain('A &amp; B</text>');
+        expect(svg).toContain('font-size="14.00"');
+        expect(svg).toContain('>origin</text>');
+        expect(svg).toContain('>4.00</text>');
+        expect(svg).toContain('90.0\u00b0</text>');
+        expect(svg).toContain('>Faces</text>');
+
+        expect(() => renderer.addAngularDimension(
+            new Point3D(0, 0, 0),
+            new Point3D(0, 0, 0),
+            new Point3D(0, 1, 0),
+            0.5,
+            new Style()
+        )).toThrow();
+        expect(() => renderer.addLegend(['Edges'], [], 10, 10, new Style())).toThrow();
+        expect(() => style.setTextAnchor('center')).toThrow();
+    });
+
+    it('scene helpers', () => {
+        const camera = Camera.orthographic(
+            new Point3D(0, 0, 10),
+            new Point3D(0, 0, 0),
+            new Vector3D(0, 1, 0),
+            10, 10
+        );
+        const renderer = new SVGRenderer(500, 500, camera);
+        expect(renderer.sceneBounds()).toBeUndefined();
+
+        const axes = new Style();
+        axes.setId('axes');
+        renderer.addAxes(new Point3D(0, 0, 0), 2, axes);
+        const ground = Plane.fromPointNormal(new Point3D(0, 0, 0), new Vector3D(0, 0, 1));
+        renderer.addGrid(ground, new Point3D(0, 0, 0), 4, 4, new Style());
+        renderer.addTicks(new Point3D(0, 0, 0), new Point3D(1, 0, 0), 0.5, 6, new Style());
+        expect(renderer.sceneBounds()).toBeUndefined();
+
+        renderer.addPoint(new Point3D(-1, -1, 0), '#000000', 3);
+        renderer.addPoint(new Point3D(1, 2, 0), '#000000', 3);
+        const bounds = renderer.addSceneBounds(new Style());
+        expect(bounds.max.y).toBeCloseTo(2);
+
+        const svg = renderer.toSvgString();
+        expect(svg).toContain('id="axes-x" stroke="#ff0000"');
+        expect(svg).toContain('>Y</text>');
+        expect(svg).toContain('stroke="#cccccc"');
+        expect(svg).toContain('>0.5</text>');
+        expect(svg).toContain('stroke-dasharray="6.00 4.00"');
+
+        expect(() => renderer.addAxes(new Point3D(0, 0, 0), 0, new Style())).toThrow();
+        expect(() => renderer.addGrid(ground, new Point3D(0, 0, 0), 4, 0, new Style())).toThrow();
+    });
+
+    it('rays, planes and vectors', () => {
+        const camera = Camera.orthographic(
+            new Point3D(0, 0, 10),
+            new Point3D(0, 0, 0),
+            new Vector3D(0, 1, 0),
+            10, 10
+        );
+        const renderer = new SVGRenderer(500, 500, camera);
+        renderer.addRay(new Ray(new Point3D(0, 0, 0), new Vector3D(1, 0, 0)), '#ff0000', 1);
+        renderer.addVector(new Point3D(0, 0, 0), new Vector3D(1, 2, 0), '#0000ff', 2);
+        const ground = Plane.fromPointNormal(new Point3D(0, 0, 0), new Vector3D(0, 0, 1));
+        const style = new Style();
+        style.setFill('#ccccff');
+        style.setId('ground');
+        renderer.addPlaneWithStyle(ground, new Point3D(0, 0, 3), 2, style);
+
+        const svg = renderer.toSvgString();
+        expect(svg).toContain('x2="500.00" y2="250.00" stroke="#ff0000"');
+        expect(svg).toContain('x2="300.00" y2="150.00" stroke="#0000ff"');
+        expect(svg).toContain('marker-end="url(#arrow-0000ff)"');
+        expect(svg).toContain('id="ground" stroke="none" fill="#ccccff"');
+
+        expect(() => renderer.addVector(new Point3D(0, 0, 0), new Vector3D(0, 0, 0), '#000000', 1)).toThrow();
+        expect(() => renderer.addPlane(ground, new Point3D(0, 0, 0), 0, '#000000', undefined, 1)).toThrow();
+    });
+
+    it('layers', () => {
+        const camera = Camera.perspective(
+            new Point3D(0, 0, 5),
+            new Point3D(0, 0, 0),
+            new Vector3D(0, 1, 0),
+            60, 1, 0.1, 100
+        );
+        const renderer = new SVGRenderer(800, 600, camera);
+        const segment = new LineSegment(new Point3D(-1, 0, 0), new Point3D(1, 0, 0));
+        const layerStyle = new Style();
+        layerStyle.setClass('hidden');
+        renderer.addLayerWithStyle('Hidden Edges', false, 'translate(10 0)', layerStyle);
+        const style = new Style();
+        style.setId('edge');
+        renderer.addLineSegmentWithStyle(segment, style);
+        renderer.addLayer('Points', true, undefined);
+        renderer.addPoint(new Point3D(0, 0, 0), '#ff0000', 3);
+        renderer.setCurrentLayer(undefined);
+        renderer.addPoint(new Point3D(0, 0, 0), '#0000ff', 3);
+
+        const svg = renderer.toSvgString();
+        expect(svg).toContain('inkscape:label="Hidden Edges"');
+        expect(svg).toContain('transform="translate(10 0)"');
+        expect(svg).toContain('display="none"');
+        expect(svg).toContain('id="Hidden-Edges-edge"');
+
+        renderer.setLayerVisible('Hidden Edges', true);
+        expect(renderer.toSvgString()).not.toContain('display="none"');
+
+        const subset = renderer.toSvgStringWithLayers(['Points']);
+        expect(subset).not.toContain('Hidden-Edges');
+        expect(subset).toContain('#ff0000');
+        expect(subset).toContain('#0000ff');
+
+        expect(() => renderer.addLayer('Points', true, undefined)).toThrow();
+        expect(() => renderer.setCurrentLayer('Lines')).toThrow();
+        expect(() => renderer.toSvgStringWithLayers(['Lines'])).toThrow();
+    });
+
     it('addAabb', () => {
         const camera = Camera.perspective(
             new Point3D(0, 0, 5),
@@ -175,6 +486,92 @@ describe('SVGRenderer', () => {
         expect(svg).toContain('#00ffff');
     });
 
+    it('setDepthSort', () => {
+        const camera = Camera.perspective(
+            new Point3D(0, 0, 5),
+            new Point3D(0, 0, 0),
+            new Vector3D(0, 1, 0),
+            60, 1, 0.1, 100
+        );
+        const renderer = new SVGRenderer(800, 600, camera);
+        const triangle = (z) => new Triangle(
+            new Point3D(-1, -1, z),
+            new Point3D(1, -1, z),
+            new Point3D(0, 1, z)
+        );
+        renderer.addTriangle(triangle(1), '#000000', '#ff0000', 1);
+        renderer.addTriangle(triangle(-3), '#000000', '#0000ff', 1);
+
+        let svg = renderer.toSvgString();
+        expect(svg.indexOf('#ff0000')).toBeLessThan(svg.indexOf('#0000ff'));
+
+        renderer.setDepthSort('painter');
+        svg = renderer.toSvgString();
+        expect(svg.indexOf('#0000ff')).toBeLessThan(svg.indexOf('#ff0000'));
+
+        renderer.setDepthSort('bsp');
+        svg = renderer.toSvgString();
+        expect(svg.indexOf('#0000ff')).toBeLessThan(svg.indexOf('#ff0000'));
+
+        expect(() => renderer.setDepthSort('z-buffer')).toThrow();
+    });
+
+    it('setHiddenLines', () => {
+        const camera = Camera.perspective(
+            new Point3D(4, 3, 5),
+            new Point3D(0, 0, 0),
+            new Vector3D(0, 1, 0),
+            60, 1, 0.1, 100
+        );
+        const renderer = new SVGRenderer(800, 800, camera);
+        renderer.addAabb(new AABB(new Point3D(-1, -1, -1), new Point3D(1, 1, 1)), '#000000', 1);
+
+        renderer.setHiddenLines('removed');
+        expect(renderer.toSvgString().match(/<line/g).length).toBe(9);
+
+        renderer.setHiddenLines('dashed');
+        const svg = renderer.toSvgString();
+        expect(svg.match(/<line/g).length).toBe(12);
+        expect(svg.match(/stroke-dasharray/g).length).toBe(3);
+
+        expect(() => renderer.setHiddenLines('invisible')).toThrow();
+    });
+
+    it('lights and shading', () => {
+        const camera = Camera.perspective(
+            new Point3D(0, 0, 5),
+            new Point3D(0, 0, 0),
+            new Vector3D(0, 1, 0),
+            60, 1, 0.1, 100
+        );
+        const renderer = new SVGRenderer(800, 800, camera);
+        renderer.addAmbientLight('#ffffff', 0.2);
+        renderer.addDirectionalLight(new Vector3D(0, 0, -1), '#ffffff', 0.6);
+        const front = new Triangle(new Point3D(-1, -1, 0), new Point3D(1, -1, 0), new Point3D(0, 1, 0));
+        const back = new Triangle(new Point3D(-1, -1, 0), new Point3D(0, 1, 0), new Point3D(1, -1, 0));
+
+        renderer.setShading('flat');
+        renderer.addTriangle(front, '#000000', '#ff8000', 1);
+        expect(renderer.toSvgString()).toContain('fill="#cc6600"');
+
+        renderer.setShading('gouraud');
+        const up = new Vector3D(0, 0, 1);
+        const style = new Style();
+        style.setFill('#ff8000');
+        renderer.addTriangleWithNormals(front, up, new Vector3D(1, 0, 0), up, style);
+        const svg = renderer.toSvgString();
+        expect(svg).toContain('<linearGradient');
+        expect(svg).toContain('url(#triangle-shade-');
+        expect(() => renderer.setShading('phong')).toThrow();
+
+        renderer.setBackFaceCulling(true);
+        renderer.addTriangle(back, '#000000', '#00ff00', 1);
+        expect(renderer.toSvgString()).not.toContain('#00ff00');
+
+        expect(() => renderer.addPointLight(new Point3D(0, 0, 5), 'white', 1)).toThrow();
+        renderer.clearLights();
+    });
+
     it('complex scene', () => {
         const camera = Camera.perspective(
             new Point3D(5, 5, 5),